@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+
+/// Auth is the authentication method that required by services.
+#[derive(Debug, Clone)]
+pub enum Auth {
+    /// No authentication required.
+    None,
+    /// `Authorization: Bearer <token>` is required.
+    Bearer(String),
+    /// `Authorization: Basic <base64(username:password)>` is required.
+    Basic { username: String, password: String },
+}
+
+impl Auth {
+    /// Check if given `Authorization` header is allowed.
+    ///
+    /// Credentials are compared in constant time to avoid leaking them
+    /// via timing.
+    pub fn check(&self, value: Option<&str>) -> bool {
+        match self {
+            Auth::None => true,
+            Auth::Bearer(token) => value
+                .and_then(|v| v.strip_prefix("Bearer "))
+                .map(|v| constant_time_eq(v.trim().as_bytes(), token.as_bytes()))
+                .unwrap_or_default(),
+            Auth::Basic { username, password } => value
+                .and_then(|v| v.strip_prefix("Basic "))
+                .and_then(|v| general_purpose::STANDARD.decode(v.trim()).ok())
+                .map(|v| constant_time_eq(&v, format!("{username}:{password}").as_bytes()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The value of `WWW-Authenticate` header while auth failed.
+    pub fn challenge(&self) -> &'static str {
+        match self {
+            Auth::None => "",
+            Auth::Bearer(_) => "Bearer",
+            Auth::Basic { .. } => r#"Basic realm="oay""#,
+        }
+    }
+}
+
+/// Compare two bytes without returning early on the first mismatch.
+///
+/// Only the length of input could be learned from timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_check() {
+        assert!(Auth::None.check(None));
+
+        let auth = Auth::Bearer("token".to_string());
+        assert!(auth.check(Some("Bearer token")));
+        assert!(!auth.check(Some("Bearer other")));
+        assert!(!auth.check(Some("Bearer tokens")));
+        assert!(!auth.check(None));
+
+        let auth = Auth::Basic {
+            username: "user".to_string(),
+            password: "pass".to_string(),
+        };
+        // base64("user:pass")
+        assert!(auth.check(Some("Basic dXNlcjpwYXNz")));
+        assert!(!auth.check(Some("Basic dXNlcjpvdGhlcg==")));
+        assert!(!auth.check(Some("Bearer token")));
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"", b""));
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+    }
+}
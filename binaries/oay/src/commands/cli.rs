@@ -25,6 +25,12 @@ pub async fn main() -> Result<()> {
 
     match cli().get_matches().subcommand() {
         Some(("http", _)) => crate::services::http::Service::new().await?.start().await?,
+        Some(("webdav", _)) => {
+            crate::services::webdav::Service::new()
+                .await?
+                .start()
+                .await?
+        }
         _ => return Err(anyhow!("not handled subcommands")),
     }
 
@@ -36,4 +42,5 @@ fn cli() -> Command {
         .version(crate_version!())
         .about(crate_description!())
         .subcommand(Command::new("http"))
+        .subcommand(Command::new("webdav"))
 }
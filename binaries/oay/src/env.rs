@@ -19,13 +19,35 @@ use opendal::services;
 use opendal::Operator;
 use opendal::Scheme;
 
+use crate::auth::Auth;
+
 pub static OAY_ADDR: &str = "OAY_ADDR";
 pub static OAY_BACKEND_TYPE: &str = "OAY_BACKEND_TYPE";
+pub static OAY_HTTP_AUTH_TOKEN: &str = "OAY_HTTP_AUTH_TOKEN";
+pub static OAY_HTTP_AUTH_USERNAME: &str = "OAY_HTTP_AUTH_USERNAME";
+pub static OAY_HTTP_AUTH_PASSWORD: &str = "OAY_HTTP_AUTH_PASSWORD";
 
 pub fn get_oay_addr() -> String {
     env::var(OAY_ADDR).unwrap_or_else(|_| "127.0.0.1:8080".to_string())
 }
 
+/// Load the auth method of http service.
+///
+/// Bearer token will take precedence over basic auth if both are set.
+pub fn get_oay_http_auth() -> Auth {
+    if let Ok(token) = env::var(OAY_HTTP_AUTH_TOKEN) {
+        return Auth::Bearer(token);
+    }
+
+    match env::var(OAY_HTTP_AUTH_USERNAME) {
+        Ok(username) => Auth::Basic {
+            username,
+            password: env::var(OAY_HTTP_AUTH_PASSWORD).unwrap_or_default(),
+        },
+        Err(_) => Auth::None,
+    }
+}
+
 pub fn get_oay_backend_type() -> Result<Scheme> {
     Ok(env::var(OAY_BACKEND_TYPE)
         .unwrap_or_else(|_| "fs".to_string())
@@ -14,4 +14,6 @@
 pub mod commands;
 pub mod services;
 
+pub(crate) mod auth;
 pub(crate) mod env;
+pub(crate) mod range;
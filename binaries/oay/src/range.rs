@@ -0,0 +1,84 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use opendal::raw::BytesContentRange;
+use opendal::raw::BytesRange;
+
+/// Resolve the requested range against an object with `size` bytes.
+///
+/// Returns `None` if the range is not satisfiable, which should be
+/// answered with `416 Range Not Satisfiable`. Ranges exceeding the end
+/// of object will be truncated as described in
+/// [RFC 7233](https://datatracker.ietf.org/doc/html/rfc7233#section-2.1).
+pub fn resolve_range(size: u64, range: BytesRange) -> Option<BytesContentRange> {
+    let (start, end) = match (range.offset(), range.size()) {
+        (Some(offset), _) if offset >= size => return None,
+        (Some(offset), Some(n)) => (offset, offset.saturating_add(n).min(size)),
+        (Some(offset), None) => (offset, size),
+        (None, Some(n)) => (size.saturating_sub(n), size),
+        (None, None) => (0, size),
+    };
+
+    if start >= end {
+        return None;
+    }
+
+    Some(
+        BytesContentRange::default()
+            .with_range(start, end - 1)
+            .with_size(size),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_range() {
+        let cases = vec![
+            ("full", 10, BytesRange::new(None, None), Some((0, 9))),
+            ("offset", 10, BytesRange::new(Some(3), None), Some((3, 9))),
+            ("suffix", 10, BytesRange::new(None, Some(4)), Some((6, 9))),
+            (
+                "suffix too large",
+                10,
+                BytesRange::new(None, Some(20)),
+                Some((0, 9)),
+            ),
+            (
+                "truncated",
+                10,
+                BytesRange::new(Some(8), Some(5)),
+                Some((8, 9)),
+            ),
+            (
+                "offset out of bound",
+                10,
+                BytesRange::new(Some(10), None),
+                None,
+            ),
+            ("zero suffix", 10, BytesRange::new(None, Some(0)), None),
+            ("empty object", 0, BytesRange::new(None, None), None),
+        ];
+
+        for (name, size, input, expected) in cases {
+            let actual = resolve_range(size, input).map(|v| {
+                let r = v.range_inclusive().expect("range must be specified");
+                (*r.start(), *r.end())
+            });
+            assert_eq!(expected, actual, "{name}")
+        }
+    }
+}
@@ -13,42 +13,72 @@
 // limitations under the License.
 
 use std::convert::Infallible;
+use std::fmt::Write;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
 use std::str::FromStr;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use actix_web::body::SizedStream;
 use actix_web::http;
 use actix_web::http::header;
+use actix_web::http::header::HttpDate;
 use actix_web::http::Method;
 use actix_web::http::StatusCode;
 use actix_web::middleware;
 use actix_web::web;
+use actix_web::web::Bytes;
 use actix_web::web::Data;
 use actix_web::App;
 use actix_web::HttpRequest;
 use actix_web::HttpResponse;
+use actix_web::HttpResponseBuilder;
 use actix_web::HttpServer;
 use anyhow::anyhow;
+use futures::future;
 use futures::stream;
 use futures::try_join;
 use futures::AsyncWriteExt;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use log::error;
 use log::warn;
 use opendal::raw::input::into_stream;
-use opendal::raw::BytesContentRange;
 use opendal::raw::BytesRange;
+use opendal::Object;
+use opendal::ObjectMetadata;
+use opendal::ObjectMode;
 use opendal::Operator;
 use percent_encoding::percent_decode;
+use serde_json::json;
 
+use crate::auth::Auth;
 use crate::env;
+use crate::range::resolve_range;
+
+/// The part size used while uploading chunked body via multipart.
+///
+/// Most services require the part size to be larger than 5 MiB except
+/// the last part, we use 8 MiB here to leave some room.
+const MULTIPART_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// The max size of chunked body that can be buffered in memory if the
+/// service doesn't support multipart.
+const MAX_BUFFERED_BODY_SIZE: usize = 64 * 1024 * 1024;
+
+/// The max number of ranges in one request.
+///
+/// Range header exceeding this limit will be ignored and the whole
+/// object will be returned, like nginx's `max_ranges`.
+const MAX_RANGES: usize = 16;
 
 #[derive(Debug, Clone)]
 pub struct Service {
     addr: String,
     op: Operator,
+    auth: Auth,
 }
 
 impl Service {
@@ -56,6 +86,7 @@ impl Service {
         Ok(Service {
             addr: env::get_oay_addr(),
             op: env::get_oay_operator().await?,
+            auth: env::get_oay_http_auth(),
         })
     }
 
@@ -74,87 +105,254 @@ impl Service {
     }
 
     async fn get(&self, req: HttpRequest) -> Result<HttpResponse> {
-        let o = self
-            .op
-            .object(&percent_decode(req.path().as_bytes()).decode_utf8_lossy());
+        let path = request_path(&req);
+        if path.ends_with('/') {
+            return self.list(req, &path).await;
+        }
 
+        let o = self.op.object(&path);
         let meta = o.metadata().await?;
+        if meta.mode() == ObjectMode::DIR {
+            return self.list(req, &format!("{path}/")).await;
+        }
+
+        if is_not_modified(&req, &meta) {
+            let mut resp = HttpResponse::NotModified();
+            set_metadata_headers(&mut resp, &meta);
+            return Ok(resp.finish());
+        }
+
+        let mut resp = HttpResponse::Ok();
+        set_metadata_headers(&mut resp, &meta);
+        resp.insert_header((header::ACCEPT_RANGES, "bytes"));
 
-        let (size, r) = if let Some(range) = req.headers().get(header::RANGE) {
-            let br = BytesRange::from_str(range.to_str().map_err(|e| {
+        let ranges = match req.headers().get(header::RANGE) {
+            None => vec![],
+            Some(v) => parse_ranges(v.to_str().map_err(|e| {
                 Error::new(
                     ErrorKind::InvalidInput,
                     anyhow!("header range is invalid: {e}"),
                 )
-            })?)?;
-
-            let bcr = BytesContentRange::from_bytes_range(meta.content_length(), br);
+            })?)?,
+        };
 
-            (
-                bcr.len().expect("range must be specifed"),
-                o.range_reader(bcr.range().expect("range must be specifed"))
-                    .await?,
-            )
+        let ranges = if ranges.len() > MAX_RANGES {
+            warn!("too many ranges in request, ignored: {}", ranges.len());
+            vec![]
         } else {
-            (meta.content_length(), o.reader().await?)
+            ranges
         };
 
-        Ok(HttpResponse::Ok().body(SizedStream::new(size, into_stream(r, 8 * 1024))))
+        if ranges.is_empty() {
+            let r = o.reader().await?;
+            return Ok(resp.body(SizedStream::new(
+                meta.content_length(),
+                into_stream(r, 8 * 1024),
+            )));
+        }
+
+        // Unsatisfiable ranges will be ignored, and we should return 416
+        // only if none of them is satisfiable.
+        let ranges: Vec<_> = ranges
+            .into_iter()
+            .filter_map(|br| resolve_range(meta.content_length(), br))
+            .collect();
+
+        match ranges.len() {
+            0 => Ok(HttpResponse::RangeNotSatisfiable()
+                .insert_header((
+                    header::CONTENT_RANGE,
+                    format!("bytes */{}", meta.content_length()),
+                ))
+                .finish()),
+            1 => {
+                let bcr = &ranges[0];
+                let r = o
+                    .range_reader(bcr.range().expect("range must be specifed"))
+                    .await?;
+
+                resp.status(StatusCode::PARTIAL_CONTENT);
+                resp.insert_header((header::CONTENT_RANGE, bcr.to_header()));
+                Ok(resp.body(SizedStream::new(
+                    bcr.len().expect("range must be specifed"),
+                    into_stream(r, 8 * 1024),
+                )))
+            }
+            _ => {
+                let boundary = format!(
+                    "oay{:x}",
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_nanos()
+                );
+                let content_type = meta
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+
+                // Stream all parts instead of buffering them in memory.
+                let mut size = 0;
+                let mut parts = Vec::with_capacity(ranges.len() + 1);
+                for bcr in ranges {
+                    let head = format!(
+                        "\r\n--{boundary}\r\nContent-Type: {content_type}\r\nContent-Range: {}\r\n\r\n",
+                        bcr.to_header()
+                    );
+                    let r = o
+                        .range_reader(bcr.range().expect("range must be specifed"))
+                        .await?;
+
+                    size += head.len() as u64 + bcr.len().expect("range must be specifed");
+                    parts.push(
+                        stream::once(future::ready(Ok(Bytes::from(head))))
+                            .chain(into_stream(r, 8 * 1024))
+                            .boxed(),
+                    );
+                }
+                let tail = format!("\r\n--{boundary}--\r\n");
+                size += tail.len() as u64;
+                parts.push(stream::once(future::ready(Ok(Bytes::from(tail)))).boxed());
+
+                resp.status(StatusCode::PARTIAL_CONTENT);
+                resp.insert_header((
+                    header::CONTENT_TYPE,
+                    format!("multipart/byteranges; boundary={boundary}"),
+                ));
+                Ok(resp.body(SizedStream::new(size, stream::iter(parts).flatten())))
+            }
+        }
     }
 
-    async fn put(&self, req: HttpRequest, mut body: web::Payload) -> Result<HttpResponse> {
-        let o = self
-            .op
-            .object(&percent_decode(req.path().as_bytes()).decode_utf8_lossy());
+    /// List the dir and return entries in JSON or HTML based on `Accept`.
+    async fn list(&self, req: HttpRequest, path: &str) -> Result<HttpResponse> {
+        let mut entries = Vec::new();
+        let mut ds = self.op.object(path).list().await?;
+        while let Some(de) = ds.try_next().await? {
+            let meta = de.metadata().await?;
+            entries.push((de, meta));
+        }
 
-        let content_length: u64 = req
+        let want_html = req
             .headers()
-            .get(http::header::CONTENT_LENGTH)
-            .ok_or_else(|| {
-                Error::new(
-                    ErrorKind::InvalidInput,
-                    anyhow!("content-length is required"),
-                )
-            })?
-            .to_str()
-            .map_err(|e| {
-                Error::new(
-                    ErrorKind::InvalidInput,
-                    anyhow!("content-length is invalid: {e:?}"),
-                )
-            })?
-            .parse()
-            .map_err(|e| {
-                Error::new(
-                    ErrorKind::InvalidInput,
-                    anyhow!("content-length is invalid: {e:?}"),
-                )
-            })?;
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.contains("text/html"))
+            .unwrap_or_default();
 
-        if content_length == 0 {
-            o.create().await?
-        } else {
-            let (pr, mut pw) = sluice::pipe::pipe();
-
-            try_join!(
-                async {
-                    o.write_from(content_length, pr).await?;
-
-                    Ok::<(), Error>(())
-                },
-                async {
-                    while let Some(bs) = body.next().await {
-                        let bs = bs.map_err(|e| {
-                            Error::new(ErrorKind::UnexpectedEof, anyhow!("read body: {e:?}"))
-                        })?;
-                        pw.write_all(&bs).await?;
-                    }
+        if want_html {
+            let mut body = String::new();
+            let _ = write!(
+                body,
+                "<!DOCTYPE html><html><head><title>Index of {0}</title></head><body><h1>Index of {0}</h1><ul>",
+                html_escape(path)
+            );
+            for (de, _) in &entries {
+                let _ = write!(
+                    body,
+                    r#"<li><a href="/{0}">{1}</a></li>"#,
+                    html_escape(de.path()),
+                    html_escape(de.name())
+                );
+            }
+            body.push_str("</ul></body></html>");
+
+            return Ok(HttpResponse::Ok()
+                .content_type("text/html; charset=utf-8")
+                .body(body));
+        }
+
+        let body = entries
+            .iter()
+            .map(|(de, meta)| {
+                json!({
+                    "path": de.path(),
+                    "name": de.name(),
+                    "mode": meta.mode().to_string(),
+                    "content_length": meta.content_length(),
+                    "content_type": meta.content_type(),
+                    "etag": meta.etag(),
+                    "last_modified": meta
+                        .last_modified()
+                        .map(|v| HttpDate::from(SystemTime::from(v)).to_string()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(HttpResponse::Ok().json(body))
+    }
+
+    async fn put(&self, req: HttpRequest, mut body: web::Payload) -> Result<HttpResponse> {
+        let o = self.op.object(&request_path(&req));
+
+        let content_length: Option<u64> = match req.headers().get(http::header::CONTENT_LENGTH) {
+            None => None,
+            Some(v) => Some(
+                v.to_str()
+                    .map_err(|e| {
+                        Error::new(
+                            ErrorKind::InvalidInput,
+                            anyhow!("content-length is invalid: {e:?}"),
+                        )
+                    })?
+                    .parse()
+                    .map_err(|e| {
+                        Error::new(
+                            ErrorKind::InvalidInput,
+                            anyhow!("content-length is invalid: {e:?}"),
+                        )
+                    })?,
+            ),
+        };
+
+        match content_length {
+            Some(0) => o.create().await?,
+            Some(content_length) => {
+                let (pr, mut pw) = sluice::pipe::pipe();
+
+                try_join!(
+                    async {
+                        o.write_from(content_length, pr).await?;
+
+                        Ok::<(), Error>(())
+                    },
+                    async {
+                        while let Some(bs) = body.next().await {
+                            let bs = bs.map_err(|e| {
+                                Error::new(ErrorKind::UnexpectedEof, anyhow!("read body: {e:?}"))
+                            })?;
+                            pw.write_all(&bs).await?;
+                        }
 
-                    pw.close().await?;
+                        pw.close().await?;
 
-                    Ok::<(), Error>(())
+                        Ok::<(), Error>(())
+                    }
+                )?;
+            }
+            None => {
+                let mut w = ChunkedWriter::new(o, self.op.metadata().can_multipart());
+                while let Some(bs) = body.next().await {
+                    // Abort the started multipart upload before returning
+                    // early, otherwise it will be leaked on the backend.
+                    let bs = match bs {
+                        Ok(bs) => bs,
+                        Err(e) => {
+                            w.abort().await;
+                            return Err(Error::new(
+                                ErrorKind::UnexpectedEof,
+                                anyhow!("read body: {e:?}"),
+                            ));
+                        }
+                    };
+                    if w.exceeds_limit(bs.len()) {
+                        w.abort().await;
+                        return Ok(HttpResponse::new(StatusCode::PAYLOAD_TOO_LARGE));
+                    }
+                    w.write(&bs).await?;
                 }
-            )?;
+                w.close().await?;
+            }
         }
 
         Ok(HttpResponse::new(StatusCode::CREATED))
@@ -164,33 +362,48 @@ impl Service {
     /// - The body's size.
     /// - The body returned by HEAD should not be read.
     async fn head(&self, req: HttpRequest) -> Result<HttpResponse> {
-        let o = self
-            .op
-            .object(&percent_decode(req.path().as_bytes()).decode_utf8_lossy());
+        let o = self.op.object(&request_path(&req));
         let meta = o.metadata().await?;
 
-        Ok(HttpResponse::Ok().body(SizedStream::new(
+        if is_not_modified(&req, &meta) {
+            let mut resp = HttpResponse::NotModified();
+            set_metadata_headers(&mut resp, &meta);
+            return Ok(resp.finish());
+        }
+
+        let mut resp = HttpResponse::Ok();
+        set_metadata_headers(&mut resp, &meta);
+        resp.insert_header((header::ACCEPT_RANGES, "bytes"));
+
+        Ok(resp.body(SizedStream::new(
             meta.content_length(),
             stream::empty::<std::result::Result<_, Infallible>>(),
         )))
     }
 
     async fn delete(&self, req: HttpRequest) -> Result<HttpResponse> {
-        self.op
-            .object(&percent_decode(req.path().as_bytes()).decode_utf8_lossy())
-            .delete()
-            .await?;
+        self.op.object(&request_path(&req)).delete().await?;
         Ok(HttpResponse::new(StatusCode::NO_CONTENT))
     }
 }
 
 async fn index(service: Data<Service>, req: HttpRequest, body: web::Payload) -> HttpResponse {
+    let auth = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok());
+    if !service.auth.check(auth) {
+        return HttpResponse::Unauthorized()
+            .insert_header((header::WWW_AUTHENTICATE, service.auth.challenge()))
+            .finish();
+    }
+
     let resp = match req.method().clone() {
         Method::GET => service.get_ref().get(req).await,
         Method::PUT => service.get_ref().put(req, body).await,
         Method::HEAD => service.get_ref().head(req).await,
         Method::DELETE => service.get_ref().delete(req).await,
-        _ => Ok(HttpResponse::new(StatusCode::INTERNAL_SERVER_ERROR)),
+        _ => Ok(HttpResponse::new(StatusCode::METHOD_NOT_ALLOWED)),
     };
 
     match resp {
@@ -209,9 +422,215 @@ async fn index(service: Data<Service>, req: HttpRequest, body: web::Payload) ->
             warn!("resource permission denied: {err:?}");
             HttpResponse::new(StatusCode::FORBIDDEN)
         }
+        Err(err) if err.kind() == ErrorKind::InvalidInput => {
+            warn!("request is invalid: {err:?}");
+            HttpResponse::new(StatusCode::BAD_REQUEST)
+        }
         Err(err) => {
             error!("request can't handle: {err:?}");
             HttpResponse::new(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
 }
+
+/// ChunkedWriter is used to write body without `Content-Length`.
+///
+/// - If the service supports multipart, we will upload the body in parts.
+/// - Otherwise, we have to buffer the whole body in memory, which is
+///   limited by [`MAX_BUFFERED_BODY_SIZE`].
+struct ChunkedWriter {
+    object: Object,
+    use_multipart: bool,
+
+    buf: Vec<u8>,
+    multipart: Option<opendal::ObjectMultipart>,
+    parts: Vec<opendal::ObjectPart>,
+}
+
+impl ChunkedWriter {
+    fn new(object: Object, use_multipart: bool) -> Self {
+        Self {
+            object,
+            use_multipart,
+            buf: Vec::new(),
+            multipart: None,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Check if writing `size` more bytes will exceed the limit of
+    /// buffered body.
+    fn exceeds_limit(&self, size: usize) -> bool {
+        !self.use_multipart && self.buf.len() + size > MAX_BUFFERED_BODY_SIZE
+    }
+
+    async fn write(&mut self, bs: &[u8]) -> Result<()> {
+        self.buf.extend_from_slice(bs);
+
+        if self.use_multipart && self.buf.len() >= MULTIPART_PART_SIZE {
+            if let Err(err) = self.flush_part().await {
+                self.abort().await;
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn flush_part(&mut self) -> Result<()> {
+        if self.multipart.is_none() {
+            self.multipart = Some(self.object.create_multipart().await?);
+        }
+        let mp = self.multipart.as_ref().expect("multipart must be created");
+
+        let bs = std::mem::take(&mut self.buf);
+        let part = mp.write(self.parts.len() + 1, bs).await?;
+        self.parts.push(part);
+
+        Ok(())
+    }
+
+    async fn abort(&mut self) {
+        if let Some(mp) = self.multipart.take() {
+            if let Err(err) = mp.abort().await {
+                warn!("abort multipart failed: {err:?}");
+            }
+        }
+    }
+
+    async fn close(mut self) -> Result<()> {
+        if self.multipart.is_none() {
+            if self.buf.is_empty() {
+                self.object.create().await?;
+            } else {
+                self.object.write(self.buf).await?;
+            }
+            return Ok(());
+        }
+
+        if !self.buf.is_empty() {
+            if let Err(err) = self.flush_part().await {
+                self.abort().await;
+                return Err(err);
+            }
+        }
+
+        let mp = self.multipart.take().expect("multipart must be created");
+        mp.complete(self.parts).await?;
+
+        Ok(())
+    }
+}
+
+fn request_path(req: &HttpRequest) -> String {
+    percent_decode(req.path().as_bytes())
+        .decode_utf8_lossy()
+        .to_string()
+}
+
+/// Parse `Range` header which could contain multiple ranges like
+/// `bytes=0-99,200-299`.
+fn parse_ranges(value: &str) -> Result<Vec<BytesRange>> {
+    let s = value.strip_prefix("bytes=").ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("header range is invalid: {value}"),
+        )
+    })?;
+
+    let mut ranges = Vec::new();
+    for v in s.split(',') {
+        ranges.push(BytesRange::from_str(&format!("bytes={}", v.trim()))?);
+    }
+
+    Ok(ranges)
+}
+
+/// Check `If-None-Match` and `If-Modified-Since` against object metadata.
+///
+/// `If-Modified-Since` will be ignored if `If-None-Match` is present, as
+/// described in [RFC 7232](https://datatracker.ietf.org/doc/html/rfc7232#section-3.3).
+fn is_not_modified(req: &HttpRequest, meta: &ObjectMetadata) -> bool {
+    if let Some(v) = req
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    {
+        let etag = match meta.etag() {
+            Some(etag) => etag.trim_start_matches("W/"),
+            None => return false,
+        };
+
+        return v
+            .split(',')
+            .map(|v| v.trim())
+            .any(|v| v == "*" || v.trim_start_matches("W/") == etag);
+    }
+
+    if let Some(v) = req
+        .headers()
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|v| v.to_str().ok())
+    {
+        let since = match HttpDate::from_str(v) {
+            Ok(v) => SystemTime::from(v),
+            Err(_) => return false,
+        };
+
+        // HTTP date only has second precision.
+        return match meta.last_modified() {
+            Some(lm) => lm.unix_timestamp() <= to_unix_timestamp(since),
+            None => false,
+        };
+    }
+
+    false
+}
+
+fn to_unix_timestamp(t: SystemTime) -> i64 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+fn set_metadata_headers(resp: &mut HttpResponseBuilder, meta: &ObjectMetadata) {
+    if let Some(v) = meta.etag() {
+        resp.insert_header((header::ETAG, v));
+    }
+    if let Some(v) = meta.content_type() {
+        resp.insert_header((header::CONTENT_TYPE, v));
+    }
+    if let Some(v) = meta.last_modified() {
+        resp.insert_header((
+            header::LAST_MODIFIED,
+            HttpDate::from(SystemTime::from(v)).to_string(),
+        ));
+    }
+}
+
+fn html_escape(v: &str) -> String {
+    v.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ranges() {
+        let ranges = parse_ranges("bytes=0-99, 200-").expect("must succeed");
+        assert_eq!(
+            ranges,
+            vec![
+                BytesRange::new(Some(0), Some(100)),
+                BytesRange::new(Some(200), None)
+            ]
+        );
+
+        assert!(parse_ranges("items=0-1").is_err());
+    }
+}
@@ -0,0 +1,574 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WebDAV frontend for OpenDAL.
+//!
+//! Only the class 1 subset of [RFC 4918](https://datatracker.ietf.org/doc/html/rfc4918)
+//! is supported, which means there is no `LOCK`/`UNLOCK` and `PROPPATCH`
+//! support.
+
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use actix_web::body::SizedStream;
+use actix_web::http::header;
+use actix_web::http::header::HttpDate;
+use actix_web::http::StatusCode;
+use actix_web::http::Uri;
+use actix_web::middleware;
+use actix_web::web;
+use actix_web::web::Data;
+use actix_web::App;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::HttpServer;
+use anyhow::anyhow;
+use futures::stream;
+use futures::try_join;
+use futures::AsyncWriteExt;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use log::error;
+use log::warn;
+use opendal::raw::input::into_stream;
+use opendal::raw::BytesRange;
+use opendal::Object;
+use opendal::ObjectMetadata;
+use opendal::ObjectMode;
+use opendal::Operator;
+use percent_encoding::percent_decode;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+
+use crate::env;
+use crate::range::resolve_range;
+
+/// Characters that must be escaped in the `href` of a PROPFIND response.
+const HREF_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+#[derive(Debug, Clone)]
+pub struct Service {
+    addr: String,
+    op: Operator,
+}
+
+impl Service {
+    pub async fn new() -> Result<Service> {
+        Ok(Service {
+            addr: env::get_oay_addr(),
+            op: env::get_oay_operator().await?,
+        })
+    }
+
+    pub async fn start(self) -> Result<()> {
+        let addr = self.addr.clone();
+
+        HttpServer::new(move || {
+            App::new()
+                .app_data(Data::new(self.clone()))
+                .wrap(middleware::Logger::default())
+                .service(web::resource(r"{path:.*}").to(index))
+        })
+        .bind(&addr)?
+        .run()
+        .await
+    }
+
+    /// Advertise the WebDAV compliance class so that clients like
+    /// `davfs2` or Windows' mini-redirector are willing to talk with us.
+    async fn options(&self) -> Result<HttpResponse> {
+        Ok(HttpResponse::Ok()
+            .insert_header(("DAV", "1"))
+            .insert_header((
+                header::ALLOW,
+                "OPTIONS, GET, HEAD, PUT, DELETE, PROPFIND, MKCOL, MOVE, COPY",
+            ))
+            .finish())
+    }
+
+    async fn propfind(&self, req: HttpRequest) -> Result<HttpResponse> {
+        let path = request_path(&req);
+
+        // We don't support `Depth: infinity`, treat it as `1` instead.
+        let depth = match req.headers().get("Depth").map(|v| v.as_bytes()) {
+            Some(b"0") => 0,
+            _ => 1,
+        };
+
+        let o = self.op.object(&path);
+        let meta = match o.metadata().await {
+            Ok(meta) => meta,
+            // Dir could be absent on object storage services, fallback
+            // to dir metadata if the path is the root.
+            Err(err) if err.kind() == opendal::ErrorKind::ObjectNotFound && path == "/" => {
+                ObjectMetadata::new(ObjectMode::DIR)
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut body = String::new();
+        body.push_str(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        body.push_str(r#"<D:multistatus xmlns:D="DAV:">"#);
+        if meta.mode() == ObjectMode::DIR {
+            write_propfind_response(&mut body, &dir_path(&path), &meta);
+        } else {
+            write_propfind_response(&mut body, &path, &meta);
+        }
+
+        if depth == 1 && meta.mode() == ObjectMode::DIR {
+            let dir = self.op.object(&dir_path(&path));
+            let mut ds = dir.list().await?;
+            while let Some(de) = ds.try_next().await? {
+                let meta = de.metadata().await?;
+                write_propfind_response(&mut body, &format!("/{}", de.path()), &meta);
+            }
+        }
+
+        body.push_str("</D:multistatus>");
+
+        Ok(HttpResponse::build(StatusCode::MULTI_STATUS)
+            .content_type("application/xml; charset=utf-8")
+            .body(body))
+    }
+
+    async fn get(&self, req: HttpRequest) -> Result<HttpResponse> {
+        let o = self.op.object(&request_path(&req));
+
+        let meta = o.metadata().await?;
+        if meta.mode() == ObjectMode::DIR {
+            return Ok(HttpResponse::new(StatusCode::METHOD_NOT_ALLOWED));
+        }
+
+        let mut resp = HttpResponse::Ok();
+        set_metadata_headers(&mut resp, &meta);
+        resp.insert_header((header::ACCEPT_RANGES, "bytes"));
+
+        let (size, r) = if let Some(range) = req.headers().get(header::RANGE) {
+            let br = BytesRange::from_str(range.to_str().map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    anyhow!("header range is invalid: {e}"),
+                )
+            })?)?;
+
+            let bcr = match resolve_range(meta.content_length(), br) {
+                Some(bcr) => bcr,
+                None => {
+                    return Ok(HttpResponse::RangeNotSatisfiable()
+                        .insert_header((
+                            header::CONTENT_RANGE,
+                            format!("bytes */{}", meta.content_length()),
+                        ))
+                        .finish())
+                }
+            };
+
+            resp.status(StatusCode::PARTIAL_CONTENT);
+            resp.insert_header((header::CONTENT_RANGE, bcr.to_header()));
+
+            (
+                bcr.len().expect("range must be specifed"),
+                o.range_reader(bcr.range().expect("range must be specifed"))
+                    .await?,
+            )
+        } else {
+            (meta.content_length(), o.reader().await?)
+        };
+
+        Ok(resp.body(SizedStream::new(size, into_stream(r, 8 * 1024))))
+    }
+
+    /// Actix handles content length automatically, we use a sized empty stream here to indicate:
+    /// - The body's size.
+    /// - The body returned by HEAD should not be read.
+    async fn head(&self, req: HttpRequest) -> Result<HttpResponse> {
+        let o = self.op.object(&request_path(&req));
+        let meta = o.metadata().await?;
+
+        let mut resp = HttpResponse::Ok();
+        set_metadata_headers(&mut resp, &meta);
+
+        Ok(resp.body(SizedStream::new(
+            meta.content_length(),
+            stream::empty::<std::result::Result<_, Infallible>>(),
+        )))
+    }
+
+    async fn put(&self, req: HttpRequest, mut body: web::Payload) -> Result<HttpResponse> {
+        let path = request_path(&req);
+        if path.ends_with('/') {
+            return Ok(HttpResponse::new(StatusCode::METHOD_NOT_ALLOWED));
+        }
+
+        let o = self.op.object(&path);
+        let content_length: u64 = match req.headers().get(header::CONTENT_LENGTH) {
+            // OpenDAL needs to know the size before writing, reject chunked
+            // body instead of writing an empty file.
+            None if req.headers().contains_key(header::TRANSFER_ENCODING) => {
+                return Ok(HttpResponse::new(StatusCode::LENGTH_REQUIRED))
+            }
+            None => 0,
+            Some(v) => v
+                .to_str()
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        anyhow!("content-length is invalid: {e:?}"),
+                    )
+                })?
+                .parse()
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        anyhow!("content-length is invalid: {e:?}"),
+                    )
+                })?,
+        };
+
+        if content_length == 0 {
+            o.create().await?
+        } else {
+            let (pr, mut pw) = sluice::pipe::pipe();
+
+            try_join!(
+                async {
+                    o.write_from(content_length, pr).await?;
+
+                    Ok::<(), Error>(())
+                },
+                async {
+                    while let Some(bs) = body.next().await {
+                        let bs = bs.map_err(|e| {
+                            Error::new(ErrorKind::UnexpectedEof, anyhow!("read body: {e:?}"))
+                        })?;
+                        pw.write_all(&bs).await?;
+                    }
+
+                    pw.close().await?;
+
+                    Ok::<(), Error>(())
+                }
+            )?;
+        }
+
+        Ok(HttpResponse::new(StatusCode::CREATED))
+    }
+
+    async fn delete(&self, req: HttpRequest) -> Result<HttpResponse> {
+        let path = request_path(&req);
+        let o = self.op.object(&path);
+
+        if o.metadata().await?.mode() == ObjectMode::DIR {
+            self.op.batch().remove_all(&dir_path(&path)).await?;
+        } else {
+            o.delete().await?;
+        }
+
+        Ok(HttpResponse::new(StatusCode::NO_CONTENT))
+    }
+
+    async fn mkcol(&self, req: HttpRequest) -> Result<HttpResponse> {
+        let path = dir_path(&request_path(&req));
+        let o = self.op.object(&path);
+
+        if o.is_exist().await? {
+            return Ok(HttpResponse::new(StatusCode::METHOD_NOT_ALLOWED));
+        }
+
+        let parent = opendal::raw::get_parent(&path);
+        if parent != "/" && !self.op.object(parent).is_exist().await? {
+            return Ok(HttpResponse::new(StatusCode::CONFLICT));
+        }
+
+        o.create().await?;
+
+        Ok(HttpResponse::new(StatusCode::CREATED))
+    }
+
+    /// Handle both `COPY` and `MOVE`.
+    ///
+    /// OpenDAL doesn't have native copy or rename support, so we will
+    /// copy data via read and write, and remove the source for `MOVE`.
+    async fn transfer(&self, req: HttpRequest, remove_source: bool) -> Result<HttpResponse> {
+        let src_path = request_path(&req);
+        let dst_path = destination_path(&req)?;
+
+        let overwrite = !matches!(
+            req.headers().get("Overwrite").map(|v| v.as_bytes()),
+            Some(b"F") | Some(b"f")
+        );
+
+        let src = self.op.object(&src_path);
+        let src_meta = src.metadata().await?;
+
+        // Copying a collection into itself or its subtree will never end.
+        let is_dir = src_meta.mode() == ObjectMode::DIR;
+        if dst_path == src_path || (is_dir && dir_path(&dst_path).starts_with(&dir_path(&src_path)))
+        {
+            return Ok(HttpResponse::new(StatusCode::FORBIDDEN));
+        }
+
+        let dst = self.op.object(&dst_path);
+        let existed = dst.is_exist().await?;
+        if existed && !overwrite {
+            return Ok(HttpResponse::new(StatusCode::PRECONDITION_FAILED));
+        }
+
+        if is_dir {
+            let (src_dir, dst_dir) = (dir_path(&src_path), dir_path(&dst_path));
+            if existed {
+                self.op.batch().remove_all(&dst_dir).await?;
+            }
+            self.op.object(&dst_dir).create().await?;
+
+            let mut obs = self.op.batch().walk_top_down(&src_dir)?;
+            while let Some(o) = obs.try_next().await? {
+                let rel = o
+                    .path()
+                    .strip_prefix(src_dir.trim_start_matches('/'))
+                    .unwrap_or_else(|| o.path());
+                let target = self.op.object(&format!("{dst_dir}{rel}"));
+                match o.mode().await? {
+                    ObjectMode::DIR => target.create().await?,
+                    ObjectMode::FILE => copy_object(&o, &target).await?,
+                    ObjectMode::Unknown => continue,
+                }
+            }
+
+            if remove_source {
+                self.op.batch().remove_all(&src_dir).await?;
+            }
+        } else {
+            copy_object(&src, &dst).await?;
+
+            if remove_source {
+                src.delete().await?;
+            }
+        }
+
+        if existed {
+            Ok(HttpResponse::new(StatusCode::NO_CONTENT))
+        } else {
+            Ok(HttpResponse::new(StatusCode::CREATED))
+        }
+    }
+}
+
+async fn index(service: Data<Service>, req: HttpRequest, body: web::Payload) -> HttpResponse {
+    let resp = match req.method().as_str() {
+        "OPTIONS" => service.get_ref().options().await,
+        "PROPFIND" => service.get_ref().propfind(req).await,
+        "GET" => service.get_ref().get(req).await,
+        "HEAD" => service.get_ref().head(req).await,
+        "PUT" => service.get_ref().put(req, body).await,
+        "DELETE" => service.get_ref().delete(req).await,
+        "MKCOL" => service.get_ref().mkcol(req).await,
+        "COPY" => service.get_ref().transfer(req, false).await,
+        "MOVE" => service.get_ref().transfer(req, true).await,
+        _ => Ok(HttpResponse::new(StatusCode::METHOD_NOT_ALLOWED)),
+    };
+
+    match resp {
+        Ok(resp) => match resp.error() {
+            None => resp,
+            Some(err) => {
+                error!("request can't handle: {err:?}");
+                HttpResponse::new(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        },
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            warn!("resource not found: {err:?}");
+            HttpResponse::new(StatusCode::NOT_FOUND)
+        }
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+            warn!("resource permission denied: {err:?}");
+            HttpResponse::new(StatusCode::FORBIDDEN)
+        }
+        Err(err) if err.kind() == ErrorKind::InvalidInput => {
+            warn!("request is invalid: {err:?}");
+            HttpResponse::new(StatusCode::BAD_REQUEST)
+        }
+        Err(err) => {
+            error!("request can't handle: {err:?}");
+            HttpResponse::new(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+fn request_path(req: &HttpRequest) -> String {
+    percent_decode(req.path().as_bytes())
+        .decode_utf8_lossy()
+        .to_string()
+}
+
+fn dir_path(path: &str) -> String {
+    if path.ends_with('/') {
+        path.to_string()
+    } else {
+        format!("{path}/")
+    }
+}
+
+/// Extract the path from `Destination` header which could be an absolute
+/// uri like `http://127.0.0.1:8080/path/to/file`.
+fn destination_path(req: &HttpRequest) -> Result<String> {
+    let dest = req
+        .headers()
+        .get("Destination")
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, anyhow!("destination is required")))?
+        .to_str()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!("destination is invalid: {e}"),
+            )
+        })?;
+
+    let uri = Uri::from_str(dest).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("destination is invalid: {e}"),
+        )
+    })?;
+
+    Ok(percent_decode(uri.path().as_bytes())
+        .decode_utf8_lossy()
+        .to_string())
+}
+
+async fn copy_object(src: &Object, dst: &Object) -> Result<()> {
+    let size = src.metadata().await?.content_length();
+    if size == 0 {
+        dst.create().await?;
+    } else {
+        dst.write_from(size, src.reader().await?).await?;
+    }
+
+    Ok(())
+}
+
+fn set_metadata_headers(resp: &mut actix_web::HttpResponseBuilder, meta: &ObjectMetadata) {
+    if let Some(v) = meta.etag() {
+        resp.insert_header((header::ETAG, v));
+    }
+    if let Some(v) = meta.content_type() {
+        resp.insert_header((header::CONTENT_TYPE, v));
+    }
+    if let Some(v) = meta.last_modified() {
+        resp.insert_header((
+            header::LAST_MODIFIED,
+            HttpDate::from(SystemTime::from(v)).to_string(),
+        ));
+    }
+}
+
+/// Write a `<D:response>` for given path into the multistatus body.
+///
+/// `ObjectMode::DIR` will be mapped to `<D:collection/>` and other fields
+/// in `ObjectMetadata` will be mapped to `getcontentlength`, `getetag`,
+/// `getcontenttype` and `getlastmodified`.
+fn write_propfind_response(body: &mut String, path: &str, meta: &ObjectMetadata) {
+    let href = utf8_percent_encode(path, HREF_ENCODE_SET);
+    let name = path.trim_end_matches('/').rsplit('/').next().unwrap_or("");
+
+    let _ = write!(body, "<D:response><D:href>{href}</D:href>");
+    body.push_str("<D:propstat><D:prop>");
+    let _ = write!(body, "<D:displayname>{}</D:displayname>", xml_escape(name));
+
+    if meta.mode() == ObjectMode::DIR {
+        body.push_str("<D:resourcetype><D:collection/></D:resourcetype>");
+    } else {
+        body.push_str("<D:resourcetype/>");
+        let _ = write!(
+            body,
+            "<D:getcontentlength>{}</D:getcontentlength>",
+            meta.content_length()
+        );
+        if let Some(v) = meta.content_type() {
+            let _ = write!(
+                body,
+                "<D:getcontenttype>{}</D:getcontenttype>",
+                xml_escape(v)
+            );
+        }
+        if let Some(v) = meta.etag() {
+            let _ = write!(body, "<D:getetag>{}</D:getetag>", xml_escape(v));
+        }
+    }
+    if let Some(v) = meta.last_modified() {
+        let _ = write!(
+            body,
+            "<D:getlastmodified>{}</D:getlastmodified>",
+            HttpDate::from(SystemTime::from(v))
+        );
+    }
+
+    body.push_str("</D:prop><D:status>HTTP/1.1 200 OK</D:status></D:propstat>");
+    body.push_str("</D:response>");
+}
+
+fn xml_escape(v: &str) -> String {
+    let mut s = String::with_capacity(v.len());
+    for c in v.chars() {
+        match c {
+            '&' => s.push_str("&amp;"),
+            '<' => s.push_str("&lt;"),
+            '>' => s.push_str("&gt;"),
+            '"' => s.push_str("&quot;"),
+            '\'' => s.push_str("&apos;"),
+            _ => s.push(c),
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_propfind_response() {
+        let mut body = String::new();
+        write_propfind_response(
+            &mut body,
+            "/dir/a b&c.txt",
+            &ObjectMetadata::new(ObjectMode::FILE)
+                .with_content_length(3)
+                .with_etag("\"abc\""),
+        );
+
+        assert!(body.contains("<D:href>/dir/a%20b%26c.txt</D:href>"));
+        assert!(body.contains("<D:displayname>a b&amp;c.txt</D:displayname>"));
+        assert!(body.contains("<D:getcontentlength>3</D:getcontentlength>"));
+        assert!(body.contains("<D:getetag>&quot;abc&quot;</D:getetag>"));
+        assert!(body.contains("<D:resourcetype/>"));
+
+        let mut body = String::new();
+        write_propfind_response(&mut body, "/dir/", &ObjectMetadata::new(ObjectMode::DIR));
+        assert!(body.contains("<D:displayname>dir</D:displayname>"));
+        assert!(body.contains("<D:collection/>"));
+        assert!(!body.contains("getcontentlength"));
+    }
+}
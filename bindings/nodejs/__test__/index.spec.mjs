@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import test from 'ava'
+
+import opendal from '../index.js'
+
+const { Operator } = opendal
+
+test('test memory write & read', async (t) => {
+  const op = new Operator('memory')
+  const o = op.object('test')
+
+  await o.write('Hello, World!')
+  t.is((await o.read()).toString(), 'Hello, World!')
+  t.is((await o.rangeRead(7, 5)).toString(), 'World')
+
+  const meta = await o.stat()
+  t.true(meta.isFile())
+  t.is(meta.contentLength, 13)
+
+  await o.delete()
+  const err = await t.throwsAsync(() => o.stat())
+  t.is(err.code, 'ObjectNotFound')
+})
+
+test('test range read with negative arguments', async (t) => {
+  const op = new Operator('memory')
+  const o = op.object('test')
+  await o.write('Hello, World!')
+
+  const err = await t.throwsAsync(async () => o.rangeRead(-1, 5))
+  t.is(err.code, 'InvalidArg')
+})
+
+test('test unsupported scheme', (t) => {
+  const err = t.throws(() => new Operator('unknown'))
+  t.is(err.code, 'Unsupported')
+})
+
+test('test read stream', async (t) => {
+  const op = new Operator('memory')
+  const o = op.object('stream')
+  await o.write(Buffer.alloc(1024 * 1024, 'a'))
+
+  const chunks = []
+  for await (const chunk of o.createReadStream({ highWaterMark: 4096 })) {
+    chunks.push(chunk)
+  }
+  t.is(Buffer.concat(chunks).length, 1024 * 1024)
+})
+
+test('test list', async (t) => {
+  const op = new Operator('memory')
+  await op.object('dir/a').write('a')
+  await op.object('dir/b').write('b')
+
+  const names = []
+  for await (const o of await op.object('dir/').list()) {
+    names.push(o.name)
+  }
+  t.deepEqual(names.sort(), ['a', 'b'])
+})
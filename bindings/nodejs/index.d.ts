@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// `generated.d.ts` is generated by `napi build`, this file only adds the
+// APIs that implemented in `index.js`.
+
+import { Readable } from 'stream'
+
+import { Object as DataObject } from './generated'
+
+export * from './generated'
+
+declare module './generated' {
+  interface Object {
+    /** Create a Node.js `Readable` stream backed by `ObjectReader`. */
+    createReadStream(options?: { highWaterMark?: number }): Readable
+  }
+
+  interface ObjectLister {
+    [Symbol.asyncIterator](): AsyncIterableIterator<DataObject>
+  }
+}
+
+/** `code` of errors thrown by opendal is the `ErrorKind`, like `ObjectNotFound`. */
+export type ErrorKind =
+  | 'Unexpected'
+  | 'Unsupported'
+  | 'BackendConfigInvalid'
+  | 'ObjectNotFound'
+  | 'ObjectPermissionDenied'
+  | 'ObjectIsADirectory'
+  | 'ObjectNotADirectory'
+  | 'ObjectAlreadyExists'
+  | 'ObjectRateLimited'
+  | 'ChecksumMismatch'
@@ -12,6 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+const { Readable } = require('stream')
 const { loadBinding } = require('@node-rs/helper')
 
-module.exports = loadBinding(__dirname, 'opendal', 'opendal')
+const binding = loadBinding(__dirname, 'opendal', 'opendal')
+
+binding.ObjectLister.prototype[Symbol.asyncIterator] = async function* () {
+  let o
+  while ((o = await this.next()) !== null) {
+    yield o
+  }
+}
+
+/**
+ * Create a Node.js `Readable` stream backed by `ObjectReader`.
+ *
+ * `highWaterMark` is used as the size of every read, default to 64 KiB.
+ */
+binding.Object.prototype.createReadStream = function (options) {
+  const highWaterMark = (options && options.highWaterMark) || 64 * 1024
+  const object = this
+  let reader = null
+
+  return new Readable({
+    highWaterMark,
+    async read(size) {
+      try {
+        if (reader === null) {
+          reader = await object.reader()
+        }
+        this.push(await reader.read(size || highWaterMark))
+      } catch (err) {
+        this.destroy(err)
+      }
+    },
+  })
+}
+
+module.exports = binding
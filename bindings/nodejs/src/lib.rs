@@ -12,14 +12,381 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use napi_derive::napi;
+#[macro_use]
+extern crate napi_derive;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use futures::lock::Mutex;
+use futures::AsyncReadExt;
+use futures::TryStreamExt;
+use napi::bindgen_prelude::*;
+use napi::Env;
+use napi::JsObject;
+use napi::JsUnknown;
+use napi::NapiValue;
 use opendal::services;
-use opendal::Operator;
+use time::Duration;
+
+/// Convert opendal's error into js error with `code` set to its `ErrorKind`,
+/// like `ObjectNotFound`.
+fn format_napi_error(env: &Env, err: opendal::Error) -> Error {
+    let msg = err.to_string();
+    let build = || -> Result<Error> {
+        let mut obj = env.create_error(Error::new(Status::GenericFailure, msg))?;
+        obj.set_named_property("code", env.create_string(err.kind().into_static())?)?;
+        Ok(Error::from(obj.into_unknown()))
+    };
+
+    build().unwrap_or_else(|e| e)
+}
+
+/// Run the future on napi's tokio runtime and return a promise.
+///
+/// Errors will be rejected via [`format_napi_error`] so that `code` is
+/// available in js.
+fn spawn<T, F>(env: Env, fut: F) -> Result<JsObject>
+where
+    T: ToNapiValue + Send + 'static,
+    F: Future<Output = opendal::Result<T>> + Send + 'static,
+{
+    env.execute_tokio_future(async move { Ok(fut.await) }, |env, res| match res {
+        Ok(v) => unsafe {
+            let raw = T::to_napi_value(env.raw(), v)?;
+            Ok(JsUnknown::from_raw_unchecked(env.raw(), raw))
+        },
+        Err(err) => Err(format_napi_error(env, err)),
+    })
+}
+
+fn build_operator(
+    scheme: opendal::Scheme,
+    map: HashMap<String, String>,
+) -> opendal::Result<opendal::Operator> {
+    use opendal::Operator as Op;
+    use opendal::Scheme;
+
+    let op = match scheme {
+        Scheme::Azblob => Op::from_map::<services::Azblob>(map),
+        Scheme::Azdfs => Op::from_map::<services::Azdfs>(map),
+        Scheme::Fs => Op::from_map::<services::Fs>(map),
+        Scheme::Gcs => Op::from_map::<services::Gcs>(map),
+        Scheme::Ghac => Op::from_map::<services::Ghac>(map),
+        Scheme::Http => Op::from_map::<services::Http>(map),
+        Scheme::Ipmfs => Op::from_map::<services::Ipmfs>(map),
+        Scheme::Memory => Op::from_map::<services::Memory>(map),
+        Scheme::Obs => Op::from_map::<services::Obs>(map),
+        Scheme::Oss => Op::from_map::<services::Oss>(map),
+        Scheme::S3 => Op::from_map::<services::S3>(map),
+        Scheme::Webdav => Op::from_map::<services::Webdav>(map),
+        Scheme::Webhdfs => Op::from_map::<services::Webhdfs>(map),
+        _ => {
+            return Err(opendal::Error::new(
+                opendal::ErrorKind::Unsupported,
+                "scheme is not supported",
+            )
+            .with_context("scheme", scheme))
+        }
+    }?;
+
+    Ok(op.finish())
+}
+
+#[napi]
+pub struct Operator(opendal::Operator);
+
+#[napi]
+impl Operator {
+    /// Create a new operator with given scheme and options.
+    ///
+    /// ```js
+    /// const op = new Operator("fs", { root: "/tmp" })
+    /// ```
+    #[napi(constructor)]
+    pub fn new(env: Env, scheme: String, options: Option<HashMap<String, String>>) -> Result<Self> {
+        opendal::Scheme::from_str(&scheme)
+            .and_then(|scheme| build_operator(scheme, options.unwrap_or_default()))
+            .map(Operator)
+            .map_err(|err| format_napi_error(&env, err))
+    }
+
+    /// Create an object handle for given path.
+    #[napi]
+    pub fn object(&self, path: String) -> DataObject {
+        DataObject(self.0.object(&path))
+    }
+}
+
+/// Object is the handle for all object related operations.
+#[napi(js_name = "Object")]
+pub struct DataObject(opendal::Object);
+
+#[napi]
+impl DataObject {
+    /// Path of object, relative to operator's root.
+    #[napi(getter)]
+    pub fn path(&self) -> String {
+        self.0.path().to_string()
+    }
+
+    /// Name of object, the last segment of path.
+    #[napi(getter)]
+    pub fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    /// Get current object's metadata **without cache**.
+    #[napi(ts_return_type = "Promise<ObjectMetadata>")]
+    pub fn stat(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move { o.stat().await.map(ObjectMetadata) })
+    }
+
+    /// Check if this object exists or not.
+    #[napi(ts_return_type = "Promise<boolean>")]
+    pub fn is_exist(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move { o.is_exist().await })
+    }
+
+    /// Create this object, dir will be created if path ends with `/`.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn create(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move { o.create().await })
+    }
+
+    /// Write content into object.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn write(&self, env: Env, content: Either<Buffer, String>) -> Result<JsObject> {
+        let c = match content {
+            Either::A(buf) => buf.as_ref().to_owned(),
+            Either::B(s) => s.into_bytes(),
+        };
+        let o = self.0.clone();
+        spawn(env, async move { o.write(c).await })
+    }
+
+    /// Read the whole object into a buffer.
+    #[napi(ts_return_type = "Promise<Buffer>")]
+    pub fn read(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move { o.read().await.map(Buffer::from) })
+    }
+
+    /// Read `size` bytes of object starting from `offset`.
+    ///
+    /// Both `offset` and `size` must be non-negative.
+    #[napi(ts_return_type = "Promise<Buffer>")]
+    pub fn range_read(&self, env: Env, offset: i64, size: i64) -> Result<JsObject> {
+        if offset < 0 || size < 0 {
+            return Err(Error::new(
+                Status::InvalidArg,
+                format!("offset and size must be non-negative, got {offset} and {size}"),
+            ));
+        }
+
+        let (offset, size) = (offset as u64, size as u64);
+        let o = self.0.clone();
+        spawn(env, async move {
+            o.range_read(offset..offset + size).await.map(Buffer::from)
+        })
+    }
+
+    /// Open a reader of this object.
+    ///
+    /// Use `createReadStream()` to get a Node.js `Readable` instead.
+    #[napi(ts_return_type = "Promise<ObjectReader>")]
+    pub fn reader(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move {
+            let r = o.reader().await?;
+            Ok(ObjectReader(Arc::new(Mutex::new(r))))
+        })
+    }
+
+    /// Delete this object.
+    #[napi(ts_return_type = "Promise<void>")]
+    pub fn delete(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move { o.delete().await })
+    }
+
+    /// List current dir object.
+    ///
+    /// `ObjectLister` is an async iterator:
+    ///
+    /// ```js
+    /// for await (const o of await op.object("dir/").list()) {
+    ///   console.log(o.path)
+    /// }
+    /// ```
+    #[napi(ts_return_type = "Promise<ObjectLister>")]
+    pub fn list(&self, env: Env) -> Result<JsObject> {
+        let o = self.0.clone();
+        spawn(env, async move {
+            let l = o.list().await?;
+            Ok(ObjectLister(Arc::new(Mutex::new(l))))
+        })
+    }
+
+    /// Presign an operation for stat(head), `expires` is in seconds.
+    #[napi]
+    pub fn presign_stat(&self, env: Env, expires: u32) -> Result<PresignedRequest> {
+        let req = self
+            .0
+            .presign_stat(Duration::seconds(expires as i64))
+            .map_err(|err| format_napi_error(&env, err))?;
+        Ok(PresignedRequest::new(req))
+    }
+
+    /// Presign an operation for read, `expires` is in seconds.
+    #[napi]
+    pub fn presign_read(&self, env: Env, expires: u32) -> Result<PresignedRequest> {
+        let req = self
+            .0
+            .presign_read(Duration::seconds(expires as i64))
+            .map_err(|err| format_napi_error(&env, err))?;
+        Ok(PresignedRequest::new(req))
+    }
+
+    /// Presign an operation for write, `expires` is in seconds.
+    #[napi]
+    pub fn presign_write(&self, env: Env, expires: u32) -> Result<PresignedRequest> {
+        let req = self
+            .0
+            .presign_write(Duration::seconds(expires as i64))
+            .map_err(|err| format_napi_error(&env, err))?;
+        Ok(PresignedRequest::new(req))
+    }
+}
+
+#[napi]
+pub struct ObjectLister(Arc<Mutex<opendal::ObjectLister>>);
+
+#[napi]
+impl ObjectLister {
+    /// Return the next object, or `null` if the list is exhausted.
+    #[napi(ts_return_type = "Promise<Object | null>")]
+    pub fn next(&self, env: Env) -> Result<JsObject> {
+        let l = self.0.clone();
+        spawn(env, async move {
+            let o = l.lock().await.try_next().await?;
+            Ok(o.map(DataObject))
+        })
+    }
+}
+
+#[napi]
+pub struct ObjectReader(Arc<Mutex<opendal::ObjectReader>>);
+
+#[napi]
+impl ObjectReader {
+    /// Read at most `size` bytes, returns `null` if EOF reached.
+    #[napi(ts_return_type = "Promise<Buffer | null>")]
+    pub fn read(&self, env: Env, size: u32) -> Result<JsObject> {
+        let r = self.0.clone();
+        spawn(env, async move {
+            let mut buf = vec![0; size as usize];
+            let n = r.lock().await.read(&mut buf).await.map_err(|err| {
+                // Errors returned by `ObjectReader` are opendal's error
+                // wrapped in io error, recover the kind from them.
+                let kind = err
+                    .get_ref()
+                    .and_then(|e| e.downcast_ref::<opendal::Error>())
+                    .map(|e| e.kind())
+                    .unwrap_or(opendal::ErrorKind::Unexpected);
+                opendal::Error::new(kind, &err.to_string())
+            })?;
+
+            if n == 0 {
+                return Ok(None);
+            }
+            buf.truncate(n);
+            Ok(Some(Buffer::from(buf)))
+        })
+    }
+}
+
+#[napi]
+pub struct ObjectMetadata(opendal::ObjectMetadata);
 
 #[napi]
-pub fn debug() -> String {
-    let op = Operator::create(services::Memory::default())
-        .unwrap()
-        .finish();
-    format!("{:?}", op.metadata())
+impl ObjectMetadata {
+    /// Mode of object, could be `file`, `dir` or `unknown`.
+    #[napi(getter)]
+    pub fn mode(&self) -> String {
+        self.0.mode().to_string()
+    }
+
+    #[napi]
+    pub fn is_file(&self) -> bool {
+        self.0.mode().is_file()
+    }
+
+    #[napi]
+    pub fn is_dir(&self) -> bool {
+        self.0.mode().is_dir()
+    }
+
+    #[napi(getter)]
+    pub fn content_length(&self) -> i64 {
+        self.0.content_length() as i64
+    }
+
+    #[napi(getter)]
+    pub fn content_md5(&self) -> Option<String> {
+        self.0.content_md5().map(|v| v.to_string())
+    }
+
+    #[napi(getter)]
+    pub fn content_type(&self) -> Option<String> {
+        self.0.content_type().map(|v| v.to_string())
+    }
+
+    #[napi(getter)]
+    pub fn etag(&self) -> Option<String> {
+        self.0.etag().map(|v| v.to_string())
+    }
+
+    /// Last modified time of object in milliseconds since unix epoch.
+    #[napi(getter)]
+    pub fn last_modified(&self) -> Option<i64> {
+        self.0
+            .last_modified()
+            .map(|v| (v.unix_timestamp_nanos() / 1_000_000) as i64)
+    }
+}
+
+#[napi(object)]
+pub struct PresignedRequest {
+    /// HTTP method of this request.
+    pub method: String,
+    /// URL of this request.
+    pub url: String,
+    /// HTTP headers of this request.
+    pub headers: HashMap<String, String>,
+}
+
+impl PresignedRequest {
+    fn new(req: opendal::raw::PresignedRequest) -> Self {
+        let headers = req
+            .header()
+            .iter()
+            .filter_map(|(k, v)| {
+                v.to_str()
+                    .ok()
+                    .map(|v| (k.as_str().to_string(), v.to_string()))
+            })
+            .collect();
+
+        Self {
+            method: req.method().as_str().to_string(),
+            url: req.uri().to_string(),
+            headers,
+        }
+    }
 }
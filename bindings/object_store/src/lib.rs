@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io;
 use std::ops::Range;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
 
@@ -22,8 +24,14 @@ use bytes::Bytes;
 use chrono::DateTime;
 use chrono::NaiveDateTime;
 use chrono::Utc;
+use futures::future;
+use futures::future::BoxFuture;
+use futures::ready;
 use futures::stream::BoxStream;
+use futures::FutureExt;
 use futures::Stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
 use object_store::path::Path;
 use object_store::GetResult;
 use object_store::ListResult;
@@ -31,6 +39,12 @@ use object_store::MultipartId;
 use object_store::ObjectMeta;
 use object_store::ObjectStore;
 use object_store::Result;
+use opendal::ErrorKind;
+use opendal::Object;
+use opendal::ObjectMetadata;
+use opendal::ObjectMode;
+use opendal::ObjectMultipart;
+use opendal::ObjectPart;
 use opendal::ObjectReader;
 use opendal::Operator;
 use tokio::io::AsyncWrite;
@@ -64,23 +78,41 @@ impl ObjectStore for OpendalStore {
 
     async fn put_multipart(
         &self,
-        _location: &Path,
+        location: &Path,
     ) -> Result<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "put_multipart is not implemented so far",
-            )),
-        })
+        let o = self.inner.object(location.as_ref());
+
+        // Fallback to buffer all content in memory if underlying services
+        // don't support multipart.
+        if !self.inner.metadata().can_multipart() {
+            return Ok((
+                MultipartId::new(),
+                Box::new(OpendalMultipartWriter::new(o, None)),
+            ));
+        }
+
+        let mp = o
+            .create_multipart()
+            .await
+            .map_err(|err| format_object_store_error(err, location.as_ref()))?;
+
+        Ok((
+            mp.upload_id().to_string(),
+            Box::new(OpendalMultipartWriter::new(o, Some(mp))),
+        ))
     }
 
-    async fn abort_multipart(&self, _location: &Path, _multipart_id: &MultipartId) -> Result<()> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "abort_multipart is not implemented so far",
-            )),
-        })
+    async fn abort_multipart(&self, location: &Path, multipart_id: &MultipartId) -> Result<()> {
+        // Empty multipart id means we are buffering in memory.
+        if multipart_id.is_empty() {
+            return Ok(());
+        }
+
+        let o = self.inner.object(location.as_ref());
+        o.to_multipart(multipart_id)
+            .abort()
+            .await
+            .map_err(|err| format_object_store_error(err, location.as_ref()))
     }
 
     async fn get(&self, location: &Path) -> Result<GetResult> {
@@ -110,20 +142,7 @@ impl ObjectStore for OpendalStore {
             .await
             .map_err(|err| format_object_store_error(err, location.as_ref()))?;
 
-        let (secs, nsecs) = meta
-            .last_modified()
-            .map(|v| (v.unix_timestamp(), v.nanosecond()))
-            .unwrap_or((0, 0));
-
-        Ok(ObjectMeta {
-            location: location.clone(),
-            last_modified: DateTime::from_utc(
-                NaiveDateTime::from_timestamp_opt(secs, nsecs)
-                    .expect("returning timestamp must be valid"),
-                Utc,
-            ),
-            size: meta.content_length() as usize,
-        })
+        Ok(format_object_meta(location.clone(), &meta))
     }
 
     async fn delete(&self, location: &Path) -> Result<()> {
@@ -135,39 +154,166 @@ impl ObjectStore for OpendalStore {
         Ok(())
     }
 
-    async fn list(&self, _prefix: Option<&Path>) -> Result<BoxStream<'_, Result<ObjectMeta>>> {
-        todo!()
+    async fn list(&self, prefix: Option<&Path>) -> Result<BoxStream<'_, Result<ObjectMeta>>> {
+        let path = format_dir_path(prefix);
+
+        let stream = self
+            .inner
+            .batch()
+            .walk_top_down(&path)
+            .map_err(|err| format_object_store_error(err, &path))?
+            // Listing a missing prefix returns nothing instead of an error.
+            .filter(|res| {
+                future::ready(!matches!(res, Err(err) if err.kind() == ErrorKind::ObjectNotFound))
+            })
+            .map_err(move |err| format_object_store_error(err, &path))
+            .try_filter_map(|o| async move {
+                let meta = o
+                    .metadata()
+                    .await
+                    .map_err(|err| format_object_store_error(err, o.path()))?;
+                if meta.mode() != ObjectMode::FILE {
+                    return Ok(None);
+                }
+
+                Ok(Some(format_object_meta(Path::parse(o.path())?, &meta)))
+            });
+
+        Ok(stream.boxed())
     }
 
-    async fn list_with_delimiter(&self, _prefix: Option<&Path>) -> Result<ListResult> {
-        todo!()
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> Result<ListResult> {
+        let path = format_dir_path(prefix);
+
+        let mut stream = match self.inner.object(&path).list().await {
+            Ok(v) => v,
+            // Listing a missing prefix returns nothing instead of an error.
+            Err(err) if err.kind() == ErrorKind::ObjectNotFound => {
+                return Ok(ListResult {
+                    common_prefixes: vec![],
+                    objects: vec![],
+                })
+            }
+            Err(err) => return Err(format_object_store_error(err, &path)),
+        };
+
+        let mut common_prefixes = Vec::new();
+        let mut objects = Vec::new();
+
+        while let Some(o) = stream
+            .try_next()
+            .await
+            .map_err(|err| format_object_store_error(err, &path))?
+        {
+            let meta = o
+                .metadata()
+                .await
+                .map_err(|err| format_object_store_error(err, o.path()))?;
+
+            match meta.mode() {
+                ObjectMode::DIR => {
+                    common_prefixes.push(Path::parse(o.path().trim_end_matches('/'))?);
+                }
+                ObjectMode::FILE => {
+                    objects.push(format_object_meta(Path::parse(o.path())?, &meta));
+                }
+                ObjectMode::Unknown => continue,
+            }
+        }
+
+        Ok(ListResult {
+            common_prefixes,
+            objects,
+        })
     }
 
-    async fn copy(&self, _from: &Path, _to: &Path) -> Result<()> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "copy is not implemented so far",
-            )),
-        })
+    async fn copy(&self, from: &Path, to: &Path) -> Result<()> {
+        let src = self.inner.object(from.as_ref());
+        let dst = self.inner.object(to.as_ref());
+
+        copy_object(&src, &dst).await
     }
 
-    async fn rename(&self, _from: &Path, _to: &Path) -> Result<()> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "rename is not implemented so far",
-            )),
-        })
+    async fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let src = self.inner.object(from.as_ref());
+        let dst = self.inner.object(to.as_ref());
+
+        copy_object(&src, &dst).await?;
+        src.delete()
+            .await
+            .map_err(|err| format_object_store_error(err, from.as_ref()))
     }
 
-    async fn copy_if_not_exists(&self, _from: &Path, _to: &Path) -> Result<()> {
-        Err(object_store::Error::NotSupported {
-            source: Box::new(opendal::Error::new(
-                opendal::ErrorKind::Unsupported,
-                "copy_if_not_exists is not implemented so far",
-            )),
-        })
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> Result<()> {
+        let src = self.inner.object(from.as_ref());
+        let dst = self.inner.object(to.as_ref());
+
+        // # Notes
+        //
+        // This check is not atomic, another writer could create the object
+        // between `is_exist` and `write`.
+        if dst
+            .is_exist()
+            .await
+            .map_err(|err| format_object_store_error(err, to.as_ref()))?
+        {
+            return Err(object_store::Error::AlreadyExists {
+                path: to.to_string(),
+                source: Box::new(opendal::Error::new(
+                    opendal::ErrorKind::ObjectAlreadyExists,
+                    "object already exists",
+                )),
+            });
+        }
+
+        copy_object(&src, &dst).await
+    }
+}
+
+/// Copy object via read and write since OpenDAL doesn't have native copy
+/// support so far.
+async fn copy_object(src: &Object, dst: &Object) -> Result<()> {
+    let size = src
+        .metadata()
+        .await
+        .map_err(|err| format_object_store_error(err, src.path()))?
+        .content_length();
+    let r = src
+        .reader()
+        .await
+        .map_err(|err| format_object_store_error(err, src.path()))?;
+
+    dst.write_from(size, r)
+        .await
+        .map_err(|err| format_object_store_error(err, dst.path()))
+}
+
+/// Convert object_store's prefix into OpenDAL's dir path.
+///
+/// - `None` => `/`
+/// - `Some("a/b")` => `a/b/`
+fn format_dir_path(prefix: Option<&Path>) -> String {
+    match prefix {
+        None => "/".to_string(),
+        Some(p) if p.as_ref().is_empty() => "/".to_string(),
+        Some(p) => format!("{}/", p.as_ref()),
+    }
+}
+
+fn format_object_meta(location: Path, meta: &ObjectMetadata) -> ObjectMeta {
+    let (secs, nsecs) = meta
+        .last_modified()
+        .map(|v| (v.unix_timestamp(), v.nanosecond()))
+        .unwrap_or((0, 0));
+
+    ObjectMeta {
+        location,
+        last_modified: DateTime::from_utc(
+            NaiveDateTime::from_timestamp_opt(secs, nsecs)
+                .expect("returning timestamp must be valid"),
+            Utc,
+        ),
+        size: meta.content_length() as usize,
     }
 }
 
@@ -211,6 +357,126 @@ impl Stream for OpendalReader {
     }
 }
 
+/// The part size used by `put_multipart`.
+///
+/// Most services require the part size to be larger than 5 MiB except
+/// the last part, we use 8 MiB here to leave some room.
+const MULTIPART_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// OpendalMultipartWriter implements `AsyncWrite` for `put_multipart`.
+///
+/// - If `multipart` is `Some`, content will be uploaded as parts of
+///   `MULTIPART_PART_SIZE` while writing.
+/// - Otherwise, all content will be buffered in memory and written while
+///   shutdown.
+struct OpendalMultipartWriter {
+    object: Object,
+    multipart: Option<Arc<ObjectMultipart>>,
+
+    buf: Vec<u8>,
+    parts: Vec<ObjectPart>,
+    state: WriterState,
+}
+
+enum WriterState {
+    Idle,
+    Writing(BoxFuture<'static, opendal::Result<ObjectPart>>),
+    Closing(BoxFuture<'static, opendal::Result<()>>),
+}
+
+impl OpendalMultipartWriter {
+    fn new(object: Object, multipart: Option<ObjectMultipart>) -> Self {
+        Self {
+            object,
+            multipart: multipart.map(Arc::new),
+            buf: Vec::new(),
+            parts: Vec::new(),
+            state: WriterState::Idle,
+        }
+    }
+
+    /// Start uploading current buffer as the next part.
+    fn start_write_part(&mut self) {
+        let mp = self
+            .multipart
+            .clone()
+            .expect("multipart must be valid while writing part");
+        let part_number = self.parts.len() + 1;
+        let bs = std::mem::take(&mut self.buf);
+
+        self.state = WriterState::Writing(Box::pin(async move { mp.write(part_number, bs).await }));
+    }
+
+    /// Drive the in-progress part upload to finish.
+    fn poll_write_part(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if let WriterState::Writing(fut) = &mut self.state {
+            let res = ready!(fut.poll_unpin(cx));
+            self.state = WriterState::Idle;
+            self.parts.push(res.map_err(io::Error::from)?);
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for OpendalMultipartWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        ready!(self.poll_write_part(cx))?;
+
+        self.buf.extend_from_slice(buf);
+
+        if self.multipart.is_some() && self.buf.len() >= MULTIPART_PART_SIZE {
+            self.start_write_part();
+            // Poll the future once to make sure it has been registered.
+            if let Poll::Ready(Err(err)) = self.poll_write_part(cx) {
+                return Poll::Ready(Err(err));
+            }
+        }
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_write_part(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            match &mut self.state {
+                WriterState::Writing(_) => ready!(self.poll_write_part(cx))?,
+                WriterState::Closing(fut) => {
+                    let res = ready!(fut.poll_unpin(cx));
+                    self.state = WriterState::Idle;
+                    return Poll::Ready(res.map_err(io::Error::from));
+                }
+                WriterState::Idle => {
+                    let object = self.object.clone();
+                    let bs = std::mem::take(&mut self.buf);
+
+                    let fut: BoxFuture<'static, opendal::Result<()>> = match self.multipart.take() {
+                        None => Box::pin(async move { object.write(bs).await }),
+                        Some(mp) => {
+                            let mut parts = std::mem::take(&mut self.parts);
+                            Box::pin(async move {
+                                if !bs.is_empty() || parts.is_empty() {
+                                    parts.push(mp.write(parts.len() + 1, bs).await?);
+                                }
+                                mp.complete(parts).await?;
+                                Ok(())
+                            })
+                        }
+                    };
+                    self.state = WriterState::Closing(fut);
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -238,4 +504,124 @@ mod tests {
 
         assert_eq!(meta.size, 13)
     }
+
+    #[tokio::test]
+    async fn test_list() {
+        // Memory service doesn't support list, use fs instead.
+        let root = std::env::temp_dir().join(format!(
+            "opendal-object-store-test-list-{}",
+            uuid::Uuid::new_v4()
+        ));
+        let mut builder = services::Fs::default();
+        builder.root(&root.to_string_lossy());
+        let op = Operator::create(builder).unwrap().finish();
+        let object_store: Arc<dyn ObjectStore> = Arc::new(OpendalStore::new(op));
+
+        for p in ["data/a.txt", "data/b.txt", "data/nested/c.txt"] {
+            let path: Path = p.try_into().unwrap();
+            object_store
+                .put(&path, Bytes::from_static(b"hello"))
+                .await
+                .unwrap();
+        }
+
+        let prefix: Path = "data".try_into().unwrap();
+        let mut paths = object_store
+            .list(Some(&prefix))
+            .await
+            .unwrap()
+            .map_ok(|v| v.location.to_string())
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        paths.sort();
+        assert_eq!(paths, ["data/a.txt", "data/b.txt", "data/nested/c.txt"]);
+
+        let res = object_store
+            .list_with_delimiter(Some(&prefix))
+            .await
+            .unwrap();
+        let mut objects = res
+            .objects
+            .iter()
+            .map(|v| v.location.to_string())
+            .collect::<Vec<_>>();
+        objects.sort();
+        assert_eq!(objects, ["data/a.txt", "data/b.txt"]);
+        assert_eq!(res.common_prefixes, vec![Path::from("data/nested")]);
+
+        let missing: Path = "not_exist".try_into().unwrap();
+        let paths = object_store
+            .list(Some(&missing))
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert!(paths.is_empty());
+        let res = object_store
+            .list_with_delimiter(Some(&missing))
+            .await
+            .unwrap();
+        assert!(res.objects.is_empty());
+        assert!(res.common_prefixes.is_empty());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_copy_and_rename() {
+        let op = Operator::create(services::Memory::default())
+            .unwrap()
+            .finish();
+        let object_store: Arc<dyn ObjectStore> = Arc::new(OpendalStore::new(op));
+
+        let from: Path = "data/from.txt".try_into().unwrap();
+        let to: Path = "data/to.txt".try_into().unwrap();
+        object_store
+            .put(&from, Bytes::from_static(b"hello"))
+            .await
+            .unwrap();
+
+        object_store.copy(&from, &to).await.unwrap();
+        assert_eq!(object_store.head(&to).await.unwrap().size, 5);
+
+        let err = object_store
+            .copy_if_not_exists(&from, &to)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, object_store::Error::AlreadyExists { .. }));
+
+        let renamed: Path = "data/renamed.txt".try_into().unwrap();
+        object_store.rename(&from, &renamed).await.unwrap();
+        assert!(matches!(
+            object_store.head(&from).await.unwrap_err(),
+            object_store::Error::NotFound { .. }
+        ));
+        assert_eq!(
+            object_store.get_range(&renamed, 0..5).await.unwrap(),
+            Bytes::from_static(b"hello")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_put_multipart() {
+        use tokio::io::AsyncWriteExt;
+
+        let op = Operator::create(services::Memory::default())
+            .unwrap()
+            .finish();
+        let object_store: Arc<dyn ObjectStore> = Arc::new(OpendalStore::new(op));
+
+        let path: Path = "data/multipart.txt".try_into().unwrap();
+        let (_, mut w) = object_store.put_multipart(&path).await.unwrap();
+        w.write_all(b"hello, ").await.unwrap();
+        w.write_all(b"world!").await.unwrap();
+        w.shutdown().await.unwrap();
+
+        assert_eq!(
+            object_store.get_range(&path, 0..13).await.unwrap(),
+            Bytes::from_static(b"hello, world!")
+        );
+    }
 }
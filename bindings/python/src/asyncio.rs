@@ -0,0 +1,188 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! asyncio support for OpenDAL, all futures are driven by the tokio
+//! runtime that managed by `pyo3-asyncio`.
+
+use std::sync::Arc;
+
+use ::opendal as od;
+use futures::TryStreamExt;
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::types::PyDict;
+use pyo3_asyncio::tokio::future_into_py;
+use tokio::sync::Mutex;
+
+use crate::build_operator;
+use crate::format_pyerr;
+use crate::into_map;
+use crate::layers::Layer;
+use crate::ObjectMetadata;
+
+/// `AsyncOperator` is the entry for all asyncio operations.
+///
+/// ```python
+/// import opendal
+///
+/// op = opendal.AsyncOperator("fs", root="/tmp")
+/// await op.object("test").write(b"Hello, World!")
+/// ```
+#[pyclass(module = "opendal")]
+#[derive(Clone)]
+pub struct AsyncOperator(od::Operator);
+
+#[pymethods]
+impl AsyncOperator {
+    #[new]
+    #[pyo3(signature = (scheme, **map))]
+    fn new(scheme: &str, map: Option<&PyDict>) -> PyResult<Self> {
+        Ok(AsyncOperator(build_operator(scheme, into_map(map)?)?))
+    }
+
+    /// Add new layers upon existing operator.
+    fn layer(&self, layer: PyRef<Layer>) -> Self {
+        AsyncOperator(layer.layer(self.0.clone()))
+    }
+
+    /// Create an object handle for given path.
+    fn object(&self, path: &str) -> AsyncObject {
+        AsyncObject(self.0.object(path))
+    }
+
+    fn __repr__(&self) -> String {
+        let meta = self.0.metadata();
+        format!(
+            "AsyncOperator(scheme=\"{}\", root=\"{}\")",
+            meta.scheme(),
+            meta.root()
+        )
+    }
+}
+
+/// `AsyncObject` is the handle for asyncio object operations.
+#[pyclass(module = "opendal")]
+#[derive(Clone)]
+pub struct AsyncObject(od::Object);
+
+#[pymethods]
+impl AsyncObject {
+    /// Path of object, relative to operator's root.
+    #[getter]
+    fn path(&self) -> &str {
+        self.0.path()
+    }
+
+    /// Name of object, the last segment of path.
+    #[getter]
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Read the whole object into bytes.
+    fn read<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move {
+            let bs = o.read().await.map_err(format_pyerr)?;
+            Ok(Python::with_gil(|py| PyBytes::new(py, &bs).to_object(py)))
+        })
+    }
+
+    /// Read `size` bytes of object starting from `offset`.
+    fn range_read<'p>(&self, py: Python<'p>, offset: u64, size: u64) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move {
+            let bs = o
+                .range_read(offset..offset + size)
+                .await
+                .map_err(format_pyerr)?;
+            Ok(Python::with_gil(|py| PyBytes::new(py, &bs).to_object(py)))
+        })
+    }
+
+    /// Write bytes into object.
+    fn write<'p>(&self, py: Python<'p>, bs: &PyBytes) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        let bs = bs.as_bytes().to_vec();
+        future_into_py(py, async move { o.write(bs).await.map_err(format_pyerr) })
+    }
+
+    /// Create this object, dir will be created if path ends with `/`.
+    fn create<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move { o.create().await.map_err(format_pyerr) })
+    }
+
+    /// Get current object's metadata **without cache**.
+    fn stat<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move {
+            o.stat().await.map(ObjectMetadata).map_err(format_pyerr)
+        })
+    }
+
+    /// Check if this object exists or not.
+    fn is_exist<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move { o.is_exist().await.map_err(format_pyerr) })
+    }
+
+    /// Delete this object.
+    fn delete<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move { o.delete().await.map_err(format_pyerr) })
+    }
+
+    /// List current dir object, returns an async iterator of `AsyncObject`.
+    ///
+    /// ```python
+    /// async for o in await op.object("dir/").list():
+    ///     print(o.path)
+    /// ```
+    fn list<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let o = self.0.clone();
+        future_into_py(py, async move {
+            let l = o.list().await.map_err(format_pyerr)?;
+            Ok(AsyncObjectLister(Arc::new(Mutex::new(l))))
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AsyncObject(path=\"{}\")", self.0.path())
+    }
+}
+
+/// Async iterator of `AsyncObject` returned by `AsyncObject.list()`.
+#[pyclass(module = "opendal")]
+pub struct AsyncObjectLister(Arc<Mutex<od::ObjectLister>>);
+
+#[pymethods]
+impl AsyncObjectLister {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'p>(&self, py: Python<'p>) -> PyResult<Option<&'p PyAny>> {
+        let l = self.0.clone();
+        let fut = future_into_py(py, async move {
+            let mut l = l.lock().await;
+            match l.try_next().await.map_err(format_pyerr)? {
+                Some(o) => Ok(AsyncObject(o)),
+                None => Err(PyStopAsyncIteration::new_err("stream exhausted")),
+            }
+        })?;
+        Ok(Some(fut))
+    }
+}
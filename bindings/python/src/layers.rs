@@ -0,0 +1,175 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Layers that can be configured from python.
+//!
+//! ```python
+//! import opendal
+//! from opendal import layers
+//!
+//! op = opendal.Operator("memory").layer(layers.RetryLayer(max_times=3))
+//! ```
+
+use std::time::Duration;
+
+use ::opendal as od;
+use pyo3::prelude::*;
+
+/// PythonLayer is the trait that all layers exposed to python must implement.
+///
+/// Layers in OpenDAL are generic over the accessor, so we apply them on
+/// `Operator` directly instead of storing them as trait objects.
+pub trait PythonLayer: Send + Sync {
+    fn layer(&self, op: od::Operator) -> od::Operator;
+}
+
+/// Base class of all layers.
+#[pyclass(module = "opendal.layers", subclass)]
+pub struct Layer(Box<dyn PythonLayer>);
+
+impl Layer {
+    pub fn layer(&self, op: od::Operator) -> od::Operator {
+        self.0.layer(op)
+    }
+}
+
+struct RetryConfig {
+    max_times: Option<usize>,
+    factor: Option<f32>,
+    jitter: bool,
+    min_delay: Option<f64>,
+    max_delay: Option<f64>,
+}
+
+impl PythonLayer for RetryConfig {
+    fn layer(&self, op: od::Operator) -> od::Operator {
+        let mut l = od::layers::RetryLayer::new();
+        if let Some(max_times) = self.max_times {
+            l = l.with_max_times(max_times);
+        }
+        if let Some(factor) = self.factor {
+            l = l.with_factor(factor);
+        }
+        if self.jitter {
+            l = l.with_jitter();
+        }
+        if let Some(min_delay) = self.min_delay {
+            l = l.with_min_delay(Duration::from_secs_f64(min_delay));
+        }
+        if let Some(max_delay) = self.max_delay {
+            l = l.with_max_delay(Duration::from_secs_f64(max_delay));
+        }
+
+        op.layer(l)
+    }
+}
+
+/// Retry temporary failed operations with exponential backoff.
+///
+/// `min_delay` and `max_delay` are in seconds.
+#[pyclass(module = "opendal.layers", extends = Layer)]
+pub struct RetryLayer;
+
+#[pymethods]
+impl RetryLayer {
+    #[new]
+    #[pyo3(signature = (max_times = None, factor = None, jitter = false, min_delay = None, max_delay = None))]
+    fn new(
+        max_times: Option<usize>,
+        factor: Option<f32>,
+        jitter: bool,
+        min_delay: Option<f64>,
+        max_delay: Option<f64>,
+    ) -> PyResult<(Self, Layer)> {
+        if let Some(factor) = factor {
+            if factor < 1.0 {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "factor must be larger than 1.0",
+                ));
+            }
+        }
+
+        Ok((
+            RetryLayer,
+            Layer(Box::new(RetryConfig {
+                max_times,
+                factor,
+                jitter,
+                min_delay,
+                max_delay,
+            })),
+        ))
+    }
+}
+
+struct ConcurrentLimitConfig {
+    permits: usize,
+}
+
+impl PythonLayer for ConcurrentLimitConfig {
+    fn layer(&self, op: od::Operator) -> od::Operator {
+        op.layer(od::layers::ConcurrentLimitLayer::new(self.permits))
+    }
+}
+
+/// Limit the number of concurrent requests.
+#[pyclass(module = "opendal.layers", extends = Layer)]
+pub struct ConcurrentLimitLayer;
+
+#[pymethods]
+impl ConcurrentLimitLayer {
+    #[new]
+    fn new(permits: usize) -> (Self, Layer) {
+        (
+            ConcurrentLimitLayer,
+            Layer(Box::new(ConcurrentLimitConfig { permits })),
+        )
+    }
+}
+
+struct ImmutableIndexConfig {
+    keys: Vec<String>,
+}
+
+impl PythonLayer for ImmutableIndexConfig {
+    fn layer(&self, op: od::Operator) -> od::Operator {
+        let mut l = od::layers::ImmutableIndexLayer::default();
+        l.extend_iter(self.keys.clone());
+        op.layer(l)
+    }
+}
+
+/// Add an immutable in-memory index for services that don't support `list`.
+#[pyclass(module = "opendal.layers", extends = Layer)]
+pub struct ImmutableIndexLayer;
+
+#[pymethods]
+impl ImmutableIndexLayer {
+    #[new]
+    fn new(keys: Vec<String>) -> (Self, Layer) {
+        (
+            ImmutableIndexLayer,
+            Layer(Box::new(ImmutableIndexConfig { keys })),
+        )
+    }
+}
+
+pub fn create_submodule(py: Python) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "layers")?;
+    m.add_class::<Layer>()?;
+    m.add_class::<RetryLayer>()?;
+    m.add_class::<ConcurrentLimitLayer>()?;
+    m.add_class::<ImmutableIndexLayer>()?;
+    Ok(m)
+}
@@ -12,20 +12,439 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use ::opendal::services;
-use ::opendal::Operator;
+use std::collections::HashMap;
+use std::future::Future;
+use std::io::SeekFrom;
+use std::str::FromStr;
+
+use ::opendal as od;
+use futures::AsyncReadExt;
+use futures::AsyncSeekExt;
+use futures::TryStreamExt;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::types::PyDateTime;
+use pyo3::types::PyDict;
+use pyo3::types::PyTzInfo;
+
+mod asyncio;
+use asyncio::*;
+
+mod layers;
+use layers::Layer;
+
+create_exception!(opendal, Error, PyException, "OpenDAL related errors");
+create_exception!(opendal, UnexpectedError, Error, "Unexpected errors");
+create_exception!(opendal, UnsupportedError, Error, "Unsupported operation");
+create_exception!(opendal, ConfigInvalidError, Error, "Config is invalid");
+create_exception!(opendal, NotFoundError, Error, "Object is not found");
+create_exception!(opendal, PermissionDeniedError, Error, "Permission denied");
+create_exception!(opendal, IsADirectoryError, Error, "Object is a directory");
+create_exception!(opendal, NotADirectoryError, Error, "Object is not a directory");
+create_exception!(opendal, AlreadyExistsError, Error, "Object already exists");
+create_exception!(opendal, RateLimitedError, Error, "Object is rate limited");
+create_exception!(opendal, ChecksumMismatchError, Error, "Checksum mismatch");
+
+/// Convert opendal's error into python exception based on `ErrorKind`.
+fn format_pyerr(err: od::Error) -> PyErr {
+    use od::ErrorKind::*;
+    match err.kind() {
+        Unexpected => UnexpectedError::new_err(err.to_string()),
+        Unsupported => UnsupportedError::new_err(err.to_string()),
+        BackendConfigInvalid => ConfigInvalidError::new_err(err.to_string()),
+        ObjectNotFound => NotFoundError::new_err(err.to_string()),
+        ObjectPermissionDenied => PermissionDeniedError::new_err(err.to_string()),
+        ObjectIsADirectory => IsADirectoryError::new_err(err.to_string()),
+        ObjectNotADirectory => NotADirectoryError::new_err(err.to_string()),
+        ObjectAlreadyExists => AlreadyExistsError::new_err(err.to_string()),
+        ObjectRateLimited => RateLimitedError::new_err(err.to_string()),
+        ChecksumMismatch => ChecksumMismatchError::new_err(err.to_string()),
+        _ => Error::new_err(err.to_string()),
+    }
+}
+
+/// Run given future on the shared tokio runtime without holding the GIL.
+fn block_on<F>(py: Python, fut: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    py.allow_threads(|| pyo3_asyncio::tokio::get_runtime().block_on(fut))
+}
+
+fn build_operator(scheme: &str, map: HashMap<String, String>) -> PyResult<od::Operator> {
+    use od::services::*;
+
+    let scheme = od::Scheme::from_str(scheme)
+        .map_err(|err| PyValueError::new_err(format!("invalid scheme {scheme}: {err}")))?;
+
+    let op = match scheme {
+        od::Scheme::Azblob => od::Operator::from_map::<Azblob>(map),
+        od::Scheme::Azdfs => od::Operator::from_map::<Azdfs>(map),
+        od::Scheme::Fs => od::Operator::from_map::<Fs>(map),
+        od::Scheme::Gcs => od::Operator::from_map::<Gcs>(map),
+        od::Scheme::Ghac => od::Operator::from_map::<Ghac>(map),
+        od::Scheme::Http => od::Operator::from_map::<Http>(map),
+        od::Scheme::Ipmfs => od::Operator::from_map::<Ipmfs>(map),
+        od::Scheme::Memory => od::Operator::from_map::<Memory>(map),
+        od::Scheme::Obs => od::Operator::from_map::<Obs>(map),
+        od::Scheme::Oss => od::Operator::from_map::<Oss>(map),
+        od::Scheme::S3 => od::Operator::from_map::<S3>(map),
+        od::Scheme::Webdav => od::Operator::from_map::<Webdav>(map),
+        od::Scheme::Webhdfs => od::Operator::from_map::<Webhdfs>(map),
+        _ => {
+            return Err(UnsupportedError::new_err(format!(
+                "scheme {scheme} is not supported"
+            )))
+        }
+    }
+    .map_err(format_pyerr)?;
+
+    Ok(op.finish())
+}
+
+fn into_map(map: Option<&PyDict>) -> PyResult<HashMap<String, String>> {
+    map.map(|v| v.extract::<HashMap<String, String>>())
+        .transpose()
+        .map(|v| v.unwrap_or_default())
+}
+
+/// `Operator` is the entry for all blocking operations.
+///
+/// ```python
+/// import opendal
+///
+/// op = opendal.Operator("fs", root="/tmp")
+/// op.object("test").write(b"Hello, World!")
+/// ```
+#[pyclass(module = "opendal")]
+#[derive(Clone)]
+struct Operator(od::Operator);
+
+#[pymethods]
+impl Operator {
+    #[new]
+    #[pyo3(signature = (scheme, **map))]
+    fn new(scheme: &str, map: Option<&PyDict>) -> PyResult<Self> {
+        Ok(Operator(build_operator(scheme, into_map(map)?)?))
+    }
 
-#[pyfunction]
-fn debug() -> PyResult<String> {
-    let op = Operator::create(services::Memory::default())
-        .unwrap()
-        .finish();
-    Ok(format!("{:?}", op.metadata()))
+    /// Add new layers upon existing operator.
+    fn layer(&self, layer: PyRef<Layer>) -> Self {
+        Operator(layer.layer(self.0.clone()))
+    }
+
+    /// Create an object handle for given path.
+    fn object(&self, path: &str) -> Object {
+        Object(self.0.object(path))
+    }
+
+    fn __repr__(&self) -> String {
+        let meta = self.0.metadata();
+        format!(
+            "Operator(scheme=\"{}\", root=\"{}\")",
+            meta.scheme(),
+            meta.root()
+        )
+    }
+}
+
+/// `Object` is the handle for blocking object operations.
+#[pyclass(module = "opendal")]
+#[derive(Clone)]
+struct Object(od::Object);
+
+#[pymethods]
+impl Object {
+    /// Path of object, relative to operator's root.
+    #[getter]
+    fn path(&self) -> &str {
+        self.0.path()
+    }
+
+    /// Name of object, the last segment of path.
+    #[getter]
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    /// Read the whole object into bytes.
+    fn read<'p>(&self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        let o = self.0.clone();
+        let bs = block_on(py, async move { o.read().await }).map_err(format_pyerr)?;
+        Ok(PyBytes::new(py, &bs))
+    }
+
+    /// Read `size` bytes of object starting from `offset`.
+    fn range_read<'p>(&self, py: Python<'p>, offset: u64, size: u64) -> PyResult<&'p PyBytes> {
+        let o = self.0.clone();
+        let bs = block_on(py, async move { o.range_read(offset..offset + size).await })
+            .map_err(format_pyerr)?;
+        Ok(PyBytes::new(py, &bs))
+    }
+
+    /// Open a file-like reader for this object.
+    fn open_reader(&self, py: Python) -> PyResult<Reader> {
+        let o = self.0.clone();
+        let r = block_on(py, async move { o.reader().await }).map_err(format_pyerr)?;
+        Ok(Reader(Some(r)))
+    }
+
+    /// Write bytes into object.
+    fn write(&self, py: Python, bs: &PyBytes) -> PyResult<()> {
+        let o = self.0.clone();
+        let bs = bs.as_bytes().to_vec();
+        block_on(py, async move { o.write(bs).await }).map_err(format_pyerr)
+    }
+
+    /// Create this object, dir will be created if path ends with `/`.
+    fn create(&self, py: Python) -> PyResult<()> {
+        let o = self.0.clone();
+        block_on(py, async move { o.create().await }).map_err(format_pyerr)
+    }
+
+    /// Get current object's metadata **without cache**.
+    fn stat(&self, py: Python) -> PyResult<ObjectMetadata> {
+        let o = self.0.clone();
+        block_on(py, async move { o.stat().await })
+            .map(ObjectMetadata)
+            .map_err(format_pyerr)
+    }
+
+    /// Check if this object exists or not.
+    fn is_exist(&self, py: Python) -> PyResult<bool> {
+        let o = self.0.clone();
+        block_on(py, async move { o.is_exist().await }).map_err(format_pyerr)
+    }
+
+    /// Delete this object.
+    fn delete(&self, py: Python) -> PyResult<()> {
+        let o = self.0.clone();
+        block_on(py, async move { o.delete().await }).map_err(format_pyerr)
+    }
+
+    /// List current dir object, returns an iterator of `Object`.
+    fn list(&self, py: Python) -> PyResult<ObjectLister> {
+        let o = self.0.clone();
+        let l = block_on(py, async move { o.list().await }).map_err(format_pyerr)?;
+        Ok(ObjectLister(l))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Object(path=\"{}\")", self.0.path())
+    }
+}
+
+/// Iterator of `Object` returned by `Object.list()`.
+#[pyclass(module = "opendal")]
+struct ObjectLister(od::ObjectLister);
+
+#[pymethods]
+impl ObjectLister {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<Object>> {
+        let l = &mut self.0;
+        let o = block_on(py, async move { l.try_next().await }).map_err(format_pyerr)?;
+        Ok(o.map(Object))
+    }
+}
+
+/// A file-like reader which supports `read`, `seek` and `tell`.
+///
+/// ```python
+/// with op.object("test").open_reader() as r:
+///     r.seek(1024)
+///     bs = r.read(4096)
+/// ```
+#[pyclass(module = "opendal")]
+struct Reader(Option<od::ObjectReader>);
+
+impl Reader {
+    fn inner(&mut self) -> PyResult<&mut od::ObjectReader> {
+        self.0
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("I/O operation on closed reader"))
+    }
+}
+
+#[pymethods]
+impl Reader {
+    /// Read at most `size` bytes, read until EOF if `size` is not set or negative.
+    #[pyo3(signature = (size = None))]
+    fn read<'p>(&mut self, py: Python<'p>, size: Option<i64>) -> PyResult<&'p PyBytes> {
+        let r = self.inner()?;
+        let bs = block_on(py, async move {
+            let mut bs = Vec::new();
+            match size {
+                Some(size) if size >= 0 => {
+                    r.take(size as u64).read_to_end(&mut bs).await?;
+                }
+                _ => {
+                    r.read_to_end(&mut bs).await?;
+                }
+            }
+            Ok::<_, std::io::Error>(bs)
+        })?;
+        Ok(PyBytes::new(py, &bs))
+    }
+
+    /// Change the stream position to the given offset, returns the new position.
+    ///
+    /// `whence` follows the python convention: `0` for start, `1` for current
+    /// and `2` for end.
+    #[pyo3(signature = (offset, whence = 0))]
+    fn seek(&mut self, py: Python, offset: i64, whence: u8) -> PyResult<u64> {
+        let pos = match whence {
+            0 => SeekFrom::Start(offset as u64),
+            1 => SeekFrom::Current(offset),
+            2 => SeekFrom::End(offset),
+            _ => return Err(PyValueError::new_err(format!("invalid whence: {whence}"))),
+        };
+
+        let r = self.inner()?;
+        Ok(block_on(py, async move { r.seek(pos).await })?)
+    }
+
+    /// Return the current stream position.
+    fn tell(&mut self, py: Python) -> PyResult<u64> {
+        let r = self.inner()?;
+        Ok(block_on(py, async move { r.seek(SeekFrom::Current(0)).await })?)
+    }
+
+    fn readable(&self) -> bool {
+        true
+    }
+
+    fn seekable(&self) -> bool {
+        true
+    }
+
+    fn close(&mut self) {
+        self.0 = None;
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.0.is_none()
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&mut self, _exc_type: PyObject, _exc_value: PyObject, _traceback: PyObject) {
+        self.close()
+    }
+}
+
+/// Metadata of object.
+#[pyclass(module = "opendal")]
+#[derive(Clone)]
+struct ObjectMetadata(od::ObjectMetadata);
+
+#[pymethods]
+impl ObjectMetadata {
+    /// Mode of object, could be `file`, `dir` or `unknown`.
+    #[getter]
+    fn mode(&self) -> String {
+        self.0.mode().to_string()
+    }
+
+    #[getter]
+    fn is_file(&self) -> bool {
+        self.0.mode().is_file()
+    }
+
+    #[getter]
+    fn is_dir(&self) -> bool {
+        self.0.mode().is_dir()
+    }
+
+    #[getter]
+    fn content_length(&self) -> u64 {
+        self.0.content_length()
+    }
+
+    #[getter]
+    fn content_md5(&self) -> Option<&str> {
+        self.0.content_md5()
+    }
+
+    #[getter]
+    fn content_type(&self) -> Option<&str> {
+        self.0.content_type()
+    }
+
+    #[getter]
+    fn etag(&self) -> Option<&str> {
+        self.0.etag()
+    }
+
+    /// Last modified time of object as a timezone aware `datetime`.
+    #[getter]
+    fn last_modified<'p>(&self, py: Python<'p>) -> PyResult<Option<&'p PyDateTime>> {
+        let lm = match self.0.last_modified() {
+            Some(lm) => lm,
+            None => return Ok(None),
+        };
+
+        let utc: &PyTzInfo = py
+            .import("datetime")?
+            .getattr("timezone")?
+            .getattr("utc")?
+            .downcast()?;
+        let ts = lm.unix_timestamp_nanos() as f64 / 1e9;
+
+        PyDateTime::from_timestamp(py, ts, Some(utc)).map(Some)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ObjectMetadata(mode=\"{}\", content_length={})",
+            self.0.mode(),
+            self.0.content_length()
+        )
+    }
 }
 
 #[pymodule]
-fn opendal(_py: Python, m: &PyModule) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(debug, m)?)?;
+fn opendal(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<Operator>()?;
+    m.add_class::<Object>()?;
+    m.add_class::<ObjectLister>()?;
+    m.add_class::<ObjectMetadata>()?;
+    m.add_class::<Reader>()?;
+    m.add_class::<AsyncOperator>()?;
+    m.add_class::<AsyncObject>()?;
+    m.add_class::<AsyncObjectLister>()?;
+
+    m.add("Error", py.get_type::<Error>())?;
+    m.add("UnexpectedError", py.get_type::<UnexpectedError>())?;
+    m.add("UnsupportedError", py.get_type::<UnsupportedError>())?;
+    m.add("ConfigInvalidError", py.get_type::<ConfigInvalidError>())?;
+    m.add("NotFoundError", py.get_type::<NotFoundError>())?;
+    m.add(
+        "PermissionDeniedError",
+        py.get_type::<PermissionDeniedError>(),
+    )?;
+    m.add("IsADirectoryError", py.get_type::<IsADirectoryError>())?;
+    m.add("NotADirectoryError", py.get_type::<NotADirectoryError>())?;
+    m.add("AlreadyExistsError", py.get_type::<AlreadyExistsError>())?;
+    m.add("RateLimitedError", py.get_type::<RateLimitedError>())?;
+    m.add("ChecksumMismatchError", py.get_type::<ChecksumMismatchError>())?;
+
+    let layers = layers::create_submodule(py)?;
+    m.add_submodule(layers)?;
+    // Register the submodule so that `import opendal.layers` works.
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("opendal.layers", layers)?;
+
     Ok(())
 }
@@ -0,0 +1,76 @@
+# Copyright 2023 Datafuse Labs.
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+import opendal
+import pytest
+from opendal import layers
+
+
+def test_blocking():
+    op = opendal.Operator("memory").layer(layers.RetryLayer(max_times=3))
+    o = op.object("test")
+    o.write(b"Hello, World!")
+
+    assert o.read() == b"Hello, World!"
+    assert o.range_read(7, 5) == b"World"
+
+    meta = o.stat()
+    assert meta.is_file
+    assert meta.content_length == 13
+
+    with o.open_reader() as r:
+        assert r.seek(7) == 7
+        assert r.read(5) == b"World"
+        assert r.tell() == 12
+        assert r.read() == b"!"
+
+    o.delete()
+    with pytest.raises(opendal.NotFoundError):
+        o.stat()
+
+
+def test_blocking_list(tmp_path):
+    op = opendal.Operator("fs", root=str(tmp_path))
+    op.object("dir/a").write(b"a")
+    op.object("dir/b").write(b"b")
+
+    names = sorted(o.name for o in op.object("dir/").list())
+    assert names == ["a", "b"]
+
+
+async def test_async():
+    op = opendal.AsyncOperator("memory")
+    o = op.object("test")
+    await o.write(b"Hello, World!")
+
+    assert await o.read() == b"Hello, World!"
+    assert await o.range_read(7, 5) == b"World"
+    assert (await o.stat()).content_length == 13
+
+    await o.delete()
+    assert not await o.is_exist()
+
+
+async def test_async_list(tmp_path):
+    op = opendal.AsyncOperator("fs", root=str(tmp_path))
+    await op.object("dir/a").write(b"a")
+    await op.object("dir/b").write(b"b")
+
+    names = sorted([o.name async for o in await op.object("dir/").list()])
+    assert names == ["a", "b"]
+
+
+def test_invalid_scheme():
+    with pytest.raises(opendal.UnsupportedError):
+        opendal.Operator("unknown")
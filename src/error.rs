@@ -66,6 +66,10 @@ pub enum ErrorKind {
     ObjectAlreadyExists,
     /// Requests that sent to this object is over the limit, please slow down.
     ObjectRateLimited,
+    /// Checksum of the object content doesn't match the expected one.
+    ///
+    /// For example, data has been corrupted during transfer or at rest.
+    ChecksumMismatch,
 }
 
 impl ErrorKind {
@@ -93,6 +97,7 @@ impl From<ErrorKind> for &'static str {
             ErrorKind::ObjectNotADirectory => "ObjectNotADirectory",
             ErrorKind::ObjectAlreadyExists => "ObjectAlreadyExists",
             ErrorKind::ObjectRateLimited => "ObjectRateLimited",
+            ErrorKind::ChecksumMismatch => "ChecksumMismatch",
         }
     }
 }
@@ -305,6 +310,7 @@ impl From<Error> for io::Error {
         let kind = match err.kind() {
             ErrorKind::ObjectNotFound => io::ErrorKind::NotFound,
             ErrorKind::ObjectPermissionDenied => io::ErrorKind::PermissionDenied,
+            ErrorKind::ChecksumMismatch => io::ErrorKind::InvalidData,
             _ => io::ErrorKind::Other,
         };
 
@@ -38,6 +38,7 @@ use crate::*;
 /// - If both `seekable` and `streamable`, we will return directly.
 /// - If not `streamable`, we will wrap with [`output::into_streamable_reader`].
 /// - If not `seekable`, we will wrap with [`output::into_reader::by_range`]
+///   (or [`output::into_blocking_reader::by_range`] for blocking readers)
 /// - If neither not supported, we will wrap both by_range and into_streamable.
 ///
 /// [`AccessorHint`]: crate::raw::AccessorHint
@@ -128,7 +129,9 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
             self.meta.hints().contains(AccessorHint::ReadIsStreamable),
         );
 
+        let range = args.range();
         let (rp, r) = self.inner.blocking_read(path, args)?;
+        let content_length = rp.metadata().content_length();
 
         match (seekable, streamable) {
             (true, true) => Ok((rp, CompleteBlockingReader::AlreadyComplete(r))),
@@ -136,10 +139,40 @@ impl<A: Accessor> CompleteReaderAccessor<A> {
                 let r = output::into_streamable_reader(r, 256 * 1024);
                 Ok((rp, CompleteBlockingReader::NeedStreamable(r)))
             }
-            (false, _) => Err(Error::new(
-                ErrorKind::Unsupported,
-                "non seekable blocking reader is not supported",
-            )),
+            _ => {
+                let (offset, size) = match (range.offset(), range.size()) {
+                    (Some(offset), _) => (offset, content_length),
+                    (None, None) => (0, content_length),
+                    (None, Some(size)) => {
+                        let om = self
+                            .inner
+                            .blocking_stat(path, OpStat::new())?
+                            .into_metadata();
+                        let total_size = om.content_length();
+                        let (offset, size) = if size > total_size {
+                            (0, total_size)
+                        } else {
+                            (total_size - size, size)
+                        };
+
+                        (offset, size)
+                    }
+                };
+                let r = output::into_blocking_reader::by_range(
+                    self.inner.clone(),
+                    path,
+                    r,
+                    offset,
+                    size,
+                );
+
+                if streamable {
+                    Ok((rp, CompleteBlockingReader::NeedSeekable(r)))
+                } else {
+                    let r = output::into_streamable_reader(r, 256 * 1024);
+                    Ok((rp, CompleteBlockingReader::NeedBoth(r)))
+                }
+            }
         }
     }
 }
@@ -217,7 +250,9 @@ impl<A: Accessor> output::Read for CompleteReader<A> {
 
 pub enum CompleteBlockingReader<A: Accessor> {
     AlreadyComplete(A::BlockingReader),
+    NeedSeekable(output::into_blocking_reader::RangeReader<A>),
     NeedStreamable(output::IntoStreamableReader<A::BlockingReader>),
+    NeedBoth(output::IntoStreamableReader<output::into_blocking_reader::RangeReader<A>>),
 }
 
 impl<A: Accessor> output::BlockingRead for CompleteBlockingReader<A> {
@@ -226,7 +261,9 @@ impl<A: Accessor> output::BlockingRead for CompleteBlockingReader<A> {
 
         match self {
             AlreadyComplete(r) => r.read(buf),
+            NeedSeekable(r) => r.read(buf),
             NeedStreamable(r) => r.read(buf),
+            NeedBoth(r) => r.read(buf),
         }
     }
 
@@ -235,7 +272,9 @@ impl<A: Accessor> output::BlockingRead for CompleteBlockingReader<A> {
 
         match self {
             AlreadyComplete(r) => r.seek(pos),
+            NeedSeekable(r) => r.seek(pos),
             NeedStreamable(r) => r.seek(pos),
+            NeedBoth(r) => r.seek(pos),
         }
     }
 
@@ -244,7 +283,9 @@ impl<A: Accessor> output::BlockingRead for CompleteBlockingReader<A> {
 
         match self {
             AlreadyComplete(r) => r.next(),
+            NeedSeekable(r) => r.next(),
             NeedStreamable(r) => r.next(),
+            NeedBoth(r) => r.next(),
         }
     }
 }
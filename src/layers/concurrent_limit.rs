@@ -208,6 +208,26 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
         self.inner.abort_multipart(path, args).await
     }
 
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("semaphore must be valid");
+
+        self.inner.read_ranges(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let _permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("semaphore must be valid");
+
+        self.inner.batch(args).await
+    }
+
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         let _permit = self
             .semaphore
@@ -272,6 +292,59 @@ impl<A: Accessor> LayeredAccessor for ConcurrentLimitAccessor<A> {
             .blocking_list(path, args)
             .map(|(rp, it)| (rp, ConcurrentLimitWrapper::new(it, permit)))
     }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let _permit = self
+            .semaphore
+            .try_acquire()
+            .expect("semaphore must be valid");
+
+        self.inner.blocking_create_multipart(path, args)
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let _permit = self
+            .semaphore
+            .try_acquire()
+            .expect("semaphore must be valid");
+
+        self.inner.blocking_write_multipart(path, args, r)
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let _permit = self
+            .semaphore
+            .try_acquire()
+            .expect("semaphore must be valid");
+
+        self.inner.blocking_complete_multipart(path, args)
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let _permit = self
+            .semaphore
+            .try_acquire()
+            .expect("semaphore must be valid");
+
+        self.inner.blocking_abort_multipart(path, args)
+    }
 }
 
 pub struct ConcurrentLimitWrapper<R> {
@@ -210,6 +210,45 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
             .await
     }
 
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        self.inner
+            .read_ranges(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::ReadRanges.into_static())
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+            .await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.inner
+            .batch(args)
+            .map_ok(|v| {
+                let res = match v.into_results() {
+                    BatchedResults::Delete(v) => BatchedResults::Delete(
+                        v.into_iter()
+                            .map(|(path, res)| {
+                                let res = res.map_err(|err| {
+                                    err.with_operation(Operation::Delete.into_static())
+                                        .with_context("service", self.meta.scheme())
+                                        .with_context("path", &path)
+                                });
+                                (path, res)
+                            })
+                            .collect(),
+                    ),
+                };
+
+                RpBatch::new(res)
+            })
+            .map_err(|err| {
+                err.with_operation(Operation::Batch.into_static())
+                    .with_context("service", self.meta.scheme())
+            })
+            .await
+    }
+
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         self.inner.blocking_create(path, args).map_err(|err| {
             err.with_operation(Operation::BlockingCreate.into_static())
@@ -274,6 +313,63 @@ impl<A: Accessor> LayeredAccessor for ErrorContextAccessor<A> {
                     .with_context("path", path)
             })
     }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.inner
+            .blocking_create_multipart(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::BlockingCreateMultipart.into_static())
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        self.inner
+            .blocking_write_multipart(path, args, r)
+            .map_err(|err| {
+                err.with_operation(Operation::BlockingWriteMultipart.into_static())
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner
+            .blocking_complete_multipart(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::BlockingCompleteMultipart.into_static())
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.inner
+            .blocking_abort_multipart(path, args)
+            .map_err(|err| {
+                err.with_operation(Operation::BlockingAbortMultipart.into_static())
+                    .with_context("service", self.meta.scheme())
+                    .with_context("path", path)
+            })
+    }
 }
 
 pub struct ErrorContextWrapper<T> {
@@ -685,6 +685,82 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
             .await
     }
 
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        let count = args.ranges().len();
+
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} count={count} -> started",
+            self.scheme,
+            Operation::ReadRanges,
+            path
+        );
+
+        self.inner
+            .read_ranges(path, args)
+            .inspect_ok(|_| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} count={count} -> finished",
+                    self.scheme,
+                    Operation::ReadRanges,
+                    path
+                );
+            })
+            .inspect_err(|err| {
+                if let Some(lvl) = self.err_level(err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} count={count} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::ReadRanges,
+                        path,
+                        self.err_status(err)
+                    );
+                }
+            })
+            .await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let (op, count) = (args.operation().operation(), args.operation().len());
+
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={}-{op} count={count} -> started",
+            self.scheme,
+            Operation::Batch,
+        );
+
+        self.inner
+            .batch(args)
+            .inspect_ok(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={}-{op} count={count} -> finished: {}, succeed: {}, failed: {}",
+                    self.scheme,
+                    Operation::Batch,
+                    v.results().len_ok() + v.results().len_err(),
+                    v.results().len_ok(),
+                    v.results().len_err(),
+                );
+            })
+            .inspect_err(|err| {
+                if let Some(lvl) = self.err_level(err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={}-{op} count={count} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::Batch,
+                        self.err_status(err)
+                    );
+                }
+            })
+            .await
+    }
+
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         debug!(
             target: LOGGING_TARGET,
@@ -936,6 +1012,195 @@ impl<A: Accessor> LayeredAccessor for LoggingAccessor<A> {
                 err
             })
     }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} -> started",
+            self.scheme,
+            Operation::BlockingCreateMultipart,
+            path
+        );
+
+        self.inner
+            .blocking_create_multipart(path, args)
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} -> finished",
+                    self.scheme,
+                    Operation::BlockingCreateMultipart,
+                    path
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.err_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::BlockingCreateMultipart,
+                        path,
+                        self.err_status(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} upload_id={} part_number={:?} size={:?} -> started",
+            self.scheme,
+            Operation::BlockingWriteMultipart,
+            path,
+            args.upload_id(),
+            args.part_number(),
+            args.size()
+        );
+
+        let reader = LoggingReader::new(
+            self.scheme,
+            Operation::BlockingWriteMultipart,
+            path,
+            Some(args.size()),
+            r,
+            self.failure_level,
+        );
+
+        self.inner
+            .blocking_write_multipart(path, args.clone(), Box::new(reader))
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} upload_id={} part_number={:?} size={:?} -> written",
+                    self.scheme,
+                    Operation::BlockingWriteMultipart,
+                    path,
+                    args.upload_id(),
+                    args.part_number(),
+                    args.size()
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.err_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} upload_id={} part_number={:?} size={:?} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::BlockingWriteMultipart,
+                        path,
+                        args.upload_id(),
+                        args.part_number(),
+                        args.size(),
+                        self.err_status(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} upload_id={} -> started",
+            self.scheme,
+            Operation::BlockingCompleteMultipart,
+            path,
+            args.upload_id()
+        );
+
+        self.inner
+            .blocking_complete_multipart(path, args.clone())
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} upload_id={} -> finished",
+                    self.scheme,
+                    Operation::BlockingCompleteMultipart,
+                    path,
+                    args.upload_id()
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.err_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} upload_id={} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::BlockingCompleteMultipart,
+                        path,
+                        args.upload_id(),
+                        self.err_status(&err)
+                    );
+                }
+                err
+            })
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        debug!(
+            target: LOGGING_TARGET,
+            "service={} operation={} path={} upload_id={} -> started",
+            self.scheme,
+            Operation::BlockingAbortMultipart,
+            path,
+            args.upload_id()
+        );
+
+        self.inner
+            .blocking_abort_multipart(path, args.clone())
+            .map(|v| {
+                debug!(
+                    target: LOGGING_TARGET,
+                    "service={} operation={} path={} upload_id={} -> finished",
+                    self.scheme,
+                    Operation::BlockingAbortMultipart,
+                    path,
+                    args.upload_id()
+                );
+                v
+            })
+            .map_err(|err| {
+                if let Some(lvl) = self.err_level(&err) {
+                    log!(
+                        target: LOGGING_TARGET,
+                        lvl,
+                        "service={} operation={} path={} upload_id={} -> {}: {err:?}",
+                        self.scheme,
+                        Operation::BlockingAbortMultipart,
+                        path,
+                        args.upload_id(),
+                        self.err_status(&err)
+                    );
+                }
+                err
+            })
+    }
 }
 
 /// `LoggingReader` is a wrapper of `BytesReader`, with logging functionality.
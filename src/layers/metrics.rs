@@ -176,6 +176,12 @@ struct MetricsHandler {
     requests_total_abort_multipart: Counter,
     requests_duration_seconds_abort_multipart: Histogram,
 
+    requests_total_batch: Counter,
+    requests_duration_seconds_batch: Histogram,
+
+    requests_total_read_ranges: Counter,
+    requests_duration_seconds_read_ranges: Histogram,
+
     requests_total_blocking_create: Counter,
     requests_duration_seconds_blocking_create: Histogram,
 
@@ -195,6 +201,19 @@ struct MetricsHandler {
 
     requests_total_blocking_list: Counter,
     requests_duration_seconds_blocking_list: Histogram,
+
+    requests_total_blocking_create_multipart: Counter,
+    requests_duration_seconds_blocking_create_multipart: Histogram,
+
+    requests_total_blocking_write_multipart: Counter,
+    requests_duration_seconds_blocking_write_multipart: Histogram,
+    bytes_total_blocking_write_multipart: Counter,
+
+    requests_total_blocking_complete_multipart: Counter,
+    requests_duration_seconds_blocking_complete_multipart: Histogram,
+
+    requests_total_blocking_abort_multipart: Counter,
+    requests_duration_seconds_blocking_abort_multipart: Histogram,
 }
 
 impl MetricsHandler {
@@ -350,6 +369,28 @@ impl MetricsHandler {
                 LABEL_OPERATION => Operation::AbortMultipart.into_static(),
             ),
 
+            requests_total_batch: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::Batch.into_static(),
+            ),
+            requests_duration_seconds_batch: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::Batch.into_static(),
+            ),
+
+            requests_total_read_ranges: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::ReadRanges.into_static(),
+            ),
+            requests_duration_seconds_read_ranges: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::ReadRanges.into_static(),
+            ),
+
             requests_total_blocking_create: register_counter!(
                 METRIC_REQUESTS_TOTAL,
                 LABEL_SERVICE => service,
@@ -425,6 +466,55 @@ impl MetricsHandler {
                 LABEL_SERVICE => service,
                 LABEL_OPERATION => Operation::BlockingList.into_static(),
             ),
+
+            requests_total_blocking_create_multipart: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingCreateMultipart.into_static(),
+            ),
+            requests_duration_seconds_blocking_create_multipart: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingCreateMultipart.into_static(),
+            ),
+
+            requests_total_blocking_write_multipart: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingWriteMultipart.into_static(),
+            ),
+            requests_duration_seconds_blocking_write_multipart: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingWriteMultipart.into_static(),
+            ),
+            bytes_total_blocking_write_multipart: register_counter!(
+                METRIC_BYTES_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingWriteMultipart.into_static(),
+            ),
+
+            requests_total_blocking_complete_multipart: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingCompleteMultipart.into_static(),
+            ),
+            requests_duration_seconds_blocking_complete_multipart: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingCompleteMultipart.into_static(),
+            ),
+
+            requests_total_blocking_abort_multipart: register_counter!(
+                METRIC_REQUESTS_TOTAL,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingAbortMultipart.into_static(),
+            ),
+            requests_duration_seconds_blocking_abort_multipart: register_histogram!(
+                METRIC_REQUESTS_DURATION_SECONDS,
+                LABEL_SERVICE => service,
+                LABEL_OPERATION => Operation::BlockingAbortMultipart.into_static(),
+            ),
         }
     }
 
@@ -742,6 +832,46 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
             .await
     }
 
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        self.handle.requests_total_read_ranges.increment(1);
+
+        let start = Instant::now();
+
+        self.inner
+            .read_ranges(path, args)
+            .inspect_ok(|_| {
+                let dur = start.elapsed().as_secs_f64();
+
+                self.handle
+                    .requests_duration_seconds_read_ranges
+                    .record(dur);
+            })
+            .inspect_err(|err| {
+                self.handle
+                    .increment_errors_total(Operation::ReadRanges, err.kind());
+            })
+            .await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.handle.requests_total_batch.increment(1);
+
+        let start = Instant::now();
+
+        self.inner
+            .batch(args)
+            .inspect_ok(|_| {
+                let dur = start.elapsed().as_secs_f64();
+
+                self.handle.requests_duration_seconds_batch.record(dur);
+            })
+            .inspect_err(|err| {
+                self.handle
+                    .increment_errors_total(Operation::Batch, err.kind());
+            })
+            .await
+    }
+
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         self.handle.requests_total_blocking_create.increment(1);
 
@@ -870,6 +1000,114 @@ impl<A: Accessor> LayeredAccessor for MetricsAccessor<A> {
             e
         })
     }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.handle
+            .requests_total_blocking_create_multipart
+            .increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_create_multipart(path, args);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_create_multipart
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingCreateMultipart, e.kind());
+            e
+        })
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        self.handle
+            .requests_total_blocking_write_multipart
+            .increment(1);
+
+        let r = Box::new(MetricReader::new(
+            r,
+            Operation::BlockingWriteMultipart,
+            self.handle.clone(),
+            self.handle.bytes_total_blocking_write_multipart.clone(),
+            self.handle
+                .requests_duration_seconds_blocking_write_multipart
+                .clone(),
+            None,
+        ));
+
+        let start = Instant::now();
+        let result = self.inner.blocking_write_multipart(path, args, r);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_write_multipart
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingWriteMultipart, e.kind());
+            e
+        })
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.handle
+            .requests_total_blocking_complete_multipart
+            .increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_complete_multipart(path, args);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_complete_multipart
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingCompleteMultipart, e.kind());
+            e
+        })
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.handle
+            .requests_total_blocking_abort_multipart
+            .increment(1);
+
+        let start = Instant::now();
+        let result = self.inner.blocking_abort_multipart(path, args);
+        let dur = start.elapsed().as_secs_f64();
+
+        self.handle
+            .requests_duration_seconds_blocking_abort_multipart
+            .record(dur);
+
+        result.map_err(|e| {
+            self.handle
+                .increment_errors_total(Operation::BlockingAbortMultipart, e.kind());
+            e
+        })
+    }
 }
 
 pub struct MetricReader<R> {
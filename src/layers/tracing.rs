@@ -228,6 +228,16 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
         self.inner.abort_multipart(path, args).await
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        self.inner.read_ranges(path, args).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.inner.batch(args).await
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         self.inner.blocking_create(path, args)
@@ -266,6 +276,44 @@ impl<A: Accessor> LayeredAccessor for TracingAccessor<A> {
             .blocking_list(path, args)
             .map(|(rp, it)| (rp, TracingWrapper::new(Span::current(), it)))
     }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.inner.blocking_create_multipart(path, args)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self, r))]
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let r = Box::new(TracingWrapper::new(Span::current(), r));
+        self.inner.blocking_write_multipart(path, args, r)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.inner.blocking_complete_multipart(path, args)
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.inner.blocking_abort_multipart(path, args)
+    }
 }
 
 pub struct TracingWrapper<R> {
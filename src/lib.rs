@@ -76,6 +76,7 @@ pub use operator::OperatorBuilder;
 pub use operator::OperatorMetadata;
 
 mod object;
+pub use object::BlockingObjectWriter;
 pub use object::Object;
 pub use object::ObjectLister;
 pub use object::ObjectMetadata;
@@ -115,7 +116,7 @@ mod tests {
     fn assert_size() {
         assert_eq!(88, size_of::<AccessorMetadata>());
         assert_eq!(16, size_of::<Operator>());
-        assert_eq!(16, size_of::<BatchOperator>());
+        assert_eq!(32, size_of::<BatchOperator>());
         assert_eq!(184, size_of::<output::Entry>());
         assert_eq!(48, size_of::<Object>());
         assert_eq!(160, size_of::<ObjectMetadata>());
@@ -47,15 +47,15 @@ impl BlockingObjectReader {
     ) -> Result<Self> {
         let acc_meta = acc.metadata();
 
-        let r = if acc_meta.hints().contains(AccessorHint::ReadIsSeekable) {
-            let (_, r) = acc.blocking_read(path, op)?;
-            r
-        } else {
+        if !acc_meta.hints().contains(AccessorHint::ReadIsSeekable) {
             return Err(Error::new(
                 ErrorKind::Unsupported,
                 "non seekable blocking reader is not supported",
             ));
-        };
+        }
+
+        let is_full = op.range().is_full();
+        let (rp, r) = acc.blocking_read(path, op)?;
 
         let r = if acc_meta.hints().contains(AccessorHint::ReadIsStreamable) {
             r
@@ -64,6 +64,14 @@ impl BlockingObjectReader {
             Box::new(output::into_streamable_reader(r, 256 * 1024))
         };
 
+        // Verify the content if we are reading the whole object.
+        let r: output::BlockingReader = match rp.into_metadata().verifiable_checksum() {
+            Some((algorithm, expected)) if is_full => {
+                Box::new(output::into_verified_reader(r, algorithm, &expected))
+            }
+            _ => r,
+        };
+
         Ok(BlockingObjectReader { inner: r })
     }
 }
@@ -0,0 +1,280 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::mem;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use parking_lot::Mutex;
+
+use crate::ops::*;
+use crate::raw::*;
+use crate::*;
+
+/// The default size of parts that uploaded by [`BlockingObjectWriter`].
+///
+/// Most services require parts except the last one to be larger than 5 MiB.
+///
+/// TODO: make this value configurable
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// BlockingObjectWriter is the public API for users to write data into
+/// object via [`std::io::Write`].
+///
+/// Written data will be buffered and uploaded as parts of a multipart
+/// upload once the buffer is full. If the underlying service doesn't
+/// support multipart, all data will be buffered and written at
+/// [`BlockingObjectWriter::close`].
+///
+/// # Notes
+///
+/// Users **MUST** call [`BlockingObjectWriter::close`] after all data has
+/// been written, otherwise the object will not be created.
+pub struct BlockingObjectWriter {
+    acc: FusedAccessor,
+    path: String,
+    meta: Arc<Mutex<ObjectMetadata>>,
+
+    buf: Vec<u8>,
+    upload_id: Option<String>,
+    parts: Vec<ObjectPart>,
+    written: u64,
+}
+
+impl BlockingObjectWriter {
+    /// Create a new blocking object writer.
+    pub(crate) fn create(acc: FusedAccessor, path: &str, meta: Arc<Mutex<ObjectMetadata>>) -> Self {
+        BlockingObjectWriter {
+            acc,
+            path: path.to_string(),
+            meta,
+
+            buf: Vec::new(),
+            upload_id: None,
+            parts: Vec::new(),
+            written: 0,
+        }
+    }
+
+    /// Upload all buffered data as a new part.
+    fn write_part(&mut self) -> Result<()> {
+        let upload_id = match &self.upload_id {
+            Some(upload_id) => upload_id.clone(),
+            None => {
+                let rp = self
+                    .acc
+                    .blocking_create_multipart(&self.path, OpCreateMultipart::new())?;
+                self.upload_id = Some(rp.upload_id().to_string());
+                rp.upload_id().to_string()
+            }
+        };
+
+        let bs = Bytes::from(mem::take(&mut self.buf));
+        let size = bs.len() as u64;
+        // Part number starts from 1.
+        let op = OpWriteMultipart::new(upload_id, self.parts.len() + 1, size);
+        let rp = match self.acc.blocking_write_multipart(
+            &self.path,
+            op,
+            Box::new(io::Cursor::new(bs.clone())),
+        ) {
+            Ok(rp) => rp,
+            Err(err) => {
+                // Keep the buffered data so that this part can be retried.
+                self.buf = bs.to_vec();
+                return Err(err);
+            }
+        };
+
+        self.parts.push(rp.into_object_part());
+        self.written += size;
+        Ok(())
+    }
+
+    /// Close the writer and make sure all data has been written.
+    ///
+    /// - If no part has been uploaded, all buffered data will be written
+    ///   in one request.
+    /// - Otherwise, the left data will be uploaded as the last part and
+    ///   the multipart upload will be completed.
+    pub fn close(mut self) -> Result<()> {
+        let upload_id = match self.upload_id.clone() {
+            Some(upload_id) => upload_id,
+            None => {
+                let bs = mem::take(&mut self.buf);
+                let size = bs.len() as u64;
+                self.acc.blocking_write(
+                    &self.path,
+                    OpWrite::new(size),
+                    Box::new(io::Cursor::new(bs)),
+                )?;
+
+                self.written = size;
+                self.update_metadata();
+                return Ok(());
+            }
+        };
+
+        if !self.buf.is_empty() {
+            self.write_part()?;
+        }
+
+        let parts = mem::take(&mut self.parts);
+        self.acc
+            .blocking_complete_multipart(&self.path, OpCompleteMultipart::new(upload_id, parts))?;
+
+        self.update_metadata();
+        Ok(())
+    }
+
+    /// Abort the writer and remove all parts that already uploaded.
+    pub fn abort(self) -> Result<()> {
+        if let Some(upload_id) = self.upload_id {
+            self.acc
+                .blocking_abort_multipart(&self.path, OpAbortMultipart::new(upload_id))?;
+        }
+
+        Ok(())
+    }
+
+    /// Always write latest metadata into cache.
+    fn update_metadata(&self) {
+        let mut guard = self.meta.lock();
+        *guard = ObjectMetadata::new(ObjectMode::FILE).with_content_length(self.written);
+    }
+}
+
+impl io::Write for BlockingObjectWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+
+        if self.buf.len() >= DEFAULT_PART_SIZE
+            && self
+                .acc
+                .metadata()
+                .capabilities()
+                .contains(AccessorCapability::Multipart)
+        {
+            if let Err(err) = self.write_part() {
+                // Drop data of this call so that callers can retry it.
+                self.buf.truncate(self.buf.len() - buf.len());
+                return Err(err.into());
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Parts must be large enough, so we don't upload anything while
+    /// flushing. Buffered data will be written at `close`.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::io::Write;
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// MockService fails the first `write_multipart` and records the
+    /// content of succeeded parts.
+    #[derive(Debug, Default)]
+    struct MockService {
+        attempt: Mutex<usize>,
+        parts: Mutex<Vec<(usize, Vec<u8>)>>,
+    }
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = output::Reader;
+        type BlockingReader = output::BlockingReader;
+        type Pager = output::Pager;
+        type BlockingPager = output::BlockingPager;
+
+        fn metadata(&self) -> AccessorMetadata {
+            let mut am = AccessorMetadata::default();
+            am.set_capabilities(AccessorCapability::Multipart | AccessorCapability::Blocking);
+
+            am
+        }
+
+        fn blocking_create_multipart(
+            &self,
+            _: &str,
+            _: OpCreateMultipart,
+        ) -> Result<RpCreateMultipart> {
+            Ok(RpCreateMultipart::new("upload_id"))
+        }
+
+        fn blocking_write_multipart(
+            &self,
+            _: &str,
+            args: OpWriteMultipart,
+            mut r: input::BlockingReader,
+        ) -> Result<RpWriteMultipart> {
+            let mut attempt = self.attempt.lock();
+            *attempt += 1;
+            if *attempt == 1 {
+                return Err(Error::new(ErrorKind::Unexpected, "write part failed"));
+            }
+
+            let mut bs = Vec::new();
+            r.read_to_end(&mut bs).expect("read must succeed");
+            self.parts.lock().push((args.part_number(), bs));
+
+            Ok(RpWriteMultipart::new(args.part_number(), "etag"))
+        }
+
+        fn blocking_complete_multipart(
+            &self,
+            _: &str,
+            _: OpCompleteMultipart,
+        ) -> Result<RpCompleteMultipart> {
+            Ok(RpCompleteMultipart::default())
+        }
+    }
+
+    #[test]
+    fn test_write_part_failed() {
+        let srv = Arc::new(MockService::default());
+        let mut w = BlockingObjectWriter::create(
+            srv.clone(),
+            "path",
+            Arc::new(Mutex::new(ObjectMetadata::new(ObjectMode::FILE))),
+        );
+
+        let first = vec![1; DEFAULT_PART_SIZE - 1];
+        w.write_all(&first).expect("write must succeed");
+
+        // This write fills the buffer and triggers a failed upload.
+        let second = vec![2; 1];
+        w.write_all(&second).expect_err("write must fail");
+        assert_eq!(w.buf, first, "buffered data must be kept");
+
+        // Retry should upload all data exactly once.
+        w.write_all(&second).expect("write must succeed");
+        w.close().expect("close must succeed");
+
+        let parts = srv.parts.lock();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].0, 1);
+        assert_eq!(parts[0].1, [first, second].concat());
+    }
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use time::OffsetDateTime;
 
+use crate::ops::ChecksumAlgorithm;
 use crate::raw::*;
 use crate::*;
 
@@ -42,6 +45,8 @@ pub struct ObjectMetadata {
     content_length: Option<u64>,
     /// Content MD5 of this object.
     content_md5: Option<String>,
+    /// Checksum of the whole content, which is base64 encoded.
+    checksum: Option<(ChecksumAlgorithm, String)>,
     /// Content Type of this object.
     content_type: Option<String>,
     /// Content Range of this object.
@@ -62,6 +67,7 @@ impl ObjectMetadata {
 
             content_length: None,
             content_md5: None,
+            checksum: None,
             content_type: None,
             content_range: None,
             last_modified: None,
@@ -156,6 +162,46 @@ impl ObjectMetadata {
         self
     }
 
+    /// Checksum of the whole object content, the value is base64 encoded
+    /// as returned by [`ChecksumAlgorithm::checksum`].
+    ///
+    /// OpenDAL will verify the content against it while reading the whole
+    /// object.
+    pub fn checksum(&self) -> Option<(ChecksumAlgorithm, &str)> {
+        self.checksum.as_ref().map(|(a, v)| (*a, v.as_str()))
+    }
+
+    /// Set checksum of this object.
+    pub fn set_checksum(&mut self, algorithm: ChecksumAlgorithm, checksum: &str) -> &mut Self {
+        self.checksum = Some((algorithm, checksum.to_string()));
+        self
+    }
+
+    /// Set checksum of this object.
+    pub fn with_checksum(mut self, algorithm: ChecksumAlgorithm, checksum: &str) -> Self {
+        self.checksum = Some((algorithm, checksum.to_string()));
+        self
+    }
+
+    /// Get the checksum that can be used to verify the whole content.
+    ///
+    /// Falls back to `content_md5` if checksum is not set. Only a real
+    /// `Content-MD5`, which is a base64 encoded md5 digest, is accepted.
+    /// ETag is not the md5 of content for multipart uploads or objects
+    /// encrypted by SSE-KMS/SSE-C, so services must not set it as
+    /// `content_md5`.
+    pub(crate) fn verifiable_checksum(&self) -> Option<(ChecksumAlgorithm, String)> {
+        if let Some((algorithm, v)) = &self.checksum {
+            return Some((*algorithm, v.clone()));
+        }
+
+        let md5 = self.content_md5.as_deref()?;
+        match BASE64_STANDARD.decode(md5) {
+            Ok(digest) if digest.len() == 16 => Some((ChecksumAlgorithm::Md5, md5.to_string())),
+            _ => None,
+        }
+    }
+
     /// Content Type of this object.
     ///
     /// Content Type is defined by [RFC 9110](https://httpwg.org/specs/rfc9110.html#field.content-type).
@@ -32,6 +32,11 @@ pub use reader::ObjectReader;
 mod blocking_reader;
 pub use blocking_reader::BlockingObjectReader;
 
+mod blocking_writer;
+pub use blocking_writer::BlockingObjectWriter;
+
 mod list;
 pub use list::BlockingObjectLister;
 pub use list::ObjectLister;
+
+mod ranges;
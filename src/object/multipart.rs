@@ -54,6 +54,16 @@ impl ObjectMultipart {
         self.acc.clone().into()
     }
 
+    /// Path of this multipart object.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Upload id of this multipart object.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
     /// Write a new [`ObjectPart`] with specified part number.
     pub async fn write(&self, part_number: usize, bs: impl Into<Vec<u8>>) -> Result<ObjectPart> {
         let bs = bs.into();
@@ -67,6 +77,20 @@ impl ObjectMultipart {
         Ok(rp.into_object_part())
     }
 
+    /// Write a new [`ObjectPart`] with specified part number.
+    ///
+    /// This function is the blocking version of [`ObjectMultipart::write`].
+    pub fn blocking_write(&self, part_number: usize, bs: impl Into<Vec<u8>>) -> Result<ObjectPart> {
+        let bs = bs.into();
+
+        let op = OpWriteMultipart::new(self.upload_id.clone(), part_number, bs.len() as u64);
+        let r = std::io::Cursor::new(bs);
+        let rp = self
+            .acc
+            .blocking_write_multipart(&self.path, op, Box::new(r))?;
+        Ok(rp.into_object_part())
+    }
+
     /// Complete multipart uploads with specified parts.
     ///
     /// # Notes
@@ -81,6 +105,16 @@ impl ObjectMultipart {
         Ok(Object::new(self.operator(), &self.path))
     }
 
+    /// Complete multipart uploads with specified parts.
+    ///
+    /// This function is the blocking version of [`ObjectMultipart::complete`].
+    pub fn blocking_complete(&self, parts: Vec<ObjectPart>) -> Result<Object> {
+        let op = OpCompleteMultipart::new(self.upload_id.clone(), parts);
+        self.acc.blocking_complete_multipart(&self.path, op)?;
+
+        Ok(Object::new(self.operator(), &self.path))
+    }
+
     /// Abort multipart uploads.
     ///
     /// # Notes
@@ -95,6 +129,16 @@ impl ObjectMultipart {
         Ok(())
     }
 
+    /// Abort multipart uploads.
+    ///
+    /// This function is the blocking version of [`ObjectMultipart::abort`].
+    pub fn blocking_abort(&self) -> Result<()> {
+        let op = OpAbortMultipart::new(self.upload_id.clone());
+        let _ = self.acc.blocking_abort_multipart(&self.path, op)?;
+
+        Ok(())
+    }
+
     /// Presign an operation for write multipart.
     ///
     /// # TODO
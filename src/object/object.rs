@@ -14,19 +14,32 @@
 
 use std::fmt::Debug;
 use std::io::Read;
+use std::io::SeekFrom;
+use std::ops::Range;
 use std::ops::RangeBounds;
+use std::path::Path;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use futures::io::Cursor;
+use futures::stream;
 use futures::AsyncReadExt;
+use futures::StreamExt;
+use futures::TryStreamExt;
 use parking_lot::Mutex;
 use parking_lot::MutexGuard;
 use time::Duration;
 use time::OffsetDateTime;
+use tokio::io::AsyncSeekExt;
+use tokio::io::AsyncWriteExt;
 use tokio::io::ReadBuf;
 
+use super::ranges::chunk_ranges;
+use super::ranges::merge_ranges;
+use super::ranges::slice_ranges;
 use super::BlockingObjectLister;
 use super::BlockingObjectReader;
+use super::BlockingObjectWriter;
 use super::ObjectLister;
 use crate::ops::*;
 use crate::raw::*;
@@ -373,7 +386,8 @@ impl Object {
 
         let (rp, mut s) = self.acc.read(self.path(), op).await?;
 
-        let length = rp.into_metadata().content_length() as usize;
+        let meta = rp.into_metadata();
+        let length = meta.content_length() as usize;
         let mut buffer = Vec::with_capacity(length);
 
         let dst = buffer.spare_capacity_mut();
@@ -392,6 +406,14 @@ impl Object {
         // Safety: this buffer has been filled.
         unsafe { buffer.set_len(length) }
 
+        if br.is_full() {
+            verify_checksum(&meta, &buffer).map_err(|err| {
+                err.with_operation("Object:range_read")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path())
+            })?;
+        }
+
         Ok(buffer)
     }
 
@@ -429,7 +451,8 @@ impl Object {
             .acc
             .blocking_read(self.path(), OpRead::new().with_range(br))?;
 
-        let mut buffer = Vec::with_capacity(rp.into_metadata().content_length() as usize);
+        let meta = rp.into_metadata();
+        let mut buffer = Vec::with_capacity(meta.content_length() as usize);
         s.read_to_end(&mut buffer).map_err(|err| {
             Error::new(ErrorKind::Unexpected, "blocking range read failed")
                 .with_operation("Object::blocking_range_read")
@@ -439,9 +462,161 @@ impl Object {
                 .set_source(err)
         })?;
 
+        if br.is_full() {
+            verify_checksum(&meta, &buffer).map_err(|err| {
+                err.with_operation("Object::blocking_range_read")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path())
+            })?;
+        }
+
         Ok(buffer)
     }
 
+    /// Read multiple ranges of object into bytes.
+    ///
+    /// Ranges that are close enough will be merged into one request and
+    /// merged ranges will be fetched concurrently. Please use
+    /// [`Object::read_ranges_with`] to tune this behavior.
+    ///
+    /// # Notes
+    ///
+    /// - Returned data is in the same order of input ranges.
+    /// - The returning content's length may be smaller than the range specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// # o.write(vec![0; 4096]).await?;
+    /// let bs = o.read_ranges(vec![0..1024, 2048..3072]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_ranges(&self, ranges: Vec<Range<u64>>) -> Result<Vec<Bytes>> {
+        self.read_ranges_with(OpReadRanges::new(ranges)).await
+    }
+
+    /// Read multiple ranges of object into bytes with extra options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::ops::OpReadRanges;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// # o.write(vec![0; 4096]).await?;
+    /// let op = OpReadRanges::new(vec![0..1024, 2048..3072])
+    ///     .with_gap(4096)
+    ///     .with_concurrent(4);
+    /// let bs = o.read_ranges_with(op).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_ranges_with(&self, op: OpReadRanges) -> Result<Vec<Bytes>> {
+        if !validate_path(self.path(), ObjectMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::ObjectIsADirectory, "read path is a directory")
+                    .with_operation("Object::read_ranges")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path()),
+            );
+        }
+
+        let merged = merge_ranges(op.ranges(), op.gap());
+        if merged.is_empty() {
+            return Ok(slice_ranges(op.ranges(), &merged, &[]));
+        }
+
+        let data: Vec<Bytes> = if self
+            .acc
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::ReadRanges)
+        {
+            self.acc
+                .read_ranges(
+                    self.path(),
+                    OpReadRanges::new(merged.clone()).with_concurrent(op.concurrent()),
+                )
+                .await?
+                .into_data()
+        } else {
+            stream::iter(merged.iter().cloned())
+                .map(|r| self.range_read(r))
+                .buffered(op.concurrent())
+                .map_ok(Bytes::from)
+                .try_collect()
+                .await?
+        };
+
+        if data.len() != merged.len() {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "read_ranges returned data doesn't match ranges",
+            )
+            .with_operation("Object::read_ranges")
+            .with_context("service", self.accessor().metadata().scheme().into_static())
+            .with_context("path", self.path())
+            .with_context("expect", merged.len().to_string())
+            .with_context("actual", data.len().to_string()));
+        }
+
+        Ok(slice_ranges(op.ranges(), &merged, &data))
+    }
+
+    /// Read multiple ranges of object into bytes.
+    ///
+    /// Ranges that are close enough will be merged into one request, and
+    /// merged ranges will be fetched one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// # o.blocking_write(vec![0; 4096])?;
+    /// let bs = o.blocking_read_ranges(vec![0..1024, 2048..3072])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocking_read_ranges(&self, ranges: Vec<Range<u64>>) -> Result<Vec<Bytes>> {
+        self.blocking_read_ranges_with(OpReadRanges::new(ranges))
+    }
+
+    /// Read multiple ranges of object into bytes with extra options.
+    ///
+    /// `concurrent` in [`OpReadRanges`] will be ignored since all merged
+    /// ranges are fetched one by one.
+    pub fn blocking_read_ranges_with(&self, op: OpReadRanges) -> Result<Vec<Bytes>> {
+        if !validate_path(self.path(), ObjectMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::ObjectIsADirectory, "read path is a directory")
+                    .with_operation("Object::blocking_read_ranges")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path()),
+            );
+        }
+
+        let merged = merge_ranges(op.ranges(), op.gap());
+        let data = merged
+            .iter()
+            .map(|r| self.blocking_range_read(r.clone()).map(Bytes::from))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(slice_ranges(op.ranges(), &merged, &data))
+    }
+
     /// Create a new reader which can read the whole object.
     ///
     /// # Examples
@@ -546,6 +721,235 @@ impl Object {
         BlockingObjectReader::create(self.accessor(), self.path(), self.meta.clone(), op)
     }
 
+    /// Create a new reader which prefetches chunks ahead of current position.
+    ///
+    /// This reader is suitable for sequential scans of large objects: it
+    /// keeps multiple chunk-sized reads in flight instead of waiting for
+    /// every read call. Please use [`Object::prefetch_reader_with`] to tune
+    /// the chunk size and memory usage.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// let r = o.prefetch_reader().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn prefetch_reader(&self) -> Result<ObjectReader> {
+        self.prefetch_reader_with(OpPrefetch::new()).await
+    }
+
+    /// Create a new reader which prefetches chunks with extra options.
+    ///
+    /// # Notes
+    ///
+    /// - The number of chunks in flight will grow while reading sequentially
+    ///   until reaching the limit of `op`.
+    /// - Seeking outside current chunk will drop all prefetched chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::ops::OpPrefetch;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// let op = OpPrefetch::new()
+    ///     .with_chunk_size(8 * 1024 * 1024)
+    ///     .with_concurrent(8);
+    /// let r = o.prefetch_reader_with(op).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn prefetch_reader_with(&self, op: OpPrefetch) -> Result<ObjectReader> {
+        if !validate_path(self.path(), ObjectMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::ObjectIsADirectory, "read path is a directory")
+                    .with_operation("Object::prefetch_reader")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path()),
+            );
+        }
+
+        let meta = self.metadata().await?;
+
+        Ok(ObjectReader::create_prefetch(
+            self.accessor(),
+            self.path(),
+            &meta,
+            op,
+        ))
+    }
+
+    /// Download the whole object into a bytes by fetching chunks
+    /// concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::ops::OpPrefetch;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// # o.write(vec![0; 4096]).await?;
+    /// let bs = o.download(OpPrefetch::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download(&self, op: OpPrefetch) -> Result<Vec<u8>> {
+        if !validate_path(self.path(), ObjectMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::ObjectIsADirectory, "read path is a directory")
+                    .with_operation("Object::download")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path()),
+            );
+        }
+
+        let meta = self.metadata().await?;
+        let size = meta.content_length();
+
+        let mut buf = Vec::with_capacity(size as usize);
+        let mut chunks = stream::iter(chunk_ranges(size, op.chunk_size() as u64))
+            .map(|r| self.read_chunk(r, "Object::download"))
+            .buffered(op.max_window());
+        while let Some(bs) = chunks.try_next().await? {
+            buf.extend_from_slice(&bs);
+        }
+
+        verify_checksum(&meta, &buf).map_err(|err| {
+            err.with_operation("Object::download")
+                .with_context("service", self.accessor().metadata().scheme().into_static())
+                .with_context("path", self.path())
+        })?;
+
+        Ok(buf)
+    }
+
+    /// Download the whole object into a local file by fetching chunks
+    /// concurrently.
+    ///
+    /// Chunks will be written into file as soon as they are fetched, so
+    /// the memory usage is limited by `op`. Returns the size of the object.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Result;
+    /// # use opendal::Operator;
+    /// use opendal::ops::OpPrefetch;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// let size = o.download_to_file("/tmp/file", OpPrefetch::new()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_to_file(&self, path: impl AsRef<Path>, op: OpPrefetch) -> Result<u64> {
+        if !validate_path(self.path(), ObjectMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::ObjectIsADirectory, "read path is a directory")
+                    .with_operation("Object::download_to_file")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path()),
+            );
+        }
+
+        let meta = self.metadata().await?;
+        let size = meta.content_length();
+
+        let path = path.as_ref();
+        let new_io_error = |err: std::io::Error| {
+            Error::new(ErrorKind::Unexpected, "write into local file")
+                .with_operation("Object::download_to_file")
+                .with_context("service", self.accessor().metadata().scheme().into_static())
+                .with_context("path", self.path())
+                .with_context("file", path.to_string_lossy())
+                .set_source(err)
+        };
+
+        let mut f = tokio::fs::File::create(path).await.map_err(new_io_error)?;
+        f.set_len(size).await.map_err(new_io_error)?;
+
+        let mut chunks = stream::iter(chunk_ranges(size, op.chunk_size() as u64))
+            .map(|r| async move {
+                let offset = r.start;
+                self.read_chunk(r, "Object::download_to_file")
+                    .await
+                    .map(|bs| (offset, bs))
+            })
+            .buffer_unordered(op.max_window());
+        while let Some((offset, bs)) = chunks.try_next().await? {
+            f.seek(SeekFrom::Start(offset))
+                .await
+                .map_err(new_io_error)?;
+            f.write_all(&bs).await.map_err(new_io_error)?;
+        }
+        f.flush().await.map_err(new_io_error)?;
+
+        // Chunks are written out of order, read the file back to verify it.
+        if let Some((algorithm, expected)) = meta.verifiable_checksum() {
+            let mut hasher = algorithm.hasher();
+            let mut buf = vec![0; op.chunk_size()];
+
+            let mut f = tokio::fs::File::open(path).await.map_err(new_io_error)?;
+            loop {
+                let n = tokio::io::AsyncReadExt::read(&mut f, &mut buf)
+                    .await
+                    .map_err(new_io_error)?;
+                if n == 0 {
+                    break;
+                }
+                hasher.update(&buf[..n]);
+            }
+            hasher.verify(&expected).map_err(|err| {
+                err.with_operation("Object::download_to_file")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path())
+                    .with_context("file", path.to_string_lossy())
+            })?;
+        }
+
+        Ok(size)
+    }
+
+    /// Read a chunk of object and make sure the whole chunk has been read.
+    ///
+    /// Chunks are split by the size we got before downloading, a short
+    /// chunk means the object has been changed and we should not return
+    /// a corrupted content.
+    async fn read_chunk(&self, range: Range<u64>, operation: &'static str) -> Result<Vec<u8>> {
+        let bs = self.range_read(range.clone()).await?;
+
+        let expected = range.end - range.start;
+        if bs.len() as u64 != expected {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "size of chunk mismatch, object may be changed",
+            )
+            .with_operation(operation)
+            .with_context("service", self.accessor().metadata().scheme().into_static())
+            .with_context("path", self.path())
+            .with_context("range", format!("{}-{}", range.start, range.end - 1))
+            .with_context("expected", expected.to_string())
+            .with_context("actual", bs.len().to_string()));
+        }
+
+        Ok(bs)
+    }
+
     /// Read the whole object into a bytes with auto detected compress algorithm.
     ///
     /// If we can't find the correct algorithm, we return `Ok(None)` instead.
@@ -742,6 +1146,7 @@ impl Object {
         }
 
         let bs = bs.into();
+        let args = with_checksum(args, &bs);
         let r = Cursor::new(bs);
         let rp = self.acc.write(self.path(), args, Box::new(r)).await?;
 
@@ -814,6 +1219,7 @@ impl Object {
         }
 
         let bs = bs.into();
+        let args = with_checksum(args, &bs);
         let r = std::io::Cursor::new(bs);
         let rp = self.acc.blocking_write(self.path(), args, Box::new(r))?;
 
@@ -910,6 +1316,46 @@ impl Object {
         Ok(())
     }
 
+    /// Create a new writer which implements [`std::io::Write`].
+    ///
+    /// Written data will be uploaded in parts if the service supports
+    /// multipart.
+    ///
+    /// # Notes
+    ///
+    /// - [`BlockingObjectWriter::close`] must be called to finish the write.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io::Write;
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # fn test(op: Operator) -> Result<()> {
+    /// let o = op.object("path/to/file");
+    /// let mut w = o.blocking_writer()?;
+    /// w.write_all(b"hello, world!")?;
+    /// w.close()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocking_writer(&self) -> Result<BlockingObjectWriter> {
+        if !validate_path(self.path(), ObjectMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::ObjectIsADirectory, "write path is a directory")
+                    .with_operation("Object::blocking_writer")
+                    .with_context("service", self.accessor().metadata().scheme().into_static())
+                    .with_context("path", self.path()),
+            );
+        }
+
+        Ok(BlockingObjectWriter::create(
+            self.acc.clone(),
+            self.path(),
+            self.meta.clone(),
+        ))
+    }
+
     /// Delete object.
     ///
     /// # Notes
@@ -1456,6 +1902,52 @@ impl Object {
         Ok(rp.into_presigned_request())
     }
 
+    /// Presign a browser based POST upload.
+    ///
+    /// Unlike [`Object::presign_write`], the returned policy could restrict
+    /// the size and content type of uploads.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use anyhow::Result;
+    /// use opendal::ops::OpPresignPost;
+    /// use opendal::Operator;
+    /// use time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let args = OpPresignPost::new()
+    ///         .with_content_type_prefix("image/")
+    ///         .with_content_length_range(0, 10 * 1024 * 1024);
+    ///     let signed_req = op
+    ///         .object("uploads/")
+    ///         .presign_post(args, Duration::hours(1))?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// - `signed_req.method()`: `POST`
+    /// - `signed_req.uri()`: `https://examplebucket.s3.amazonaws.com/`
+    /// - `signed_req.form_fields()`: `[("key", "uploads/${filename}"), ("policy", "<policy>"), ...]`
+    ///
+    /// Browsers can upload files via a form with all returned fields and a
+    /// `file` field at last:
+    ///
+    /// ```html
+    /// <form action="https://examplebucket.s3.amazonaws.com/" method="post" enctype="multipart/form-data">
+    ///   <input type="hidden" name="key" value="uploads/${filename}" />
+    ///   <!-- other form fields -->
+    ///   <input type="file" name="file" />
+    /// </form>
+    /// ```
+    pub fn presign_post(&self, op: OpPresignPost, expire: Duration) -> Result<PresignedRequest> {
+        let op = OpPresign::new(op, expire);
+
+        let rp = self.acc.presign(self.path(), op)?;
+        Ok(rp.into_presigned_request())
+    }
+
     /// Construct a multipart with existing upload id.
     pub fn to_multipart(&self, upload_id: &str) -> ObjectMultipart {
         ObjectMultipart::new(self.operator(), &self.path, upload_id)
@@ -1469,4 +1961,101 @@ impl Object {
             .await?;
         Ok(self.to_multipart(rp.upload_id()))
     }
+
+    /// Create a new multipart for current path.
+    ///
+    /// This function is the blocking version of [`Object::create_multipart`].
+    pub fn blocking_create_multipart(&self) -> Result<ObjectMultipart> {
+        let rp = self
+            .acc
+            .blocking_create_multipart(self.path(), OpCreateMultipart::new())?;
+        Ok(self.to_multipart(rp.upload_id()))
+    }
+}
+
+/// Compute the checksum of content if checksum algorithm is specified but
+/// checksum is not provided.
+fn with_checksum(args: OpWrite, content: &[u8]) -> OpWrite {
+    match args.checksum_algorithm() {
+        Some(algorithm) if args.checksum().is_none() => {
+            let checksum = algorithm.checksum(content);
+            args.with_checksum(algorithm, &checksum)
+        }
+        _ => args,
+    }
+}
+
+/// Verify the whole content of object against the checksum in metadata.
+fn verify_checksum(meta: &ObjectMetadata, content: &[u8]) -> Result<()> {
+    match meta.verifiable_checksum() {
+        Some((algorithm, expected)) => algorithm.verify(&expected, content),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// MockService claims the object has 10 bytes but only 6 of them
+    /// can be read, like an object truncated during downloading.
+    #[derive(Debug, Default)]
+    struct MockService {}
+
+    #[async_trait]
+    impl Accessor for MockService {
+        type Reader = output::Cursor;
+        type BlockingReader = ();
+        type Pager = ();
+        type BlockingPager = ();
+
+        fn metadata(&self) -> AccessorMetadata {
+            let mut am = AccessorMetadata::default();
+            am.set_capabilities(AccessorCapability::Read);
+
+            am
+        }
+
+        async fn stat(&self, _: &str, _: OpStat) -> Result<RpStat> {
+            Ok(RpStat::new(
+                ObjectMetadata::new(ObjectMode::FILE).with_content_length(10),
+            ))
+        }
+
+        async fn read(&self, _: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            let data = vec![1; 6];
+
+            let br = args.range();
+            let start = br.offset().unwrap_or_default().min(6) as usize;
+            let end = match br.size() {
+                Some(size) => (start + size as usize).min(6),
+                None => 6,
+            };
+            let bs = data[start..end].to_vec();
+
+            Ok((RpRead::new(bs.len() as u64), output::Cursor::from(bs)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_with_short_chunk() {
+        let op = Operator::new(MockService::default()).finish();
+        let o = op.object("path");
+
+        let err = o
+            .download(OpPrefetch::new().with_chunk_size(4))
+            .await
+            .expect_err("download must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+        let path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        let err = o
+            .download_to_file(&path, OpPrefetch::new().with_chunk_size(4))
+            .await
+            .expect_err("download to file must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+        let _ = std::fs::remove_file(&path);
+    }
 }
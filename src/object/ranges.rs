@@ -0,0 +1,152 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use bytes::Bytes;
+
+/// Merge ranges that are close enough into larger ones.
+///
+/// - Empty ranges will be ignored.
+/// - Returned ranges are sorted by start and never overlap.
+/// - Two ranges will be merged if the gap between them is not larger
+///   than `gap`.
+pub(crate) fn merge_ranges(ranges: &[Range<u64>], gap: u64) -> Vec<Range<u64>> {
+    let mut sorted: Vec<_> = ranges.iter().filter(|r| r.start < r.end).cloned().collect();
+    sorted.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(sorted.len());
+    for r in sorted {
+        match merged.last_mut() {
+            Some(last) if r.start <= last.end.saturating_add(gap) => {
+                last.end = last.end.max(r.end);
+            }
+            _ => merged.push(r),
+        }
+    }
+
+    merged
+}
+
+/// Split `0..size` into ranges of `chunk_size`.
+///
+/// The last range could be smaller than `chunk_size`.
+pub(crate) fn chunk_ranges(size: u64, chunk_size: u64) -> impl Iterator<Item = Range<u64>> {
+    (0..size)
+        .step_by(chunk_size as usize)
+        .map(move |start| start..(start + chunk_size).min(size))
+}
+
+/// Slice the data of input ranges out of the data of merged ranges.
+///
+/// `merged` must be returned by [`merge_ranges`] with the same `ranges`
+/// and `data` must be in the same order of `merged`.
+///
+/// The data of merged range could be shorter than expected if it reaches
+/// the end of object, so the returned data will be truncated too.
+pub(crate) fn slice_ranges(
+    ranges: &[Range<u64>],
+    merged: &[Range<u64>],
+    data: &[Bytes],
+) -> Vec<Bytes> {
+    debug_assert_eq!(merged.len(), data.len());
+
+    ranges
+        .iter()
+        .map(|r| {
+            if r.start >= r.end {
+                return Bytes::new();
+            }
+
+            // Find the last merged range whose start is not larger than
+            // input range's start.
+            let idx = merged.partition_point(|m| m.start <= r.start) - 1;
+            let (m, bs) = (&merged[idx], &data[idx]);
+            debug_assert!(m.start <= r.start && r.end <= m.end);
+
+            let start = ((r.start - m.start) as usize).min(bs.len());
+            let end = ((r.end - m.start) as usize).min(bs.len());
+            bs.slice(start..end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ranges() {
+        let cases = vec![
+            ("empty", vec![], 0, vec![]),
+            ("ignore empty range", vec![0..0, 5..5], 0, vec![]),
+            ("single", vec![0..10], 0, vec![0..10]),
+            ("adjacent", vec![0..10, 10..20], 0, vec![0..20]),
+            ("gap too large", vec![0..10, 20..30], 5, vec![0..10, 20..30]),
+            ("gap small enough", vec![0..10, 20..30], 10, vec![0..30]),
+            ("unsorted", vec![20..30, 0..10], 10, vec![0..30]),
+            ("overlapped", vec![0..20, 5..10, 15..30], 0, vec![0..30]),
+            (
+                "multiple groups",
+                vec![100..110, 0..10, 12..20, 105..120],
+                2,
+                vec![0..20, 100..120],
+            ),
+        ];
+
+        for (name, input, gap, expected) in cases {
+            assert_eq!(merge_ranges(&input, gap), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_chunk_ranges() {
+        let cases = vec![
+            ("empty", 0, 4, vec![]),
+            ("exact", 8, 4, vec![0..4, 4..8]),
+            ("last smaller", 10, 4, vec![0..4, 4..8, 8..10]),
+            ("single", 3, 4, vec![0..3]),
+        ];
+
+        for (name, size, chunk_size, expected) in cases {
+            assert_eq!(
+                chunk_ranges(size, chunk_size).collect::<Vec<_>>(),
+                expected,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_slice_ranges() {
+        let ranges = vec![20..25, 0..5, 3..8, 7..7, 22..40];
+        let merged = merge_ranges(&ranges, 10);
+        assert_eq!(merged, vec![0..40]);
+
+        // Object only has 30 bytes.
+        let data = vec![Bytes::from((0..30).collect::<Vec<u8>>())];
+        let actual = slice_ranges(&ranges, &merged, &data);
+
+        assert_eq!(
+            actual,
+            vec![
+                Bytes::from(vec![20, 21, 22, 23, 24]),
+                Bytes::from(vec![0, 1, 2, 3, 4]),
+                Bytes::from(vec![3, 4, 5, 6, 7]),
+                Bytes::new(),
+                Bytes::from((22..30).collect::<Vec<u8>>()),
+            ]
+        );
+    }
+}
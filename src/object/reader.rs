@@ -24,8 +24,10 @@ use futures::AsyncSeek;
 use futures::Stream;
 
 use crate::error::Result;
+use crate::ops::OpPrefetch;
 use crate::ops::OpRead;
 use crate::raw::*;
+use crate::ObjectMetadata;
 
 /// ObjectReader is the public API for users.
 ///
@@ -107,14 +109,51 @@ impl ObjectReader {
     ///
     /// We don't want to expose those detials to users so keep this fuction
     /// in crate only.
+    ///
+    /// Content will be verified against the checksum returned by service
+    /// if the whole object is read.
     pub(crate) async fn create(acc: FusedAccessor, path: &str, op: OpRead) -> Result<Self> {
-        let (_, r) = acc.read(path, op).await?;
+        let is_full = op.range().is_full();
+        let (rp, r) = acc.read(path, op).await?;
+
+        let r: output::Reader = match rp.into_metadata().verifiable_checksum() {
+            Some((algorithm, expected)) if is_full => {
+                Box::new(output::into_verified_reader(r, algorithm, &expected))
+            }
+            _ => r,
+        };
 
         Ok(ObjectReader {
             inner: r,
             seek_state: SeekState::Init,
         })
     }
+
+    /// Create a new object reader which prefetches data ahead of current
+    /// position.
+    ///
+    /// The metadata of object is required to schedule prefetching and
+    /// verify the content.
+    pub(crate) fn create_prefetch(
+        acc: FusedAccessor,
+        path: &str,
+        meta: &ObjectMetadata,
+        op: OpPrefetch,
+    ) -> Self {
+        let r = output::into_reader::by_prefetch(acc, path, meta.content_length(), op);
+
+        let r: output::Reader = match meta.verifiable_checksum() {
+            Some((algorithm, expected)) => {
+                Box::new(output::into_verified_reader(r, algorithm, &expected))
+            }
+            None => Box::new(r),
+        };
+
+        ObjectReader {
+            inner: r,
+            seek_state: SeekState::Init,
+        }
+    }
 }
 
 impl output::Read for ObjectReader {
@@ -223,6 +262,7 @@ mod tests {
     use tokio::io::AsyncReadExt;
     use tokio::io::AsyncSeekExt;
 
+    use crate::ops::OpPrefetch;
     use crate::services;
     use crate::Operator;
 
@@ -257,6 +297,44 @@ mod tests {
         assert_eq!(buf, content);
     }
 
+    #[tokio::test]
+    async fn test_prefetch_reader() {
+        let op = Operator::create(services::Memory::default())
+            .unwrap()
+            .finish();
+        let obj = op.object("test_file");
+
+        let content = gen_random_bytes();
+        obj.write(&*content)
+            .await
+            .expect("writ to object must succeed");
+
+        let mut reader = obj
+            .prefetch_reader_with(OpPrefetch::new().with_chunk_size(1024 * 1024))
+            .await
+            .unwrap();
+        let mut buf = Vec::new();
+        reader
+            .read_to_end(&mut buf)
+            .await
+            .expect("read to end must succeed");
+        assert_eq!(buf, content);
+
+        let offset = content.len() as u64 / 2;
+        let n = reader
+            .seek(tokio::io::SeekFrom::Start(offset))
+            .await
+            .unwrap();
+        assert_eq!(n, offset, "seek position must be {offset}");
+
+        let mut buf = Vec::new();
+        reader
+            .read_to_end(&mut buf)
+            .await
+            .expect("read to end must succeed");
+        assert_eq!(buf, content[offset as usize..]);
+    }
+
     #[tokio::test]
     async fn test_reader_async_seek() {
         let op = Operator::create(services::Memory::default())
@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use futures::future;
+use futures::stream;
+use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
 use crate::layers::*;
 use crate::object::ObjectLister;
+use crate::ops::*;
 use crate::raw::*;
 use crate::*;
 
@@ -241,6 +246,37 @@ impl Operator {
         Object::new(self.clone(), path)
     }
 
+    /// List objects whose paths match the given glob pattern.
+    ///
+    /// We will walk from the longest literal prefix of the pattern and
+    /// skip dirs that can't contain any matched paths. Refer to [`Glob`]
+    /// for the supported syntax.
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// use futures::TryStreamExt;
+    /// use opendal::Operator;
+    ///
+    /// # #[tokio::main]
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut ol = op.glob("data/2023/**/*.parquet")?;
+    /// while let Some(o) = ol.try_next().await? {
+    ///     println!("{}", o.path());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn glob(&self, pattern: &str) -> Result<ObjectLister> {
+        let glob = Arc::new(Glob::new(pattern)?);
+        let dir_glob = glob.clone();
+
+        let walker = TopDownWalker::new(self.inner(), glob.prefix())
+            .with_dir_filter(move |de| dir_glob.could_match_in(de.path()))
+            .with_filter(move |de| glob.is_match(de.path()));
+
+        Ok(ObjectLister::new(self.clone(), Box::new(walker)))
+    }
+
     /// Check if this operator can work correctly.
     ///
     /// We will send a `list` request to path and return any errors we met.
@@ -380,11 +416,34 @@ impl<A: Accessor> OperatorBuilder<A> {
 #[derive(Clone, Debug)]
 pub struct BatchOperator {
     src: Operator,
+
+    limit: usize,
+    concurrent: usize,
 }
 
 impl BatchOperator {
     pub(crate) fn new(op: Operator) -> Self {
-        BatchOperator { src: op }
+        BatchOperator {
+            src: op,
+            limit: 1000,
+            concurrent: 8,
+        }
+    }
+
+    /// Specify the max number of paths in one batch request.
+    ///
+    /// Default to `1000` which is the limit of most services.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.max(1);
+        self
+    }
+
+    /// Specify the max number of batch requests in flight.
+    ///
+    /// Default to `8`.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
     }
 
     /// Walk a dir in the best way that suitable for underlying storage.
@@ -421,6 +480,10 @@ impl BatchOperator {
 
     /// Remove the path and all nested dirs and files recursively.
     ///
+    /// If underlying services support `batch`, paths will be removed in
+    /// batches of [`BatchOperator::with_limit`] with at most
+    /// [`BatchOperator::with_concurrent`] requests in flight.
+    ///
     /// **Use this function in cautions to avoid unexpected data loss.**
     pub async fn remove_all(&self, path: &str) -> Result<()> {
         let parent = self.src.object(path);
@@ -431,7 +494,65 @@ impl BatchOperator {
         }
 
         let obs = self.walk_bottom_up(path)?;
-        obs.try_for_each(|v| async move { v.delete().await }).await
+
+        if !self.src.metadata().can_batch() {
+            return obs.try_for_each(|v| async move { v.delete().await }).await;
+        }
+
+        // Files can be removed in any order, collect dirs and remove them
+        // after all files have been removed.
+        let mut dirs: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        let files = obs.try_filter_map(|v| {
+            let path = v.path().to_string();
+            let file = if path.ends_with('/') {
+                let depth = path.trim_end_matches('/').matches('/').count();
+                dirs.entry(depth).or_default().push(path);
+                None
+            } else {
+                Some(path)
+            };
+            future::ready(Ok(file))
+        });
+        self.batch_delete(files).await?;
+
+        // Remove dirs from the deepest ones, dirs at the same depth can be
+        // removed concurrently.
+        for (_, paths) in dirs.into_iter().rev() {
+            self.batch_delete(stream::iter(paths.into_iter().map(Ok)))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete paths in batches of `limit` with at most `concurrent` requests
+    /// in flight.
+    async fn batch_delete(&self, paths: impl Stream<Item = Result<String>>) -> Result<()> {
+        paths
+            .map_ok(|v| (v, OpDelete::new()))
+            .try_chunks(self.limit)
+            .map_err(|err| err.1)
+            .map_ok(|ops| {
+                let acc = self.src.inner();
+                async move {
+                    let rp = acc
+                        .batch(OpBatch::new(BatchOperations::Delete(ops)))
+                        .await?;
+
+                    match rp.into_results() {
+                        BatchedResults::Delete(results) => {
+                            for (_, result) in results {
+                                result?;
+                            }
+                        }
+                    }
+
+                    Ok(())
+                }
+            })
+            .try_buffer_unordered(self.concurrent)
+            .try_for_each(|_| async { Ok(()) })
+            .await
     }
 }
 
@@ -484,6 +605,13 @@ impl OperatorMetadata {
             .contains(AccessorCapability::Presign)
     }
 
+    /// Check if current backend supports presign post or not.
+    pub fn can_presign_post(&self) -> bool {
+        self.acc
+            .capabilities()
+            .contains(AccessorCapability::PresignPost)
+    }
+
     /// Check if current backend supports multipart operations or not.
     pub fn can_multipart(&self) -> bool {
         self.acc
@@ -497,4 +625,16 @@ impl OperatorMetadata {
             .capabilities()
             .contains(AccessorCapability::Blocking)
     }
+
+    /// Check if current backend supports batch operations or not.
+    pub fn can_batch(&self) -> bool {
+        self.acc.capabilities().contains(AccessorCapability::Batch)
+    }
+
+    /// Check if current backend supports [`Accessor::read_ranges`] or not.
+    pub fn can_read_ranges(&self) -> bool {
+        self.acc
+            .capabilities()
+            .contains(AccessorCapability::ReadRanges)
+    }
 }
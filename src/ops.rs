@@ -16,6 +16,12 @@
 //!
 //! By using ops, users can add more context for operation.
 
+use std::ops::Range;
+use std::str::FromStr;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use md5::Digest;
 use time::Duration;
 
 use crate::raw::*;
@@ -156,6 +162,58 @@ impl OpAbortMultipart {
     }
 }
 
+/// Args for `batch` operation.
+#[derive(Debug, Clone)]
+pub struct OpBatch {
+    ops: BatchOperations,
+}
+
+impl OpBatch {
+    /// Create a new `OpBatch`.
+    pub fn new(ops: BatchOperations) -> Self {
+        Self { ops }
+    }
+
+    /// Get operation from op.
+    pub fn operation(&self) -> &BatchOperations {
+        &self.ops
+    }
+
+    /// Consume OpBatch into BatchOperations
+    pub fn into_operation(self) -> BatchOperations {
+        self.ops
+    }
+}
+
+/// Batch operations used for batch.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum BatchOperations {
+    /// Batch delete operations.
+    Delete(Vec<(String, OpDelete)>),
+}
+
+impl BatchOperations {
+    /// Return the operation name of this batch.
+    pub fn operation(&self) -> Operation {
+        match self {
+            BatchOperations::Delete(_) => Operation::Delete,
+        }
+    }
+
+    /// Return the count of operations in this batch.
+    pub fn len(&self) -> usize {
+        match self {
+            BatchOperations::Delete(v) => v.len(),
+        }
+    }
+
+    /// Check if this batch is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 /// Args for `presign` operation.
 ///
 /// The path must be normalized.
@@ -198,6 +256,8 @@ pub enum PresignOperation {
     Write(OpWrite),
     /// Presign a write multipart operation.
     WriteMultipart(OpWriteMultipart),
+    /// Presign a browser based POST upload with policy.
+    Post(OpPresignPost),
 }
 
 impl From<OpStat> for PresignOperation {
@@ -224,6 +284,67 @@ impl From<OpWriteMultipart> for PresignOperation {
     }
 }
 
+impl From<OpPresignPost> for PresignOperation {
+    fn from(v: OpPresignPost) -> Self {
+        Self::Post(v)
+    }
+}
+
+/// Args for presign post operation.
+///
+/// All conditions here will be added into the signed policy document,
+/// uploads that don't match them will be rejected by services.
+///
+/// If the path ends with `/`, the policy will allow uploading any key
+/// that starts with this path instead of the exact key.
+#[derive(Debug, Clone, Default)]
+pub struct OpPresignPost {
+    content_type: Option<String>,
+    content_type_prefix: Option<String>,
+    content_length_range: Option<(u64, u64)>,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require the content type of upload to be exactly `content_type`.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Require the content type of upload to start with `prefix`, for
+    /// example: `image/`.
+    pub fn with_content_type_prefix(mut self, prefix: &str) -> Self {
+        self.content_type_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Require the size of upload in `[min, max]` bytes.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// Get content type from option.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Get content type prefix from option.
+    pub fn content_type_prefix(&self) -> Option<&str> {
+        self.content_type_prefix.as_deref()
+    }
+
+    /// Get content length range from option.
+    pub fn content_length_range(&self) -> Option<(u64, u64)> {
+        self.content_length_range
+    }
+}
+
 /// Args for `read` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRead {
@@ -248,6 +369,137 @@ impl OpRead {
     }
 }
 
+/// Args for `read_ranges` operation.
+#[derive(Debug, Clone)]
+pub struct OpReadRanges {
+    ranges: Vec<Range<u64>>,
+    gap: u64,
+    concurrent: usize,
+}
+
+impl Default for OpReadRanges {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl OpReadRanges {
+    /// Create a new `OpReadRanges` with given ranges.
+    pub fn new(ranges: Vec<Range<u64>>) -> Self {
+        Self {
+            ranges,
+            gap: 1024 * 1024,
+            concurrent: 8,
+        }
+    }
+
+    /// Specify the max gap between two ranges that can be merged.
+    ///
+    /// Default to `1 MiB`.
+    pub fn with_gap(mut self, gap: u64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Specify the max number of merged ranges that fetched in flight.
+    ///
+    /// Default to `8`.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
+    }
+
+    /// Get ranges from option.
+    pub fn ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    /// Get gap from option.
+    pub fn gap(&self) -> u64 {
+        self.gap
+    }
+
+    /// Get concurrent from option.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+}
+
+/// Args for prefetching reads like `prefetch_reader` and `download`.
+#[derive(Debug, Clone)]
+pub struct OpPrefetch {
+    chunk_size: usize,
+    concurrent: usize,
+    max_buffer_size: usize,
+}
+
+impl Default for OpPrefetch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpPrefetch {
+    /// Create a new `OpPrefetch`.
+    pub fn new() -> Self {
+        Self {
+            chunk_size: 4 * 1024 * 1024,
+            concurrent: 4,
+            max_buffer_size: 64 * 1024 * 1024,
+        }
+    }
+
+    /// Specify the size of every chunk that fetched by one `read` call.
+    ///
+    /// Default to `4 MiB`.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Specify the max number of chunks that fetched in flight.
+    ///
+    /// Default to `4`.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent.max(1);
+        self
+    }
+
+    /// Specify the max size of data that buffered by prefetching.
+    ///
+    /// The number of chunks in flight will be limited to make sure
+    /// `chunk_size * concurrent` doesn't exceed this value. At least
+    /// one chunk will be fetched even if the chunk is larger.
+    ///
+    /// Default to `64 MiB`.
+    pub fn with_max_buffer_size(mut self, max_buffer_size: usize) -> Self {
+        self.max_buffer_size = max_buffer_size;
+        self
+    }
+
+    /// Get chunk_size from option.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Get concurrent from option.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Get max_buffer_size from option.
+    pub fn max_buffer_size(&self) -> usize {
+        self.max_buffer_size
+    }
+
+    /// Get the max number of chunks in flight under the memory limit.
+    pub(crate) fn max_window(&self) -> usize {
+        (self.max_buffer_size / self.chunk_size)
+            .min(self.concurrent)
+            .max(1)
+    }
+}
+
 /// Args for `stat` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpStat {}
@@ -264,6 +516,8 @@ impl OpStat {
 pub struct OpWrite {
     size: u64,
     content_type: Option<String>,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+    checksum: Option<String>,
 }
 
 impl OpWrite {
@@ -274,14 +528,41 @@ impl OpWrite {
         Self {
             size,
             content_type: None,
+            checksum_algorithm: None,
+            checksum: None,
         }
     }
 
     /// Set the content type of option
     pub fn with_content_type(self, content_type: &str) -> Self {
         Self {
-            size: self.size(),
             content_type: Some(content_type.to_string()),
+            ..self
+        }
+    }
+
+    /// Set the checksum algorithm of option.
+    ///
+    /// The checksum will be computed while writing content by
+    /// [`Object::write_with`] and sent to services for verification.
+    /// Services will return [`ErrorKind::ChecksumMismatch`] if the
+    /// content they received doesn't match.
+    pub fn with_checksum_algorithm(self, algorithm: ChecksumAlgorithm) -> Self {
+        Self {
+            checksum_algorithm: Some(algorithm),
+            ..self
+        }
+    }
+
+    /// Set the computed checksum of content, which is the base64 encoded
+    /// digest returned by [`ChecksumAlgorithm::checksum`].
+    ///
+    /// This is useful while writing content from a reader.
+    pub fn with_checksum(self, algorithm: ChecksumAlgorithm, checksum: &str) -> Self {
+        Self {
+            checksum_algorithm: Some(algorithm),
+            checksum: Some(checksum.to_string()),
+            ..self
         }
     }
 
@@ -293,4 +574,148 @@ impl OpWrite {
     pub fn content_type(&self) -> Option<&str> {
         self.content_type.as_deref()
     }
+
+    /// Get the checksum algorithm from option.
+    pub fn checksum_algorithm(&self) -> Option<ChecksumAlgorithm> {
+        self.checksum_algorithm
+    }
+
+    /// Get the checksum algorithm and computed checksum from option.
+    ///
+    /// Returns `None` if checksum hasn't been computed.
+    pub fn checksum(&self) -> Option<(ChecksumAlgorithm, &str)> {
+        match (self.checksum_algorithm, &self.checksum) {
+            (Some(algorithm), Some(checksum)) => Some((algorithm, checksum.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Verify the given content against the checksum of option.
+    ///
+    /// This is used by services that don't verify checksum themselves.
+    pub(crate) fn verify_checksum(&self, content: &[u8]) -> Result<()> {
+        let (algorithm, expected) = match self.checksum() {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+
+        algorithm.verify(expected, content)
+    }
+}
+
+/// ChecksumAlgorithm is the algorithm used to compute checksum of content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum ChecksumAlgorithm {
+    /// MD5 digest, which is sent as `Content-MD5` by most services.
+    Md5,
+    /// CRC32C (Castagnoli) checksum in big-endian.
+    Crc32c,
+    /// SHA-256 digest.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// Convert self into static str.
+    pub fn into_static(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "md5",
+            ChecksumAlgorithm::Crc32c => "crc32c",
+            ChecksumAlgorithm::Sha256 => "sha256",
+        }
+    }
+
+    /// Compute the checksum of content and return the base64 encoded digest.
+    pub fn checksum(&self, content: &[u8]) -> String {
+        match self {
+            ChecksumAlgorithm::Md5 => BASE64_STANDARD.encode(md5::Md5::digest(content)),
+            ChecksumAlgorithm::Crc32c => {
+                BASE64_STANDARD.encode(crc32c::crc32c(content).to_be_bytes())
+            }
+            ChecksumAlgorithm::Sha256 => BASE64_STANDARD.encode(sha2::Sha256::digest(content)),
+        }
+    }
+
+    /// Verify content against the base64 encoded checksum.
+    pub(crate) fn verify(self, expected: &str, content: &[u8]) -> Result<()> {
+        let mut hasher = self.hasher();
+        hasher.update(content);
+        hasher.verify(expected)
+    }
+
+    /// Create a hasher to compute checksum incrementally.
+    pub(crate) fn hasher(self) -> ChecksumHasher {
+        match self {
+            ChecksumAlgorithm::Md5 => ChecksumHasher::Md5(md5::Md5::new()),
+            ChecksumAlgorithm::Crc32c => ChecksumHasher::Crc32c(0),
+            ChecksumAlgorithm::Sha256 => ChecksumHasher::Sha256(sha2::Sha256::new()),
+        }
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "md5" => Ok(ChecksumAlgorithm::Md5),
+            "crc32c" => Ok(ChecksumAlgorithm::Crc32c),
+            "sha256" => Ok(ChecksumAlgorithm::Sha256),
+            v => Err(Error::new(
+                ErrorKind::Unsupported,
+                "checksum algorithm is not supported",
+            )
+            .with_context("algorithm", v)),
+        }
+    }
+}
+
+/// ChecksumHasher computes checksum of content incrementally.
+pub(crate) enum ChecksumHasher {
+    Md5(md5::Md5),
+    Crc32c(u32),
+    Sha256(sha2::Sha256),
+}
+
+impl ChecksumHasher {
+    /// Feed more content into hasher.
+    pub(crate) fn update(&mut self, content: &[u8]) {
+        match self {
+            ChecksumHasher::Md5(h) => h.update(content),
+            ChecksumHasher::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, content),
+            ChecksumHasher::Sha256(h) => h.update(content),
+        }
+    }
+
+    fn algorithm(&self) -> ChecksumAlgorithm {
+        match self {
+            ChecksumHasher::Md5(_) => ChecksumAlgorithm::Md5,
+            ChecksumHasher::Crc32c(_) => ChecksumAlgorithm::Crc32c,
+            ChecksumHasher::Sha256(_) => ChecksumAlgorithm::Sha256,
+        }
+    }
+
+    /// Finish hashing and return the base64 encoded checksum.
+    pub(crate) fn finish(self) -> String {
+        match self {
+            ChecksumHasher::Md5(h) => BASE64_STANDARD.encode(h.finalize()),
+            ChecksumHasher::Crc32c(crc) => BASE64_STANDARD.encode(crc.to_be_bytes()),
+            ChecksumHasher::Sha256(h) => BASE64_STANDARD.encode(h.finalize()),
+        }
+    }
+
+    /// Finish hashing and compare with the base64 encoded checksum.
+    pub(crate) fn verify(self, expected: &str) -> Result<()> {
+        let algorithm = self.algorithm();
+        let actual = self.finish();
+        if actual != expected {
+            return Err(
+                Error::new(ErrorKind::ChecksumMismatch, "checksum of content mismatch")
+                    .with_context("algorithm", algorithm.into_static())
+                    .with_context("expected", expected)
+                    .with_context("actual", actual),
+            );
+        }
+
+        Ok(())
+    }
 }
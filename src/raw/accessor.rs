@@ -41,15 +41,22 @@ use crate::*;
 /// | [`delete`][Accessor::delete] | - |
 /// | [`list`][Accessor::list] | - |
 /// | [`presign`][Accessor::presign] | `Presign` |
+/// | [`presign`][Accessor::presign] with post | `PresignPost` |
 /// | [`create_multipart`][Accessor::create_multipart] | `Multipart` |
 /// | [`write_multipart`][Accessor::write_multipart] | `Multipart` |
 /// | [`complete_multipart`][Accessor::complete_multipart] | `Multipart` |
 /// | [`abort_multipart`][Accessor::abort_multipart] | `Multipart` |
+/// | [`batch`][Accessor::batch] | `Batch` |
+/// | [`read_ranges`][Accessor::read_ranges] | `ReadRanges` |
 /// | [`blocking_create`][Accessor::blocking_create] | `Blocking` |
 /// | [`blocking_read`][Accessor::blocking_read] | `Blocking` |
 /// | [`blocking_write`][Accessor::blocking_write] | `Blocking` |
 /// | [`blocking_delete`][Accessor::blocking_delete] | `Blocking` |
 /// | [`blocking_list`][Accessor::blocking_list] | `Blocking` |
+/// | [`blocking_create_multipart`][Accessor::blocking_create_multipart] | `Blocking` & `Multipart` |
+/// | [`blocking_write_multipart`][Accessor::blocking_write_multipart] | `Blocking` & `Multipart` |
+/// | [`blocking_complete_multipart`][Accessor::blocking_complete_multipart] | `Blocking` & `Multipart` |
+/// | [`blocking_abort_multipart`][Accessor::blocking_abort_multipart] | `Blocking` & `Multipart` |
 ///
 /// - Path in args will all be normalized into the same style, services
 ///   should handle them based on services' requirement.
@@ -263,6 +270,39 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
         ))
     }
 
+    /// Invoke the `read_ranges` operation on the specified path.
+    ///
+    /// # Behavior
+    ///
+    /// - Require capability: `ReadRanges`
+    /// - Services should return the data of all ranges in one request, for
+    ///   example, via `multipart/byteranges` response.
+    /// - Data must be returned in the same order of input ranges.
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `batch` operations.
+    ///
+    /// # Behavior
+    ///
+    /// - Require capability: `Batch`
+    /// - Services should return per path results in [`BatchedResults`].
+    /// - Services should return `Err` only when the whole batch failed.
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let _ = args;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
     /// Invoke the `blocking_create` operation on the specified path.
     ///
     /// This operation is the blocking version of [`Accessor::create`]
@@ -364,6 +404,87 @@ pub trait Accessor: Send + Sync + Debug + Unpin + 'static {
             "operation is not supported",
         ))
     }
+
+    /// Invoke the `blocking_create_multipart` operation on the specified path.
+    ///
+    /// This operation is the blocking version of [`Accessor::create_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Require capability: `Blocking` and `Multipart`
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `blocking_write_multipart` operation on the specified path.
+    ///
+    /// This operation is the blocking version of [`Accessor::write_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Require capability: `Blocking` and `Multipart`
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let (_, _, _) = (path, args, r);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `blocking_complete_multipart` operation on the specified path.
+    ///
+    /// This operation is the blocking version of [`Accessor::complete_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Require capability: `Blocking` and `Multipart`
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
+
+    /// Invoke the `blocking_abort_multipart` operation on the specified path.
+    ///
+    /// This operation is the blocking version of [`Accessor::abort_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Require capability: `Blocking` and `Multipart`
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let (_, _) = (path, args);
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        ))
+    }
 }
 
 /// All functions in `Accessor` only requires `&self`, so it's safe to implement
@@ -433,6 +554,14 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
         self.as_ref().abort_multipart(path, args).await
     }
 
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        self.as_ref().read_ranges(path, args).await
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.as_ref().batch(args).await
+    }
+
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         self.as_ref().blocking_create(path, args)
     }
@@ -456,6 +585,35 @@ impl<T: Accessor + ?Sized> Accessor for Arc<T> {
     fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
         self.as_ref().blocking_list(path, args)
     }
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        args: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        self.as_ref().blocking_create_multipart(path, args)
+    }
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        self.as_ref().blocking_write_multipart(path, args, r)
+    }
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        self.as_ref().blocking_complete_multipart(path, args)
+    }
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        self.as_ref().blocking_abort_multipart(path, args)
+    }
 }
 
 /// FusedAccessor is the type erased accessor with `Box<dyn Reader>`.
@@ -556,10 +714,17 @@ flags! {
         List,
         /// Add this capability if service supports `presign`
         Presign,
+        /// Add this capability if service supports `presign` with
+        /// [`PresignOperation::Post`][crate::ops::PresignOperation::Post]
+        PresignPost,
         /// Add this capability if service supports `multipart`
         Multipart,
         /// Add this capability if service supports `blocking`
         Blocking,
+        /// Add this capability if service supports `batch`
+        Batch,
+        /// Add this capability if service supports `read_ranges`
+        ReadRanges,
     }
 }
 
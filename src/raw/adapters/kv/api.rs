@@ -20,6 +20,8 @@ use flagset::FlagSet;
 use crate::raw::*;
 use crate::Error;
 use crate::ErrorKind;
+use crate::ObjectMetadata;
+use crate::ObjectMode;
 use crate::Result;
 use crate::Scheme;
 
@@ -47,6 +49,41 @@ pub trait Adapter: Send + Sync + Debug + Unpin + Clone + 'static {
         .with_operation("kv::Adapter::blocking_get"))
     }
 
+    /// Get the given range of a key from service.
+    ///
+    /// Adapters that can read part of the value without loading the whole
+    /// value should overwrite this function.
+    ///
+    /// - return `Ok(None)` if this key is not exist.
+    async fn get_range(&self, path: &str, br: BytesRange) -> Result<Option<Vec<u8>>> {
+        Ok(self.get(path).await?.map(|bs| apply_range(bs, br)))
+    }
+
+    /// The blocking version of get_range.
+    fn blocking_get_range(&self, path: &str, br: BytesRange) -> Result<Option<Vec<u8>>> {
+        Ok(self.blocking_get(path)?.map(|bs| apply_range(bs, br)))
+    }
+
+    /// Get the metadata of a key from service.
+    ///
+    /// Adapters that can get the metadata without loading the whole value
+    /// or have more metadata like etag should overwrite this function.
+    ///
+    /// - return `Ok(None)` if this key is not exist.
+    async fn stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        Ok(self
+            .get(path)
+            .await?
+            .map(|bs| ObjectMetadata::new(ObjectMode::FILE).with_content_length(bs.len() as u64)))
+    }
+
+    /// The blocking version of stat.
+    fn blocking_stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        Ok(self
+            .blocking_get(path)?
+            .map(|bs| ObjectMetadata::new(ObjectMode::FILE).with_content_length(bs.len() as u64)))
+    }
+
     /// Set a key into service.
     async fn set(&self, path: &str, value: &[u8]) -> Result<()>;
 
@@ -78,6 +115,54 @@ pub trait Adapter: Send + Sync + Debug + Unpin + Clone + 'static {
         )
         .with_operation("kv::Adapter::blocking_delete"))
     }
+
+    /// Scan all keys that start with the given prefix.
+    ///
+    /// Adapters that support prefix scan should overwrite this function and
+    /// add `List` capability.
+    async fn scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let _ = prefix;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::scan"))
+    }
+
+    /// The blocking version of scan.
+    fn blocking_scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let _ = prefix;
+
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::blocking_scan"))
+    }
+
+    /// Delete keys from service in batch.
+    ///
+    /// Adapters that support deleting multiple keys in one request should
+    /// overwrite this function and add `Batch` capability.
+    ///
+    /// - return `Ok(())` even if some keys are not exist.
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        for path in paths {
+            self.delete(path).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Apply range on the whole value, out of bound ranges will be truncated.
+fn apply_range(mut bs: Vec<u8>, br: BytesRange) -> Vec<u8> {
+    let len = bs.len() as u64;
+    let (start, end) = br.to_bounds(len);
+
+    bs.truncate(end as usize);
+    bs.split_off(start as usize)
 }
 
 /// Metadata for this key value accessor.
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use async_trait::async_trait;
 use futures::AsyncReadExt;
 
@@ -20,7 +22,18 @@ use crate::ops::*;
 use crate::raw::*;
 use crate::*;
 
+/// Prefix of keys used to store checksum of objects.
+///
+/// Keys under this prefix are reserved and will not be listed.
+const CHECKSUM_PREFIX: &str = ".opendal_checksum/";
+
 /// Backend of kv service.
+///
+/// # Notes
+///
+/// Checksum of object is stored in a separate key under
+/// `.opendal_checksum/`, which takes extra round trips while writing,
+/// deleting, stating and reading the whole object.
 #[derive(Debug, Clone)]
 pub struct Backend<S: Adapter> {
     kv: S,
@@ -44,14 +57,89 @@ where
         self.root = normalize_root(root);
         self
     }
+
+    /// Get the underlying kv adapter.
+    pub(crate) fn adapter(&self) -> &S {
+        &self.kv
+    }
+
+    fn can_list(&self) -> bool {
+        self.kv
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::List)
+    }
+
+    /// Write object along with its checksum.
+    ///
+    /// The old checksum will be removed before writing content so that
+    /// a failed write will not leave a stale checksum behind.
+    async fn set_with_checksum(&self, path: &str, bs: &[u8], args: &OpWrite) -> Result<()> {
+        let key = checksum_key(path);
+
+        self.kv.delete(&key).await?;
+        self.kv.set(path, bs).await?;
+        if let Some((algorithm, checksum)) = args.checksum() {
+            self.kv
+                .set(&key, format_checksum(algorithm, checksum).as_bytes())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// The blocking version of set_with_checksum.
+    fn blocking_set_with_checksum(&self, path: &str, bs: &[u8], args: &OpWrite) -> Result<()> {
+        let key = checksum_key(path);
+
+        self.kv.blocking_delete(&key)?;
+        self.kv.blocking_set(path, bs)?;
+        if let Some((algorithm, checksum)) = args.checksum() {
+            self.kv
+                .blocking_set(&key, format_checksum(algorithm, checksum).as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_checksum(&self, path: &str) -> Result<Option<(ChecksumAlgorithm, String)>> {
+        Ok(self
+            .kv
+            .get(&checksum_key(path))
+            .await?
+            .and_then(parse_checksum))
+    }
+
+    fn blocking_get_checksum(&self, path: &str) -> Result<Option<(ChecksumAlgorithm, String)>> {
+        Ok(self
+            .kv
+            .blocking_get(&checksum_key(path))?
+            .and_then(parse_checksum))
+    }
+}
+
+fn checksum_key(path: &str) -> String {
+    format!("{CHECKSUM_PREFIX}{path}")
+}
+
+fn format_checksum(algorithm: ChecksumAlgorithm, checksum: &str) -> String {
+    format!("{}:{}", algorithm.into_static(), checksum)
+}
+
+/// Parse checksum stored as `<algorithm>:<checksum>`, malformed value
+/// will be ignored.
+fn parse_checksum(bs: Vec<u8>) -> Option<(ChecksumAlgorithm, String)> {
+    let v = String::from_utf8(bs).ok()?;
+    let (algorithm, checksum) = v.split_once(':')?;
+    Some((algorithm.parse().ok()?, checksum.to_string()))
 }
 
 #[async_trait]
 impl<S: Adapter> Accessor for Backend<S> {
     type Reader = output::Cursor;
     type BlockingReader = output::Cursor;
-    type Pager = ();
-    type BlockingPager = ();
+    type Pager = KvPager;
+    type BlockingPager = KvPager;
 
     fn metadata(&self) -> AccessorMetadata {
         let mut am: AccessorMetadata = self.kv.metadata().into();
@@ -62,7 +150,10 @@ impl<S: Adapter> Accessor for Backend<S> {
     }
 
     async fn create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
+        // Dirs are stored as empty keys only if adapter supports list.
         if args.mode() == ObjectMode::FILE {
+            self.set_with_checksum(path, &[], &OpWrite::new(0)).await?;
+        } else if self.can_list() {
             self.kv.set(path, &[]).await?;
         }
 
@@ -71,6 +162,8 @@ impl<S: Adapter> Accessor for Backend<S> {
 
     fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
         if args.mode() == ObjectMode::FILE {
+            self.blocking_set_with_checksum(path, &[], &OpWrite::new(0))?;
+        } else if self.can_list() {
             self.kv.blocking_set(path, &[])?;
         }
 
@@ -78,9 +171,7 @@ impl<S: Adapter> Accessor for Backend<S> {
     }
 
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
-        let path = path.to_string();
-
-        let bs = match self.kv.get(&path).await? {
+        let bs = match self.kv.get_range(path, args.range()).await? {
             Some(bs) => bs,
             None => {
                 return Err(Error::new(
@@ -90,14 +181,19 @@ impl<S: Adapter> Accessor for Backend<S> {
             }
         };
 
-        let bs = self.apply_range(bs, args.range());
+        let mut meta = ObjectMetadata::new(ObjectMode::FILE).with_content_length(bs.len() as u64);
+        // Checksum is only useful while reading the whole object.
+        if args.range().is_full() {
+            if let Some((algorithm, checksum)) = self.get_checksum(path).await? {
+                meta.set_checksum(algorithm, &checksum);
+            }
+        }
 
-        let length = bs.len();
-        Ok((RpRead::new(length as u64), output::Cursor::from(bs)))
+        Ok((RpRead::with_metadata(meta), output::Cursor::from(bs)))
     }
 
     fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
-        let bs = match self.kv.blocking_get(path)? {
+        let bs = match self.kv.blocking_get_range(path, args.range())? {
             Some(bs) => bs,
             None => {
                 return Err(Error::new(
@@ -107,8 +203,15 @@ impl<S: Adapter> Accessor for Backend<S> {
             }
         };
 
-        let bs = self.apply_range(bs, args.range());
-        Ok((RpRead::new(bs.len() as u64), output::Cursor::from(bs)))
+        let mut meta = ObjectMetadata::new(ObjectMode::FILE).with_content_length(bs.len() as u64);
+        // Checksum is only useful while reading the whole object.
+        if args.range().is_full() {
+            if let Some((algorithm, checksum)) = self.blocking_get_checksum(path)? {
+                meta.set_checksum(algorithm, &checksum);
+            }
+        }
+
+        Ok((RpRead::with_metadata(meta), output::Cursor::from(bs)))
     }
 
     async fn write(&self, path: &str, args: OpWrite, mut r: input::Reader) -> Result<RpWrite> {
@@ -116,8 +219,9 @@ impl<S: Adapter> Accessor for Backend<S> {
         r.read_to_end(&mut bs)
             .await
             .map_err(|err| Error::new(ErrorKind::Unexpected, "read from source").set_source(err))?;
+        args.verify_checksum(&bs)?;
 
-        self.kv.set(path, &bs).await?;
+        self.set_with_checksum(path, &bs, &args).await?;
 
         Ok(RpWrite::new(args.size()))
     }
@@ -131,8 +235,9 @@ impl<S: Adapter> Accessor for Backend<S> {
         let mut bs = Vec::with_capacity(args.size() as usize);
         r.read_to_end(&mut bs)
             .map_err(|err| Error::new(ErrorKind::Unexpected, "read from source").set_source(err))?;
+        args.verify_checksum(&bs)?;
 
-        self.kv.blocking_set(path, &bs)?;
+        self.blocking_set_with_checksum(path, &bs, &args)?;
 
         Ok(RpWrite::new(args.size()))
     }
@@ -141,11 +246,13 @@ impl<S: Adapter> Accessor for Backend<S> {
         if path.ends_with('/') {
             Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
         } else {
-            let bs = self.kv.get(path).await?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    ObjectMetadata::new(ObjectMode::FILE).with_content_length(bs.len() as u64),
-                )),
+            match self.kv.stat(path).await? {
+                Some(mut meta) => {
+                    if let Some((algorithm, checksum)) = self.get_checksum(path).await? {
+                        meta.set_checksum(algorithm, &checksum);
+                    }
+                    Ok(RpStat::new(meta))
+                }
                 None => Err(Error::new(
                     ErrorKind::ObjectNotFound,
                     "kv doesn't have this path",
@@ -158,11 +265,13 @@ impl<S: Adapter> Accessor for Backend<S> {
         if path.ends_with('/') {
             Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
         } else {
-            let bs = self.kv.blocking_get(path)?;
-            match bs {
-                Some(bs) => Ok(RpStat::new(
-                    ObjectMetadata::new(ObjectMode::FILE).with_content_length(bs.len() as u64),
-                )),
+            match self.kv.blocking_stat(path)? {
+                Some(mut meta) => {
+                    if let Some((algorithm, checksum)) = self.blocking_get_checksum(path)? {
+                        meta.set_checksum(algorithm, &checksum);
+                    }
+                    Ok(RpStat::new(meta))
+                }
                 None => Err(Error::new(
                     ErrorKind::ObjectNotFound,
                     "kv doesn't have this path",
@@ -171,33 +280,156 @@ impl<S: Adapter> Accessor for Backend<S> {
         }
     }
 
+    async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+        let keys = self.kv.scan(path).await?;
+        Ok((RpList::default(), KvPager::new(path, keys)))
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        let keys = self.kv.blocking_scan(path)?;
+        Ok((RpList::default(), KvPager::new(path, keys)))
+    }
+
     async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         self.kv.delete(path).await?;
+        if !path.ends_with('/') {
+            self.kv.delete(&checksum_key(path)).await?;
+        }
         Ok(RpDelete::default())
     }
 
     fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
         self.kv.blocking_delete(path)?;
+        if !path.ends_with('/') {
+            self.kv.blocking_delete(&checksum_key(path))?;
+        }
         Ok(RpDelete::default())
     }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        match args.into_operation() {
+            BatchOperations::Delete(ops) => {
+                let paths: Vec<String> = ops.into_iter().map(|(path, _)| path).collect();
+                let keys: Vec<String> = paths
+                    .iter()
+                    .cloned()
+                    .chain(
+                        paths
+                            .iter()
+                            .filter(|path| !path.ends_with('/'))
+                            .map(|path| checksum_key(path)),
+                    )
+                    .collect();
+                self.kv.batch_delete(&keys).await?;
+
+                Ok(RpBatch::new(BatchedResults::Delete(
+                    paths
+                        .into_iter()
+                        .map(|path| (path, Ok(RpDelete::default())))
+                        .collect(),
+                )))
+            }
+        }
+    }
 }
 
-impl<S> Backend<S>
-where
-    S: Adapter,
-{
-    fn apply_range(&self, mut bs: Vec<u8>, br: BytesRange) -> Vec<u8> {
-        match (br.offset(), br.size()) {
-            (Some(offset), Some(size)) => {
-                let mut bs = bs.split_off(offset as usize);
-                if (size as usize) < bs.len() {
-                    let _ = bs.split_off(size as usize);
+/// KvPager returns the direct children of a dir from scanned keys.
+pub struct KvPager {
+    entries: Option<Vec<output::Entry>>,
+}
+
+impl KvPager {
+    fn new(path: &str, keys: Vec<String>) -> Self {
+        let prefix = if path == "/" { "" } else { path };
+
+        let mut dirs = HashSet::new();
+        let mut entries = Vec::new();
+        for key in keys {
+            // Keys used to store checksum are invisible to users.
+            if key.starts_with(CHECKSUM_PREFIX) {
+                continue;
+            }
+
+            let rest = match key.strip_prefix(prefix) {
+                Some(rest) if !rest.is_empty() => rest,
+                _ => continue,
+            };
+
+            match rest.find('/') {
+                // Nested keys will be returned as dirs.
+                Some(idx) => {
+                    let dir = format!("{prefix}{}", &rest[..=idx]);
+                    if dirs.insert(dir.clone()) {
+                        entries.push(output::Entry::with(
+                            dir,
+                            ObjectMetadata::new(ObjectMode::DIR),
+                        ));
+                    }
                 }
-                bs
+                None => entries.push(output::Entry::with(
+                    key,
+                    ObjectMetadata::new(ObjectMode::FILE),
+                )),
             }
-            (Some(offset), None) => bs.split_off(offset as usize),
-            (None, Some(size)) => bs.split_off(bs.len() - size as usize),
-            (None, None) => bs,
+        }
+
+        KvPager {
+            entries: Some(entries),
+        }
+    }
+}
+
+#[async_trait]
+impl output::Page for KvPager {
+    async fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        Ok(self.entries.take().filter(|v| !v.is_empty()))
+    }
+}
+
+impl output::BlockingPage for KvPager {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        Ok(self.entries.take().filter(|v| !v.is_empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kv_pager() {
+        let keys = vec![
+            "a",
+            "dir/b",
+            "dir/c",
+            "dir/nested/d",
+            "other",
+            "dir/",
+            ".opendal_checksum/a",
+        ];
+
+        let cases = vec![
+            ("root", "/", vec!["a", "dir/", "other"]),
+            ("dir", "dir/", vec!["dir/b", "dir/c", "dir/nested/"]),
+            ("nested", "dir/nested/", vec!["dir/nested/d"]),
+        ];
+
+        for (name, path, expected) in cases {
+            let keys = keys
+                .iter()
+                .filter(|v| v.starts_with(path.trim_start_matches('/')))
+                .map(|v| v.to_string())
+                .collect();
+            let mut pager = KvPager::new(path, keys);
+
+            let mut actual: Vec<String> = output::BlockingPage::next_page(&mut pager)
+                .expect("next page must succeed")
+                .unwrap_or_default()
+                .into_iter()
+                .map(|v| v.path().to_string())
+                .collect();
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "{name}");
         }
     }
 }
@@ -79,6 +79,97 @@ impl Read for Body {
     }
 }
 
+/// IncomingBody carries the content returned by remote servers in blocking way.
+///
+/// # Notes
+///
+/// Client SHOULD NEVER construct this body.
+pub struct IncomingBody {
+    inner: Box<dyn Read + Send + Sync>,
+    size: Option<u64>,
+    consumed: u64,
+}
+
+impl IncomingBody {
+    /// Construct a new incoming body
+    pub fn new(r: Box<dyn Read + Send + Sync>, size: Option<u64>) -> Self {
+        Self {
+            inner: r,
+            size,
+            consumed: 0,
+        }
+    }
+
+    /// Consume the entire body.
+    pub fn consume(mut self) -> Result<()> {
+        std::io::copy(&mut self, &mut std::io::sink()).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "consuming response")
+                .with_operation("http_util::IncomingBody::consume")
+                .set_source(err)
+        })?;
+
+        Ok(())
+    }
+
+    /// Consume the response to bytes.
+    pub fn bytes(mut self) -> Result<Bytes> {
+        let mut vec = Vec::with_capacity(self.size.unwrap_or_default() as usize);
+        self.read_to_end(&mut vec).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "read bytes from response")
+                .with_operation("http_util::IncomingBody::bytes")
+                .set_source(err)
+        })?;
+
+        Ok(vec.into())
+    }
+}
+
+impl Read for IncomingBody {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let n = self.inner.read(buf)?;
+        self.consumed += n as u64;
+
+        if n == 0 {
+            if let Some(size) = self.size {
+                IncomingAsyncBody::check(size, self.consumed)?;
+            }
+        }
+
+        Ok(n)
+    }
+}
+
+impl output::BlockingRead for IncomingBody {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Read::read(self, buf)
+    }
+
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let _ = pos;
+
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "output reader doesn't support seeking",
+        ))
+    }
+
+    fn next(&mut self) -> Option<io::Result<Bytes>> {
+        let mut buf = vec![0; 256 * 1024];
+        match Read::read(self, &mut buf) {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some(Ok(buf.into()))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
 /// Body used in async HTTP requests.
 pub enum AsyncBody {
     /// An empty body.
@@ -100,6 +191,23 @@ impl Default for AsyncBody {
     }
 }
 
+impl AsyncBody {
+    /// Convert into [`Body`] so that requests built for async client
+    /// can be sent by blocking client too.
+    ///
+    /// Only `Empty` and `Bytes` body can be converted.
+    pub fn into_blocking(self) -> Result<Body> {
+        match self {
+            AsyncBody::Empty => Ok(Body::Empty),
+            AsyncBody::Bytes(bs) => Ok(Body::Bytes(bs)),
+            _ => Err(Error::new(
+                ErrorKind::Unexpected,
+                "streaming body can't be converted into blocking body",
+            )),
+        }
+    }
+}
+
 impl From<AsyncBody> for reqwest::Body {
     fn from(v: AsyncBody) -> Self {
         match v {
@@ -110,6 +110,18 @@ impl BytesRange {
         )
     }
 
+    /// Convert bytes range into `(start, end)` of an object with `size`.
+    ///
+    /// Ranges out of the object will be truncated.
+    pub fn to_bounds(&self, size: u64) -> (u64, u64) {
+        match (self.0, self.1) {
+            (None, None) => (0, size),
+            (None, Some(n)) => (size.saturating_sub(n), size),
+            (Some(offset), None) => (offset.min(size), size),
+            (Some(offset), Some(n)) => (offset.min(size), offset.saturating_add(n).min(size)),
+        }
+    }
+
     /// apply_on_bytes will apply range on bytes.
     pub fn apply_on_bytes(&self, mut bs: Bytes) -> Bytes {
         match (self.0, self.1) {
@@ -322,4 +334,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_to_bounds() {
+        let cases = vec![
+            ("full", (None, None), (0, 13)),
+            ("with_offset", (Some(1), None), (1, 13)),
+            ("with_size", (None, Some(1)), (12, 13)),
+            ("with_larger_size", (None, Some(100)), (0, 13)),
+            ("with_offset_and_size", (Some(1), Some(1)), (1, 2)),
+            ("with_offset_and_larger_size", (Some(1), Some(100)), (1, 13)),
+            ("with_larger_offset", (Some(100), Some(1)), (13, 13)),
+        ];
+
+        for (name, input, expected) in cases {
+            let actual = BytesRange(input.0, input.1).to_bounds(13);
+
+            assert_eq!(expected, actual, "{name}");
+        }
+    }
 }
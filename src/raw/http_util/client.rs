@@ -28,6 +28,7 @@ use reqwest::ClientBuilder;
 use reqwest::Url;
 
 use super::body::IncomingAsyncBody;
+use super::body::IncomingBody;
 use super::dns::*;
 use super::parse_content_length;
 use super::AsyncBody;
@@ -126,7 +127,8 @@ impl HttpClient {
     }
 
     /// Send a request in blocking way.
-    pub fn send(&self, req: Request<Body>) -> Result<Response<Body>> {
+    pub fn send(&self, req: Request<Body>) -> Result<Response<IncomingBody>> {
+        let is_head = req.method() == http::Method::HEAD;
         let (parts, body) = req.into_parts();
 
         let mut ur = self
@@ -166,11 +168,20 @@ impl HttpClient {
                 hr = hr.header(name, value);
             }
         }
-
         let resp = hr
-            .body(Body::Reader(Box::new(resp.into_reader())))
+            .body(resp.into_reader())
             .expect("response must build succeed");
 
+        // Get content length from header so that we can check it.
+        // If the request method is HEAD, we will ignore this.
+        let content_length = if is_head {
+            None
+        } else {
+            parse_content_length(resp.headers()).expect("response content length must be valid")
+        };
+
+        let resp = resp.map(|r| IncomingBody::new(r, content_length));
+
         Ok(resp)
     }
 
@@ -26,6 +26,7 @@ mod body;
 pub use body::AsyncBody;
 pub use body::Body;
 pub use body::IncomingAsyncBody;
+pub use body::IncomingBody;
 
 mod header;
 pub use header::parse_content_length;
@@ -45,6 +46,14 @@ pub use error::new_request_sign_error;
 pub use error::parse_error_response;
 pub use error::ErrorResponse;
 
+mod post_policy;
+pub use post_policy::PostPolicy;
+
+mod multipart;
+pub use multipart::parse_byteranges;
+pub use multipart::MixedMultipart;
+pub use multipart::MixedPart;
+
 mod bytes_range;
 pub use bytes_range::BytesRange;
 
@@ -0,0 +1,451 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use bytes::BufMut;
+use bytes::Bytes;
+use bytes::BytesMut;
+use http::header::CONTENT_TYPE;
+use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+
+use super::AsyncBody;
+use super::BytesContentRange;
+use crate::*;
+
+/// MixedMultipart builds and parses `multipart/mixed` content which
+/// carries a batch of http messages.
+///
+/// It's used by batch APIs like:
+///
+/// - [Azure Blob Batch](https://learn.microsoft.com/en-us/rest/api/storageservices/blob-batch)
+/// - [GCS Batch](https://cloud.google.com/storage/docs/batch)
+#[derive(Debug)]
+pub struct MixedMultipart {
+    boundary: String,
+    parts: Vec<MixedPart>,
+}
+
+impl Default for MixedMultipart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MixedMultipart {
+    /// Create a new multipart with random boundary.
+    pub fn new() -> Self {
+        Self {
+            boundary: format!("opendal-{}", uuid::Uuid::new_v4()),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Set the boundary of this multipart.
+    pub fn with_boundary(mut self, boundary: &str) -> Self {
+        self.boundary = boundary.to_string();
+        self
+    }
+
+    /// Append a new part into this multipart.
+    pub fn part(mut self, part: MixedPart) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    /// Get the value of `Content-Type` header for this multipart.
+    pub fn content_type(&self) -> String {
+        format!("multipart/mixed; boundary={}", self.boundary)
+    }
+
+    /// Build the multipart into bytes.
+    pub fn build(&self) -> Bytes {
+        let mut bs = BytesMut::new();
+
+        for part in &self.parts {
+            bs.put_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            part.format(&mut bs);
+        }
+        bs.put_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+
+        bs.freeze()
+    }
+
+    /// Parse the `multipart/mixed` response into http responses.
+    ///
+    /// Responses are returned in the same order as they are in content.
+    pub fn parse_responses(headers: &HeaderMap, bs: Bytes) -> Result<Vec<Response<Bytes>>> {
+        let boundary = parse_boundary(headers)?;
+
+        let content = std::str::from_utf8(&bs).map_err(|err| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "multipart response is not valid utf-8",
+            )
+            .set_source(err)
+        })?;
+
+        let delimiter = format!("--{boundary}");
+        let mut responses = Vec::new();
+        // The first element is the preamble which should be ignored.
+        for part in content.split(delimiter.as_str()).skip(1) {
+            // The close delimiter is `--boundary--`.
+            if part.starts_with("--") {
+                break;
+            }
+
+            responses.push(parse_response_part(part)?);
+        }
+
+        Ok(responses)
+    }
+}
+
+/// Parse the `multipart/byteranges` response into content ranges and
+/// their data.
+///
+/// Services will return `multipart/byteranges` response for requests
+/// with multiple ranges like `Range: bytes=0-99,200-299`.
+///
+/// Parts are returned in the same order as they are in content.
+pub fn parse_byteranges(headers: &HeaderMap, bs: Bytes) -> Result<Vec<(BytesContentRange, Bytes)>> {
+    let boundary = parse_boundary(headers)?;
+    let delimiter = format!("--{boundary}");
+    let delimiter = delimiter.as_bytes();
+
+    let new_error = || {
+        Error::new(
+            ErrorKind::Unexpected,
+            "multipart byteranges response is invalid",
+        )
+        .with_context("boundary", boundary)
+    };
+
+    let mut parts = Vec::new();
+    // Skip the preamble before the first delimiter.
+    let mut pos = find_bytes(&bs, delimiter, 0).ok_or_else(new_error)?;
+    loop {
+        pos += delimiter.len();
+        // The close delimiter is `--boundary--`.
+        if bs[pos..].starts_with(b"--") {
+            break;
+        }
+
+        let head_end = find_bytes(&bs, b"\r\n\r\n", pos).ok_or_else(new_error)?;
+        let head = String::from_utf8_lossy(&bs[pos..head_end]);
+        let range = head
+            .split("\r\n")
+            .filter_map(|line| line.split_once(':'))
+            .find(|(k, _)| k.trim().eq_ignore_ascii_case("content-range"))
+            .map(|(_, v)| v.trim().parse::<BytesContentRange>())
+            .transpose()?
+            .ok_or_else(|| new_error().with_context("part", head.as_ref()))?;
+        let len = range.len().ok_or_else(new_error)? as usize;
+
+        let start = head_end + 4;
+        if start + len > bs.len() {
+            return Err(new_error().with_context("content-range", range.to_string()));
+        }
+        parts.push((range, bs.slice(start..start + len)));
+
+        pos = find_bytes(&bs, delimiter, start + len).ok_or_else(new_error)?;
+    }
+
+    Ok(parts)
+}
+
+/// Extract the boundary from the `Content-Type` header of multipart response.
+fn parse_boundary(headers: &HeaderMap) -> Result<&str> {
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "multipart response doesn't have content type",
+            )
+        })?;
+
+    content_type
+        .split(';')
+        .filter_map(|v| v.trim().strip_prefix("boundary="))
+        .map(|v| v.trim_matches('"'))
+        .next()
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "multipart response doesn't have boundary",
+            )
+            .with_context("content-type", content_type)
+        })
+}
+
+/// Find the first position of `needle` in `bs` starting from `from`.
+fn find_bytes(bs: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    if from > bs.len() {
+        return None;
+    }
+
+    bs[from..]
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|idx| idx + from)
+}
+
+/// MixedPart is a part of `multipart/mixed` which carries an http message.
+#[derive(Debug)]
+pub struct MixedPart {
+    part_headers: HeaderMap,
+
+    method: http::Method,
+    uri: http::Uri,
+    headers: HeaderMap,
+    content: Bytes,
+}
+
+impl MixedPart {
+    /// Create a part from an http request.
+    ///
+    /// Only request with empty or bytes body is supported.
+    pub fn from_request(req: Request<AsyncBody>) -> Result<Self> {
+        let (parts, body) = req.into_parts();
+
+        let content = match body {
+            AsyncBody::Empty => Bytes::new(),
+            AsyncBody::Bytes(bs) => bs,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "multipart part doesn't support streaming body",
+                ))
+            }
+        };
+
+        let mut part_headers = HeaderMap::new();
+        part_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/http"));
+        part_headers.insert(
+            HeaderName::from_static("content-transfer-encoding"),
+            HeaderValue::from_static("binary"),
+        );
+
+        Ok(Self {
+            part_headers,
+            method: parts.method,
+            uri: parts.uri,
+            headers: parts.headers,
+            content,
+        })
+    }
+
+    /// Insert a header for this part (not the embedded request) like `Content-ID`.
+    pub fn with_part_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.part_headers.insert(name, value);
+        self
+    }
+
+    fn format(&self, bs: &mut BytesMut) {
+        for (k, v) in self.part_headers.iter() {
+            bs.put_slice(k.as_str().as_bytes());
+            bs.put_slice(b": ");
+            bs.put_slice(v.as_bytes());
+            bs.put_slice(b"\r\n");
+        }
+        bs.put_slice(b"\r\n");
+
+        let path = self.uri.path_and_query().map(|v| v.as_str()).unwrap_or("/");
+        bs.put_slice(format!("{} {} HTTP/1.1\r\n", self.method, path).as_bytes());
+        for (k, v) in self.headers.iter() {
+            bs.put_slice(k.as_str().as_bytes());
+            bs.put_slice(b": ");
+            bs.put_slice(v.as_bytes());
+            bs.put_slice(b"\r\n");
+        }
+        bs.put_slice(b"\r\n");
+        bs.put_slice(&self.content);
+        bs.put_slice(b"\r\n");
+    }
+}
+
+/// Parse a part of `multipart/mixed` response into http response.
+fn parse_response_part(part: &str) -> Result<Response<Bytes>> {
+    let new_error = || {
+        Error::new(ErrorKind::Unexpected, "multipart response part is invalid")
+            .with_context("part", part)
+    };
+
+    let part = part.trim_start_matches(['\r', '\n']);
+    // Skip the part headers like `Content-Type: application/http`.
+    let (_, message) = part.split_once("\r\n\r\n").ok_or_else(new_error)?;
+    let (head, body) = message.split_once("\r\n\r\n").unwrap_or((message, ""));
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|v| v.split_whitespace().nth(1))
+        .and_then(|v| StatusCode::from_str(v).ok())
+        .ok_or_else(new_error)?;
+
+    let mut builder = Response::builder().status(status);
+    for line in lines {
+        if let Some((k, v)) = line.split_once(':') {
+            builder = builder.header(k.trim(), v.trim());
+        }
+    }
+
+    builder
+        .body(Bytes::from(body.trim_end_matches(['\r', '\n']).to_string()))
+        .map_err(|err| new_error().set_source(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_multipart() {
+        let req = Request::delete("https://example.com/container/blob")
+            .header("x-ms-date", "Thu, 14 Jun 2018 16:46:54 GMT")
+            .body(AsyncBody::Empty)
+            .unwrap();
+
+        let multipart = MixedMultipart::new().with_boundary("batch").part(
+            MixedPart::from_request(req)
+                .unwrap()
+                .with_part_header(HeaderName::from_static("content-id"), HeaderValue::from(0)),
+        );
+
+        assert_eq!(multipart.content_type(), "multipart/mixed; boundary=batch");
+        assert_eq!(
+            multipart.build(),
+            Bytes::from(
+                "--batch\r\n\
+                 content-type: application/http\r\n\
+                 content-transfer-encoding: binary\r\n\
+                 content-id: 0\r\n\
+                 \r\n\
+                 DELETE /container/blob HTTP/1.1\r\n\
+                 x-ms-date: Thu, 14 Jun 2018 16:46:54 GMT\r\n\
+                 \r\n\
+                 \r\n\
+                 --batch--\r\n"
+            )
+        );
+    }
+
+    /// This example is from https://learn.microsoft.com/en-us/rest/api/storageservices/blob-batch#response
+    #[test]
+    fn test_parse_responses() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(
+                "multipart/mixed; boundary=batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed",
+            ),
+        );
+
+        let bs = Bytes::from(
+            "--batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed\r\n\
+             Content-Type: application/http\r\n\
+             Content-ID: 0\r\n\
+             \r\n\
+             HTTP/1.1 202 Accepted\r\n\
+             x-ms-delete-type-permanent: true\r\n\
+             x-ms-version: 2018-11-09\r\n\
+             \r\n\
+             --batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed\r\n\
+             Content-Type: application/http\r\n\
+             Content-ID: 1\r\n\
+             \r\n\
+             HTTP/1.1 404 The specified blob does not exist.\r\n\
+             x-ms-error-code: BlobNotFound\r\n\
+             Content-Length: 216\r\n\
+             Content-Type: application/xml\r\n\
+             \r\n\
+             <?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n\
+             <Error><Code>BlobNotFound</Code></Error>\r\n\
+             --batchresponse_66925647-d0cb-4109-b6d3-28efe3e1e5ed--",
+        );
+
+        let resps = MixedMultipart::parse_responses(&headers, bs).unwrap();
+        assert_eq!(resps.len(), 2);
+        assert_eq!(resps[0].status(), StatusCode::ACCEPTED);
+        assert_eq!(
+            resps[0]
+                .headers()
+                .get("x-ms-delete-type-permanent")
+                .unwrap(),
+            "true"
+        );
+        assert!(resps[0].body().is_empty());
+        assert_eq!(resps[1].status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            resps[1].body(),
+            &Bytes::from(
+                "<?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n<Error><Code>BlobNotFound</Code></Error>"
+            )
+        );
+    }
+
+    /// This example is from https://developer.mozilla.org/en-US/docs/Web/HTTP/Range_requests#multipart_ranges
+    #[test]
+    fn test_parse_byteranges() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("multipart/byteranges; boundary=3d6b6a416f9b5"),
+        );
+
+        let bs = Bytes::from(
+            "--3d6b6a416f9b5\r\n\
+             Content-Type: text/html\r\n\
+             Content-Range: bytes 0-50/1270\r\n\
+             \r\n\
+             <!doctype html>\r\n<html>\r\n<head>\r\n    <title>Example\r\n\
+             --3d6b6a416f9b5\r\n\
+             Content-Type: text/html\r\n\
+             content-range: bytes 100-150/1270\r\n\
+             \r\n\
+             eta http-equiv=\"Content-type\" content=\"text/html; c\r\n\
+             --3d6b6a416f9b5--",
+        );
+
+        let parts = parse_byteranges(&headers, bs).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            parts[0].0,
+            BytesContentRange::default()
+                .with_range(0, 50)
+                .with_size(1270)
+        );
+        assert_eq!(
+            parts[0].1,
+            Bytes::from("<!doctype html>\r\n<html>\r\n<head>\r\n    <title>Example")
+        );
+        assert_eq!(
+            parts[1].0,
+            BytesContentRange::default()
+                .with_range(100, 150)
+                .with_size(1270)
+        );
+        assert_eq!(
+            parts[1].1,
+            Bytes::from("eta http-equiv=\"Content-type\" content=\"text/html; c")
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use serde_json::json;
+use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::ops::OpPresignPost;
+use crate::*;
+
+/// PostPolicy is the policy document of browser based POST uploads that
+/// shared by S3 compatible services.
+///
+/// - [AWS S3](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html)
+/// - [Aliyun OSS](https://help.aliyun.com/document_detail/31988.html)
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+    expiration: OffsetDateTime,
+    conditions: Vec<Value>,
+}
+
+impl PostPolicy {
+    /// Create a new policy that expires at `expiration`.
+    pub fn new(expiration: OffsetDateTime) -> Self {
+        Self {
+            expiration,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Require form field `name` to be exactly `value`.
+    pub fn eq(mut self, name: &str, value: &str) -> Self {
+        self.conditions.push(json!({ name: value }));
+        self
+    }
+
+    /// Require form field `name` to start with `prefix`.
+    pub fn starts_with(mut self, name: &str, prefix: &str) -> Self {
+        self.conditions
+            .push(json!(["starts-with", format!("${name}"), prefix]));
+        self
+    }
+
+    /// Require the size of upload in `[min, max]` bytes.
+    pub fn content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.conditions
+            .push(json!(["content-length-range", min, max]));
+        self
+    }
+
+    /// Add key and all conditions in [`OpPresignPost`] into policy.
+    ///
+    /// If `key` ends with `/`, all keys start with it will be allowed.
+    ///
+    /// Returns the value of `key` form field.
+    pub fn with_op(mut self, key: &str, op: &OpPresignPost) -> (Self, String) {
+        let key_field = if key.ends_with('/') {
+            self = self.starts_with("key", key);
+            // `${filename}` will be replaced by the name of uploading file.
+            format!("{key}${{filename}}")
+        } else {
+            self = self.eq("key", key);
+            key.to_string()
+        };
+
+        if let Some(v) = op.content_type() {
+            self = self.eq("Content-Type", v);
+        }
+        if let Some(v) = op.content_type_prefix() {
+            self = self.starts_with("Content-Type", v);
+        }
+        if let Some((min, max)) = op.content_length_range() {
+            self = self.content_length_range(min, max);
+        }
+
+        (self, key_field)
+    }
+
+    /// Encode the policy document into base64 which will be signed and
+    /// sent as `policy` field.
+    pub fn to_base64(&self) -> Result<String> {
+        let expiration = self
+            .expiration
+            .replace_nanosecond(0)
+            .expect("zero nanosecond must be valid")
+            .format(&Rfc3339)
+            .map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "format expiration with rfc 3339").set_source(e)
+            })?;
+
+        let doc = json!({
+            "expiration": expiration,
+            "conditions": self.conditions,
+        });
+
+        Ok(BASE64_STANDARD.encode(doc.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_policy() {
+        let expiration = OffsetDateTime::from_unix_timestamp(1672531200).unwrap();
+        let op = OpPresignPost::new()
+            .with_content_type_prefix("image/")
+            .with_content_length_range(1, 1024);
+
+        let (policy, key) = PostPolicy::new(expiration)
+            .eq("bucket", "test")
+            .with_op("path/to/dir/", &op);
+        assert_eq!(key, "path/to/dir/${filename}");
+
+        let doc = BASE64_STANDARD.decode(policy.to_base64().unwrap()).unwrap();
+        let doc: Value = serde_json::from_slice(&doc).unwrap();
+
+        assert_eq!(
+            doc,
+            json!({
+                "expiration": "2023-01-01T00:00:00Z",
+                "conditions": [
+                    {"bucket": "test"},
+                    ["starts-with", "$key", "path/to/dir/"],
+                    ["starts-with", "$Content-Type", "image/"],
+                    ["content-length-range", 1, 1024],
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,453 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::*;
+
+/// Glob is a compiled glob pattern which matches paths relative to
+/// operator's root.
+///
+/// Supported syntax:
+///
+/// - `?` matches any single char except `/`.
+/// - `*` matches any chars except `/`.
+/// - `**` as a whole segment matches zero or more segments.
+/// - `[abc]`, `[a-z]` matches any char in the set, `[!abc]` or `[^abc]`
+///   matches any char not in the set.
+/// - `\` escapes the next char.
+///
+/// Patterns ending with `/` will only match dirs.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    pattern: String,
+    prefix: String,
+    dir_only: bool,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// `**`: matches zero or more segments.
+    Recursive,
+    Tokens(Vec<Token>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    /// `?`
+    Any,
+    /// `*`
+    Star,
+    /// `[...]`
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Token {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Token::Char(v) => *v == c,
+            Token::Any | Token::Star => true,
+            Token::Class { negated, ranges } => {
+                ranges.iter().any(|(start, end)| *start <= c && c <= *end) != *negated
+            }
+        }
+    }
+}
+
+impl Glob {
+    /// Compile the given glob pattern.
+    pub fn new(pattern: &str) -> Result<Self> {
+        let path = pattern.trim().trim_start_matches('/');
+        let dir_only = path.ends_with('/');
+
+        let parts: Vec<&str> = path.split('/').filter(|v| !v.is_empty()).collect();
+
+        // All literal segments except the last one consist the prefix.
+        let literal = parts
+            .iter()
+            .take(parts.len().saturating_sub(1))
+            .take_while(|v| !has_meta(v))
+            .count();
+        let prefix: String = parts[..literal].iter().map(|v| format!("{v}/")).collect();
+
+        let mut segments = Vec::new();
+        for part in &parts[literal..] {
+            let seg = parse_segment(part).map_err(|err| {
+                err.with_operation("Glob::new")
+                    .with_context("pattern", pattern)
+            })?;
+            if seg == Segment::Recursive && segments.last() == Some(&Segment::Recursive) {
+                continue;
+            }
+            segments.push(seg);
+        }
+
+        Ok(Glob {
+            pattern: pattern.to_string(),
+            prefix,
+            dir_only,
+            segments,
+        })
+    }
+
+    /// Return the input pattern.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Return the longest literal dir prefix of the pattern.
+    ///
+    /// All matched paths must start with this prefix, so we can start
+    /// walking from here. Empty prefix means walking from the root.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Check if the given path matches the pattern.
+    ///
+    /// Dir paths must end with `/`.
+    pub fn is_match(&self, path: &str) -> bool {
+        let is_dir = path.ends_with('/');
+        if self.dir_only && !is_dir {
+            return false;
+        }
+
+        let rest = match path.strip_prefix(&self.prefix) {
+            Some(v) => v.trim_end_matches('/'),
+            None => return false,
+        };
+        if rest.is_empty() {
+            return false;
+        }
+
+        let parts: Vec<&str> = rest.split('/').collect();
+        match_segments(&self.segments, &parts)
+    }
+
+    /// Check if paths under the given dir could match the pattern.
+    ///
+    /// Walkers can use this to prune dirs that can't contain any matched
+    /// paths.
+    pub fn could_match_in(&self, dir: &str) -> bool {
+        let rest = match dir.strip_prefix(&self.prefix) {
+            Some(v) => v.trim_end_matches('/'),
+            // The dir is the parent of prefix, only happens for the root.
+            None => return self.prefix.starts_with(dir.trim_start_matches('/')),
+        };
+
+        let parts: Vec<&str> = if rest.is_empty() {
+            vec![]
+        } else {
+            rest.split('/').collect()
+        };
+        match_dir_segments(&self.segments, &parts)
+    }
+}
+
+fn has_meta(s: &str) -> bool {
+    s.contains(|c| matches!(c, '*' | '?' | '[' | '\\'))
+}
+
+fn parse_segment(s: &str) -> Result<Segment> {
+    if s == "**" {
+        return Ok(Segment::Recursive);
+    }
+
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '\\' => Token::Char(chars.next().unwrap_or('\\')),
+            '?' => Token::Any,
+            '*' => {
+                // Consecutive stars are the same as one star.
+                if tokens.last() == Some(&Token::Star) {
+                    continue;
+                }
+                Token::Star
+            }
+            '[' => parse_class(&mut chars)?,
+            c => Token::Char(c),
+        };
+        tokens.push(token);
+    }
+
+    Ok(Segment::Tokens(tokens))
+}
+
+/// Parse class after `[` until `]`.
+fn parse_class(chars: &mut Peekable<Chars<'_>>) -> Result<Token> {
+    let negated = matches!(chars.peek(), Some('!') | Some('^'));
+    if negated {
+        chars.next();
+    }
+
+    let mut ranges = Vec::new();
+    loop {
+        let c = match chars.next() {
+            // `]` at the beginning is treated as a literal char.
+            Some(']') if !ranges.is_empty() => break,
+            Some('\\') => chars.next().unwrap_or('\\'),
+            Some(c) => c,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "glob pattern has unclosed char class",
+                ))
+            }
+        };
+
+        // Parse range like `a-z`, `-` before `]` is treated as a literal char.
+        let mut lookahead = chars.clone();
+        if lookahead.next() == Some('-') && !matches!(lookahead.peek(), Some(']') | None) {
+            chars.next();
+            let end = match chars.next() {
+                Some('\\') => chars.next().unwrap_or('\\'),
+                Some(c) => c,
+                None => unreachable!("range end must be checked before"),
+            };
+            if end < c {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "glob pattern has invalid char range",
+                ));
+            }
+            ranges.push((c, end));
+        } else {
+            ranges.push((c, c));
+        }
+    }
+
+    Ok(Token::Class { negated, ranges })
+}
+
+/// Match parts against segments with `**` backtracking.
+///
+/// Only the last `**` needs to be retried: any match found by retrying an
+/// earlier `**` can also be found by the last one, so this runs in
+/// `O(segs * parts)` instead of exponential time.
+fn match_segments(segs: &[Segment], parts: &[&str]) -> bool {
+    let (mut s, mut p) = (0, 0);
+    // The segment after the last `**` and the part it starts to match.
+    let mut backtrack = None;
+
+    while p < parts.len() {
+        match segs.get(s) {
+            Some(Segment::Recursive) => {
+                backtrack = Some((s + 1, p));
+                s += 1;
+                continue;
+            }
+            Some(Segment::Tokens(tokens)) => {
+                let chars: Vec<char> = parts[p].chars().collect();
+                if match_tokens(tokens, &chars) {
+                    s += 1;
+                    p += 1;
+                    continue;
+                }
+            }
+            None => {}
+        }
+
+        // Let the last `**` consume one more part.
+        match backtrack {
+            Some((bs, bp)) => {
+                backtrack = Some((bs, bp + 1));
+                s = bs;
+                p = bp + 1;
+            }
+            None => return false,
+        }
+    }
+
+    segs[s..].iter().all(|v| *v == Segment::Recursive)
+}
+
+/// Check if the children of given dir parts could match segments.
+fn match_dir_segments(segs: &[Segment], parts: &[&str]) -> bool {
+    match segs.split_first() {
+        // Pattern has been consumed, children can't match.
+        None => false,
+        Some((Segment::Recursive, _)) => true,
+        Some((Segment::Tokens(tokens), rest)) => match parts.split_first() {
+            Some((part, parts)) => {
+                let chars: Vec<char> = part.chars().collect();
+                match_tokens(tokens, &chars) && match_dir_segments(rest, parts)
+            }
+            None => true,
+        },
+    }
+}
+
+/// Match chars against tokens with `*` backtracking, the same as
+/// [`match_segments`].
+fn match_tokens(tokens: &[Token], chars: &[char]) -> bool {
+    let (mut t, mut c) = (0, 0);
+    // The token after the last `*` and the char it starts to match.
+    let mut backtrack = None;
+
+    while c < chars.len() {
+        match tokens.get(t) {
+            Some(Token::Star) => {
+                backtrack = Some((t + 1, c));
+                t += 1;
+                continue;
+            }
+            Some(token) if token.matches(chars[c]) => {
+                t += 1;
+                c += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        // Let the last `*` consume one more char.
+        match backtrack {
+            Some((bt, bc)) => {
+                backtrack = Some((bt, bc + 1));
+                t = bt;
+                c = bc + 1;
+            }
+            None => return false,
+        }
+    }
+
+    tokens[t..].iter().all(|v| *v == Token::Star)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_prefix() {
+        let cases = vec![
+            ("empty", "", ""),
+            ("root", "/", ""),
+            ("plain file", "a.txt", ""),
+            ("plain path", "a/b/c.txt", "a/b/"),
+            ("star", "data/2023/*.parquet", "data/2023/"),
+            ("recursive", "data/**/*.parquet", "data/"),
+            ("meta at first", "d?ta/x", ""),
+            ("dir only", "data/*/", "data/"),
+            ("leading slash", "/data/x/*", "data/x/"),
+            ("duplicated slash", "data//x/*", "data/x/"),
+        ];
+
+        for (name, pattern, expected) in cases {
+            let glob = Glob::new(pattern).expect("glob must be valid");
+            assert_eq!(glob.prefix(), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_glob_is_match() {
+        let cases = vec![
+            ("plain", "a/b.txt", "a/b.txt", true),
+            ("plain mismatch", "a/b.txt", "a/c.txt", false),
+            ("star", "a/*.txt", "a/b.txt", true),
+            ("star empty", "a/*.txt", "a/.txt", true),
+            ("star not cross dir", "a/*.txt", "a/b/c.txt", false),
+            ("question", "a/?.txt", "a/b.txt", true),
+            ("question too long", "a/?.txt", "a/bc.txt", false),
+            ("class", "a/[bc].txt", "a/c.txt", true),
+            ("class mismatch", "a/[bc].txt", "a/d.txt", false),
+            ("class range", "a/[a-c]x", "a/bx", true),
+            ("class negated", "a/[!a-c]x", "a/bx", false),
+            ("class negated caret", "a/[^a-c]x", "a/dx", true),
+            ("class bracket", "a/[]]x", "a/]x", true),
+            ("class dash", "a/[a-]x", "a/-x", true),
+            ("escape", "a/\\*.txt", "a/*.txt", true),
+            ("escape mismatch", "a/\\*.txt", "a/b.txt", false),
+            ("recursive zero", "a/**/*.txt", "a/b.txt", true),
+            ("recursive many", "a/**/*.txt", "a/x/y/z/b.txt", true),
+            ("recursive suffix", "a/**", "a/x/y", true),
+            ("recursive not prefix", "a/**", "a/", false),
+            ("recursive middle", "a/**/x/*", "a/b/c/x/y", true),
+            ("recursive middle mismatch", "a/**/x/*", "a/b/c/y/y", false),
+            ("dir", "a/*", "a/b/", true),
+            ("dir only", "a/*/", "a/b/", true),
+            ("dir only file", "a/*/", "a/b", false),
+            ("prefix mismatch", "a/b/*", "a/c/d", false),
+            ("multi star", "a/*b*c", "a/xbyc", true),
+            ("multi star mismatch", "a/*b*c", "a/xbyd", false),
+            ("star backtrack", "a/*ab", "a/aab", true),
+            ("star backtrack mismatch", "a/*ab*", "a/aaa", false),
+            ("star only", "a/*", "a/b.txt", true),
+            ("recursive backtrack", "a/**/x/y", "a/x/x/y", true),
+            ("recursive backtrack mismatch", "a/**/x/y", "a/x/y/x", false),
+            ("multi recursive", "a/**/x/**/y", "a/b/x/c/x/d/y", true),
+            (
+                "multi recursive mismatch",
+                "a/**/x/**/y",
+                "a/b/y/c/x/d",
+                false,
+            ),
+        ];
+
+        for (name, pattern, path, expected) in cases {
+            let glob = Glob::new(pattern).expect("glob must be valid");
+            assert_eq!(glob.is_match(path), expected, "{name}");
+        }
+    }
+
+    /// Patterns like these take exponential time with naive recursion.
+    #[test]
+    fn test_glob_is_match_pathological() {
+        let name = "a".repeat(64);
+        let glob = Glob::new(&format!("x/{}b", "*a".repeat(32))).expect("glob must be valid");
+        assert!(!glob.is_match(&format!("x/{name}")));
+        assert!(glob.is_match(&format!("x/{name}b")));
+
+        let path = format!("x/{}", vec!["a"; 64].join("/"));
+        let glob = Glob::new(&format!("x/{}b", "**/a/".repeat(16))).expect("glob must be valid");
+        assert!(!glob.is_match(&path));
+        assert!(glob.is_match(&format!("{path}/b")));
+    }
+
+    #[test]
+    fn test_glob_could_match_in() {
+        let cases = vec![
+            ("root", "a/b/*", "/", true),
+            ("parent of prefix", "a/b/*", "a/", true),
+            ("prefix", "a/b/*", "a/b/", true),
+            ("too deep", "a/b/*", "a/b/c/", false),
+            ("sibling", "a/b/*", "a/c/", false),
+            ("star dir", "a/*/x.txt", "a/b/", true),
+            ("star dir too deep", "a/*/x.txt", "a/b/c/", false),
+            ("class dir mismatch", "a/[xy]/x.txt", "a/b/", false),
+            ("recursive", "a/**/x.txt", "a/b/c/d/", true),
+            ("after recursive", "a/*/**/x.txt", "a/b/c/d/", true),
+        ];
+
+        for (name, pattern, dir, expected) in cases {
+            let glob = Glob::new(pattern).expect("glob must be valid");
+            assert_eq!(glob.could_match_in(dir), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_glob_invalid() {
+        for pattern in ["a/[abc", "a/[z-a]", "a/[!"] {
+            let err = Glob::new(pattern).expect_err("glob must be invalid");
+            assert_eq!(err.kind(), ErrorKind::Unexpected, "{pattern}");
+        }
+    }
+}
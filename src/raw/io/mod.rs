@@ -38,3 +38,6 @@ pub use compress::DecompressState;
 mod walk;
 pub use walk::BottomUpWalker;
 pub use walk::TopDownWalker;
+
+mod glob;
+pub use glob::Glob;
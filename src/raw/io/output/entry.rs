@@ -59,6 +59,14 @@ impl Entry {
         self.meta.mode()
     }
 
+    /// Get entry's object metadata.
+    ///
+    /// Metadata returned by list could be incomplete, only `mode` is
+    /// guaranteed to be valid.
+    pub fn metadata(&self) -> &ObjectMetadata {
+        &self.meta
+    }
+
     /// Consume to convert into an object.
     pub fn into_object(self, op: Operator) -> Object {
         Object::with(op, &self.path, self.meta)
@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::io;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::ops::*;
+use crate::raw::*;
+
+/// Convert given reader into [`output::BlockingReader`] by range.
+///
+/// # Notes
+///
+/// This operation is not zero cost. If the accessor already returns a
+/// seekable reader, please don't use this.
+pub fn by_range<A: Accessor>(
+    acc: Arc<A>,
+    path: &str,
+    reader: A::BlockingReader,
+    offset: u64,
+    size: u64,
+) -> RangeReader<A> {
+    RangeReader {
+        acc,
+        path: path.to_string(),
+        offset,
+        size,
+        cur: 0,
+        reader: Some(reader),
+        sink: Vec::new(),
+    }
+}
+
+/// RangeReader that can do seek on non-seekable blocking reader.
+pub struct RangeReader<A: Accessor> {
+    acc: Arc<A>,
+    path: String,
+
+    offset: u64,
+    size: u64,
+    cur: u64,
+    reader: Option<A::BlockingReader>,
+
+    /// sink is to consume bytes for seek optimize.
+    sink: Vec<u8>,
+}
+
+impl<A: Accessor> RangeReader<A> {
+    /// Return the current reader, or send a new read request if we don't
+    /// have one.
+    fn reader(&mut self) -> io::Result<&mut A::BlockingReader> {
+        if self.reader.is_none() {
+            let op = OpRead::default().with_range(BytesRange::new(
+                Some(self.offset + self.cur),
+                Some(self.size - self.cur),
+            ));
+
+            // TODO
+            //
+            // we can use RpRead returned here to correct size.
+            let (_, r) = self.acc.blocking_read(&self.path, op)?;
+            self.reader = Some(r);
+        }
+
+        Ok(self.reader.as_mut().expect("reader must be valid"))
+    }
+}
+
+impl<A: Accessor> output::BlockingRead for RangeReader<A> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.cur >= self.size {
+            return Ok(0);
+        }
+
+        match self.reader()?.read(buf) {
+            Ok(n) if n == 0 => {
+                // Reset reader after all data has been consumed.
+                self.reader = None;
+                Ok(0)
+            }
+            Ok(n) => {
+                self.cur += n as u64;
+                Ok(n)
+            }
+            Err(e) => {
+                self.reader = None;
+                Err(e)
+            }
+        }
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, amt) = match pos {
+            SeekFrom::Start(n) => (0, n as i64),
+            SeekFrom::End(n) => (self.size as i64, n),
+            SeekFrom::Current(n) => (self.cur as i64, n),
+        };
+
+        let seek_pos = match base.checked_add(amt) {
+            Some(n) if n >= 0 => n as u64,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                ))
+            }
+        };
+
+        if seek_pos == self.cur {
+            return Ok(self.cur);
+        }
+
+        // If the next seek pos is close enough, we can just
+        // read the cnt instead of droping the reader.
+        //
+        // TODO: make this value configurable
+        if self.reader.is_some() && seek_pos > self.cur && seek_pos - self.cur < 1024 * 1024 {
+            while self.cur < seek_pos {
+                // 212992 is the default read mem buffer of archlinux.
+                //
+                // TODO: make this value configurable
+                let consume = cmp::min((seek_pos - self.cur) as usize, 212992);
+                self.sink.resize(consume, 0);
+
+                let r = self.reader.as_mut().expect("reader must be valid");
+                match r.read(&mut self.sink[..consume]) {
+                    Ok(n) if n > 0 => self.cur += n as u64,
+                    // If we are hitting errors or EOF while read ahead.
+                    // It's better to drop this reader and seek to
+                    // correct position directly.
+                    _ => {
+                        self.reader = None;
+                        break;
+                    }
+                }
+            }
+        } else {
+            // If we are trying to seek to far more away.
+            // Let's just drop the reader.
+            self.reader = None;
+        }
+
+        self.cur = seek_pos;
+        Ok(self.cur)
+    }
+
+    fn next(&mut self) -> Option<io::Result<Bytes>> {
+        if self.cur >= self.size {
+            return None;
+        }
+
+        let r = match self.reader() {
+            Ok(r) => r,
+            Err(err) => return Some(Err(err)),
+        };
+
+        match r.next() {
+            Some(Ok(bs)) => {
+                self.cur += bs.len() as u64;
+                Some(Ok(bs))
+            }
+            Some(Err(err)) => {
+                self.reader = None;
+                Some(Err(err))
+            }
+            None => {
+                self.reader = None;
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::io;
+use std::io::SeekFrom;
+use std::mem;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::ready;
+use futures::stream::FuturesOrdered;
+use futures::StreamExt;
+
+use crate::ops::*;
+use crate::raw::*;
+use crate::*;
+
+/// Convert given accessor into [`output::Reader`] which prefetches
+/// chunks ahead of current position.
+///
+/// # Notes
+///
+/// The number of chunks in flight starts from one and doubles every time
+/// a chunk is consumed, until reaching [`OpPrefetch`]'s limit. Seeking
+/// will drop all prefetched chunks and reset the window, so random
+/// access will not waste too much bandwidth.
+pub fn by_prefetch<A: Accessor + ?Sized>(
+    acc: Arc<A>,
+    path: &str,
+    size: u64,
+    op: OpPrefetch,
+) -> PrefetchReader<A> {
+    PrefetchReader {
+        acc,
+        path: path.to_string(),
+        size,
+        chunk_size: op.chunk_size() as u64,
+        max_window: op.max_window(),
+
+        cur: 0,
+        next_offset: 0,
+        window: 1,
+        buf: Bytes::new(),
+        chunks: FuturesOrdered::new(),
+    }
+}
+
+/// PrefetchReader keeps chunk-sized reads in flight ahead of current
+/// position for sequential reading.
+pub struct PrefetchReader<A: Accessor + ?Sized> {
+    acc: Arc<A>,
+    path: String,
+    size: u64,
+    chunk_size: u64,
+    max_window: usize,
+
+    /// Position of the next byte returned to users.
+    cur: u64,
+    /// Start position of the next chunk to fetch.
+    next_offset: u64,
+    /// Max number of chunks in flight for now.
+    window: usize,
+    /// The remaining data of current chunk.
+    buf: Bytes,
+    chunks: FuturesOrdered<BoxFuture<'static, Result<Bytes>>>,
+}
+
+/// Safety: PrefetchReader will only be accessed under &mut.
+unsafe impl<A: Accessor + ?Sized> Sync for PrefetchReader<A> {}
+
+impl<A: Accessor + ?Sized> PrefetchReader<A> {
+    fn read_future(&self, offset: u64, size: u64) -> BoxFuture<'static, Result<Bytes>> {
+        let acc = self.acc.clone();
+        let path = self.path.clone();
+        let br = BytesRange::new(Some(offset), Some(size));
+        let op = OpRead::new().with_range(br);
+
+        Box::pin(async move {
+            let (_, mut r) = acc.read(&path, op).await?;
+
+            let mut buf = vec![0; size as usize];
+            let mut n = 0;
+            while n < buf.len() {
+                let read = output::ReadExt::read(&mut r, &mut buf[n..])
+                    .await
+                    .map_err(|err| {
+                        Error::new(ErrorKind::Unexpected, "read prefetch chunk")
+                            .with_operation("PrefetchReader::read")
+                            .with_context("path", &path)
+                            .with_context("range", br.to_string())
+                            .set_source(err)
+                    })?;
+                if read == 0 {
+                    break;
+                }
+                n += read;
+            }
+            buf.truncate(n);
+
+            Ok(Bytes::from(buf))
+        })
+    }
+
+    /// Fill the prefetch queue until reaching the window.
+    fn prefetch(&mut self) {
+        while self.chunks.len() < self.window && self.next_offset < self.size {
+            let size = cmp::min(self.chunk_size, self.size - self.next_offset);
+            let fut = self.read_future(self.next_offset, size);
+            self.chunks.push_back(fut);
+            self.next_offset += size;
+        }
+    }
+
+    /// Drop all prefetched chunks and start again from `pos`.
+    fn reset(&mut self, pos: u64) {
+        self.chunks = FuturesOrdered::new();
+        self.buf = Bytes::new();
+        self.window = 1;
+        self.cur = pos;
+        self.next_offset = pos;
+    }
+
+    /// Make sure current chunk has data, return `false` if we reach the end.
+    fn poll_fill(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<bool>> {
+        while self.buf.is_empty() {
+            if self.cur >= self.size {
+                return Poll::Ready(Ok(false));
+            }
+
+            self.prefetch();
+            match ready!(self.chunks.poll_next_unpin(cx)) {
+                Some(Ok(bs)) if bs.is_empty() => {
+                    // Object has been truncated since we get the size, the
+                    // left chunks are useless.
+                    self.size = self.cur;
+                    self.reset(self.cur);
+                }
+                Some(Ok(bs)) => {
+                    // Sequential read detected, enlarge the window.
+                    self.window = cmp::min(self.window * 2, self.max_window);
+                    self.buf = bs;
+                }
+                Some(Err(err)) => {
+                    // Drop all chunks so that we can retry from current
+                    // position.
+                    self.reset(self.cur);
+                    return Poll::Ready(Err(err.into()));
+                }
+                None => return Poll::Ready(Ok(false)),
+            }
+        }
+
+        Poll::Ready(Ok(true))
+    }
+}
+
+impl<A: Accessor + ?Sized> output::Read for PrefetchReader<A> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        if buf.is_empty() || !ready!(self.poll_fill(cx))? {
+            return Poll::Ready(Ok(0));
+        }
+
+        let n = cmp::min(buf.len(), self.buf.len());
+        buf[..n].copy_from_slice(&self.buf[..n]);
+        self.buf.advance(n);
+        self.cur += n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, _: &mut Context<'_>, pos: SeekFrom) -> Poll<io::Result<u64>> {
+        let (base, amt) = match pos {
+            SeekFrom::Start(n) => (0, n as i64),
+            SeekFrom::End(n) => (self.size as i64, n),
+            SeekFrom::Current(n) => (self.cur as i64, n),
+        };
+
+        let seek_pos = match base.checked_add(amt) {
+            Some(n) if n >= 0 => n as u64,
+            _ => {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )))
+            }
+        };
+
+        if seek_pos == self.cur {
+            return Poll::Ready(Ok(self.cur));
+        }
+
+        // Seek inside current chunk, we can just consume the data.
+        if seek_pos > self.cur && seek_pos - self.cur < self.buf.len() as u64 {
+            self.buf.advance((seek_pos - self.cur) as usize);
+            self.cur = seek_pos;
+            return Poll::Ready(Ok(self.cur));
+        }
+
+        self.reset(seek_pos);
+        Poll::Ready(Ok(self.cur))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<Bytes>>> {
+        match ready!(self.poll_fill(cx)) {
+            Ok(true) => {
+                let bs = mem::take(&mut self.buf);
+                self.cur += bs.len() as u64;
+                Poll::Ready(Some(Ok(bs)))
+            }
+            Ok(false) => Poll::Ready(None),
+            Err(err) => Poll::Ready(Some(Err(err))),
+        }
+    }
+}
@@ -38,6 +38,10 @@ mod by_range;
 pub use by_range::by_range;
 pub use by_range::RangeReader;
 
+mod by_prefetch;
+pub use by_prefetch::by_prefetch;
+pub use by_prefetch::PrefetchReader;
+
 mod from_fd;
 pub use from_fd::from_fd;
 pub use from_fd::FdReader;
@@ -0,0 +1,203 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::Result;
+use std::io::SeekFrom;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use futures::ready;
+
+use crate::ops::ChecksumAlgorithm;
+use crate::ops::ChecksumHasher;
+use crate::raw::*;
+
+/// into_verified_reader is used to verify the content read from [`output::Read`]
+/// or [`output::BlockingRead`] against the checksum of the whole object.
+///
+/// An error with [`ErrorKind::ChecksumMismatch`][crate::ErrorKind::ChecksumMismatch]
+/// will be returned at the end of reading if the content mismatch.
+///
+/// Verification will be skipped after seeking, because the content read
+/// is not the whole object anymore.
+pub fn into_verified_reader<R>(
+    r: R,
+    algorithm: ChecksumAlgorithm,
+    expected: &str,
+) -> IntoVerifiedReader<R> {
+    IntoVerifiedReader {
+        r,
+        hasher: Some(algorithm.hasher()),
+        expected: expected.to_string(),
+    }
+}
+
+/// Verify the content of given read.
+pub struct IntoVerifiedReader<R> {
+    r: R,
+    /// Set to `None` after verified or seeked.
+    hasher: Option<ChecksumHasher>,
+    expected: String,
+}
+
+impl<R> IntoVerifiedReader<R> {
+    fn update(&mut self, bs: &[u8]) -> Result<()> {
+        let hasher = match &mut self.hasher {
+            Some(hasher) => hasher,
+            None => return Ok(()),
+        };
+
+        if !bs.is_empty() {
+            hasher.update(bs);
+            return Ok(());
+        }
+
+        // Reach the end of content.
+        let hasher = self.hasher.take().expect("hasher must be valid");
+        hasher.verify(&self.expected).map_err(io::Error::from)
+    }
+}
+
+impl<R: output::Read> output::Read for IntoVerifiedReader<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let n = ready!(self.r.poll_read(cx, buf))?;
+        // Empty buf can't tell us whether we reach the end.
+        if !buf.is_empty() {
+            self.update(&buf[..n])?;
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: SeekFrom) -> Poll<Result<u64>> {
+        let n = ready!(self.r.poll_seek(cx, pos))?;
+        self.hasher = None;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        match ready!(self.r.poll_next(cx)) {
+            Some(Ok(bs)) => {
+                if let Some(hasher) = &mut self.hasher {
+                    hasher.update(&bs);
+                }
+                Poll::Ready(Some(Ok(bs)))
+            }
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => match self.update(&[]) {
+                Ok(()) => Poll::Ready(None),
+                Err(err) => Poll::Ready(Some(Err(err))),
+            },
+        }
+    }
+}
+
+impl<R: output::BlockingRead> output::BlockingRead for IntoVerifiedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.r.read(buf)?;
+        if !buf.is_empty() {
+            self.update(&buf[..n])?;
+        }
+        Ok(n)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let n = self.r.seek(pos)?;
+        self.hasher = None;
+        Ok(n)
+    }
+
+    fn next(&mut self) -> Option<Result<Bytes>> {
+        match self.r.next() {
+            Some(Ok(bs)) => {
+                if let Some(hasher) = &mut self.hasher {
+                    hasher.update(&bs);
+                }
+                Some(Ok(bs))
+            }
+            Some(Err(err)) => Some(Err(err)),
+            None => self.update(&[]).err().map(Err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_into_verified_reader() {
+        use output::ReadExt;
+
+        let content = b"Hello, World!".to_vec();
+        let algorithm = ChecksumAlgorithm::Crc32c;
+
+        let r = output::Cursor::from(content.clone());
+        let mut r = into_verified_reader(
+            Box::new(r) as output::Reader,
+            algorithm,
+            &algorithm.checksum(&content),
+        );
+        let mut bs = Vec::new();
+        while let Some(v) = r.next().await {
+            bs.extend_from_slice(&v.expect("read must succeed"));
+        }
+        assert_eq!(bs, content);
+
+        let r = output::Cursor::from(content.clone());
+        let mut r = into_verified_reader(
+            Box::new(r) as output::Reader,
+            algorithm,
+            &algorithm.checksum(b"Hello"),
+        );
+        let mut result = Ok(());
+        while let Some(v) = r.next().await {
+            if let Err(err) = v {
+                result = Err(err);
+                break;
+            }
+        }
+        assert_eq!(
+            result.expect_err("read must fail on mismatch").kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_into_verified_reader_blocking() {
+        use output::BlockingRead;
+
+        let content = b"Hello, World!".to_vec();
+        let algorithm = ChecksumAlgorithm::Sha256;
+
+        let r = output::Cursor::from(content.clone());
+        let mut r = into_verified_reader(
+            Box::new(r) as output::BlockingReader,
+            algorithm,
+            &algorithm.checksum(b"Hello"),
+        );
+        let mut result = Ok(());
+        while let Some(bs) = r.next() {
+            if let Err(err) = bs {
+                result = Err(err);
+                break;
+            }
+        }
+        assert_eq!(
+            result.expect_err("read must fail on mismatch").kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
@@ -42,6 +42,10 @@ mod into_streamable;
 pub use into_streamable::into_streamable_reader;
 pub use into_streamable::IntoStreamableReader;
 
+mod into_verified;
+pub use into_verified::into_verified_reader;
+pub use into_verified::IntoVerifiedReader;
+
 mod entry;
 pub use entry::Entry;
 
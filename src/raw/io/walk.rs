@@ -14,6 +14,7 @@
 
 use std::collections::VecDeque;
 use std::mem;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 
@@ -24,6 +25,101 @@ use crate::*;
 
 const WALK_BUFFER_SIZE: usize = 256;
 
+type EntryFilter = Arc<dyn Fn(&output::Entry) -> bool + Send + Sync>;
+
+/// WalkFilter decides which entries to return and which dirs to walk into.
+#[derive(Clone)]
+struct WalkFilter {
+    root: String,
+    max_depth: Option<usize>,
+    filter: Option<EntryFilter>,
+    dir_filter: Option<EntryFilter>,
+}
+
+impl WalkFilter {
+    fn new(root: &str) -> Self {
+        WalkFilter {
+            root: root.to_string(),
+            max_depth: None,
+            filter: None,
+            dir_filter: None,
+        }
+    }
+
+    /// Depth of path relative to root, entries inside root have depth `1`.
+    fn depth(&self, path: &str) -> usize {
+        let rel = if self.root == "/" {
+            path
+        } else {
+            path.strip_prefix(&self.root).unwrap_or(path)
+        };
+        let rel = rel.trim_matches('/');
+
+        if rel.is_empty() {
+            0
+        } else {
+            rel.split('/').count()
+        }
+    }
+
+    fn should_return(&self, de: &output::Entry) -> bool {
+        self.filter.as_ref().map(|f| f(de)).unwrap_or(true)
+    }
+
+    fn should_walk(&self, de: &output::Entry) -> bool {
+        if let Some(max_depth) = self.max_depth {
+            if self.depth(de.path()) >= max_depth {
+                return false;
+            }
+        }
+
+        self.dir_filter.as_ref().map(|f| f(de)).unwrap_or(true)
+    }
+}
+
+/// Generate filter setters for walkers.
+macro_rules! impl_walk_filter {
+    ($walker:ident) => {
+        impl $walker {
+            /// Only return entries that the filter returns `true`.
+            ///
+            /// Dirs that filtered out will still be walked into, use
+            /// [`Self::with_dir_filter`] to skip them.
+            pub fn with_filter(
+                mut self,
+                f: impl Fn(&output::Entry) -> bool + Send + Sync + 'static,
+            ) -> Self {
+                self.filter.filter = Some(Arc::new(f));
+                self
+            }
+
+            /// Only walk into dirs that the filter returns `true`.
+            ///
+            /// Dirs that not walked into will still be returned if they
+            /// pass [`Self::with_filter`].
+            pub fn with_dir_filter(
+                mut self,
+                f: impl Fn(&output::Entry) -> bool + Send + Sync + 'static,
+            ) -> Self {
+                self.filter.dir_filter = Some(Arc::new(f));
+                self
+            }
+
+            /// Stop walking into dirs deeper than `depth`.
+            ///
+            /// Entries inside the given path have depth `1`, so `1` means
+            /// only list the given path.
+            pub fn with_max_depth(mut self, depth: usize) -> Self {
+                self.filter.max_depth = Some(depth);
+                self
+            }
+        }
+    };
+}
+
+impl_walk_filter!(TopDownWalker);
+impl_walk_filter!(BottomUpWalker);
+
 /// TopDownWalker will walk dir in top down way:
 ///
 /// - List current dir first
@@ -58,6 +154,7 @@ const WALK_BUFFER_SIZE: usize = 256;
 /// We only make sure the parent dirs will show up before nest dirs.
 pub struct TopDownWalker {
     acc: FusedAccessor,
+    filter: WalkFilter,
     dirs: VecDeque<output::Entry>,
     pagers: Vec<(output::Pager, Vec<output::Entry>)>,
     res: Vec<output::Entry>,
@@ -69,6 +166,7 @@ impl TopDownWalker {
         let path = normalize_path(path);
         TopDownWalker {
             acc,
+            filter: WalkFilter::new(&path),
             dirs: VecDeque::from([output::Entry::with(
                 path,
                 ObjectMetadata::new(ObjectMode::DIR),
@@ -85,7 +183,9 @@ impl output::Page for TopDownWalker {
         loop {
             if let Some(de) = self.dirs.pop_front() {
                 let (_, op) = self.acc.list(de.path(), OpList::default()).await?;
-                self.res.push(de);
+                if self.filter.should_return(&de) {
+                    self.res.push(de);
+                }
                 self.pagers.push((op, vec![]))
             }
 
@@ -113,11 +213,11 @@ impl output::Page for TopDownWalker {
             let mut buf = VecDeque::from(buf);
             loop {
                 if let Some(oe) = buf.pop_front() {
-                    if oe.mode().is_dir() {
+                    if oe.mode().is_dir() && self.filter.should_walk(&oe) {
                         self.dirs.push_back(oe);
                         self.pagers.push((pager, buf.into()));
                         break;
-                    } else {
+                    } else if self.filter.should_return(&oe) {
                         self.res.push(oe)
                     }
                 } else {
@@ -171,6 +271,7 @@ impl output::Page for TopDownWalker {
 /// always output directly while listing.
 pub struct BottomUpWalker {
     acc: FusedAccessor,
+    filter: WalkFilter,
     dirs: VecDeque<output::Entry>,
     pagers: Vec<(output::Pager, output::Entry, Vec<output::Entry>)>,
     res: Vec<output::Entry>,
@@ -181,6 +282,7 @@ impl BottomUpWalker {
     pub fn new(acc: FusedAccessor, path: &str) -> Self {
         BottomUpWalker {
             acc,
+            filter: WalkFilter::new(&normalize_path(path)),
             dirs: VecDeque::from([output::Entry::new(
                 path,
                 ObjectMetadata::new(ObjectMode::DIR),
@@ -216,7 +318,9 @@ impl output::Page for BottomUpWalker {
                         buf = v;
                     }
                     None => {
-                        self.res.push(de);
+                        if self.filter.should_return(&de) {
+                            self.res.push(de);
+                        }
                         continue;
                     }
                 }
@@ -225,11 +329,11 @@ impl output::Page for BottomUpWalker {
             let mut buf = VecDeque::from(buf);
             loop {
                 if let Some(oe) = buf.pop_front() {
-                    if oe.mode().is_dir() {
+                    if oe.mode().is_dir() && self.filter.should_walk(&oe) {
                         self.dirs.push_back(oe);
                         self.pagers.push((pager, de, buf.into()));
                         break;
-                    } else {
+                    } else if self.filter.should_return(&oe) {
                         self.res.push(oe)
                     }
                 } else {
@@ -404,4 +508,89 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_walk_with_filter() -> Result<()> {
+        let _ = env_logger::try_init();
+
+        let mut builder = Fs::default();
+        builder.root(&format!(
+            "{}/{}",
+            env::temp_dir().display(),
+            uuid::Uuid::new_v4()
+        ));
+        let op = Operator::create(builder)?.finish();
+        for path in ["x/y", "x/x/", "x/x/y", "x/x/x/", "x/x/x/y"] {
+            op.object(path).create().await?;
+        }
+
+        let w = TopDownWalker::new(op.inner(), "x/")
+            .with_max_depth(2)
+            .with_filter(|de| de.mode().is_file());
+        let mut actual = ObjectLister::new(op.clone(), Box::new(w))
+            .map_ok(|v| v.path().to_string())
+            .try_collect::<Vec<_>>()
+            .await?;
+        actual.sort_unstable();
+        assert_eq!(actual, vec!["x/x/y", "x/y"]);
+
+        let w = BottomUpWalker::new(op.inner(), "x/")
+            .with_dir_filter(|de| de.path() != "x/x/x/")
+            .with_filter(|de| de.mode().is_dir());
+        let actual = ObjectLister::new(op, Box::new(w))
+            .map_ok(|v| v.path().to_string())
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(actual, vec!["x/x/x/", "x/x/", "x/"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_glob() -> Result<()> {
+        let _ = env_logger::try_init();
+
+        let mut builder = Fs::default();
+        builder.root(&format!(
+            "{}/{}",
+            env::temp_dir().display(),
+            uuid::Uuid::new_v4()
+        ));
+        let op = Operator::create(builder)?.finish();
+        for path in [
+            "data/a.parquet",
+            "data/a.csv",
+            "data/2023/b.parquet",
+            "data/2023/01/c.parquet",
+            "other/d.parquet",
+        ] {
+            op.object(path).create().await?;
+        }
+
+        let cases = vec![
+            ("data/*.parquet", vec!["data/a.parquet"]),
+            (
+                "data/**/*.parquet",
+                vec![
+                    "data/2023/01/c.parquet",
+                    "data/2023/b.parquet",
+                    "data/a.parquet",
+                ],
+            ),
+            ("*/?.parquet", vec!["data/a.parquet", "other/d.parquet"]),
+            ("data/*/", vec!["data/2023/"]),
+            ("data/[a-b].*", vec!["data/a.csv", "data/a.parquet"]),
+            ("not_exist/**", vec![]),
+        ];
+
+        for (pattern, expected) in cases {
+            let mut actual = op
+                .glob(pattern)?
+                .map_ok(|v| v.path().to_string())
+                .try_collect::<Vec<_>>()
+                .await?;
+            actual.sort_unstable();
+            assert_eq!(actual, expected, "{pattern}");
+        }
+        Ok(())
+    }
 }
@@ -43,6 +43,10 @@ pub enum Operation {
     CompleteMultipart,
     /// Operation for [`crate::raw::Accessor::abort_multipart`]
     AbortMultipart,
+    /// Operation for [`crate::raw::Accessor::batch`]
+    Batch,
+    /// Operation for [`crate::raw::Accessor::read_ranges`]
+    ReadRanges,
     /// Operation for [`crate::raw::Accessor::blocking_create`]
     BlockingCreate,
     /// Operation for [`crate::raw::Accessor::blocking_read`]
@@ -55,6 +59,14 @@ pub enum Operation {
     BlockingDelete,
     /// Operation for [`crate::raw::Accessor::blocking_list`]
     BlockingList,
+    /// Operation for [`crate::raw::Accessor::blocking_create_multipart`]
+    BlockingCreateMultipart,
+    /// Operation for [`crate::raw::Accessor::blocking_write_multipart`]
+    BlockingWriteMultipart,
+    /// Operation for [`crate::raw::Accessor::blocking_complete_multipart`]
+    BlockingCompleteMultipart,
+    /// Operation for [`crate::raw::Accessor::blocking_abort_multipart`]
+    BlockingAbortMultipart,
 }
 
 impl Operation {
@@ -91,12 +103,18 @@ impl From<Operation> for &'static str {
             Operation::WriteMultipart => "write_multipart",
             Operation::CompleteMultipart => "complete_multipart",
             Operation::AbortMultipart => "abort_multipart",
+            Operation::Batch => "batch",
+            Operation::ReadRanges => "read_ranges",
             Operation::BlockingCreate => "blocking_create",
             Operation::BlockingRead => "blocking_read",
             Operation::BlockingWrite => "blocking_write",
             Operation::BlockingStat => "blocking_stat",
             Operation::BlockingDelete => "blocking_delete",
             Operation::BlockingList => "blocking_list",
+            Operation::BlockingCreateMultipart => "blocking_create_multipart",
+            Operation::BlockingWriteMultipart => "blocking_write_multipart",
+            Operation::BlockingCompleteMultipart => "blocking_complete_multipart",
+            Operation::BlockingAbortMultipart => "blocking_abort_multipart",
         }
     }
 }
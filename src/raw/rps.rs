@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bytes::Bytes;
 use http::Request;
 
 use crate::*;
@@ -88,6 +89,56 @@ pub struct RpCompleteMultipart {}
 #[derive(Debug, Clone, Default)]
 pub struct RpAbortMultipart {}
 
+/// Reply for `batch` operation.
+#[derive(Debug)]
+pub struct RpBatch {
+    results: BatchedResults,
+}
+
+impl RpBatch {
+    /// Create a new RpBatch.
+    pub fn new(results: BatchedResults) -> Self {
+        Self { results }
+    }
+
+    /// Get the results from RpBatch.
+    pub fn results(&self) -> &BatchedResults {
+        &self.results
+    }
+
+    /// Consume RpBatch to get the batched results.
+    pub fn into_results(self) -> BatchedResults {
+        self.results
+    }
+}
+
+/// Batch results of `batch` operations.
+///
+/// Results are returned per path, the order could be different from
+/// the input operations.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BatchedResults {
+    /// results of delete batch operation
+    Delete(Vec<(String, Result<RpDelete>)>),
+}
+
+impl BatchedResults {
+    /// Return the count of succeeded operations.
+    pub fn len_ok(&self) -> usize {
+        match self {
+            BatchedResults::Delete(v) => v.iter().filter(|(_, v)| v.is_ok()).count(),
+        }
+    }
+
+    /// Return the count of failed operations.
+    pub fn len_err(&self) -> usize {
+        match self {
+            BatchedResults::Delete(v) => v.iter().filter(|(_, v)| v.is_err()).count(),
+        }
+    }
+}
+
 /// Reply for `presign` operation.
 #[derive(Debug, Clone)]
 pub struct RpPresign {
@@ -112,6 +163,7 @@ pub struct PresignedRequest {
     method: http::Method,
     uri: http::Uri,
     headers: http::HeaderMap,
+    form_fields: Vec<(String, String)>,
 }
 
 impl PresignedRequest {
@@ -121,9 +173,16 @@ impl PresignedRequest {
             method,
             uri,
             headers,
+            form_fields: Vec::new(),
         }
     }
 
+    /// Set form fields of this request.
+    pub fn with_form_fields(mut self, fields: Vec<(String, String)>) -> Self {
+        self.form_fields = fields;
+        self
+    }
+
     /// Return request's method.
     pub fn method(&self) -> &http::Method {
         &self.method
@@ -138,6 +197,14 @@ impl PresignedRequest {
     pub fn header(&self) -> &http::HeaderMap {
         &self.headers
     }
+
+    /// Return request's form fields.
+    ///
+    /// Only presigned POST uploads have form fields, they must be sent as
+    /// `multipart/form-data` in order with the file field at last.
+    pub fn form_fields(&self) -> &[(String, String)] {
+        &self.form_fields
+    }
 }
 
 impl<T: Default> From<PresignedRequest> for Request<T> {
@@ -183,6 +250,26 @@ impl RpRead {
     }
 }
 
+/// Reply for `read_ranges` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpReadRanges {
+    data: Vec<Bytes>,
+}
+
+impl RpReadRanges {
+    /// Create a new reply for `read_ranges`.
+    ///
+    /// The data must be in the same order of ranges in args.
+    pub fn new(data: Vec<Bytes>) -> Self {
+        Self { data }
+    }
+
+    /// Consume reply to get the data of all ranges.
+    pub fn into_data(self) -> Vec<Bytes> {
+        self.data
+    }
+}
+
 /// Reply for `stat` operation.
 #[derive(Debug, Clone)]
 pub struct RpStat {
@@ -28,16 +28,31 @@ use crate::Error;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum Scheme {
+    /// [archive][crate::services::Archive]: Read-only zip and tar archives.
+    #[cfg(feature = "services-archive")]
+    Archive,
     /// [azblob][crate::services::Azblob]: Azure Storage Blob services.
     Azblob,
     /// [azdfs][crate::services::Azdfs]: Azure Data Lake Storage Gen2.
     Azdfs,
+    /// [cacache][crate::services::Cacache]: Content-addressable cache directory.
+    #[cfg(feature = "services-cacache")]
+    Cacache,
+    /// [etcd][crate::services::Etcd]: Etcd services
+    #[cfg(feature = "services-etcd")]
+    Etcd,
     /// [fs][crate::services::Fs]: POSIX alike file system.
     Fs,
     /// [gcs][crate::services::Gcs]: Google Cloud Storage backend.
     Gcs,
     /// [ghac][crate::services::Ghac]: Github Action Cache services.
     Ghac,
+    /// [git][crate::services::Git]: Read-only tree of git repository.
+    #[cfg(feature = "services-git")]
+    Git,
+    /// [gridfs][crate::services::Gridfs]: MongoDB GridFS services.
+    #[cfg(feature = "services-gridfs")]
+    Gridfs,
     /// [hdfs][crate::services::Hdfs]: Hadoop Distributed File System.
     #[cfg(feature = "services-hdfs")]
     Hdfs,
@@ -59,10 +74,19 @@ pub enum Scheme {
     /// [moka][crate::services::Moka]: moka backend support.
     #[cfg(feature = "services-moka")]
     Moka,
+    /// [mysql][crate::services::Mysql]: MySQL services
+    #[cfg(feature = "services-mysql")]
+    Mysql,
     /// [obs][crate::services::Obs]: Huawei Cloud OBS services.
     Obs,
     /// [oss][crate::services::Oss]: Aliyun Object Storage Services
     Oss,
+    /// [postgresql][crate::services::Postgresql]: PostgreSQL services
+    #[cfg(feature = "services-postgresql")]
+    Postgresql,
+    /// [redb][crate::services::Redb]: Redb services
+    #[cfg(feature = "services-redb")]
+    Redb,
     /// [redis][crate::services::Redis]: Redis services
     #[cfg(feature = "services-redis")]
     Redis,
@@ -74,6 +98,11 @@ pub enum Scheme {
     /// [sled][crate::services::Sled]: Sled services
     #[cfg(feature = "services-sled")]
     Sled,
+    /// [sqlite][crate::services::Sqlite]: SQLite services
+    #[cfg(feature = "services-sqlite")]
+    Sqlite,
+    /// [swift][crate::services::Swift]: OpenStack Swift services.
+    Swift,
     /// [webdav][crate::services::Webdav]: WebDAV support.
     Webdav,
     /// [webhdfs][crate::services::Webhdfs]: WebHDFS RESTful API Services
@@ -112,11 +141,21 @@ impl FromStr for Scheme {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.to_lowercase();
         match s.as_str() {
+            #[cfg(feature = "services-archive")]
+            "archive" => Ok(Scheme::Archive),
             "azblob" => Ok(Scheme::Azblob),
             "azdfs" => Ok(Scheme::Azdfs),
+            #[cfg(feature = "services-cacache")]
+            "cacache" => Ok(Scheme::Cacache),
+            #[cfg(feature = "services-etcd")]
+            "etcd" => Ok(Scheme::Etcd),
             "fs" => Ok(Scheme::Fs),
             "gcs" => Ok(Scheme::Gcs),
             "ghac" => Ok(Scheme::Ghac),
+            #[cfg(feature = "services-git")]
+            "git" => Ok(Scheme::Git),
+            #[cfg(feature = "services-gridfs")]
+            "gridfs" => Ok(Scheme::Gridfs),
             #[cfg(feature = "services-hdfs")]
             "hdfs" => Ok(Scheme::Hdfs),
             "http" | "https" => Ok(Scheme::Http),
@@ -130,7 +169,13 @@ impl FromStr for Scheme {
             "memory" => Ok(Scheme::Memory),
             #[cfg(feature = "services-moka")]
             "moka" => Ok(Scheme::Moka),
+            #[cfg(feature = "services-mysql")]
+            "mysql" => Ok(Scheme::Mysql),
             "obs" => Ok(Scheme::Obs),
+            #[cfg(feature = "services-postgresql")]
+            "postgresql" | "postgres" => Ok(Scheme::Postgresql),
+            #[cfg(feature = "services-redb")]
+            "redb" => Ok(Scheme::Redb),
             #[cfg(feature = "services-redis")]
             "redis" => Ok(Scheme::Redis),
             #[cfg(feature = "services-rocksdb")]
@@ -138,6 +183,9 @@ impl FromStr for Scheme {
             "s3" => Ok(Scheme::S3),
             #[cfg(feature = "services-sled")]
             "sled" => Ok(Scheme::Sled),
+            #[cfg(feature = "services-sqlite")]
+            "sqlite" => Ok(Scheme::Sqlite),
+            "swift" => Ok(Scheme::Swift),
             "oss" => Ok(Scheme::Oss),
             "webdav" => Ok(Scheme::Webdav),
             "webhdfs" => Ok(Scheme::Webhdfs),
@@ -149,11 +197,21 @@ impl FromStr for Scheme {
 impl From<Scheme> for &'static str {
     fn from(v: Scheme) -> Self {
         match v {
+            #[cfg(feature = "services-archive")]
+            Scheme::Archive => "archive",
             Scheme::Azblob => "azblob",
             Scheme::Azdfs => "azdfs",
+            #[cfg(feature = "services-cacache")]
+            Scheme::Cacache => "cacache",
+            #[cfg(feature = "services-etcd")]
+            Scheme::Etcd => "etcd",
             Scheme::Fs => "fs",
             Scheme::Gcs => "gcs",
             Scheme::Ghac => "ghac",
+            #[cfg(feature = "services-git")]
+            Scheme::Git => "git",
+            #[cfg(feature = "services-gridfs")]
+            Scheme::Gridfs => "gridfs",
             #[cfg(feature = "services-hdfs")]
             Scheme::Hdfs => "hdfs",
             Scheme::Http => "http",
@@ -167,7 +225,13 @@ impl From<Scheme> for &'static str {
             Scheme::Memory => "memory",
             #[cfg(feature = "services-moka")]
             Scheme::Moka => "moka",
+            #[cfg(feature = "services-mysql")]
+            Scheme::Mysql => "mysql",
             Scheme::Obs => "obs",
+            #[cfg(feature = "services-postgresql")]
+            Scheme::Postgresql => "postgresql",
+            #[cfg(feature = "services-redb")]
+            Scheme::Redb => "redb",
             #[cfg(feature = "services-redis")]
             Scheme::Redis => "redis",
             #[cfg(feature = "services-rocksdb")]
@@ -175,6 +239,9 @@ impl From<Scheme> for &'static str {
             Scheme::S3 => "s3",
             #[cfg(feature = "services-sled")]
             Scheme::Sled => "sled",
+            #[cfg(feature = "services-sqlite")]
+            Scheme::Sqlite => "sqlite",
+            Scheme::Swift => "swift",
             Scheme::Oss => "oss",
             Scheme::Webdav => "webdav",
             Scheme::Webhdfs => "webhdfs",
@@ -0,0 +1,673 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use log::debug;
+use tokio::sync::OnceCell;
+
+use super::dir_stream::DirStream;
+use super::index::Index;
+use super::index::Location;
+use super::index::Member;
+use super::reader::MemberReader;
+use super::tar;
+use super::zip;
+use crate::ops::*;
+use crate::raw::*;
+use crate::*;
+
+/// Read-only service for members of zip and tar archives stored in
+/// another [`Operator`].
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [ ] ~~write~~
+/// - [x] list
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [ ] ~~blocking~~
+///
+/// # Notes
+///
+/// The index of archive will be built at the first operation and cached
+/// in memory:
+///
+/// - `zip`: central directory will be fetched by range reads.
+/// - `tar`: headers will be fetched by range reads, content of members
+///   are skipped.
+/// - `tar.gz` and other compressed tar: the whole archive must be
+///   decompressed once to find all headers. Reading a member also needs
+///   to decompress the archive from the beginning.
+///
+/// Compressed members are decompressed via `raw::io::compress`, so this
+/// service also enables feature `compress`.
+///
+/// # Configuration
+///
+/// - `path`: Set the path of archive in underlying operator
+/// - `format`: Set the format of archive, will be detected from `path` if not set
+/// - `root`: Set the work directory inside archive
+///
+/// The underlying [`Operator`] can only be set via [`ArchiveBuilder::operator`].
+///
+/// You can refer to [`ArchiveBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Archive;
+/// use opendal::services::S3;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let s3 = Operator::from_env::<S3>()?.finish();
+///
+///     let mut builder = Archive::default();
+///     builder.operator(s3);
+///     builder.path("datasets/images.tar.gz");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("images/0001.png");
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct ArchiveBuilder {
+    operator: Option<Operator>,
+    path: Option<String>,
+    format: Option<String>,
+    root: Option<String>,
+}
+
+impl Debug for ArchiveBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut de = f.debug_struct("Builder");
+        de.field("operator", &self.operator);
+        de.field("path", &self.path);
+        de.field("format", &self.format);
+        de.field("root", &self.root);
+
+        de.finish()
+    }
+}
+
+impl ArchiveBuilder {
+    /// Set the underlying operator which stores the archive.
+    pub fn operator(&mut self, operator: Operator) -> &mut Self {
+        self.operator = Some(operator);
+        self
+    }
+
+    /// Set the path of archive in underlying operator.
+    pub fn path(&mut self, path: &str) -> &mut Self {
+        self.path = if path.is_empty() {
+            None
+        } else {
+            Some(path.to_string())
+        };
+
+        self
+    }
+
+    /// Set the format of archive.
+    ///
+    /// Available values are `zip`, `tar`, `tgz` and `tar.<ext>` like
+    /// `tar.gz`, `tar.bz2`, `tar.xz` and `tar.zst`.
+    ///
+    /// default: detected from the extension of `path`.
+    pub fn format(&mut self, format: &str) -> &mut Self {
+        self.format = if format.is_empty() {
+            None
+        } else {
+            Some(format.to_string())
+        };
+
+        self
+    }
+
+    /// Set root path inside archive.
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        self.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for ArchiveBuilder {
+    const SCHEME: Scheme = Scheme::Archive;
+    type Accessor = ArchiveBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = ArchiveBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("path").map(|v| builder.path(v));
+        map.get("format").map(|v| builder.format(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        debug!("backend build started: {:?}", &self);
+
+        let operator = match self.operator.take() {
+            Some(v) => v,
+            None => {
+                return Err(
+                    Error::new(ErrorKind::BackendConfigInvalid, "operator is not set")
+                        .with_context("service", Scheme::Archive),
+                )
+            }
+        };
+
+        let path = match &self.path {
+            Some(v) => normalize_path(v),
+            None => {
+                return Err(Error::new(ErrorKind::BackendConfigInvalid, "path is empty")
+                    .with_context("service", Scheme::Archive))
+            }
+        };
+        if path.ends_with('/') {
+            return Err(
+                Error::new(ErrorKind::BackendConfigInvalid, "path must be a file")
+                    .with_context("service", Scheme::Archive)
+                    .with_context("path", path),
+            );
+        }
+
+        let format = match &self.format {
+            Some(v) => Format::parse(v),
+            None => Format::from_path(&path),
+        }
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "format is not supported or can't be detected from path",
+            )
+            .with_context("service", Scheme::Archive)
+            .with_context("path", &path)
+        })?;
+        debug!("backend use format {:?}", format);
+
+        let root = normalize_root(&self.root.take().unwrap_or_default());
+        debug!("backend use root {}", root);
+
+        debug!("backend build finished: {:?}", &self);
+        Ok(ArchiveBackend {
+            operator,
+            path,
+            format,
+            root,
+            index: OnceCell::new(),
+        })
+    }
+}
+
+/// Format of archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Zip,
+    /// Tar with optional compression on the whole archive.
+    Tar(Option<CompressAlgorithm>),
+}
+
+impl Format {
+    fn parse(v: &str) -> Option<Format> {
+        match v.to_lowercase().as_str() {
+            "zip" => Some(Format::Zip),
+            "tar" => Some(Format::Tar(None)),
+            "tgz" => Some(Format::Tar(Some(CompressAlgorithm::Gzip))),
+            "tbz" | "tbz2" => Some(Format::Tar(Some(CompressAlgorithm::Bz2))),
+            "txz" => Some(Format::Tar(Some(CompressAlgorithm::Xz))),
+            "tzst" => Some(Format::Tar(Some(CompressAlgorithm::Zstd))),
+            v => v
+                .strip_prefix("tar.")
+                .and_then(CompressAlgorithm::from_extension)
+                .map(|algo| Format::Tar(Some(algo))),
+        }
+    }
+
+    /// Detect format from the extensions of path like `a.zip` or `a.tar.gz`.
+    fn from_path(path: &str) -> Option<Format> {
+        let name = get_basename(path);
+
+        name.match_indices('.')
+            .map(|(idx, _)| &name[idx + 1..])
+            .find_map(Format::parse)
+    }
+}
+
+/// Backend is used to serve `Accessor` support for archives.
+pub struct ArchiveBackend {
+    operator: Operator,
+    path: String,
+    format: Format,
+    root: String,
+    index: OnceCell<Index>,
+}
+
+impl Debug for ArchiveBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Backend")
+            .field("operator", &self.operator)
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .field("root", &self.root)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Accessor for ArchiveBackend {
+    type Reader = output::Reader;
+    type BlockingReader = ();
+    type Pager = DirStream;
+    type BlockingPager = ();
+
+    fn metadata(&self) -> AccessorMetadata {
+        let mut ma = AccessorMetadata::default();
+        ma.set_scheme(Scheme::Archive)
+            .set_root(&self.root)
+            .set_name(&self.path)
+            .set_capabilities(AccessorCapability::Read | AccessorCapability::List);
+
+        ma
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let member = self.member(path).await?;
+
+        let (start, end) = args.range().to_bounds(member.size);
+        let size = end - start;
+        if size == 0 {
+            let r: output::Reader = Box::new(output::Cursor::from(Vec::new()));
+            return Ok((RpRead::new(0), r));
+        }
+
+        let object = self.operator.object(&self.path);
+        let r: output::Reader = match member.location {
+            Location::Dir => {
+                return Err(Error::new(
+                    ErrorKind::ObjectIsADirectory,
+                    "read on a directory is not supported",
+                )
+                .with_context("service", Scheme::Archive)
+                .with_context("path", path))
+            }
+            Location::Zip {
+                header_offset,
+                compressed_size,
+                method,
+                encrypted,
+            } => {
+                if encrypted {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "encrypted zip member is not supported",
+                    )
+                    .with_context("service", Scheme::Archive)
+                    .with_context("path", path));
+                }
+                let algo = zip::parse_compress_algorithm(method)?;
+
+                // Local header could have different extra fields with
+                // central directory, we need to read it to find the data.
+                let header = object
+                    .range_read(header_offset..header_offset + zip::LOCAL_HEADER_SIZE)
+                    .await?;
+                let data_offset = header_offset + zip::parse_local_header(&header)?;
+
+                match algo {
+                    None => Box::new(
+                        object
+                            .range_reader(data_offset + start..data_offset + end)
+                            .await?,
+                    ),
+                    Some(algo) => {
+                        let r = object
+                            .range_reader(data_offset..data_offset + compressed_size)
+                            .await?;
+                        Box::new(MemberReader::new(
+                            Box::new(DecompressReader::new(r, algo)),
+                            start,
+                            size,
+                        ))
+                    }
+                }
+            }
+            Location::Tar { data_offset } => match self.format {
+                Format::Tar(Some(algo)) => {
+                    let r = object.reader().await?;
+                    Box::new(MemberReader::new(
+                        Box::new(DecompressReader::new(r, algo)),
+                        data_offset + start,
+                        size,
+                    ))
+                }
+                _ => Box::new(
+                    object
+                        .range_reader(data_offset + start..data_offset + end)
+                        .await?,
+                ),
+            },
+        };
+
+        Ok((RpRead::new(size), r))
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let member = self.member(path).await?;
+        Ok(RpStat::new(member.metadata()))
+    }
+
+    async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+        let index = self.index().await?;
+
+        let p = build_abs_path(&self.root, path);
+        let entries = index
+            .children(&p)
+            .into_iter()
+            .map(|(k, member)| {
+                let path = build_rel_path(&self.root, &format!("/{k}"));
+                output::Entry::new(&path, member.metadata())
+            })
+            .collect();
+
+        Ok((RpList::default(), DirStream::new(entries)))
+    }
+}
+
+impl ArchiveBackend {
+    /// Load the index of archive, it will only be built once.
+    async fn index(&self) -> Result<&Index> {
+        self.index
+            .get_or_try_init(|| async {
+                let object = self.operator.object(&self.path);
+                let size = object.content_length().await?;
+
+                debug!("build index of archive {} in {} bytes", self.path, size);
+                match self.format {
+                    Format::Zip => zip::build_index(&object, size).await,
+                    Format::Tar(algo) => tar::build_index(&object, size, algo).await,
+                }
+                .map_err(|err| err.with_context("archive", &self.path))
+            })
+            .await
+    }
+
+    async fn member(&self, path: &str) -> Result<Member> {
+        let index = self.index().await?;
+
+        let p = build_abs_path(&self.root, path);
+        index.get(&p).ok_or_else(|| {
+            Error::new(ErrorKind::ObjectNotFound, "member not found in archive")
+                .with_context("service", Scheme::Archive)
+                .with_context("path", path)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use async_compression::futures::bufread::DeflateEncoder;
+    use async_compression::futures::bufread::GzipEncoder;
+    use futures::io::Cursor;
+    use futures::AsyncReadExt;
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    async fn deflate(bs: &[u8]) -> Vec<u8> {
+        let mut r = DeflateEncoder::new(Cursor::new(bs.to_vec()));
+        let mut buf = vec![];
+        r.read_to_end(&mut buf).await.expect("deflate must succeed");
+        buf
+    }
+
+    async fn gzip(bs: &[u8]) -> Vec<u8> {
+        let mut r = GzipEncoder::new(Cursor::new(bs.to_vec()));
+        let mut buf = vec![];
+        r.read_to_end(&mut buf).await.expect("gzip must succeed");
+        buf
+    }
+
+    /// Build a zip with given members, set `compressed` to use deflate.
+    async fn build_zip(members: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let mut buf = vec![];
+        let mut cd = vec![];
+
+        for (name, content, compressed) in members {
+            let (method, data) = if *compressed {
+                (8u16, deflate(content).await)
+            } else {
+                (0u16, content.to_vec())
+            };
+            let offset = buf.len() as u32;
+
+            buf.extend_from_slice(&0x04034b50u32.to_le_bytes());
+            buf.extend_from_slice(&20u16.to_le_bytes());
+            buf.extend_from_slice(&0u16.to_le_bytes());
+            buf.extend_from_slice(&method.to_le_bytes());
+            buf.extend_from_slice(&[0; 8]);
+            buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            buf.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            buf.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            buf.extend_from_slice(&0u16.to_le_bytes());
+            buf.extend_from_slice(name.as_bytes());
+            buf.extend_from_slice(&data);
+
+            cd.extend_from_slice(&0x02014b50u32.to_le_bytes());
+            cd.extend_from_slice(&20u16.to_le_bytes());
+            cd.extend_from_slice(&20u16.to_le_bytes());
+            cd.extend_from_slice(&0u16.to_le_bytes());
+            cd.extend_from_slice(&method.to_le_bytes());
+            cd.extend_from_slice(&[0; 8]);
+            cd.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            cd.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            cd.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            cd.extend_from_slice(&[0; 12]);
+            cd.extend_from_slice(&offset.to_le_bytes());
+            cd.extend_from_slice(name.as_bytes());
+        }
+
+        let cd_offset = buf.len() as u32;
+        buf.extend_from_slice(&cd);
+        buf.extend_from_slice(&0x06054b50u32.to_le_bytes());
+        buf.extend_from_slice(&[0; 4]);
+        buf.extend_from_slice(&(members.len() as u16).to_le_bytes());
+        buf.extend_from_slice(&(members.len() as u16).to_le_bytes());
+        buf.extend_from_slice(&(cd.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&cd_offset.to_le_bytes());
+        buf.extend_from_slice(&0u16.to_le_bytes());
+        buf
+    }
+
+    /// Build a ustar archive with given members, name ends with `/` will
+    /// be treated as dir.
+    fn build_tar(members: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut buf = vec![];
+
+        for (name, content) in members {
+            let mut header = [0u8; 512];
+            header[..name.len()].copy_from_slice(name.as_bytes());
+            header[100..108].copy_from_slice(b"0000644\0");
+            header[124..136].copy_from_slice(format!("{:011o}\0", content.len()).as_bytes());
+            header[136..148].copy_from_slice(format!("{:011o}\0", 1675168496).as_bytes());
+            header[156] = if name.ends_with('/') { b'5' } else { b'0' };
+            header[257..263].copy_from_slice(b"ustar\0");
+            header[263..265].copy_from_slice(b"00");
+            header[148..156].copy_from_slice(b"        ");
+            let checksum: u32 = header.iter().map(|v| *v as u32).sum();
+            header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+
+            buf.extend_from_slice(&header);
+            buf.extend_from_slice(content);
+            buf.resize((buf.len() + 511) / 512 * 512, 0);
+        }
+
+        buf.extend_from_slice(&[0; 1024]);
+        buf
+    }
+
+    async fn archive_operator(path: &str, content: Vec<u8>) -> Result<Operator> {
+        let op = Operator::create(Memory::default())?.finish();
+        op.object(path).write(content).await?;
+
+        let mut builder = ArchiveBuilder::default();
+        builder.operator(op);
+        builder.path(path);
+
+        Ok(Operator::create(builder)?.finish())
+    }
+
+    async fn list_names(op: &Operator, path: &str) -> Result<Vec<String>> {
+        let mut names = op
+            .object(path)
+            .list()
+            .await?
+            .map_ok(|o| o.path().to_string())
+            .try_collect::<Vec<_>>()
+            .await?;
+        names.sort();
+        Ok(names)
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        let cases = vec![
+            ("a.zip", Some(Format::Zip)),
+            ("dir/a.tar", Some(Format::Tar(None))),
+            (
+                "a.b.tar.gz",
+                Some(Format::Tar(Some(CompressAlgorithm::Gzip))),
+            ),
+            ("a.tgz", Some(Format::Tar(Some(CompressAlgorithm::Gzip)))),
+            (
+                "a.tar.zst",
+                Some(Format::Tar(Some(CompressAlgorithm::Zstd))),
+            ),
+            ("a.gz", None),
+            ("a", None),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(Format::from_path(path), expected, "{path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zip() -> Result<()> {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let large = "Hello, World!".repeat(1024);
+        let content = build_zip(&[
+            ("hello.txt", b"Hello, World!", false),
+            ("dir/", b"", false),
+            ("dir/large.txt", large.as_bytes(), true),
+            ("implicit/empty.txt", b"", false),
+        ])
+        .await;
+        let op = archive_operator("data.zip", content).await?;
+
+        assert_eq!(
+            list_names(&op, "/").await?,
+            vec!["dir/", "hello.txt", "implicit/"]
+        );
+        assert_eq!(list_names(&op, "dir/").await?, vec!["dir/large.txt"]);
+
+        let meta = op.object("dir/large.txt").metadata().await?;
+        assert_eq!(meta.mode(), ObjectMode::FILE);
+        assert_eq!(meta.content_length(), large.len() as u64);
+        assert_eq!(
+            op.object("implicit/").metadata().await?.mode(),
+            ObjectMode::DIR
+        );
+
+        assert_eq!(op.object("hello.txt").read().await?, b"Hello, World!");
+        assert_eq!(op.object("hello.txt").range_read(7..12).await?, b"World");
+        assert_eq!(op.object("dir/large.txt").read().await?, large.as_bytes());
+        assert_eq!(
+            op.object("dir/large.txt")
+                .range_read(13 * 100 + 7..13 * 100 + 12)
+                .await?,
+            b"World"
+        );
+        assert!(op.object("implicit/empty.txt").read().await?.is_empty());
+
+        let err = op.object("not_exist").metadata().await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ObjectNotFound);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tar() -> Result<()> {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let large = "Hello, World!".repeat(1024);
+        let content = build_tar(&[
+            ("hello.txt", b"Hello, World!"),
+            ("dir/", b""),
+            ("dir/large.txt", large.as_bytes()),
+        ]);
+
+        for (path, content) in [
+            ("data.tar", content.clone()),
+            ("data.tar.gz", gzip(&content).await),
+        ] {
+            let op = archive_operator(path, content).await?;
+
+            assert_eq!(list_names(&op, "/").await?, vec!["dir/", "hello.txt"]);
+
+            let meta = op.object("hello.txt").metadata().await?;
+            assert_eq!(meta.content_length(), 13);
+            assert_eq!(
+                meta.last_modified().map(|v| v.unix_timestamp()),
+                Some(1675168496)
+            );
+
+            assert_eq!(op.object("hello.txt").range_read(7..12).await?, b"World");
+            assert_eq!(op.object("dir/large.txt").read().await?, large.as_bytes());
+            assert_eq!(
+                op.object("dir/large.txt")
+                    .range_read(13 * 100 + 7..13 * 100 + 12)
+                    .await?,
+                b"World"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Error;
+use crate::ErrorKind;
+use crate::Scheme;
+
+/// Build an error for archives that can't be parsed.
+pub fn new_invalid_archive_error(msg: &str) -> Error {
+    Error::new(ErrorKind::Unexpected, msg).with_context("service", Scheme::Archive)
+}
+
+/// Build an error for std io error happened while decompressing or
+/// skipping archive content.
+pub fn new_archive_io_error(err: std::io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "read archive content failed")
+        .with_context("service", Scheme::Archive)
+        .set_source(err)
+}
@@ -0,0 +1,192 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use time::OffsetDateTime;
+
+use crate::*;
+
+/// Member is an entry inside the archive.
+#[derive(Debug, Clone)]
+pub struct Member {
+    /// Uncompressed size of this member.
+    pub size: u64,
+    pub last_modified: Option<OffsetDateTime>,
+    pub location: Location,
+}
+
+/// Location describes how to fetch the content of a member.
+#[derive(Debug, Clone)]
+pub enum Location {
+    /// Directories, including the implicit ones, don't have content.
+    Dir,
+    /// Zip member whose data starts after the local file header.
+    Zip {
+        /// Offset of the local file header.
+        header_offset: u64,
+        compressed_size: u64,
+        /// Compression method recorded in central directory.
+        method: u16,
+        encrypted: bool,
+    },
+    /// Tar member whose data starts at `data_offset` of the (decompressed)
+    /// tar stream.
+    Tar { data_offset: u64 },
+}
+
+impl Member {
+    /// Create a new directory member.
+    pub fn dir() -> Self {
+        Member {
+            size: 0,
+            last_modified: None,
+            location: Location::Dir,
+        }
+    }
+
+    pub fn mode(&self) -> ObjectMode {
+        match self.location {
+            Location::Dir => ObjectMode::DIR,
+            _ => ObjectMode::FILE,
+        }
+    }
+
+    pub fn metadata(&self) -> ObjectMetadata {
+        let mut meta = ObjectMetadata::new(self.mode());
+        if let Location::Dir = self.location {
+            return meta;
+        }
+
+        meta.set_content_length(self.size);
+        if let Some(v) = self.last_modified {
+            meta.set_last_modified(v);
+        }
+        meta
+    }
+}
+
+/// Index of all members inside an archive, keyed by normalized path like
+/// `path/to/file` or `path/to/dir/`.
+#[derive(Debug, Default)]
+pub struct Index {
+    members: BTreeMap<String, Member>,
+}
+
+impl Index {
+    /// Insert a member found in archive.
+    ///
+    /// Parent dirs that not recorded by archive will be inserted too.
+    pub fn insert(&mut self, name: &str, member: Member) {
+        let path = normalize_member_name(name, member.mode());
+        if path.is_empty() {
+            return;
+        }
+
+        let mut idx = 0;
+        while let Some(pos) = path[idx..].find('/') {
+            idx += pos + 1;
+            if idx == path.len() {
+                break;
+            }
+            self.members
+                .entry(path[..idx].to_string())
+                .or_insert_with(Member::dir);
+        }
+
+        // Later entries override earlier ones, which is the same behavior
+        // as extracting archive to local.
+        self.members.insert(path, member);
+    }
+
+    /// Get a member, the root dir is always exist.
+    pub fn get(&self, path: &str) -> Option<Member> {
+        if path.is_empty() {
+            return Some(Member::dir());
+        }
+
+        self.members.get(path).cloned()
+    }
+
+    /// List the direct children of given dir.
+    pub fn children(&self, path: &str) -> Vec<(String, Member)> {
+        self.members
+            .range(path.to_string()..)
+            .take_while(|(k, _)| k.starts_with(path))
+            .filter(|(k, _)| {
+                let rest = &k[path.len()..];
+                match rest.find('/') {
+                    None => !rest.is_empty(),
+                    Some(idx) => idx + 1 == rest.len(),
+                }
+            })
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+}
+
+/// Normalize name stored in archive into OpenDAL's path.
+///
+/// Archives could contain names like `./abc`, `/abc` or `abc\def`.
+fn normalize_member_name(name: &str, mode: ObjectMode) -> String {
+    let name = name.replace('\\', "/");
+    let mut path = name
+        .split('/')
+        .filter(|v| !v.is_empty() && *v != ".")
+        .collect::<Vec<_>>()
+        .join("/");
+
+    if mode == ObjectMode::DIR && !path.is_empty() {
+        path.push('/');
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(size: u64) -> Member {
+        Member {
+            size,
+            last_modified: None,
+            location: Location::Tar { data_offset: 0 },
+        }
+    }
+
+    #[test]
+    fn test_index_children() {
+        let mut idx = Index::default();
+        idx.insert("./a/b/c.txt", file(1));
+        idx.insert("a/d.txt", file(2));
+        idx.insert("/e.txt", file(3));
+        idx.insert("f/", Member::dir());
+
+        let names = |path: &str| {
+            idx.children(path)
+                .into_iter()
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(""), vec!["a/", "e.txt", "f/"]);
+        assert_eq!(names("a/"), vec!["a/b/", "a/d.txt"]);
+        assert_eq!(names("a/b/"), vec!["a/b/c.txt"]);
+        assert!(names("f/").is_empty());
+
+        assert_eq!(idx.get("a/b/").map(|v| v.mode()), Some(ObjectMode::DIR));
+        assert_eq!(idx.get("a/d.txt").map(|v| v.size), Some(2));
+        assert!(idx.get("a/d").is_none());
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Bytes;
+use futures::ready;
+use futures::AsyncRead;
+
+use crate::raw::*;
+
+/// MemberReader reads a range of member from the decompressed stream.
+///
+/// Compressed content can't be seeked, so we have to consume and drop all
+/// data before the start of range.
+pub struct MemberReader {
+    inner: input::Reader,
+    skip: u64,
+    remaining: u64,
+}
+
+/// input::Reader is only `Send` but all access to it requires `&mut self`,
+/// so it's safe to share MemberReader between threads.
+unsafe impl Sync for MemberReader {}
+
+impl MemberReader {
+    /// Create a new reader which skips `skip` bytes and then reads at most
+    /// `size` bytes from `inner`.
+    pub fn new(inner: input::Reader, skip: u64, size: u64) -> Self {
+        Self {
+            inner,
+            skip,
+            remaining: size,
+        }
+    }
+}
+
+impl output::Read for MemberReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        // Use the output buffer as scratch to drop data before range.
+        while self.skip > 0 {
+            let size = self.skip.min(buf.len() as u64) as usize;
+            let n = ready!(Pin::new(&mut self.inner).poll_read(cx, &mut buf[..size]))?;
+            if n == 0 {
+                return Poll::Ready(Ok(0));
+            }
+            self.skip -= n as u64;
+        }
+
+        if self.remaining == 0 {
+            return Poll::Ready(Ok(0));
+        }
+
+        let size = self.remaining.min(buf.len() as u64) as usize;
+        let n = ready!(Pin::new(&mut self.inner).poll_read(cx, &mut buf[..size]))?;
+        self.remaining -= n as u64;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_seek(&mut self, _: &mut Context<'_>, _: io::SeekFrom) -> Poll<io::Result<u64>> {
+        Poll::Ready(Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "output reader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, _: &mut Context<'_>) -> Poll<Option<io::Result<Bytes>>> {
+        Poll::Ready(Some(Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "output reader doesn't support streaming",
+        ))))
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parse tar archive via its headers.
+//!
+//! Both ustar, GNU long name and PAX extended headers are supported.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use futures::AsyncReadExt;
+use time::OffsetDateTime;
+
+use super::error::new_archive_io_error;
+use super::error::new_invalid_archive_error;
+use super::index::Index;
+use super::index::Location;
+use super::index::Member;
+use crate::raw::*;
+use crate::*;
+
+const BLOCK_SIZE: u64 = 512;
+/// Read more data while fetching headers of uncompressed tar so that
+/// small members can be indexed without one request per header.
+const READ_AHEAD_SIZE: u64 = 1024 * 1024;
+/// Max size of GNU long name and PAX extended header.
+///
+/// Both of them will be loaded into memory, limit the size so that a
+/// malformed archive can't make us allocate huge buffers.
+const MAX_EXTENDED_HEADER_SIZE: u64 = 1024 * 1024;
+
+/// Build index by walking through all headers of tar.
+///
+/// For uncompressed tar, headers will be fetched by range reads and the
+/// content of members will be skipped. For compressed tar, the whole
+/// archive must be decompressed once since there is no way to seek inside
+/// the compressed stream.
+pub async fn build_index(
+    object: &Object,
+    size: u64,
+    algo: Option<CompressAlgorithm>,
+) -> Result<Index> {
+    let mut source = match algo {
+        None => Source::Range {
+            object: object.clone(),
+            size,
+            buf: Bytes::new(),
+            buf_offset: 0,
+        },
+        Some(algo) => Source::Stream {
+            reader: DecompressReader::new(object.reader().await?, algo),
+            pos: 0,
+        },
+    };
+
+    let mut index = Index::default();
+    let mut long_name: Option<String> = None;
+    let mut pax: HashMap<String, String> = HashMap::new();
+
+    let mut offset = 0;
+    loop {
+        let header = source.read_at(offset, BLOCK_SIZE).await?;
+        // Tar ends with two zero blocks, but some writers omit them.
+        if header.len() < BLOCK_SIZE as usize || header.iter().all(|v| *v == 0) {
+            break;
+        }
+
+        let typeflag = header[156];
+        let data_offset = offset + BLOCK_SIZE;
+        let mut data_size = parse_numeric(&header[124..136])?;
+
+        match typeflag {
+            // GNU long name, the name is stored as data.
+            b'L' => {
+                check_extended_header_size(data_size)?;
+                let bs = source.read_at(data_offset, data_size).await?;
+                long_name = Some(parse_str(&bs));
+                offset = next_offset(data_offset, data_size)?;
+                continue;
+            }
+            // PAX extended header for the next member.
+            b'x' => {
+                check_extended_header_size(data_size)?;
+                let bs = source.read_at(data_offset, data_size).await?;
+                pax = parse_pax(&bs);
+                offset = next_offset(data_offset, data_size)?;
+                continue;
+            }
+            // PAX global header and GNU long link name are not used.
+            b'g' | b'K' => {
+                offset = next_offset(data_offset, data_size)?;
+                continue;
+            }
+            _ => {}
+        }
+
+        if let Some(v) = pax.get("size").and_then(|v| v.parse().ok()) {
+            data_size = v;
+        }
+        offset = next_offset(data_offset, data_size)?;
+
+        let name = pax
+            .remove("path")
+            .or_else(|| long_name.take())
+            .unwrap_or_else(|| parse_name(&header));
+        let last_modified = match pax.get("mtime") {
+            Some(v) => v.split('.').next().and_then(|v| v.parse::<i64>().ok()),
+            None => parse_numeric(&header[136..148]).ok().map(|v| v as i64),
+        }
+        .and_then(|v| OffsetDateTime::from_unix_timestamp(v).ok());
+
+        pax.clear();
+        long_name = None;
+
+        let member = match typeflag {
+            b'5' => Member::dir(),
+            // Old tar marks dir by trailing `/` in regular file.
+            b'0' | b'\0' if name.ends_with('/') => Member::dir(),
+            b'0' | b'\0' | b'7' => Member {
+                size: data_size,
+                last_modified,
+                location: Location::Tar { data_offset },
+            },
+            // Links, devices and fifos are not exposed.
+            _ => continue,
+        };
+        index.insert(&name, member);
+    }
+
+    Ok(index)
+}
+
+/// Source of tar content.
+enum Source {
+    /// Uncompressed tar that can be read at any offset.
+    Range {
+        object: Object,
+        size: u64,
+        buf: Bytes,
+        buf_offset: u64,
+    },
+    /// Compressed tar that can only be read forward.
+    Stream {
+        reader: DecompressReader<ObjectReader>,
+        pos: u64,
+    },
+}
+
+impl Source {
+    /// Read at most `size` bytes at `offset` of tar content.
+    ///
+    /// Returning bytes will be shorter than `size` only if reaching EOF.
+    async fn read_at(&mut self, offset: u64, size: u64) -> Result<Bytes> {
+        match self {
+            Source::Range {
+                object,
+                size: total,
+                buf,
+                buf_offset,
+            } => {
+                let buf_end = *buf_offset + buf.len() as u64;
+                let cached = offset >= *buf_offset
+                    && (offset + size <= buf_end || (buf_end == *total && offset <= buf_end));
+
+                if !cached {
+                    let end = (*total).min(offset + size.max(READ_AHEAD_SIZE));
+                    if offset >= end {
+                        return Ok(Bytes::new());
+                    }
+
+                    *buf = Bytes::from(object.range_read(offset..end).await?);
+                    *buf_offset = offset;
+                }
+
+                let start = (offset - *buf_offset) as usize;
+                let end = (start + size as usize).min(buf.len());
+                Ok(buf.slice(start..end))
+            }
+            Source::Stream { reader, pos } => {
+                if offset < *pos {
+                    return Err(new_invalid_archive_error(
+                        "compressed tar can't be read backward",
+                    ));
+                }
+
+                let skipped =
+                    futures::io::copy((&mut *reader).take(offset - *pos), &mut futures::io::sink())
+                        .await
+                        .map_err(new_archive_io_error)?;
+                *pos += skipped;
+                if *pos < offset {
+                    return Ok(Bytes::new());
+                }
+
+                let mut bs = Vec::with_capacity(size as usize);
+                (&mut *reader)
+                    .take(size)
+                    .read_to_end(&mut bs)
+                    .await
+                    .map_err(new_archive_io_error)?;
+                *pos += bs.len() as u64;
+
+                Ok(Bytes::from(bs))
+            }
+        }
+    }
+}
+
+/// Calculate the offset of next header which is behind `size` bytes of
+/// data started at `offset`.
+///
+/// Members are always aligned to block size. Sizes in headers are not
+/// trusted, return an error instead of overflowing.
+fn next_offset(offset: u64, size: u64) -> Result<u64> {
+    size.checked_add(BLOCK_SIZE - 1)
+        .map(|v| v / BLOCK_SIZE * BLOCK_SIZE)
+        .and_then(|v| offset.checked_add(v))
+        .ok_or_else(|| new_invalid_archive_error("tar header contains too large size"))
+}
+
+/// Check the size of GNU long name or PAX extended header before loading
+/// it into memory.
+fn check_extended_header_size(size: u64) -> Result<()> {
+    if size > MAX_EXTENDED_HEADER_SIZE {
+        return Err(new_invalid_archive_error(
+            "tar extended header is too large",
+        ));
+    }
+    Ok(())
+}
+
+/// Parse name of member, ustar could store the leading part in `prefix`.
+fn parse_name(header: &[u8]) -> String {
+    let name = parse_str(&header[0..100]);
+
+    if &header[257..262] == b"ustar" {
+        let prefix = parse_str(&header[345..500]);
+        if !prefix.is_empty() {
+            return format!("{prefix}/{name}");
+        }
+    }
+    name
+}
+
+/// Parse NUL terminated string.
+fn parse_str(bs: &[u8]) -> String {
+    let end = bs.iter().position(|v| *v == 0).unwrap_or(bs.len());
+    String::from_utf8_lossy(&bs[..end]).to_string()
+}
+
+/// Parse numeric fields which could be octal string or GNU base-256
+/// encoding for large values.
+fn parse_numeric(bs: &[u8]) -> Result<u64> {
+    if bs[0] & 0x80 != 0 {
+        let mut v = (bs[0] & 0x7f) as u64;
+        for b in &bs[1..] {
+            v = (v << 8) | *b as u64;
+        }
+        return Ok(v);
+    }
+
+    let s = String::from_utf8_lossy(bs);
+    let s = s.trim_matches(|c: char| c == '\0' || c == ' ');
+    if s.is_empty() {
+        return Ok(0);
+    }
+
+    u64::from_str_radix(s, 8).map_err(|err| {
+        new_invalid_archive_error("tar header contains invalid number").set_source(err)
+    })
+}
+
+/// Parse PAX records in format of `<length> <key>=<value>\n`.
+fn parse_pax(bs: &[u8]) -> HashMap<String, String> {
+    let mut records = HashMap::new();
+
+    let mut pos = 0;
+    while pos < bs.len() {
+        let space = match bs[pos..].iter().position(|v| *v == b' ') {
+            Some(v) => pos + v,
+            None => break,
+        };
+        let len: usize = match std::str::from_utf8(&bs[pos..space])
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            Some(v) if v > space - pos + 1 && pos + v <= bs.len() => v,
+            _ => break,
+        };
+
+        // Strip the trailing `\n`.
+        let record = String::from_utf8_lossy(&bs[space + 1..pos + len - 1]);
+        if let Some((k, v)) = record.split_once('=') {
+            records.insert(k.to_string(), v.to_string());
+        }
+
+        pos += len;
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numeric() {
+        assert_eq!(parse_numeric(b"00000001750\0").unwrap(), 1000);
+        assert_eq!(parse_numeric(b"      1750 \0").unwrap(), 1000);
+        assert_eq!(parse_numeric(b"\0\0\0\0\0\0\0\0\0\0\0\0").unwrap(), 0);
+        assert_eq!(
+            parse_numeric(&[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10, 0]).unwrap(),
+            4096
+        );
+        assert!(parse_numeric(b"0000000099\0\0").is_err());
+    }
+
+    #[test]
+    fn test_next_offset() {
+        assert_eq!(next_offset(0, 0).unwrap(), 0);
+        assert_eq!(next_offset(512, 1).unwrap(), 1024);
+        assert_eq!(next_offset(512, 512).unwrap(), 1024);
+        assert!(next_offset(0, u64::MAX).is_err());
+        assert!(next_offset(u64::MAX - 511, 512).is_err());
+    }
+
+    #[test]
+    fn test_check_extended_header_size() {
+        assert!(check_extended_header_size(MAX_EXTENDED_HEADER_SIZE).is_ok());
+        assert!(check_extended_header_size(MAX_EXTENDED_HEADER_SIZE + 1).is_err());
+        assert!(check_extended_header_size(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_parse_pax() {
+        let bs = b"30 mtime=1675168496.123456789\n21 path=dir/long.txt\n";
+        let records = parse_pax(bs);
+
+        assert_eq!(
+            records.get("mtime").map(|v| v.as_str()),
+            Some("1675168496.123456789")
+        );
+        assert_eq!(
+            records.get("path").map(|v| v.as_str()),
+            Some("dir/long.txt")
+        );
+    }
+}
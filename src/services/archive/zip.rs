@@ -0,0 +1,336 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parse zip archive via its central directory.
+//!
+//! Refer to [APPNOTE.TXT](https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT)
+//! for the details of zip format.
+
+use time::Date;
+use time::Month;
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+use time::Time;
+
+use super::error::new_invalid_archive_error;
+use super::index::Index;
+use super::index::Location;
+use super::index::Member;
+use crate::raw::*;
+use crate::*;
+
+const EOCD_SIGNATURE: u32 = 0x06054b50;
+const EOCD_SIZE: u64 = 22;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_LOCATOR_SIZE: u64 = 20;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const ZIP64_EOCD_SIZE: u64 = 56;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const CENTRAL_HEADER_SIZE: usize = 46;
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+/// Size of the fixed part of local file header.
+pub const LOCAL_HEADER_SIZE: u64 = 30;
+
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+const EXTENDED_TIMESTAMP_EXTRA_ID: u16 = 0x5455;
+
+/// Build index by reading the end of central directory record and the
+/// central directory.
+///
+/// Only two or three range reads will be sent no matter how many members
+/// the archive has.
+pub async fn build_index(object: &Object, size: u64) -> Result<Index> {
+    // EOCD is followed by a comment which is at most u16::MAX long, and
+    // zip64 locator is placed just before EOCD.
+    let tail_size = size.min(EOCD_SIZE + u16::MAX as u64 + ZIP64_LOCATOR_SIZE);
+    let tail = object.range_read(size - tail_size..size).await?;
+    if tail.len() < EOCD_SIZE as usize {
+        return Err(new_invalid_archive_error("archive is too small to be zip"));
+    }
+
+    let eocd_pos = (0..=tail.len() - EOCD_SIZE as usize)
+        .rev()
+        .find(|pos| le_u32(&tail, *pos) == EOCD_SIGNATURE)
+        .ok_or_else(|| new_invalid_archive_error("end of central directory is not found"))?;
+    let eocd = &tail[eocd_pos..];
+
+    let mut entries = le_u16(eocd, 10) as u64;
+    let mut cd_size = le_u32(eocd, 12) as u64;
+    let mut cd_offset = le_u32(eocd, 16) as u64;
+
+    if entries == u16::MAX as u64 || cd_size == u32::MAX as u64 || cd_offset == u32::MAX as u64 {
+        let locator_pos = eocd_pos
+            .checked_sub(ZIP64_LOCATOR_SIZE as usize)
+            .filter(|pos| le_u32(&tail, *pos) == ZIP64_LOCATOR_SIGNATURE)
+            .ok_or_else(|| new_invalid_archive_error("zip64 locator is not found"))?;
+        let zip64_offset = le_u64(&tail, locator_pos + 8);
+        let zip64_end = zip64_offset.checked_add(ZIP64_EOCD_SIZE).ok_or_else(|| {
+            new_invalid_archive_error("zip64 end of central directory is invalid")
+        })?;
+
+        let zip64_eocd = object.range_read(zip64_offset..zip64_end).await?;
+        if zip64_eocd.len() != ZIP64_EOCD_SIZE as usize
+            || le_u32(&zip64_eocd, 0) != ZIP64_EOCD_SIGNATURE
+        {
+            return Err(new_invalid_archive_error(
+                "zip64 end of central directory is invalid",
+            ));
+        }
+
+        entries = le_u64(&zip64_eocd, 32);
+        cd_size = le_u64(&zip64_eocd, 40);
+        cd_offset = le_u64(&zip64_eocd, 48);
+    }
+
+    let cd_end = cd_offset
+        .checked_add(cd_size)
+        .filter(|end| *end <= size)
+        .ok_or_else(|| new_invalid_archive_error("central directory is out of archive"))?;
+
+    let cd = object.range_read(cd_offset..cd_end).await?;
+    parse_central_directory(&cd, entries)
+}
+
+/// Parse all central directory headers.
+fn parse_central_directory(bs: &[u8], entries: u64) -> Result<Index> {
+    let mut index = Index::default();
+
+    let mut pos = 0;
+    for _ in 0..entries {
+        if pos + CENTRAL_HEADER_SIZE > bs.len() || le_u32(bs, pos) != CENTRAL_HEADER_SIGNATURE {
+            return Err(new_invalid_archive_error(
+                "central directory header is invalid",
+            ));
+        }
+
+        let header = &bs[pos..];
+        let flags = le_u16(header, 8);
+        let method = le_u16(header, 10);
+        let dos_time = le_u16(header, 12);
+        let dos_date = le_u16(header, 14);
+        let mut compressed_size = le_u32(header, 20) as u64;
+        let mut size = le_u32(header, 24) as u64;
+        let name_len = le_u16(header, 28) as usize;
+        let extra_len = le_u16(header, 30) as usize;
+        let comment_len = le_u16(header, 32) as usize;
+        let mut header_offset = le_u32(header, 42) as u64;
+
+        let name_start = CENTRAL_HEADER_SIZE;
+        let extra_start = name_start + name_len;
+        let next = extra_start + extra_len + comment_len;
+        if pos + next > bs.len() {
+            return Err(new_invalid_archive_error(
+                "central directory header is truncated",
+            ));
+        }
+
+        let name = String::from_utf8_lossy(&header[name_start..extra_start]).to_string();
+        let mut last_modified = parse_dos_datetime(dos_date, dos_time);
+
+        // Walk through extra fields to find zip64 sizes and unix timestamp.
+        let extra = &header[extra_start..extra_start + extra_len];
+        let mut extra_pos = 0;
+        while extra_pos + 4 <= extra.len() {
+            let id = le_u16(extra, extra_pos);
+            let len = le_u16(extra, extra_pos + 2) as usize;
+            let data_start = extra_pos + 4;
+            if data_start + len > extra.len() {
+                break;
+            }
+            let data = &extra[data_start..data_start + len];
+
+            match id {
+                ZIP64_EXTRA_ID => {
+                    // Fields only present if the value in header is u32::MAX,
+                    // and they must be in this order.
+                    let mut field_pos = 0;
+                    for v in [&mut size, &mut compressed_size, &mut header_offset] {
+                        if *v == u32::MAX as u64 && field_pos + 8 <= data.len() {
+                            *v = le_u64(data, field_pos);
+                            field_pos += 8;
+                        }
+                    }
+                }
+                EXTENDED_TIMESTAMP_EXTRA_ID => {
+                    if data.len() >= 5 && data[0] & 1 == 1 {
+                        let ts = le_u32(data, 1) as i32 as i64;
+                        if let Ok(v) = OffsetDateTime::from_unix_timestamp(ts) {
+                            last_modified = Some(v);
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            extra_pos = data_start + len;
+        }
+
+        let member = if name.ends_with('/') {
+            Member::dir()
+        } else {
+            Member {
+                size,
+                last_modified,
+                location: Location::Zip {
+                    header_offset,
+                    compressed_size,
+                    method,
+                    encrypted: flags & 1 == 1,
+                },
+            }
+        };
+        index.insert(&name, member);
+
+        pos += next;
+    }
+
+    Ok(index)
+}
+
+/// Parse the local file header and returns the offset of member data
+/// relative to the start of this header.
+pub fn parse_local_header(bs: &[u8]) -> Result<u64> {
+    if bs.len() < LOCAL_HEADER_SIZE as usize || le_u32(bs, 0) != LOCAL_HEADER_SIGNATURE {
+        return Err(new_invalid_archive_error("local file header is invalid"));
+    }
+
+    let name_len = le_u16(bs, 26) as u64;
+    let extra_len = le_u16(bs, 28) as u64;
+
+    Ok(LOCAL_HEADER_SIZE + name_len + extra_len)
+}
+
+/// Map zip compression method into [`CompressAlgorithm`].
+///
+/// `Ok(None)` means the member is stored without compression.
+pub fn parse_compress_algorithm(method: u16) -> Result<Option<CompressAlgorithm>> {
+    match method {
+        0 => Ok(None),
+        8 => Ok(Some(CompressAlgorithm::Deflate)),
+        12 => Ok(Some(CompressAlgorithm::Bz2)),
+        93 => Ok(Some(CompressAlgorithm::Zstd)),
+        95 => Ok(Some(CompressAlgorithm::Xz)),
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            "zip compression method is not supported",
+        )
+        .with_context("service", Scheme::Archive)
+        .with_context("method", method.to_string())),
+    }
+}
+
+/// Parse MS-DOS date and time, which are always in local time. We treat
+/// them as UTC since zip doesn't record time zone.
+fn parse_dos_datetime(date: u16, time: u16) -> Option<OffsetDateTime> {
+    let month = Month::try_from(((date >> 5) & 0x0f) as u8).ok()?;
+    let date =
+        Date::from_calendar_date(1980 + (date >> 9) as i32, month, (date & 0x1f) as u8).ok()?;
+    let time = Time::from_hms(
+        (time >> 11) as u8,
+        ((time >> 5) & 0x3f) as u8,
+        ((time & 0x1f) * 2).min(59) as u8,
+    )
+    .ok()?;
+
+    Some(PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+fn le_u16(bs: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([bs[pos], bs[pos + 1]])
+}
+
+fn le_u32(bs: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([bs[pos], bs[pos + 1], bs[pos + 2], bs[pos + 3]])
+}
+
+fn le_u64(bs: &[u8], pos: usize) -> u64 {
+    let mut v = [0; 8];
+    v.copy_from_slice(&bs[pos..pos + 8]);
+    u64::from_le_bytes(v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Memory;
+
+    #[test]
+    fn test_parse_dos_datetime() {
+        // 2023-01-31 12:34:56
+        let date = ((2023 - 1980) << 9) | (1 << 5) | 31;
+        let time = (12 << 11) | (34 << 5) | (56 / 2);
+
+        let t = parse_dos_datetime(date, time).expect("must be valid");
+        assert_eq!(t.unix_timestamp(), 1675168496);
+
+        assert!(parse_dos_datetime(0, 0).is_none());
+    }
+
+    #[test]
+    fn test_parse_compress_algorithm() {
+        assert_eq!(parse_compress_algorithm(0).unwrap(), None);
+        assert_eq!(
+            parse_compress_algorithm(8).unwrap(),
+            Some(CompressAlgorithm::Deflate)
+        );
+        assert_eq!(
+            parse_compress_algorithm(14).unwrap_err().kind(),
+            ErrorKind::Unsupported
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_index_with_overflowed_offset() -> Result<()> {
+        let mut buf = vec![];
+
+        // zip64 end of central directory with cd_offset + cd_size overflowed.
+        buf.extend_from_slice(&ZIP64_EOCD_SIGNATURE.to_le_bytes());
+        buf.extend_from_slice(&[0; 28]);
+        buf.extend_from_slice(&1u64.to_le_bytes());
+        buf.extend_from_slice(&1u64.to_le_bytes());
+        buf.extend_from_slice(&u64::MAX.to_le_bytes());
+        // zip64 end of central directory locator.
+        buf.extend_from_slice(&ZIP64_LOCATOR_SIGNATURE.to_le_bytes());
+        buf.extend_from_slice(&[0; 4]);
+        buf.extend_from_slice(&0u64.to_le_bytes());
+        buf.extend_from_slice(&1u32.to_le_bytes());
+        // end of central directory.
+        buf.extend_from_slice(&EOCD_SIGNATURE.to_le_bytes());
+        buf.extend_from_slice(&[0; 4]);
+        buf.extend_from_slice(&u16::MAX.to_le_bytes());
+        buf.extend_from_slice(&u16::MAX.to_le_bytes());
+        buf.extend_from_slice(&u32::MAX.to_le_bytes());
+        buf.extend_from_slice(&u32::MAX.to_le_bytes());
+        buf.extend_from_slice(&0u16.to_le_bytes());
+
+        let op = Operator::create(Memory::default())?.finish();
+        let size = buf.len() as u64;
+        op.object("test.zip").write(buf.clone()).await?;
+        let err = build_index(&op.object("test.zip"), size)
+            .await
+            .expect_err("build index must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+        // zip64 locator points to an overflowed offset.
+        let locator_offset = ZIP64_EOCD_SIZE as usize + 8;
+        buf[locator_offset..locator_offset + 8].copy_from_slice(&(u64::MAX - 1).to_le_bytes());
+        op.object("test.zip").write(buf).await?;
+        let err = build_index(&op.object("test.zip"), size)
+            .await
+            .expect_err("build index must fail");
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+
+        Ok(())
+    }
+}
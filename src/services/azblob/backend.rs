@@ -20,23 +20,35 @@ use std::mem;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Bytes;
 use http::header::HeaderName;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::NON_ALPHANUMERIC;
 use reqsign::AzureStorageSigner;
+use serde::Serialize;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
+use super::error::parse_error_from_bytes;
+use super::sas::SasSigner;
 use crate::object::ObjectMetadata;
 use crate::ops::*;
 use crate::raw::*;
 use crate::*;
 
 const X_MS_BLOB_TYPE: &str = "x-ms-blob-type";
+/// The max number of sub-requests that allowed in one blob batch request.
+const BLOB_BATCH_LIMIT: usize = 256;
 
 /// Azure Storage Blob services support.
 ///
@@ -47,9 +59,12 @@ const X_MS_BLOB_TYPE: &str = "x-ms-blob-type";
 /// - [x] read
 /// - [x] write
 /// - [x] list
-/// - [ ] presign
-/// - [ ] multipart
-/// - [ ] blocking
+/// - [x] presign
+/// - [x] multipart
+/// - [x] blocking
+///
+/// Presign is only supported while `account_name` and `account_key` are
+/// set without `sas_token`.
 ///
 /// # Configuration
 ///
@@ -372,6 +387,17 @@ impl Builder for AzblobBuilder {
             })?
         };
 
+        // Presign requires generating SAS token with account_key.
+        let sas_signer = match (&self.sas_token, &self.account_name, &self.account_key) {
+            (None, Some(name), Some(key)) => {
+                Some(Arc::new(SasSigner::new(name, key).map_err(|err| {
+                    err.with_operation("Builder::build")
+                        .with_context("service", Scheme::Azblob)
+                })?))
+            }
+            _ => None,
+        };
+
         let mut signer_builder = AzureStorageSigner::builder();
         if let Some(sas_token) = &self.sas_token {
             signer_builder.security_token(sas_token);
@@ -393,6 +419,7 @@ impl Builder for AzblobBuilder {
             root,
             endpoint,
             signer: Arc::new(signer),
+            sas_signer,
             container: self.container.clone(),
             client,
             _account_name: mem::take(&mut self.account_name).unwrap_or_default(),
@@ -408,24 +435,34 @@ pub struct AzblobBackend {
     root: String, // root will be "/" or /abc/
     endpoint: String,
     signer: Arc<AzureStorageSigner>,
+    sas_signer: Option<Arc<SasSigner>>,
     _account_name: String,
 }
 
 #[async_trait]
 impl Accessor for AzblobBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
+        let mut capabilities = AccessorCapability::Read
+            | AccessorCapability::Write
+            | AccessorCapability::List
+            | AccessorCapability::Multipart
+            | AccessorCapability::Blocking
+            | AccessorCapability::Batch;
+        // Presign is only available while we can generate SAS tokens.
+        if self.sas_signer.is_some() {
+            capabilities |= AccessorCapability::Presign;
+        }
+
         let mut am = AccessorMetadata::default();
         am.set_scheme(Scheme::Azblob)
             .set_root(&self.root)
             .set_name(&self.container)
-            .set_capabilities(
-                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
-            )
+            .set_capabilities(capabilities)
             .set_hints(AccessorHint::ReadIsStreamable);
 
         am
@@ -472,6 +509,8 @@ impl Accessor for AzblobBackend {
             AsyncBody::Reader(r),
         )?;
 
+        insert_checksum_header(&mut req, &args)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         let resp = self.client.send_async(req).await?;
@@ -522,14 +561,343 @@ impl Accessor for AzblobBackend {
 
         Ok((RpList::default(), op))
     }
-}
 
-impl AzblobBackend {
-    async fn azblob_get_blob(
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let mut req = self
+            .azblob_put_blob_request(path, Some(0), None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut req = self
+            .azblob_get_blob_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
         &self,
         path: &str,
-        range: BytesRange,
-    ) -> Result<Response<IncomingAsyncBody>> {
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let mut req = self
+            .azblob_put_blob_request(
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        insert_checksum_header(&mut req, &args)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let mut req = self
+            .azblob_get_blob_properties_request(path)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let mut req = self.azblob_delete_blob_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::ACCEPTED | StatusCode::NOT_FOUND => Ok(RpDelete::default()),
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        let op = DirStream::new(Arc::new(self.clone()), self.root.clone(), path.to_string());
+
+        Ok((RpList::default(), op))
+    }
+
+    fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        // We will not send this request out, just for signing.
+        let (mut req, permissions) = match args.operation() {
+            PresignOperation::Stat(_) => (self.azblob_get_blob_properties_request(path)?, "r"),
+            PresignOperation::Read(v) => (self.azblob_get_blob_request(path, v.range())?, "r"),
+            PresignOperation::Write(v) => (
+                self.azblob_put_blob_request(path, None, v.content_type(), AsyncBody::Empty)?,
+                "cw",
+            ),
+            PresignOperation::WriteMultipart(v) => (
+                self.azblob_put_block_request(
+                    path,
+                    &format_block_id(v.upload_id(), v.part_number()),
+                    None,
+                    AsyncBody::Empty,
+                )?,
+                "w",
+            ),
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "azblob doesn't support presign post",
+                ))
+            }
+        };
+
+        let signer = self.sas_signer.as_ref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "azblob presign requires account_name and account_key",
+            )
+        })?;
+        let p = build_abs_path(&self.root, path);
+        signer.sign_query(&mut req, &self.container, &p, permissions, args.expire())?;
+
+        // We don't need this request anymore, consume it directly.
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
+
+    /// Azblob doesn't have multipart upload API, we emulate it with
+    /// [Put Block](https://learn.microsoft.com/en-us/rest/api/storageservices/put-block)
+    /// and [Put Block List](https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list).
+    ///
+    /// The upload id is generated locally and used to build block ids.
+    async fn create_multipart(&self, _: &str, _: OpCreateMultipart) -> Result<RpCreateMultipart> {
+        Ok(RpCreateMultipart::new(&uuid::Uuid::new_v4().to_string()))
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::Reader,
+    ) -> Result<RpWriteMultipart> {
+        let block_id = format_block_id(args.upload_id(), args.part_number());
+
+        let mut req = self.azblob_put_block_request(
+            path,
+            &block_id,
+            Some(args.size()),
+            AsyncBody::Reader(r),
+        )?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED => {
+                resp.into_body().consume().await?;
+
+                // Put Block doesn't return etag, we use block id instead so
+                // that we can commit it later.
+                Ok(RpWriteMultipart::new(args.part_number(), &block_id))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp = self.azblob_put_block_list(path, args.parts()).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    /// Azblob doesn't support abort blocks, uncommitted blocks will be
+    /// garbage collected after a week.
+    async fn abort_multipart(&self, _: &str, _: OpAbortMultipart) -> Result<RpAbortMultipart> {
+        Ok(RpAbortMultipart::default())
+    }
+
+    fn blocking_create_multipart(
+        &self,
+        _: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        Ok(RpCreateMultipart::new(&uuid::Uuid::new_v4().to_string()))
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let block_id = format_block_id(args.upload_id(), args.part_number());
+
+        let mut req = self
+            .azblob_put_block_request(path, &block_id, Some(args.size()), AsyncBody::Empty)?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED => {
+                resp.into_body().consume()?;
+
+                // Put Block doesn't return etag, we use block id instead so
+                // that we can commit it later.
+                Ok(RpWriteMultipart::new(args.part_number(), &block_id))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp = self.azblob_blocking_put_block_list(path, args.parts())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    /// Azblob doesn't support abort blocks, uncommitted blocks will be
+    /// garbage collected after a week.
+    fn blocking_abort_multipart(&self, _: &str, _: OpAbortMultipart) -> Result<RpAbortMultipart> {
+        Ok(RpAbortMultipart::default())
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = match args.into_operation() {
+            BatchOperations::Delete(ops) => ops,
+        };
+        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
+
+        let mut results = Vec::with_capacity(paths.len());
+        for paths in paths.chunks(BLOB_BATCH_LIMIT) {
+            let resp = self.azblob_batch_delete(paths).await?;
+
+            if resp.status() != StatusCode::ACCEPTED {
+                return Err(parse_error(resp).await?);
+            }
+
+            let (parts, body) = resp.into_parts();
+            let bs = body.bytes().await?;
+            let resps = MixedMultipart::parse_responses(&parts.headers, bs)?;
+            if resps.len() != paths.len() {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "azblob batch response doesn't match with request",
+                )
+                .with_context("expected", paths.len().to_string())
+                .with_context("actual", resps.len().to_string()));
+            }
+
+            // Sub-responses are returned in the same order as sub-requests.
+            for (path, resp) in paths.iter().zip(resps) {
+                let result = match resp.status() {
+                    StatusCode::ACCEPTED | StatusCode::NOT_FOUND => Ok(RpDelete::default()),
+                    _ => Err(parse_error_from_bytes(resp)),
+                };
+                results.push((path.clone(), result));
+            }
+        }
+
+        Ok(RpBatch::new(BatchedResults::Delete(results)))
+    }
+}
+
+impl AzblobBackend {
+    fn azblob_get_blob_request(&self, path: &str, range: BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -555,10 +923,20 @@ impl AzblobBackend {
             req = req.header(http::header::RANGE, range.to_header());
         }
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn azblob_get_blob(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azblob_get_blob_request(path, range)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
@@ -598,7 +976,92 @@ impl AzblobBackend {
         Ok(req)
     }
 
-    async fn azblob_get_blob_properties(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn azblob_put_block_request(
+        &self,
+        path: &str,
+        block_id: &str,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        // Block id is base64 encoded which could contain `+`, `/` and `=`,
+        // so we need to encode all of them.
+        let url = format!(
+            "{}/{}/{}?comp=block&blockid={}",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+            utf8_percent_encode(block_id, NON_ALPHANUMERIC)
+        );
+
+        let mut req = Request::put(&url);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size)
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn azblob_put_block_list_request(
+        &self,
+        path: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?comp=blocklist",
+            self.endpoint,
+            self.container,
+            percent_encode_path(&p),
+        );
+
+        let content = quick_xml::se::to_string(&BlockList {
+            latest: parts.iter().map(|v| v.etag().to_string()).collect(),
+        })
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "serialize xml").set_source(e))?;
+
+        let req = Request::put(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn azblob_put_block_list(
+        &self,
+        path: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azblob_put_block_list_request(path, parts)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn azblob_blocking_put_block_list(
+        &self,
+        path: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .azblob_put_block_list_request(path, parts)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn azblob_get_blob_properties_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -610,16 +1073,22 @@ impl AzblobBackend {
 
         let req = Request::head(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn azblob_get_blob_properties(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azblob_get_blob_properties_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn azblob_delete_blob(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn azblob_delete_blob_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -631,20 +1100,66 @@ impl AzblobBackend {
 
         let req = Request::delete(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn azblob_delete_blob(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azblob_delete_blob_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    pub(crate) async fn azblob_list_blobs(
+    async fn azblob_batch_delete(&self, paths: &[String]) -> Result<Response<IncomingAsyncBody>> {
+        let mut multipart = MixedMultipart::new();
+
+        for (idx, path) in paths.iter().enumerate() {
+            let p = build_abs_path(&self.root, path);
+
+            let url = format!(
+                "{}/{}/{}",
+                self.endpoint,
+                self.container,
+                percent_encode_path(&p)
+            );
+
+            // Every sub-request should be signed separately.
+            let mut req = Request::delete(&url)
+                .header(CONTENT_LENGTH, 0)
+                .body(AsyncBody::Empty)
+                .map_err(new_request_build_error)?;
+            self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+            multipart = multipart.part(
+                MixedPart::from_request(req)?
+                    .with_part_header(HeaderName::from_static("content-id"), idx.into()),
+            );
+        }
+
+        let content = multipart.build();
+        let url = format!("{}/?comp=batch", self.endpoint);
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_TYPE, multipart.content_type())
+            .header(CONTENT_LENGTH, content.len())
+            .body(AsyncBody::Bytes(content))
+            .map_err(new_request_build_error)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn azblob_list_blobs_request(
         &self,
         path: &str,
         next_marker: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!(
@@ -659,19 +1174,87 @@ impl AzblobBackend {
             write!(url, "&marker={next_marker}").expect("write into string must succeed");
         }
 
-        let mut req = Request::get(&url)
+        let req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    pub(crate) async fn azblob_list_blobs(
+        &self,
+        path: &str,
+        next_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azblob_list_blobs_request(path, next_marker)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
+
+    pub(crate) fn azblob_blocking_list_blobs(
+        &self,
+        path: &str,
+        next_marker: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .azblob_list_blobs_request(path, next_marker)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+}
+
+/// Build block id for given part.
+///
+/// All block ids of a blob must have the same length before encoding, so
+/// we pad the part number to 5 digits (azblob allows 50,000 blocks at most).
+fn format_block_id(upload_id: &str, part_number: usize) -> String {
+    BASE64_STANDARD.encode(format!("{upload_id}-{part_number:05}"))
+}
+
+/// Request of [Put Block List](https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list#request-body)
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "BlockList", rename_all = "PascalCase")]
+struct BlockList {
+    latest: Vec<String>,
+}
+
+/// Insert checksum header for `Put Blob` if checksum has been computed.
+fn insert_checksum_header<T>(req: &mut Request<T>, args: &OpWrite) -> Result<()> {
+    let (algorithm, checksum) = match args.checksum() {
+        Some(v) => v,
+        None => return Ok(()),
+    };
+
+    let name = match algorithm {
+        ChecksumAlgorithm::Md5 => HeaderName::from_static("content-md5"),
+        ChecksumAlgorithm::Crc32c | ChecksumAlgorithm::Sha256 => {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checksum algorithm is not supported",
+            )
+            .with_context("algorithm", algorithm.into_static()))
+        }
+    };
+    let value =
+        HeaderValue::from_str(checksum).map_err(|err| new_request_build_error(err.into()))?;
+    req.headers_mut().insert(name, value);
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::format_block_id;
     use super::AzblobBuilder;
+    use super::BlockList;
+    use crate::raw::Accessor;
+    use crate::raw::AccessorCapability;
+    use crate::Builder;
 
     #[test]
     fn test_builder_from_connection_string() {
@@ -750,4 +1333,55 @@ SharedAccessSignature=sv=2021-01-01&ss=b&srt=c&sp=rwdlaciytfx&se=2022-01-01T11:0
         assert_eq!(builder.account_name, None);
         assert_eq!(builder.account_key, None);
     }
+
+    #[test]
+    fn test_presign_capability() {
+        let mut builder = AzblobBuilder::default();
+        builder
+            .container("test")
+            .endpoint("http://127.0.0.1:10000/devstoreaccount1")
+            .account_name("devstoreaccount1")
+            .account_key("Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==");
+        let backend = builder.build().expect("build must succeed");
+        assert!(backend
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::Presign));
+
+        let mut builder = AzblobBuilder::default();
+        builder
+            .container("test")
+            .endpoint("http://127.0.0.1:10000/devstoreaccount1")
+            .sas_token("sv=2021-01-01&ss=b&srt=c&sp=rwdlaciytfx&sig=abc");
+        let backend = builder.build().expect("build must succeed");
+        assert!(!backend
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::Presign));
+    }
+
+    #[test]
+    fn test_format_block_id() {
+        let upload_id = "2b5a1c3e-1e52-4c38-8a8e-4b9b5f2d2b3a";
+
+        assert_eq!(
+            format_block_id(upload_id, 1).len(),
+            format_block_id(upload_id, 10000).len()
+        );
+        assert_ne!(format_block_id(upload_id, 1), format_block_id(upload_id, 2));
+    }
+
+    #[test]
+    fn test_serialize_block_list() {
+        let req = BlockList {
+            latest: vec!["AAA=".to_string(), "BBB=".to_string()],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        assert_eq!(
+            actual,
+            "<BlockList><Latest>AAA=</Latest><Latest>BBB=</Latest></BlockList>"
+        );
+    }
 }
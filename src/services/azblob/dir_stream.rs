@@ -15,12 +15,14 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
 use quick_xml::de;
 use serde::Deserialize;
 use time::format_description::well_known::Rfc2822;
 use time::OffsetDateTime;
 
 use super::backend::AzblobBackend;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
@@ -65,6 +67,32 @@ impl output::Page for DirStream {
 
         let bs = resp.into_body().bytes().await?;
 
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .azblob_blocking_list_blobs(&self.path, &self.next_marker)?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_blocking_error(resp)?);
+        }
+
+        let bs = resp.into_body().bytes()?;
+
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<output::Entry>> {
         let output: Output = de::from_reader(bs.reader()).map_err(|e| {
             Error::new(ErrorKind::Unexpected, "deserialize xml from response").set_source(e)
         })?;
@@ -120,7 +148,7 @@ impl output::Page for DirStream {
             entries.push(de);
         }
 
-        Ok(Some(entries))
+        Ok(entries)
     }
 }
 
@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use bytes::Buf;
+use bytes::Bytes;
 use http::Response;
 use http::StatusCode;
 use quick_xml::de;
@@ -62,9 +63,36 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse blocking error response into Error.
+pub fn parse_blocking_error(resp: Response<IncomingBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes()?;
+
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse error response which body has been read into Error.
+///
+/// This is used for sub-responses of batch requests.
+pub fn parse_error_from_bytes(resp: Response<Bytes>) -> Error {
+    let (parts, bs) = resp.into_parts();
+
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::ObjectNotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::ObjectPermissionDenied, false),
+        // Azblob returns `Md5Mismatch` if the `Content-MD5` we sent doesn't match.
+        StatusCode::BAD_REQUEST
+            if parts
+                .headers
+                .get("x-ms-error-code")
+                .map(|v| v == "Md5Mismatch")
+                .unwrap_or_default() =>
+        {
+            (ErrorKind::ChecksumMismatch, false)
+        }
         StatusCode::INTERNAL_SERVER_ERROR
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
@@ -97,7 +125,7 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         err = err.set_temporary();
     }
 
-    Ok(err)
+    err
 }
 
 #[cfg(test)]
@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 use std::fmt::Write;
 use std::mem;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 use async_trait::async_trait;
 use http::header::CONTENT_LENGTH;
@@ -29,6 +30,7 @@ use log::debug;
 use reqsign::AzureStorageSigner;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::object::ObjectMetadata;
 use crate::ops::*;
@@ -49,8 +51,8 @@ use crate::*;
 /// - [x] write
 /// - [x] list
 /// - [ ] presign
-/// - [ ] multipart
-/// - [ ] blocking
+/// - [x] multipart
+/// - [x] blocking
 ///
 /// # Configuration
 ///
@@ -269,6 +271,7 @@ impl Builder for AzdfsBuilder {
             filesystem: self.filesystem.clone(),
             client,
             _account_name: mem::take(&mut self.account_name).unwrap_or_default(),
+            positions: Arc::default(),
         })
     }
 
@@ -294,14 +297,16 @@ pub struct AzdfsBackend {
     endpoint: String,
     signer: Arc<AzureStorageSigner>,
     _account_name: String,
+    /// The next position to append for every multipart upload.
+    positions: Arc<Mutex<HashMap<String, u64>>>,
 }
 
 #[async_trait]
 impl Accessor for AzdfsBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
         let mut am = AccessorMetadata::default();
@@ -309,7 +314,11 @@ impl Accessor for AzdfsBackend {
             .set_root(&self.root)
             .set_name(&self.filesystem)
             .set_capabilities(
-                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
+                AccessorCapability::Read
+                    | AccessorCapability::Write
+                    | AccessorCapability::List
+                    | AccessorCapability::Multipart
+                    | AccessorCapability::Blocking,
             )
             .set_hints(AccessorHint::ReadIsStreamable);
 
@@ -427,14 +436,345 @@ impl Accessor for AzdfsBackend {
 
         Ok((RpList::default(), op))
     }
-}
 
-impl AzdfsBackend {
-    async fn azdfs_read(
+    fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
+        let resource = match args.mode() {
+            ObjectMode::FILE => "file",
+            ObjectMode::DIR => "directory",
+            _ => unimplemented!("not supported object mode"),
+        };
+
+        let mut req = self
+            .azdfs_create_request(path, resource, None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut req = self
+            .azdfs_read_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
         &self,
         path: &str,
-        range: BytesRange,
-    ) -> Result<Response<IncomingAsyncBody>> {
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let mut req = self
+            .azdfs_create_request(path, "file", args.content_type(), AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+            }
+            _ => {
+                return Err(
+                    parse_blocking_error(resp)?.with_operation("Backend::azdfs_create_request")
+                );
+            }
+        }
+
+        let mut req = self
+            .azdfs_update_request(path, Some(args.size()), AsyncBody::Empty)?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::ACCEPTED => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?.with_operation("Backend::azdfs_update_request")),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let resp = self.azdfs_blocking_get_properties(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let resp = self.azdfs_blocking_delete(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::NOT_FOUND => Ok(RpDelete::default()),
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        let op = DirStream::new(Arc::new(self.clone()), self.root.clone(), path.to_string());
+
+        Ok((RpList::default(), op))
+    }
+
+    /// Azdfs doesn't have multipart upload API, we emulate it with
+    /// [append and flush](https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/update).
+    ///
+    /// # Notes
+    ///
+    /// Every part will be appended at the end of previous written parts,
+    /// so parts must be written in order via the same operator. Appended
+    /// data will not be visible until `complete_multipart`.
+    async fn create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let mut req = self.azdfs_create_request(path, "file", None, AsyncBody::Empty)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCreateMultipart::new(&self.start_upload()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::Reader,
+    ) -> Result<RpWriteMultipart> {
+        let position = self.reserve_position(args.upload_id(), args.size())?;
+
+        let mut req =
+            self.azdfs_append_request(path, position, Some(args.size()), AsyncBody::Reader(r))?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        match resp.status() {
+            StatusCode::ACCEPTED => {
+                resp.into_body().consume().await?;
+
+                Ok(RpWriteMultipart::new(
+                    args.part_number(),
+                    &(position + args.size()).to_string(),
+                ))
+            }
+            _ => Err(parse_error(resp)
+                .await?
+                .with_operation("Backend::azdfs_append_request")),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let position = parse_end_position(args.parts())?;
+
+        let mut req = self.azdfs_flush_request(path, position)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+                self.finish_upload(args.upload_id());
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_error(resp)
+                .await?
+                .with_operation("Backend::azdfs_flush_request")),
+        }
+    }
+
+    /// Abort will remove the partial file that created by `create_multipart`.
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self.azdfs_delete(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::NOT_FOUND => {
+                resp.into_body().consume().await?;
+                self.finish_upload(args.upload_id());
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let mut req = self
+            .azdfs_create_request(path, "file", None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCreateMultipart::new(&self.start_upload()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let position = self.reserve_position(args.upload_id(), args.size())?;
+
+        let mut req = self
+            .azdfs_append_request(path, position, Some(args.size()), AsyncBody::Empty)?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        match resp.status() {
+            StatusCode::ACCEPTED => {
+                resp.into_body().consume()?;
+
+                Ok(RpWriteMultipart::new(
+                    args.part_number(),
+                    &(position + args.size()).to_string(),
+                ))
+            }
+            _ => Err(parse_blocking_error(resp)?.with_operation("Backend::azdfs_append_request")),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let position = parse_end_position(args.parts())?;
+
+        let mut req = self
+            .azdfs_flush_request(path, position)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                resp.into_body().consume()?;
+                self.finish_upload(args.upload_id());
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?.with_operation("Backend::azdfs_flush_request")),
+        }
+    }
+
+    /// Abort will remove the partial file that created by `create_multipart`.
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self.azdfs_blocking_delete(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::NOT_FOUND => {
+                resp.into_body().consume()?;
+                self.finish_upload(args.upload_id());
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+}
+
+impl AzdfsBackend {
+    fn azdfs_read_request(&self, path: &str, range: BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -460,10 +800,20 @@ impl AzdfsBackend {
             req = req.header(http::header::RANGE, range.to_header());
         }
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn azdfs_read(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azdfs_read_request(path, range)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
@@ -535,7 +885,93 @@ impl AzdfsBackend {
         Ok(req)
     }
 
-    async fn azdfs_get_properties(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    /// Azdfs doesn't have upload id, we generate one to track the position
+    /// to append.
+    fn start_upload(&self) -> String {
+        let upload_id = uuid::Uuid::new_v4().to_string();
+        self.positions
+            .lock()
+            .expect("lock must succeed")
+            .insert(upload_id.clone(), 0);
+        upload_id
+    }
+
+    /// Reserve `size` bytes for the part, returns the position to append.
+    fn reserve_position(&self, upload_id: &str, size: u64) -> Result<u64> {
+        let mut positions = self.positions.lock().expect("lock must succeed");
+        let position = positions.get_mut(upload_id).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "upload id is not created by this operator",
+            )
+            .with_context("service", Scheme::Azdfs)
+            .with_context("upload_id", upload_id)
+        })?;
+
+        let current = *position;
+        *position += size;
+        Ok(current)
+    }
+
+    fn finish_upload(&self, upload_id: &str) {
+        self.positions
+            .lock()
+            .expect("lock must succeed")
+            .remove(upload_id);
+    }
+
+    /// Append data at given position without flush.
+    ///
+    /// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/update
+    fn azdfs_append_request(
+        &self,
+        path: &str,
+        position: u64,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?action=append&position={position}",
+            self.endpoint,
+            self.filesystem,
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::patch(&url);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size)
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Flush all appended data before position.
+    ///
+    /// ref: https://learn.microsoft.com/en-us/rest/api/storageservices/datalakestoragegen2/path/update
+    fn azdfs_flush_request(&self, path: &str, position: u64) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?action=flush&position={position}",
+            self.endpoint,
+            self.filesystem,
+            percent_encode_path(&p)
+        );
+
+        let req = Request::patch(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn azdfs_get_properties_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path)
             .trim_end_matches('/')
             .to_string();
@@ -549,16 +985,32 @@ impl AzdfsBackend {
 
         let req = Request::head(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn azdfs_get_properties(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azdfs_get_properties_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn azdfs_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn azdfs_blocking_get_properties(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .azdfs_get_properties_request(path)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn azdfs_delete_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path)
             .trim_end_matches('/')
             .to_string();
@@ -572,20 +1024,30 @@ impl AzdfsBackend {
 
         let req = Request::delete(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn azdfs_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azdfs_delete_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    pub(crate) async fn azdfs_list(
-        &self,
-        path: &str,
-        continuation: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    fn azdfs_blocking_delete(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let mut req = self.azdfs_delete_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn azdfs_list_request(&self, path: &str, continuation: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path)
             .trim_end_matches('/')
             .to_string();
@@ -602,12 +1064,50 @@ impl AzdfsBackend {
             write!(url, "&continuation={continuation}").expect("write into string must succeed");
         }
 
-        let mut req = Request::get(&url)
+        let req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    pub(crate) async fn azdfs_list(
+        &self,
+        path: &str,
+        continuation: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.azdfs_list_request(path, continuation)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
+
+    pub(crate) fn azdfs_blocking_list(
+        &self,
+        path: &str,
+        continuation: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .azdfs_list_request(path, continuation)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+}
+
+/// The etag of part is the end position of its data, the end position of
+/// the last part is the total size to flush.
+fn parse_end_position(parts: &[ObjectPart]) -> Result<u64> {
+    parts.iter().try_fold(0, |acc, part| {
+        let end = part.etag().parse::<u64>().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "part etag is not a valid position")
+                .with_context("service", Scheme::Azdfs)
+                .with_context("etag", part.etag())
+                .set_source(err)
+        })?;
+        Ok(acc.max(end))
+    })
 }
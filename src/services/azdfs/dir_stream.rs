@@ -14,12 +14,14 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use serde::Deserialize;
 use serde_json::de;
 use time::format_description::well_known::Rfc2822;
 use time::OffsetDateTime;
 
 use super::backend::AzdfsBackend;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::*;
@@ -81,6 +83,49 @@ impl output::Page for DirStream {
 
         let bs = resp.into_body().bytes().await?;
 
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .azdfs_blocking_list(&self.path, &self.continuation)?;
+
+        // Azdfs will return not found for not-exist path.
+        if resp.status() == http::StatusCode::NOT_FOUND {
+            resp.into_body().consume()?;
+            return Ok(None);
+        }
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_blocking_error(resp)?);
+        }
+
+        // Check whether this list is done.
+        if let Some(value) = resp.headers().get("x-ms-continuation") {
+            let value = value.to_str().map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "header value is not valid string")
+                    .set_source(err)
+            })?;
+            self.continuation = value.to_string();
+        } else {
+            self.continuation = "".to_string();
+            self.done = true;
+        }
+
+        let bs = resp.into_body().bytes()?;
+
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<output::Entry>> {
         let output: Output = de::from_slice(&bs).map_err(|e| {
             Error::new(ErrorKind::Unexpected, "deserialize json from response").set_source(e)
         })?;
@@ -123,7 +168,7 @@ impl output::Page for DirStream {
             entries.push(de);
         }
 
-        Ok(Some(entries))
+        Ok(entries)
     }
 }
 
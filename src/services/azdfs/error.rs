@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use bytes::Buf;
+use bytes::Bytes;
 use http::Response;
 use http::StatusCode;
 use quick_xml::de;
@@ -62,6 +63,21 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse blocking error response into Error.
+pub fn parse_blocking_error(resp: Response<IncomingBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes()?;
+
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse error response which body has been read into Error.
+fn parse_error_from_bytes(resp: Response<Bytes>) -> Error {
+    let (parts, bs) = resp.into_parts();
+
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::ObjectNotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::ObjectPermissionDenied, false),
@@ -97,5 +113,5 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         err = err.set_temporary();
     }
 
-    Ok(err)
+    err
 }
@@ -0,0 +1,349 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cacache::Error as CacacheError;
+
+use super::gc;
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+/// Content-addressable cache directory services support, powered by
+/// [cacache](https://github.com/zkat/cacache-rs).
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [x] write
+/// - [x] list
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [x] blocking
+///
+/// # Note
+///
+/// The cache directory contains an index which maps keys to the integrity
+/// hashes of content, and content files which are addressed by their hashes:
+///
+/// - Content is written to a temp file and then renamed into place, so
+///   readers never see partial content and many processes can share the
+///   same directory safely.
+/// - Objects with the same content will share the same content file.
+/// - Content will be verified against its integrity hash while reading.
+///
+/// Deleting an object only removes its index entry since the content could
+/// be shared. Use [`CacacheBackend::gc`] or [`CacacheBackend::blocking_gc`]
+/// to remove content that is no longer referenced.
+///
+/// # Configuration
+///
+/// - `datadir`: Set the path to the cache directory
+/// - `root`: Set the working directory of `OpenDAL`
+///
+/// You can refer to [`CacacheBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Cacache;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Cacache::default();
+///     builder.datadir("/tmp/opendal/cacache");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("test_file");
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Collect Garbage
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use anyhow::Result;
+/// use opendal::services::Cacache;
+/// use opendal::Builder;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Cacache::default();
+///     builder.datadir("/tmp/opendal/cacache");
+///
+///     // Keep the backend to collect garbage later.
+///     let backend = builder.build()?;
+///     let op: Operator = Operator::new(backend.clone()).finish();
+///
+///     op.object("test_file").delete().await?;
+///     backend.gc(Duration::from_secs(3600)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct CacacheBuilder {
+    /// That path to the cache directory.
+    datadir: Option<String>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    root: Option<String>,
+}
+
+impl CacacheBuilder {
+    /// Set the path to the cache directory. Will create if not exists.
+    pub fn datadir(&mut self, path: &str) -> &mut Self {
+        if !path.is_empty() {
+            self.datadir = Some(path.to_owned());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+
+    fn get_datadir(&self) -> Result<String> {
+        self.datadir.clone().ok_or_else(|| {
+            Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "datadir is required but not set",
+            )
+            .with_context("service", Scheme::Cacache)
+        })
+    }
+}
+
+impl Builder for CacacheBuilder {
+    const SCHEME: Scheme = Scheme::Cacache;
+    type Accessor = CacacheBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = CacacheBuilder::default();
+
+        map.get("datadir").map(|v| builder.datadir(v));
+        map.get("root").map(|v| builder.root(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let datadir = self.get_datadir()?;
+
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(CacacheBackend::new(Adapter {
+            datadir,
+            root: root.clone(),
+        })
+        .with_root(&root))
+    }
+}
+
+/// Backend for cacache services.
+pub type CacacheBackend = kv::Backend<Adapter>;
+
+impl CacacheBackend {
+    /// Remove content that is not referenced by any index entry, returns
+    /// the number of removed content files.
+    ///
+    /// Content and temp files modified within `grace` will be kept, so that
+    /// content being written by other processes won't be removed before its
+    /// index entry is inserted.
+    ///
+    /// The whole cache directory will be collected, regardless of `root`.
+    pub async fn gc(&self, grace: Duration) -> Result<usize> {
+        gc::gc(&self.adapter().datadir, grace).await
+    }
+
+    /// The blocking version of [`CacacheBackend::gc`].
+    pub fn blocking_gc(&self, grace: Duration) -> Result<usize> {
+        gc::blocking_gc(&self.adapter().datadir, grace)
+    }
+}
+
+#[derive(Clone)]
+pub struct Adapter {
+    datadir: String,
+    root: String,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("datadir", &self.datadir);
+        ds.field("root", &self.root);
+        ds.finish()
+    }
+}
+
+impl Adapter {
+    /// Build the cache key of given path with root as prefix.
+    fn key(&self, path: &str) -> String {
+        if path == "/" {
+            self.root.clone()
+        } else {
+            format!("{}{}", self.root, path)
+        }
+    }
+
+    fn metadata_to_object(meta: cacache::Metadata) -> Result<ObjectMetadata> {
+        let mut om = ObjectMetadata::new(ObjectMode::FILE)
+            .with_content_length(meta.size as u64)
+            .with_etag(&meta.integrity.to_string());
+
+        let last_modified =
+            time::OffsetDateTime::from_unix_timestamp_nanos(meta.time as i128 * 1_000_000)
+                .map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "cache entry has invalid time")
+                        .set_source(err)
+                })?;
+        om.set_last_modified(last_modified);
+
+        Ok(om)
+    }
+}
+
+#[async_trait]
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Cacache,
+            &self.datadir,
+            AccessorCapability::Read
+                | AccessorCapability::Write
+                | AccessorCapability::List
+                | AccessorCapability::Blocking,
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        match cacache::read(&self.datadir, self.key(path)).await {
+            Ok(bs) => Ok(Some(bs)),
+            Err(CacacheError::EntryNotFound(_, _)) => Ok(None),
+            Err(err) => Err(parse_cacache_error(err)),
+        }
+    }
+
+    fn blocking_get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        match cacache::read_sync(&self.datadir, self.key(path)) {
+            Ok(bs) => Ok(Some(bs)),
+            Err(CacacheError::EntryNotFound(_, _)) => Ok(None),
+            Err(err) => Err(parse_cacache_error(err)),
+        }
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        cacache::metadata(&self.datadir, self.key(path))
+            .await
+            .map_err(parse_cacache_error)?
+            .map(Self::metadata_to_object)
+            .transpose()
+    }
+
+    fn blocking_stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        cacache::metadata_sync(&self.datadir, self.key(path))
+            .map_err(parse_cacache_error)?
+            .map(Self::metadata_to_object)
+            .transpose()
+    }
+
+    async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
+        cacache::write(&self.datadir, self.key(path), value)
+            .await
+            .map_err(parse_cacache_error)?;
+
+        Ok(())
+    }
+
+    fn blocking_set(&self, path: &str, value: &[u8]) -> Result<()> {
+        cacache::write_sync(&self.datadir, self.key(path), value).map_err(parse_cacache_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        cacache::remove(&self.datadir, self.key(path))
+            .await
+            .map_err(parse_cacache_error)
+    }
+
+    fn blocking_delete(&self, path: &str) -> Result<()> {
+        cacache::remove_sync(&self.datadir, self.key(path)).map_err(parse_cacache_error)
+    }
+
+    async fn scan(&self, prefix: &str) -> Result<Vec<String>> {
+        self.blocking_scan(prefix)
+    }
+
+    fn blocking_scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let prefix = self.key(prefix);
+
+        let mut keys = Vec::new();
+        for meta in cacache::list_sync(&self.datadir) {
+            let meta = match meta {
+                Ok(v) => v,
+                // Index dir is not exist if nothing has been written.
+                Err(CacacheError::IoError(err, _)) if err.kind() == io::ErrorKind::NotFound => {
+                    continue
+                }
+                Err(err) => return Err(parse_cacache_error(err)),
+            };
+            if !meta.key.starts_with(&prefix) {
+                continue;
+            }
+            if let Some(key) = meta.key.strip_prefix(&self.root) {
+                keys.push(key.to_string());
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+fn parse_cacache_error(err: CacacheError) -> Error {
+    let kind = match err {
+        CacacheError::EntryNotFound(_, _) => ErrorKind::ObjectNotFound,
+        _ => ErrorKind::Unexpected,
+    };
+
+    Error::new(kind, "got cacache error").set_source(err)
+}
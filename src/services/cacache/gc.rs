@@ -0,0 +1,187 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Garbage collection for cache directories.
+//!
+//! cacache stores content at `content-v2/<algorithm>/<hex[0..2]>/<hex[2..4]>/<hex[4..]>`,
+//! content that is not referenced by any index entry could be removed
+//! safely once it's old enough.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::*;
+
+const CONTENT_DIR: &str = "content-v2";
+const TMP_DIR: &str = "tmp";
+
+/// Remove unreferenced content in cache directory.
+///
+/// cacache doesn't provide async index listing, so we run the blocking
+/// version directly.
+pub async fn gc(datadir: &str, grace: Duration) -> Result<usize> {
+    blocking_gc(datadir, grace)
+}
+
+/// Remove unreferenced content in cache directory.
+pub fn blocking_gc(datadir: &str, grace: Duration) -> Result<usize> {
+    let datadir = Path::new(datadir);
+
+    let mut referenced = HashSet::new();
+    for meta in cacache::list_sync(datadir) {
+        let meta = match meta {
+            Ok(v) => v,
+            // Index dir is not exist if nothing has been written.
+            Err(cacache::Error::IoError(err, _)) if err.kind() == io::ErrorKind::NotFound => {
+                continue
+            }
+            Err(err) => {
+                return Err(Error::new(ErrorKind::Unexpected, "list cache index").set_source(err))
+            }
+        };
+        referenced.insert(content_path(&meta.integrity));
+    }
+
+    let deadline = SystemTime::now()
+        .checked_sub(grace)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+    let content_dir = datadir.join(CONTENT_DIR);
+    let mut removed = 0;
+    for path in walk_files(&content_dir).map_err(parse_io_error)? {
+        let rel = match path.strip_prefix(&content_dir) {
+            Ok(v) => v.to_path_buf(),
+            Err(_) => continue,
+        };
+        if referenced.contains(&rel) {
+            continue;
+        }
+        if remove_if_expired(&path, deadline).map_err(parse_io_error)? {
+            removed += 1;
+        }
+    }
+
+    // Temp files are left by writers that have been interrupted.
+    for path in walk_files(&datadir.join(TMP_DIR)).map_err(parse_io_error)? {
+        remove_if_expired(&path, deadline).map_err(parse_io_error)?;
+    }
+
+    Ok(removed)
+}
+
+/// Build the content path relative to content dir for given integrity.
+fn content_path(sri: &cacache::Integrity) -> PathBuf {
+    let (algo, hex) = sri.to_hex();
+
+    let mut path = PathBuf::from(algo.to_string());
+    path.push(&hex[0..2]);
+    path.push(&hex[2..4]);
+    path.push(&hex[4..]);
+    path
+}
+
+/// Collect all files under dir, returns empty if dir is not exist.
+fn walk_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(v) => v,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                dirs.push(entry.path());
+            } else {
+                files.push(entry.path());
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Remove the file if it's not modified after deadline.
+///
+/// Files that have been removed by others will be treated as not removed.
+fn remove_if_expired(path: &Path, deadline: SystemTime) -> io::Result<bool> {
+    let modified = match fs::metadata(path).and_then(|v| v.modified()) {
+        Ok(v) => v,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err),
+    };
+    if modified > deadline {
+        return Ok(false);
+    }
+
+    match fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+fn parse_io_error(err: io::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "collect cache garbage").set_source(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocking_gc() {
+        let dir = std::env::temp_dir().join(format!("opendal-cacache-{}", uuid::Uuid::new_v4()));
+        let datadir = dir.to_str().expect("must be valid utf-8");
+
+        cacache::write_sync(datadir, "a", b"shared").expect("write must succeed");
+        cacache::write_sync(datadir, "b", b"shared").expect("write must succeed");
+        cacache::write_sync(datadir, "c", b"unique").expect("write must succeed");
+
+        // Content is still referenced by `b`.
+        cacache::remove_sync(datadir, "a").expect("remove must succeed");
+        assert_eq!(
+            blocking_gc(datadir, Duration::ZERO).expect("gc must succeed"),
+            0
+        );
+
+        // Fresh content will be kept during grace period.
+        cacache::remove_sync(datadir, "c").expect("remove must succeed");
+        assert_eq!(
+            blocking_gc(datadir, Duration::from_secs(3600)).expect("gc must succeed"),
+            0
+        );
+        assert_eq!(
+            blocking_gc(datadir, Duration::ZERO).expect("gc must succeed"),
+            1
+        );
+
+        assert_eq!(
+            cacache::read_sync(datadir, "b").expect("read must succeed"),
+            b"shared"
+        );
+        assert!(cacache::read_sync(datadir, "c").is_err());
+
+        fs::remove_dir_all(dir).expect("cleanup must succeed");
+    }
+}
@@ -0,0 +1,393 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fs;
+
+use async_trait::async_trait;
+use etcd_client::Certificate;
+use etcd_client::Client;
+use etcd_client::ConnectOptions;
+use etcd_client::Error as EtcdError;
+use etcd_client::GetOptions;
+use etcd_client::Identity;
+use etcd_client::TlsOptions;
+use tokio::sync::OnceCell;
+
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+const DEFAULT_ETCD_ENDPOINTS: &str = "http://127.0.0.1:2379";
+
+/// [etcd](https://etcd.io/) services support.
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [x] write
+/// - [x] list
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [ ] blocking
+///
+/// # Note
+///
+/// All keys will be stored under `root` as prefix, for example, object
+/// `config.json` with root `/apps/` will be stored as key
+/// `/apps/config.json`. The mod revision of a key will be returned as
+/// etag.
+///
+/// # Configuration
+///
+/// - `root`: Set the working directory of `OpenDAL`
+/// - `endpoints`: Set the network addresses of etcd servers, split by `,`
+/// - `username`: Set the username of etcd
+/// - `password`: Set the password for authentication
+/// - `ca_path`: Set the path to the CA certificate for TLS
+/// - `cert_path`: Set the path to the client certificate for TLS
+/// - `key_path`: Set the path to the client key for TLS
+///
+/// You can refer to [`EtcdBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Etcd;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Etcd::default();
+///     builder.endpoints("http://127.0.0.1:2379");
+///     builder.root("/apps/");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("test_file");
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct EtcdBuilder {
+    /// The network addresses of etcd servers.
+    ///
+    /// default is "http://127.0.0.1:2379"
+    endpoints: Option<String>,
+    /// the username to connect etcd service.
+    username: Option<String>,
+    /// the password for authentication
+    password: Option<String>,
+    /// the path to the CA certificate
+    ca_path: Option<String>,
+    /// the path to the client certificate
+    cert_path: Option<String>,
+    /// the path to the client key
+    key_path: Option<String>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    root: Option<String>,
+}
+
+// implement `Debug` manually, or password may be leaked.
+impl Debug for EtcdBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Builder");
+        ds.field("endpoints", &self.endpoints);
+        ds.field("username", &self.username);
+        if self.password.is_some() {
+            ds.field("password", &"<redacted>");
+        }
+        ds.field("ca_path", &self.ca_path);
+        ds.field("cert_path", &self.cert_path);
+        ds.field("key_path", &self.key_path);
+        ds.field("root", &self.root);
+        ds.finish()
+    }
+}
+
+impl EtcdBuilder {
+    /// Set the network addresses of etcd servers, multiple endpoints should
+    /// be split by `,`.
+    ///
+    /// default: "http://127.0.0.1:2379"
+    pub fn endpoints(&mut self, endpoints: &str) -> &mut Self {
+        if !endpoints.is_empty() {
+            self.endpoints = Some(endpoints.to_owned());
+        }
+        self
+    }
+
+    /// set the username for etcd
+    ///
+    /// default: no username
+    pub fn username(&mut self, username: &str) -> &mut Self {
+        if !username.is_empty() {
+            self.username = Some(username.to_owned());
+        }
+        self
+    }
+
+    /// set the password for etcd
+    ///
+    /// default: no password
+    pub fn password(&mut self, password: &str) -> &mut Self {
+        if !password.is_empty() {
+            self.password = Some(password.to_owned());
+        }
+        self
+    }
+
+    /// Set the path to the CA certificate, TLS will be enabled if set.
+    pub fn ca_path(&mut self, ca_path: &str) -> &mut Self {
+        if !ca_path.is_empty() {
+            self.ca_path = Some(ca_path.to_owned());
+        }
+        self
+    }
+
+    /// Set the path to the client certificate, must be used with `key_path`.
+    pub fn cert_path(&mut self, cert_path: &str) -> &mut Self {
+        if !cert_path.is_empty() {
+            self.cert_path = Some(cert_path.to_owned());
+        }
+        self
+    }
+
+    /// Set the path to the client key, must be used with `cert_path`.
+    pub fn key_path(&mut self, key_path: &str) -> &mut Self {
+        if !key_path.is_empty() {
+            self.key_path = Some(key_path.to_owned());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+}
+
+impl Builder for EtcdBuilder {
+    const SCHEME: Scheme = Scheme::Etcd;
+    type Accessor = EtcdBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = EtcdBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("endpoints").map(|v| builder.endpoints(v));
+        map.get("username").map(|v| builder.username(v));
+        map.get("password").map(|v| builder.password(v));
+        map.get("ca_path").map(|v| builder.ca_path(v));
+        map.get("cert_path").map(|v| builder.cert_path(v));
+        map.get("key_path").map(|v| builder.key_path(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let endpoints = self
+            .endpoints
+            .clone()
+            .unwrap_or_else(|| DEFAULT_ETCD_ENDPOINTS.to_string())
+            .split(',')
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut options = ConnectOptions::new();
+
+        if let (Some(username), Some(password)) = (&self.username, &self.password) {
+            options = options.with_user(username, password);
+        }
+
+        if let Some(ca_path) = &self.ca_path {
+            let ca = read_pem(ca_path)?;
+            let mut tls = TlsOptions::new().ca_certificate(Certificate::from_pem(ca));
+
+            match (&self.cert_path, &self.key_path) {
+                (Some(cert_path), Some(key_path)) => {
+                    let cert = read_pem(cert_path)?;
+                    let key = read_pem(key_path)?;
+                    tls = tls.identity(Identity::from_pem(cert, key));
+                }
+                (None, None) => {}
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::BackendConfigInvalid,
+                        "cert_path and key_path must be set together",
+                    )
+                    .with_context("service", Scheme::Etcd))
+                }
+            }
+
+            options = options.with_tls(tls);
+        }
+
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(EtcdBackend::new(Adapter {
+            endpoints,
+            options,
+            client: OnceCell::new(),
+            root: root.clone(),
+        })
+        .with_root(&root))
+    }
+}
+
+fn read_pem(path: &str) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|err| {
+        Error::new(ErrorKind::BackendConfigInvalid, "read pem file failed")
+            .with_context("service", Scheme::Etcd)
+            .with_context("path", path)
+            .set_source(err)
+    })
+}
+
+/// Backend for etcd services.
+pub type EtcdBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    endpoints: Vec<String>,
+    options: ConnectOptions,
+    client: OnceCell<Client>,
+    root: String,
+}
+
+// implement `Debug` manually, or password may be leaked.
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("endpoints", &self.endpoints);
+        ds.field("root", &self.root);
+        ds.finish()
+    }
+}
+
+impl Adapter {
+    async fn client(&self) -> Result<Client> {
+        Ok(self
+            .client
+            .get_or_try_init(|| async {
+                Client::connect(&self.endpoints, Some(self.options.clone())).await
+            })
+            .await?
+            .clone())
+    }
+
+    /// Build the etcd key of given path with root as prefix.
+    fn key(&self, path: &str) -> String {
+        if path == "/" {
+            self.root.clone()
+        } else {
+            format!("{}{}", self.root, path)
+        }
+    }
+}
+
+#[async_trait]
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Etcd,
+            &self.endpoints.join(","),
+            AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let mut client = self.client().await?;
+        let resp = client.get(self.key(path), None).await?;
+
+        Ok(resp.kvs().first().map(|kv| kv.value().to_vec()))
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        let mut client = self.client().await?;
+        let resp = client.get(self.key(path), None).await?;
+
+        Ok(resp.kvs().first().map(|kv| {
+            ObjectMetadata::new(ObjectMode::FILE)
+                .with_content_length(kv.value().len() as u64)
+                .with_etag(&kv.mod_revision().to_string())
+        }))
+    }
+
+    async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
+        let mut client = self.client().await?;
+        client.put(self.key(path), value, None).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let mut client = self.client().await?;
+        client.delete(self.key(path), None).await?;
+
+        Ok(())
+    }
+
+    async fn scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut client = self.client().await?;
+        let resp = client
+            .get(
+                self.key(prefix),
+                Some(GetOptions::new().with_prefix().with_keys_only()),
+            )
+            .await?;
+
+        Ok(resp
+            .kvs()
+            .iter()
+            .filter_map(|kv| kv.key_str().ok())
+            .filter_map(|key| key.strip_prefix(&self.root))
+            .map(|key| key.to_string())
+            .collect())
+    }
+}
+
+impl From<EtcdError> for Error {
+    fn from(e: EtcdError) -> Self {
+        let err = Error::new(ErrorKind::Unexpected, "got etcd error");
+
+        // Transport errors could be recovered by retry.
+        match e {
+            EtcdError::TransportError(_) | EtcdError::IoError(_) => {
+                err.set_temporary().set_source(e)
+            }
+            _ => err.set_source(e),
+        }
+    }
+}
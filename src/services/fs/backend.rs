@@ -21,6 +21,8 @@ use std::path::PathBuf;
 
 use async_compat::Compat;
 use async_trait::async_trait;
+use futures::AsyncReadExt;
+use futures::AsyncWriteExt;
 use log::debug;
 use time::OffsetDateTime;
 use tokio::fs;
@@ -34,6 +36,14 @@ use crate::ops::*;
 use crate::raw::*;
 use crate::*;
 
+/// Extended attribute used to store checksum of file, the value is
+/// `<algorithm>:<base64 encoded checksum>`.
+#[cfg(unix)]
+const CHECKSUM_XATTR: &str = "user.opendal.checksum";
+
+/// Buffer size used while copying content into file.
+const COPY_BUF_SIZE: usize = 64 * 1024;
+
 /// POSIX file system support.
 ///
 /// # Capabilities
@@ -47,6 +57,12 @@ use crate::*;
 /// - [ ] ~~multipart~~
 /// - [x] blocking
 ///
+/// # Notes
+///
+/// Checksum of written content is stored in the `user.opendal.checksum`
+/// extended attribute and will be verified while reading the whole file.
+/// It's skipped on filesystems without xattr support.
+///
 /// # Configuration
 ///
 /// - `root`: Set the work dir for backend.
@@ -285,6 +301,131 @@ impl FsBackend {
 
         Ok(p)
     }
+
+    /// Copy content into file and verify it against the checksum in args
+    /// while copying.
+    async fn copy_with_checksum(mut r: input::Reader, f: fs::File, args: &OpWrite) -> Result<u64> {
+        let mut hasher = args.checksum().map(|(algorithm, _)| algorithm.hasher());
+        let mut f = Compat::new(f);
+        let mut buf = vec![0; COPY_BUF_SIZE];
+        let mut size = 0;
+
+        loop {
+            let n = r.read(&mut buf).await.map_err(parse_io_error)?;
+            if n == 0 {
+                break;
+            }
+            if let Some(hasher) = &mut hasher {
+                hasher.update(&buf[..n]);
+            }
+            f.write_all(&buf[..n]).await.map_err(parse_io_error)?;
+            size += n as u64;
+        }
+        f.close().await.map_err(parse_io_error)?;
+
+        if let (Some(hasher), Some((_, expected))) = (hasher, args.checksum()) {
+            hasher.verify(expected)?;
+        }
+
+        Ok(size)
+    }
+
+    /// Synchronously copy content into file and verify it against the
+    /// checksum in args while copying.
+    fn blocking_copy_with_checksum(
+        mut r: input::BlockingReader,
+        mut f: std::fs::File,
+        args: &OpWrite,
+    ) -> Result<u64> {
+        use std::io::Read;
+        use std::io::Write;
+
+        let mut hasher = args.checksum().map(|(algorithm, _)| algorithm.hasher());
+        let mut buf = vec![0; COPY_BUF_SIZE];
+        let mut size = 0;
+
+        loop {
+            let n = match r.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(parse_io_error(err)),
+            };
+            if let Some(hasher) = &mut hasher {
+                hasher.update(&buf[..n]);
+            }
+            f.write_all(&buf[..n]).map_err(parse_io_error)?;
+            size += n as u64;
+        }
+        f.flush().map_err(parse_io_error)?;
+
+        if let (Some(hasher), Some((_, expected))) = (hasher, args.checksum()) {
+            hasher.verify(expected)?;
+        }
+
+        Ok(size)
+    }
+
+    /// Store the checksum in args into the extended attributes of file.
+    ///
+    /// Checksum left by previous writes will be removed if args doesn't
+    /// carry one, because truncating file doesn't clear its xattrs.
+    ///
+    /// Filesystems that don't support xattr will be ignored.
+    #[cfg(unix)]
+    fn write_checksum(p: &Path, args: &OpWrite) -> Result<()> {
+        let res = match args.checksum() {
+            Some((algorithm, checksum)) => {
+                let value = format!("{}:{}", algorithm.into_static(), checksum);
+                xattr::set(p, CHECKSUM_XATTR, value.as_bytes())
+            }
+            None => match xattr::get(p, CHECKSUM_XATTR) {
+                Ok(Some(_)) => xattr::remove(p, CHECKSUM_XATTR),
+                Ok(None) => Ok(()),
+                Err(err) => Err(err),
+            },
+        };
+
+        match res {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                debug!("xattr is not supported on {p:?}, checksum will not be stored");
+                Ok(())
+            }
+            Err(err) => Err(parse_io_error(err)),
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn write_checksum(_: &Path, _: &OpWrite) -> Result<()> {
+        Ok(())
+    }
+
+    /// Load the checksum stored by [`FsBackend::write_checksum`].
+    #[cfg(unix)]
+    fn read_checksum(p: &Path) -> Result<Option<(ChecksumAlgorithm, String)>> {
+        let value = match xattr::get(p, CHECKSUM_XATTR) {
+            Ok(Some(v)) => v,
+            Ok(None) => return Ok(None),
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => return Ok(None),
+            Err(err) => return Err(parse_io_error(err)),
+        };
+
+        let checksum = String::from_utf8(value).ok().and_then(|v| {
+            let (algorithm, checksum) = v.split_once(':')?;
+            Some((algorithm.parse().ok()?, checksum.to_string()))
+        });
+        if checksum.is_none() {
+            debug!("checksum stored in {p:?} is malformed, ignore it");
+        }
+
+        Ok(checksum)
+    }
+
+    #[cfg(not(unix))]
+    fn read_checksum(_: &Path) -> Result<Option<(ChecksumAlgorithm, String)>> {
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -333,6 +474,8 @@ impl Accessor for FsBackend {
                 .open(&p)
                 .await
                 .map_err(parse_io_error)?;
+            // Remove checksum left by previous writes.
+            Self::write_checksum(&p, &OpWrite::new(0))?;
 
             return Ok(RpCreate::default());
         }
@@ -415,49 +558,54 @@ impl Accessor for FsBackend {
         // Rewind to make sure we are on the correct offset.
         r.seek(SeekFrom::Start(0)).await.map_err(parse_io_error)?;
 
-        Ok((RpRead::new(end - start), r))
-    }
+        let mut meta = ObjectMetadata::new(ObjectMode::FILE).with_content_length(end - start);
+        if let Some((algorithm, checksum)) = Self::read_checksum(&p)? {
+            meta.set_checksum(algorithm, &checksum);
+        }
 
-    async fn write(&self, path: &str, _: OpWrite, r: input::Reader) -> Result<RpWrite> {
-        if let Some(atomic_write_dir) = &self.atomic_write_dir {
-            let temp_path =
-                Self::ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path)).await?;
-            let target_path = Self::ensure_write_abs_path(&self.root, path).await?;
-            let f = fs::OpenOptions::new()
-                .create(true)
-                .truncate(true)
-                .write(true)
-                .open(&temp_path)
-                .await
-                .map_err(parse_io_error)?;
+        Ok((RpRead::with_metadata(meta), r))
+    }
 
-            let size = {
-                // Implicitly flush and close temp file
-                let mut f = Compat::new(f);
-                futures::io::copy(r, &mut f).await.map_err(parse_io_error)?
-            };
-            fs::rename(&temp_path, &target_path)
-                .await
-                .map_err(parse_io_error)?;
+    async fn write(&self, path: &str, args: OpWrite, r: input::Reader) -> Result<RpWrite> {
+        let target_path = Self::ensure_write_abs_path(&self.root, path).await?;
+        let temp_path = match &self.atomic_write_dir {
+            Some(atomic_write_dir) => {
+                Some(Self::ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path)).await?)
+            }
+            // Write into a temp file next to target, so that the existing
+            // file will be kept if checksum mismatch.
+            None if args.checksum().is_some() => {
+                Some(target_path.with_file_name(tmp_file_of(path)))
+            }
+            None => None,
+        };
+        let p = temp_path.as_ref().unwrap_or(&target_path);
 
-            Ok(RpWrite::new(size))
-        } else {
-            let p = Self::ensure_write_abs_path(&self.root, path).await?;
+        let f = fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(p)
+            .await
+            .map_err(parse_io_error)?;
 
-            let f = fs::OpenOptions::new()
-                .create(true)
-                .truncate(true)
-                .write(true)
-                .open(&p)
+        let size = match Self::copy_with_checksum(r, f, &args).await {
+            Ok(size) => size,
+            Err(err) => {
+                if let Some(temp_path) = &temp_path {
+                    let _ = fs::remove_file(temp_path).await;
+                }
+                return Err(err);
+            }
+        };
+        Self::write_checksum(p, &args)?;
+        if let Some(temp_path) = &temp_path {
+            fs::rename(temp_path, &target_path)
                 .await
                 .map_err(parse_io_error)?;
-
-            let mut f = Compat::new(f);
-
-            let size = futures::io::copy(r, &mut f).await.map_err(parse_io_error)?;
-
-            Ok(RpWrite::new(size))
         }
+
+        Ok(RpWrite::new(size))
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
@@ -479,13 +627,18 @@ impl Accessor for FsBackend {
         } else {
             ObjectMode::Unknown
         };
-        let m = ObjectMetadata::new(mode)
+        let mut m = ObjectMetadata::new(mode)
             .with_content_length(meta.len())
             .with_last_modified(
                 meta.modified()
                     .map(OffsetDateTime::from)
                     .map_err(parse_io_error)?,
             );
+        if mode == ObjectMode::FILE {
+            if let Some((algorithm, checksum)) = Self::read_checksum(&p)? {
+                m.set_checksum(algorithm, &checksum);
+            }
+        }
 
         Ok(RpStat::new(m))
     }
@@ -548,9 +701,12 @@ impl Accessor for FsBackend {
 
             std::fs::OpenOptions::new()
                 .create(true)
+                .truncate(true)
                 .write(true)
                 .open(&p)
                 .map_err(parse_io_error)?;
+            // Remove checksum left by previous writes.
+            Self::write_checksum(&p, &OpWrite::new(0))?;
 
             return Ok(RpCreate::default());
         }
@@ -571,7 +727,7 @@ impl Accessor for FsBackend {
 
         let mut f = std::fs::OpenOptions::new()
             .read(true)
-            .open(p)
+            .open(&p)
             .map_err(parse_io_error)?;
 
         let total_length = if self.enable_path_check {
@@ -621,53 +777,64 @@ impl Accessor for FsBackend {
         // Rewind to make sure we are on the correct offset.
         r.seek(SeekFrom::Start(0)).map_err(parse_io_error)?;
 
-        Ok((RpRead::new(end - start), r))
+        let mut meta = ObjectMetadata::new(ObjectMode::FILE).with_content_length(end - start);
+        if let Some((algorithm, checksum)) = Self::read_checksum(&p)? {
+            meta.set_checksum(algorithm, &checksum);
+        }
+
+        Ok((RpRead::with_metadata(meta), r))
     }
 
     fn blocking_write(
         &self,
         path: &str,
-        _: OpWrite,
-        mut r: input::BlockingReader,
+        args: OpWrite,
+        r: input::BlockingReader,
     ) -> Result<RpWrite> {
-        if let Some(atomic_write_dir) = &self.atomic_write_dir {
-            let temp_path =
-                Self::blocking_ensure_write_abs_path(atomic_write_dir, &tmp_file_of(path))?;
-            let target_path =
-                Self::blocking_ensure_write_abs_path(&self.root, path.trim_end_matches('/'))?;
-
-            let size = {
-                // Implicitly flush and close temp file
-                let mut f = std::fs::OpenOptions::new()
-                    .create(true)
-                    .write(true)
-                    .open(&temp_path)
-                    .map_err(parse_io_error)?;
-
-                std::io::copy(&mut r, &mut f).map_err(parse_io_error)?
-            };
-            std::fs::rename(&temp_path, target_path).map_err(parse_io_error)?;
-
-            Ok(RpWrite::new(size))
-        } else {
-            let p = Self::blocking_ensure_write_abs_path(&self.root, path.trim_end_matches('/'))?;
-
-            let mut f = std::fs::OpenOptions::new()
-                .create(true)
-                .write(true)
-                .open(p)
-                .map_err(parse_io_error)?;
+        let target_path =
+            Self::blocking_ensure_write_abs_path(&self.root, path.trim_end_matches('/'))?;
+        let temp_path = match &self.atomic_write_dir {
+            Some(atomic_write_dir) => Some(Self::blocking_ensure_write_abs_path(
+                atomic_write_dir,
+                &tmp_file_of(path),
+            )?),
+            // Write into a temp file next to target, so that the existing
+            // file will be kept if checksum mismatch.
+            None if args.checksum().is_some() => {
+                Some(target_path.with_file_name(tmp_file_of(path)))
+            }
+            None => None,
+        };
+        let p = temp_path.as_ref().unwrap_or(&target_path);
 
-            let size = std::io::copy(&mut r, &mut f).map_err(parse_io_error)?;
+        let f = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(p)
+            .map_err(parse_io_error)?;
 
-            Ok(RpWrite::new(size))
+        let size = match Self::blocking_copy_with_checksum(r, f, &args) {
+            Ok(size) => size,
+            Err(err) => {
+                if let Some(temp_path) = &temp_path {
+                    let _ = std::fs::remove_file(temp_path);
+                }
+                return Err(err);
+            }
+        };
+        Self::write_checksum(p, &args)?;
+        if let Some(temp_path) = &temp_path {
+            std::fs::rename(temp_path, &target_path).map_err(parse_io_error)?;
         }
+
+        Ok(RpWrite::new(size))
     }
 
     fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
         let p = self.root.join(path.trim_end_matches('/'));
 
-        let meta = std::fs::metadata(p).map_err(parse_io_error)?;
+        let meta = std::fs::metadata(&p).map_err(parse_io_error)?;
 
         if self.enable_path_check && meta.is_dir() != path.ends_with('/') {
             return Err(Error::new(
@@ -683,13 +850,18 @@ impl Accessor for FsBackend {
         } else {
             ObjectMode::Unknown
         };
-        let m = ObjectMetadata::new(mode)
+        let mut m = ObjectMetadata::new(mode)
             .with_content_length(meta.len())
             .with_last_modified(
                 meta.modified()
                     .map(OffsetDateTime::from)
                     .map_err(parse_io_error)?,
             );
+        if mode == ObjectMode::FILE {
+            if let Some((algorithm, checksum)) = Self::read_checksum(&p)? {
+                m.set_checksum(algorithm, &checksum);
+            }
+        }
 
         Ok(RpStat::new(m))
     }
@@ -19,21 +19,30 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Buf;
+use bytes::Bytes;
+use http::header::HeaderName;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::HeaderValue;
 use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
 use reqsign::GoogleSigner;
 use serde::Deserialize;
+use serde::Serialize;
 use serde_json;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
+use super::error::parse_error_from_bytes;
 use super::error::parse_json_deserialize_error;
+use super::error::parse_xml_deserialize_error;
+use super::error::parse_xml_serialize_error;
 use super::uri::percent_encode_path;
 use crate::ops::*;
 use crate::raw::*;
@@ -41,6 +50,8 @@ use crate::*;
 
 const DEFAULT_GCS_ENDPOINT: &str = "https://storage.googleapis.com";
 const DEFAULT_GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+/// The max number of calls that allowed in one batch request.
+const BATCH_LIMIT: usize = 100;
 
 /// Google Cloud Storage service.
 ///
@@ -51,9 +62,9 @@ const DEFAULT_GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read
 /// - [x] read
 /// - [x] write
 /// - [x] list
-/// - [ ] presign
-/// - [ ] multipart
-/// - [ ] blocking
+/// - [x] presign
+/// - [x] multipart
+/// - [x] blocking
 ///
 /// # Configuration
 ///
@@ -64,6 +75,11 @@ const DEFAULT_GCS_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read
 ///
 /// You can refer to [`GcsBuilder`]'s docs for more information
 ///
+/// # Presign
+///
+/// GCS V4 signed URLs are signed with the service account's private key,
+/// so `credential` or `credential_path` must contain a service account key.
+///
 /// # Example
 ///
 /// ## Via Builder
@@ -345,9 +361,9 @@ impl Debug for GcsBackend {
 #[async_trait]
 impl Accessor for GcsBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
         let mut am = AccessorMetadata::default();
@@ -355,7 +371,13 @@ impl Accessor for GcsBackend {
             .set_root(&self.root)
             .set_name(&self.bucket)
             .set_capabilities(
-                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
+                AccessorCapability::Read
+                    | AccessorCapability::Write
+                    | AccessorCapability::List
+                    | AccessorCapability::Presign
+                    | AccessorCapability::Multipart
+                    | AccessorCapability::Blocking
+                    | AccessorCapability::Batch,
             )
             .set_hints(AccessorHint::ReadIsStreamable);
         am
@@ -380,7 +402,14 @@ impl Accessor for GcsBackend {
         let resp = self.gcs_get_object(path, args.range()).await?;
 
         if resp.status().is_success() {
-            let meta = parse_into_object_metadata(path, resp.headers())?;
+            let mut meta = parse_into_object_metadata(path, resp.headers())?;
+            if let Some(v) = parse_goog_hash(resp.headers(), ChecksumAlgorithm::Md5) {
+                meta.set_content_md5(v);
+            }
+            // Composite objects don't have md5, but crc32c is always returned.
+            if let Some(v) = parse_goog_hash(resp.headers(), ChecksumAlgorithm::Crc32c) {
+                meta.set_checksum(ChecksumAlgorithm::Crc32c, v);
+            }
             Ok((RpRead::with_metadata(meta), resp.into_body()))
         } else {
             Err(parse_error(resp).await?)
@@ -395,6 +424,8 @@ impl Accessor for GcsBackend {
             AsyncBody::Reader(r),
         )?;
 
+        insert_checksum_header(&mut req, &args)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         let resp = self.client.send_async(req).await?;
@@ -418,34 +449,7 @@ impl Accessor for GcsBackend {
         if resp.status().is_success() {
             // read http response body
             let slc = resp.into_body().bytes().await?;
-            let meta: GetObjectJsonResponse =
-                serde_json::from_slice(&slc).map_err(parse_json_deserialize_error)?;
-
-            let mode = if path.ends_with('/') {
-                ObjectMode::DIR
-            } else {
-                ObjectMode::FILE
-            };
-            let mut m = ObjectMetadata::new(mode);
-
-            m.set_etag(&meta.etag);
-            m.set_content_md5(&meta.md5_hash);
-
-            let size = meta
-                .size
-                .parse::<u64>()
-                .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
-            m.set_content_length(size);
-            if !meta.content_type.is_empty() {
-                m.set_content_type(&meta.content_type);
-            }
-
-            let datetime = OffsetDateTime::parse(&meta.updated, &Rfc3339).map_err(|e| {
-                Error::new(ErrorKind::Unexpected, "parse date time with rfc 3339").set_source(e)
-            })?;
-            m.set_last_modified(datetime);
-
-            Ok(RpStat::new(m))
+            parse_object_metadata(path, &slc).map(RpStat::new)
         } else if resp.status() == StatusCode::NOT_FOUND && path.ends_with('/') {
             Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
         } else {
@@ -470,9 +474,426 @@ impl Accessor for GcsBackend {
             DirStream::new(Arc::new(self.clone()), &self.root, path),
         ))
     }
+
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let mut req = self
+            .gcs_insert_object_request(path, Some(0), None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        if resp.status().is_success() {
+            resp.into_body().consume()?;
+            Ok(RpCreate::default())
+        } else {
+            Err(parse_blocking_error(resp)?)
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut req = self
+            .gcs_get_object_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        if resp.status().is_success() {
+            let mut meta = parse_into_object_metadata(path, resp.headers())?;
+            if let Some(v) = parse_goog_hash(resp.headers(), ChecksumAlgorithm::Md5) {
+                meta.set_content_md5(v);
+            }
+            // Composite objects don't have md5, but crc32c is always returned.
+            if let Some(v) = parse_goog_hash(resp.headers(), ChecksumAlgorithm::Crc32c) {
+                meta.set_checksum(ChecksumAlgorithm::Crc32c, v);
+            }
+            Ok((RpRead::with_metadata(meta), resp.into_body()))
+        } else {
+            Err(parse_blocking_error(resp)?)
+        }
+    }
+
+    fn blocking_write(
+        &self,
+        path: &str,
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let mut req = self
+            .gcs_insert_object_request(
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        insert_checksum_header(&mut req, &args)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        if resp.status().is_success() {
+            resp.into_body().consume()?;
+            Ok(RpWrite::new(args.size()))
+        } else {
+            Err(parse_blocking_error(resp)?)
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let mut req = self
+            .gcs_get_object_metadata_request(path)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        if resp.status().is_success() {
+            let slc = resp.into_body().bytes()?;
+            parse_object_metadata(path, &slc).map(RpStat::new)
+        } else if resp.status() == StatusCode::NOT_FOUND && path.ends_with('/') {
+            Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+        } else {
+            Err(parse_blocking_error(resp)?)
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let mut req = self.gcs_delete_object_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        // deleting not existing objects is ok
+        if resp.status().is_success() || resp.status() == StatusCode::NOT_FOUND {
+            Ok(RpDelete::default())
+        } else {
+            Err(parse_blocking_error(resp)?)
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+
+    fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        // We will not send this request out, just for signing.
+        //
+        // GCS only supports V4 signed URLs on XML API, so we can't reuse
+        // the JSON API requests here.
+        let mut req = match args.operation() {
+            PresignOperation::Stat(_) => self.gcs_xml_head_object_request(path)?,
+            PresignOperation::Read(v) => self.gcs_xml_get_object_request(path, v.range())?,
+            PresignOperation::Write(v) => {
+                self.gcs_xml_put_object_request(path, v.content_type(), AsyncBody::Empty)?
+            }
+            PresignOperation::WriteMultipart(v) => self.gcs_xml_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                AsyncBody::Empty,
+            )?,
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "gcs doesn't support presign post",
+                ))
+            }
+        };
+
+        self.signer
+            .sign_query(&mut req, args.expire())
+            .map_err(new_request_sign_error)?;
+
+        // We don't need this request anymore, consume it directly.
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
+
+    /// GCS supports S3 compatible multipart uploads via XML API.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/multipart-uploads>
+    async fn create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.gcs_xml_initiate_multipart_upload(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::Reader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self.gcs_xml_upload_part_request(
+            path,
+            args.upload_id(),
+            args.part_number(),
+            Some(args.size()),
+            AsyncBody::Reader(r),
+        )?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume().await?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp = self
+            .gcs_xml_complete_multipart_upload(path, args.upload_id(), args.parts())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self
+            .gcs_xml_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.gcs_blocking_xml_initiate_multipart_upload(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes()?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self
+            .gcs_xml_upload_part_request(
+                path,
+                args.upload_id(),
+                args.part_number(),
+                Some(args.size()),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume()?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp =
+            self.gcs_blocking_xml_complete_multipart_upload(path, args.upload_id(), args.parts())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self.gcs_blocking_xml_abort_multipart_upload(path, args.upload_id())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = match args.into_operation() {
+            BatchOperations::Delete(ops) => ops,
+        };
+        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
+
+        let mut results = Vec::with_capacity(paths.len());
+        for paths in paths.chunks(BATCH_LIMIT) {
+            let resp = self.gcs_batch_delete(paths).await?;
+
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let (parts, body) = resp.into_parts();
+            let bs = body.bytes().await?;
+            let resps = MixedMultipart::parse_responses(&parts.headers, bs)?;
+            if resps.len() != paths.len() {
+                return Err(Error::new(
+                    ErrorKind::Unexpected,
+                    "gcs batch response doesn't match with request",
+                )
+                .with_context("expected", paths.len().to_string())
+                .with_context("actual", resps.len().to_string()));
+            }
+
+            // Sub-responses are returned in the same order as sub-requests.
+            for (path, resp) in paths.iter().zip(resps) {
+                let result = match resp.status() {
+                    StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(RpDelete::default()),
+                    _ => Err(parse_error_from_bytes(resp)),
+                };
+                results.push((path.clone(), result));
+            }
+        }
+
+        Ok(RpBatch::new(BatchedResults::Delete(results)))
+    }
 }
 
 impl GcsBackend {
+    /// Build object url for XML API.
+    ///
+    /// Unlike JSON API, `/` in object name should not be encoded here.
+    fn gcs_xml_object_url(&self, path: &str) -> String {
+        let p = build_abs_path(&self.root, path);
+
+        format!(
+            "{}/{}/{}",
+            self.endpoint,
+            self.bucket,
+            crate::raw::percent_encode_path(&p)
+        )
+    }
+
     fn gcs_get_object_request(&self, path: &str, range: BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -540,7 +961,225 @@ impl GcsBackend {
         Ok(req)
     }
 
-    async fn gcs_get_object_metadata(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn gcs_xml_get_object_request(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Request<AsyncBody>> {
+        let url = self.gcs_xml_object_url(path);
+
+        let mut req = Request::get(&url);
+
+        if !range.is_full() {
+            req = req.header(http::header::RANGE, range.to_header());
+        }
+
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn gcs_xml_head_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let url = self.gcs_xml_object_url(path);
+
+        let req = Request::head(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn gcs_xml_put_object_request(
+        &self,
+        path: &str,
+        content_type: Option<&str>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let url = self.gcs_xml_object_url(path);
+
+        let mut req = Request::put(&url);
+
+        if let Some(mime) = content_type {
+            req = req.header(CONTENT_TYPE, mime)
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn gcs_xml_initiate_multipart_upload_request(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let url = format!("{}?uploads", self.gcs_xml_object_url(path));
+
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn gcs_xml_initiate_multipart_upload(
+        &self,
+        path: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_xml_initiate_multipart_upload_request(path)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn gcs_blocking_xml_initiate_multipart_upload(
+        &self,
+        path: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .gcs_xml_initiate_multipart_upload_request(path)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn gcs_xml_upload_part_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let url = format!(
+            "{}?partNumber={}&uploadId={}",
+            self.gcs_xml_object_url(path),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn gcs_xml_complete_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Request<AsyncBody>> {
+        let url = format!(
+            "{}?uploadId={}",
+            self.gcs_xml_object_url(path),
+            percent_encode_path(upload_id)
+        );
+
+        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest {
+            part: parts
+                .iter()
+                .map(|v| CompleteMultipartUploadRequestPart {
+                    part_number: v.part_number(),
+                    etag: v.etag().to_string(),
+                })
+                .collect(),
+        })
+        .map_err(parse_xml_serialize_error)?;
+
+        // Make sure content length has been set to avoid post with chunked encoding.
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn gcs_xml_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_xml_complete_multipart_upload_request(path, upload_id, parts)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn gcs_blocking_xml_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .gcs_xml_complete_multipart_upload_request(path, upload_id, parts)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn gcs_xml_abort_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let url = format!(
+            "{}?uploadId={}",
+            self.gcs_xml_object_url(path),
+            percent_encode_path(upload_id)
+        );
+
+        let req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn gcs_xml_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_xml_abort_multipart_upload_request(path, upload_id)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn gcs_blocking_xml_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .gcs_xml_abort_multipart_upload_request(path, upload_id)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn gcs_get_object_metadata_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -552,16 +1191,22 @@ impl GcsBackend {
 
         let req = Request::get(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn gcs_get_object_metadata(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_get_object_metadata_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn gcs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn gcs_delete_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -571,20 +1216,61 @@ impl GcsBackend {
             percent_encode_path(&p)
         );
 
-        let mut req = Request::delete(&url)
+        let req = Request::delete(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn gcs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_delete_object_request(path)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    async fn gcs_batch_delete(&self, paths: &[String]) -> Result<Response<IncomingAsyncBody>> {
+        let mut multipart = MixedMultipart::new();
+
+        for (idx, path) in paths.iter().enumerate() {
+            let p = build_abs_path(&self.root, path);
+
+            let url = format!(
+                "{}/storage/v1/b/{}/o/{}",
+                self.endpoint,
+                self.bucket,
+                percent_encode_path(&p)
+            );
+
+            // Sub-requests don't need to be signed, they will be
+            // authorized by the outer request.
+            let req = Request::delete(&url)
+                .body(AsyncBody::Empty)
+                .map_err(new_request_build_error)?;
+
+            multipart = multipart.part(
+                MixedPart::from_request(req)?
+                    .with_part_header(HeaderName::from_static("content-id"), idx.into()),
+            );
+        }
+
+        let content = multipart.build();
+        let url = format!("{}/batch/storage/v1", self.endpoint);
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_TYPE, multipart.content_type())
+            .header(CONTENT_LENGTH, content.len())
+            .body(AsyncBody::Bytes(content))
+            .map_err(new_request_build_error)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    pub(crate) async fn gcs_list_objects(
-        &self,
-        path: &str,
-        page_token: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    fn gcs_list_objects_request(&self, path: &str, page_token: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!(
@@ -604,14 +1290,73 @@ impl GcsBackend {
                 .expect("write into string must succeed");
         }
 
-        let mut req = Request::get(&url)
+        let req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    pub(crate) async fn gcs_list_objects(
+        &self,
+        path: &str,
+        page_token: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.gcs_list_objects_request(path, page_token)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
+
+    pub(crate) fn gcs_blocking_list_objects(
+        &self,
+        path: &str,
+        page_token: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .gcs_list_objects_request(path, page_token)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+}
+
+/// Parse the json response of get object metadata into [`ObjectMetadata`].
+fn parse_object_metadata(path: &str, slc: &[u8]) -> Result<ObjectMetadata> {
+    let meta: GetObjectJsonResponse =
+        serde_json::from_slice(slc).map_err(parse_json_deserialize_error)?;
+
+    let mode = if path.ends_with('/') {
+        ObjectMode::DIR
+    } else {
+        ObjectMode::FILE
+    };
+    let mut m = ObjectMetadata::new(mode);
+
+    m.set_etag(&meta.etag);
+    m.set_content_md5(&meta.md5_hash);
+    if !meta.crc32c.is_empty() {
+        m.set_checksum(ChecksumAlgorithm::Crc32c, &meta.crc32c);
+    }
+
+    let size = meta
+        .size
+        .parse::<u64>()
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "parse u64").set_source(e))?;
+    m.set_content_length(size);
+    if !meta.content_type.is_empty() {
+        m.set_content_type(&meta.content_type);
+    }
+
+    let datetime = OffsetDateTime::parse(&meta.updated, &Rfc3339).map_err(|e| {
+        Error::new(ErrorKind::Unexpected, "parse date time with rfc 3339").set_source(e)
+    })?;
+    m.set_last_modified(datetime);
+
+    Ok(m)
 }
 
 /// The raw json response returned by [`get`](https://cloud.google.com/storage/docs/json_api/v1/objects/get)
@@ -634,16 +1379,109 @@ struct GetObjectJsonResponse {
     ///
     /// For example: `"md5Hash": "fHcEH1vPwA6eTPqxuasXcg=="`
     md5_hash: String,
+    /// CRC32C checksum in base64 with big-endian byte order.
+    ///
+    /// For example: `"crc32c": "n03x6A=="`
+    crc32c: String,
     /// Content type of this object.
     ///
     /// For examlpe: `"contentType": "image/png",`
     content_type: String,
 }
 
+/// Result of InitiateMultipartUpload
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct InitiateMultipartUploadResult {
+    upload_id: String,
+}
+
+/// Request of CompleteMultipartUpload
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
+struct CompleteMultipartUploadRequest {
+    part: Vec<CompleteMultipartUploadRequestPart>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct CompleteMultipartUploadRequestPart {
+    #[serde(rename = "PartNumber")]
+    part_number: usize,
+    #[serde(rename = "ETag")]
+    etag: String,
+}
+
+/// Parse the checksum of given algorithm from `x-goog-hash` headers.
+///
+/// GCS returns multiple checksums like:
+///
+/// ```text
+/// x-goog-hash: crc32c=n03x6A==
+/// x-goog-hash: md5=Ojk9c3dhfxgoKVVHYwFbHQ==
+/// ```
+fn parse_goog_hash(headers: &http::HeaderMap, algorithm: ChecksumAlgorithm) -> Option<&str> {
+    let prefix = format!("{}=", algorithm.into_static());
+
+    headers
+        .get_all("x-goog-hash")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .find_map(|v| v.trim().strip_prefix(prefix.as_str()))
+}
+
+/// Insert checksum header for insert object if checksum has been computed.
+fn insert_checksum_header<T>(req: &mut Request<T>, args: &OpWrite) -> Result<()> {
+    let (algorithm, checksum) = match args.checksum() {
+        Some(v) => v,
+        None => return Ok(()),
+    };
+
+    let name = match algorithm {
+        ChecksumAlgorithm::Md5 | ChecksumAlgorithm::Crc32c => {
+            HeaderName::from_static("x-goog-hash")
+        }
+        ChecksumAlgorithm::Sha256 => {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "checksum algorithm is not supported",
+            )
+            .with_context("algorithm", algorithm.into_static()))
+        }
+    };
+    // The value of `x-goog-hash` is like `crc32c=n03x6A==`.
+    let value = HeaderValue::from_str(&format!("{}={checksum}", algorithm.into_static()))
+        .map_err(|err| new_request_build_error(err.into()))?;
+    req.headers_mut().insert(name, value);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_goog_hash() {
+        let mut headers = http::HeaderMap::new();
+        headers.append("x-goog-hash", HeaderValue::from_static("crc32c=n03x6A=="));
+        headers.append(
+            "x-goog-hash",
+            HeaderValue::from_static("md5=Ojk9c3dhfxgoKVVHYwFbHQ=="),
+        );
+
+        assert_eq!(
+            parse_goog_hash(&headers, ChecksumAlgorithm::Md5),
+            Some("Ojk9c3dhfxgoKVVHYwFbHQ==")
+        );
+        assert_eq!(
+            parse_goog_hash(&headers, ChecksumAlgorithm::Crc32c),
+            Some("n03x6A==")
+        );
+        assert_eq!(parse_goog_hash(&headers, ChecksumAlgorithm::Sha256), None);
+    }
+
     #[test]
     fn test_deserialize_get_object_json_response() {
         let content = r#"{
@@ -672,7 +1510,29 @@ mod tests {
         assert_eq!(meta.size, "56535");
         assert_eq!(meta.updated, "2022-08-15T11:33:34.866Z");
         assert_eq!(meta.md5_hash, "fHcEH1vPwA6eTPqxuasXcg==");
+        assert_eq!(meta.crc32c, "j/un9g==");
         assert_eq!(meta.etag, "CKWasoTgyPkCEAE=");
         assert_eq!(meta.content_type, "image/png");
     }
+
+    /// This example is from https://cloud.google.com/storage/docs/xml-api/post-object-multipart
+    #[test]
+    fn test_deserialize_initiate_multipart_upload_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<InitiateMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Bucket>travel-maps</Bucket>
+  <Key>paris.jpg</Key>
+  <UploadId>VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA</UploadId>
+</InitiateMultipartUploadResult>"#,
+        );
+
+        let out: InitiateMultipartUploadResult =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(
+            out.upload_id,
+            "VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA"
+        );
+    }
 }
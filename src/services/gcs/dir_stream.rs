@@ -15,12 +15,14 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use serde::Deserialize;
 use serde_json;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
 use super::backend::GcsBackend;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use super::error::parse_json_deserialize_error;
 use crate::raw::*;
@@ -68,6 +70,31 @@ impl output::Page for DirStream {
         }
         let bytes = resp.into_body().bytes().await?;
 
+        self.parse_page(bytes).map(Some)
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .gcs_blocking_list_objects(&self.path, &self.page_token)?;
+
+        if !resp.status().is_success() {
+            return Err(parse_blocking_error(resp)?);
+        }
+        let bytes = resp.into_body().bytes()?;
+
+        self.parse_page(bytes).map(Some)
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bytes: Bytes) -> Result<Vec<output::Entry>> {
         let output: ListResponse =
             serde_json::from_slice(&bytes).map_err(parse_json_deserialize_error)?;
 
@@ -118,7 +145,7 @@ impl output::Page for DirStream {
             entries.push(de);
         }
 
-        Ok(Some(entries))
+        Ok(entries)
     }
 }
 
@@ -0,0 +1,483 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use gix::objs::tree::EntryMode;
+use gix::ObjectId;
+use gix::Repository;
+use gix::ThreadSafeRepository;
+use log::debug;
+
+use super::dir_stream::DirStream;
+use super::error::parse_git_error;
+use crate::ops::*;
+use crate::raw::*;
+use crate::*;
+
+/// Read-only tree of a [git](https://git-scm.com/) repository at a given
+/// revision.
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [ ] ~~write~~
+/// - [x] list
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [x] blocking
+///
+/// # Notes
+///
+/// The repository is accessed via [gitoxide](https://github.com/Byron/gitoxide),
+/// a pure-Rust git implementation, so no `git` binary or `libgit2` is
+/// required. Both normal and bare repositories are supported.
+///
+/// The revision will be resolved into a commit while building the backend,
+/// so later changes to the ref are not visible to this operator.
+///
+/// `stat` returns the blob size as content length and the blob id as etag.
+/// Since git doesn't track empty directories, they can't be found either.
+///
+/// # Configuration
+///
+/// - `path`: Set the path to the repository
+/// - `reference`: Set the ref or commit to read, default to `HEAD`
+/// - `root`: Set the work directory inside the tree
+///
+/// You can refer to [`GitBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Git;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Git::default();
+///     builder.path("/path/to/repo");
+///     builder.reference("v1.0.0");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("config/app.toml");
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct GitBuilder {
+    path: Option<String>,
+    reference: Option<String>,
+    root: Option<String>,
+}
+
+impl GitBuilder {
+    /// Set the path to the repository, could be a work tree or a bare
+    /// repository.
+    pub fn path(&mut self, path: &str) -> &mut Self {
+        self.path = if path.is_empty() {
+            None
+        } else {
+            Some(path.to_string())
+        };
+
+        self
+    }
+
+    /// Set the revision to read, could be a branch, a tag or a commit id.
+    ///
+    /// default: `HEAD`
+    pub fn reference(&mut self, reference: &str) -> &mut Self {
+        self.reference = if reference.is_empty() {
+            None
+        } else {
+            Some(reference.to_string())
+        };
+
+        self
+    }
+
+    /// Set root path inside the tree.
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        self.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+}
+
+impl Builder for GitBuilder {
+    const SCHEME: Scheme = Scheme::Git;
+    type Accessor = GitBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = GitBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("path").map(|v| builder.path(v));
+        map.get("reference").map(|v| builder.reference(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        debug!("backend build started: {:?}", &self);
+
+        let path = match &self.path {
+            Some(v) => v.clone(),
+            None => {
+                return Err(Error::new(ErrorKind::BackendConfigInvalid, "path is empty")
+                    .with_context("service", Scheme::Git))
+            }
+        };
+        let reference = self.reference.clone().unwrap_or_else(|| "HEAD".to_string());
+
+        let repo = ThreadSafeRepository::open(&path).map_err(|err| {
+            Error::new(ErrorKind::BackendConfigInvalid, "open repository failed")
+                .with_context("service", Scheme::Git)
+                .with_context("path", &path)
+                .set_source(err)
+        })?;
+
+        let (commit_id, tree_id) = {
+            let local = repo.to_thread_local();
+            let commit = local
+                .rev_parse_single(reference.as_str())
+                .map_err(|err| {
+                    Error::new(ErrorKind::BackendConfigInvalid, "resolve reference failed")
+                        .with_context("service", Scheme::Git)
+                        .with_context("reference", &reference)
+                        .set_source(err)
+                })?
+                .detach();
+            let tree = local
+                .find_object(commit)
+                .map_err(parse_git_error)?
+                .peel_to_tree()
+                .map_err(parse_git_error)?;
+
+            (commit, tree.id)
+        };
+        debug!("backend use commit {} with tree {}", commit_id, tree_id);
+
+        let root = normalize_root(&self.root.take().unwrap_or_default());
+        debug!("backend use root {}", root);
+
+        debug!("backend build finished: {:?}", &self);
+        Ok(GitBackend {
+            repo,
+            path,
+            commit_id,
+            tree_id,
+            root,
+        })
+    }
+}
+
+/// Backend is used to serve `Accessor` support for git repository.
+pub struct GitBackend {
+    repo: ThreadSafeRepository,
+    path: String,
+    commit_id: ObjectId,
+    tree_id: ObjectId,
+    root: String,
+}
+
+impl Debug for GitBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Backend")
+            .field("path", &self.path)
+            .field("commit_id", &self.commit_id)
+            .field("tree_id", &self.tree_id)
+            .field("root", &self.root)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Accessor for GitBackend {
+    type Reader = output::Cursor;
+    type BlockingReader = output::Cursor;
+    type Pager = DirStream;
+    type BlockingPager = DirStream;
+
+    fn metadata(&self) -> AccessorMetadata {
+        let mut ma = AccessorMetadata::default();
+        ma.set_scheme(Scheme::Git)
+            .set_root(&self.root)
+            .set_name(&self.path)
+            .set_capabilities(
+                AccessorCapability::Read | AccessorCapability::List | AccessorCapability::Blocking,
+            );
+
+        ma
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.blocking_read(path, args)
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.blocking_stat(path, args)
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.blocking_list(path, args)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let repo = self.repo.to_thread_local();
+
+        let id = match self.lookup(&repo, path)? {
+            Some(GitEntry::Blob(id)) => id,
+            Some(GitEntry::Tree(_)) => {
+                return Err(Error::new(
+                    ErrorKind::ObjectIsADirectory,
+                    "read on a tree is not supported",
+                )
+                .with_context("service", Scheme::Git)
+                .with_context("path", path))
+            }
+            None => return Err(new_not_found_error(path)),
+        };
+
+        let mut data = repo.find_object(id).map_err(parse_git_error)?.detach().data;
+        let (start, end) = args.range().to_bounds(data.len() as u64);
+        data.truncate(end as usize);
+        data.drain(..start as usize);
+
+        Ok((RpRead::new(data.len() as u64), output::Cursor::from(data)))
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        let repo = self.repo.to_thread_local();
+
+        match self.lookup(&repo, path)? {
+            Some(GitEntry::Tree(_)) => Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR))),
+            Some(GitEntry::Blob(id)) if !path.ends_with('/') => {
+                // Only decode the object header to avoid loading the whole blob.
+                let header = repo.find_header(id).map_err(parse_git_error)?;
+
+                let mut meta = ObjectMetadata::new(ObjectMode::FILE);
+                meta.set_content_length(header.size())
+                    .set_etag(&id.to_string());
+                Ok(RpStat::new(meta))
+            }
+            _ => Err(new_not_found_error(path)),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        let repo = self.repo.to_thread_local();
+
+        let id = match self.lookup(&repo, path)? {
+            Some(GitEntry::Tree(id)) => id,
+            _ => return Ok((RpList::default(), DirStream::new(vec![]))),
+        };
+
+        let tree = repo
+            .find_object(id)
+            .map_err(parse_git_error)?
+            .try_into_tree()
+            .map_err(parse_git_error)?;
+        let decoded = tree.decode().map_err(parse_git_error)?;
+
+        let p = build_abs_path(&self.root, path);
+        let entries = decoded
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let name = entry.filename.to_string();
+                let meta = match entry.mode {
+                    EntryMode::Tree => {
+                        let p = build_rel_path(&self.root, &format!("/{p}{name}/"));
+                        return Some(output::Entry::new(&p, ObjectMetadata::new(ObjectMode::DIR)));
+                    }
+                    EntryMode::Blob | EntryMode::BlobExecutable => {
+                        ObjectMetadata::new(ObjectMode::FILE).with_etag(&entry.oid.to_string())
+                    }
+                    // Symlinks and submodules are not exposed.
+                    _ => return None,
+                };
+
+                let p = build_rel_path(&self.root, &format!("/{p}{name}"));
+                Some(output::Entry::new(&p, meta))
+            })
+            .collect();
+
+        Ok((RpList::default(), DirStream::new(entries)))
+    }
+}
+
+/// Entry resolved from the tree of commit.
+enum GitEntry {
+    Tree(ObjectId),
+    Blob(ObjectId),
+}
+
+impl GitBackend {
+    /// Walk down the tree of commit to find the entry of given path.
+    fn lookup(&self, repo: &Repository, path: &str) -> Result<Option<GitEntry>> {
+        let p = build_abs_path(&self.root, path);
+
+        let mut entry = GitEntry::Tree(self.tree_id);
+        for name in p.split('/').filter(|v| !v.is_empty()) {
+            let id = match entry {
+                GitEntry::Tree(id) => id,
+                // Blob can't have children.
+                GitEntry::Blob(_) => return Ok(None),
+            };
+
+            let tree = repo
+                .find_object(id)
+                .map_err(parse_git_error)?
+                .try_into_tree()
+                .map_err(parse_git_error)?;
+            let decoded = tree.decode().map_err(parse_git_error)?;
+
+            let found = match decoded.entries.iter().find(|v| v.filename == name) {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            entry = match found.mode {
+                EntryMode::Tree => GitEntry::Tree(found.oid.to_owned()),
+                EntryMode::Blob | EntryMode::BlobExecutable => GitEntry::Blob(found.oid.to_owned()),
+                _ => return Ok(None),
+            };
+        }
+
+        Ok(Some(entry))
+    }
+}
+
+fn new_not_found_error(path: &str) -> Error {
+    Error::new(ErrorKind::ObjectNotFound, "entry not found in tree")
+        .with_context("service", Scheme::Git)
+        .with_context("path", path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process::Command;
+
+    use super::*;
+
+    /// Init a repository with one commit in a temp dir.
+    fn init_repo() -> String {
+        let path = format!("{}/{}", env::temp_dir().display(), uuid::Uuid::new_v4());
+        fs::create_dir_all(format!("{path}/dir")).expect("create dir must succeed");
+        fs::write(format!("{path}/hello"), "Hello, World!").expect("write must succeed");
+        fs::write(format!("{path}/dir/file"), "content").expect("write must succeed");
+
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=opendal",
+                    "-c",
+                    "user.email=opendal@example.com",
+                ])
+                .args(args)
+                .current_dir(&path)
+                .status()
+                .expect("git must be installed");
+            assert!(status.success(), "git {args:?} failed");
+        };
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+
+        path
+    }
+
+    #[test]
+    fn test_read_stat_list() -> Result<()> {
+        let path = init_repo();
+
+        let mut builder = GitBuilder::default();
+        builder.path(&path);
+        let op = Operator::create(builder)?.finish();
+
+        let o = op.object("hello");
+        assert_eq!(o.blocking_read()?, b"Hello, World!");
+        assert_eq!(o.blocking_range_read(7..12)?, b"World");
+
+        let meta = o.blocking_metadata()?;
+        assert_eq!(meta.mode(), ObjectMode::FILE);
+        assert_eq!(meta.content_length(), 13);
+
+        assert_eq!(
+            op.object("dir/").blocking_metadata()?.mode(),
+            ObjectMode::DIR
+        );
+        assert_eq!(
+            op.object("not_exist")
+                .blocking_metadata()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::ObjectNotFound
+        );
+
+        let mut entries = op
+            .object("/")
+            .blocking_list()?
+            .map(|v| v.map(|v| v.path().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        entries.sort();
+        assert_eq!(entries, vec!["dir/".to_string(), "hello".to_string()]);
+
+        let entries = op
+            .object("dir/")
+            .blocking_list()?
+            .map(|v| v.map(|v| v.path().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(entries, vec!["dir/file".to_string()]);
+
+        fs::remove_dir_all(&path).expect("remove dir must succeed");
+        Ok(())
+    }
+
+    #[test]
+    fn test_root() -> Result<()> {
+        let path = init_repo();
+
+        let mut builder = GitBuilder::default();
+        builder.path(&path);
+        builder.root("/dir/");
+        let op = Operator::create(builder)?.finish();
+
+        assert_eq!(op.object("file").blocking_read()?, b"content");
+        assert_eq!(
+            op.object("hello").blocking_read().unwrap_err().kind(),
+            ErrorKind::ObjectNotFound
+        );
+
+        fs::remove_dir_all(&path).expect("remove dir must succeed");
+        Ok(())
+    }
+}
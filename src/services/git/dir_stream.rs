@@ -0,0 +1,52 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+
+use async_trait::async_trait;
+
+use crate::raw::*;
+use crate::Result;
+
+/// DirStream returns all entries of a tree in one page.
+pub struct DirStream {
+    entries: Vec<output::Entry>,
+}
+
+impl DirStream {
+    pub fn new(entries: Vec<output::Entry>) -> Self {
+        Self { entries }
+    }
+
+    fn inner_next_page(&mut self) -> Option<Vec<output::Entry>> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        Some(mem::take(&mut self.entries))
+    }
+}
+
+#[async_trait]
+impl output::Page for DirStream {
+    async fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        Ok(self.inner_next_page())
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        Ok(self.inner_next_page())
+    }
+}
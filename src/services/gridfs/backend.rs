@@ -0,0 +1,776 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::AsyncReadExt;
+use futures::TryStreamExt;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::spec::BinarySubtype;
+use mongodb::bson::Binary;
+use mongodb::bson::Bson;
+use mongodb::bson::DateTime;
+use mongodb::bson::Document;
+use mongodb::error::Error as MongoError;
+use mongodb::error::ErrorKind as MongoErrorKind;
+use mongodb::options::FindOneOptions;
+use mongodb::options::FindOptions;
+use mongodb::options::IndexOptions;
+use mongodb::Client;
+use mongodb::Collection;
+use mongodb::IndexModel;
+use time::OffsetDateTime;
+use tokio::sync::OnceCell;
+
+use super::dir_stream::DirStream;
+use super::reader::ChunkReader;
+use crate::ops::*;
+use crate::raw::*;
+use crate::*;
+
+const DEFAULT_CONNECTION_STRING: &str = "mongodb://127.0.0.1:27017";
+const DEFAULT_BUCKET: &str = "fs";
+/// The default chunk size used by GridFS spec, 255 KiB.
+const DEFAULT_CHUNK_SIZE: usize = 255 * 1024;
+/// The max number of files returned in one list page.
+pub(super) const LIST_LIMIT: usize = 1000;
+
+/// [MongoDB GridFS](https://www.mongodb.com/docs/manual/core/gridfs/) services support.
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [x] write
+/// - [x] list
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [ ] blocking
+///
+/// # Note
+///
+/// Paths are mapped to GridFS filenames under `root`, for example, object
+/// `abc/def.txt` with root `/apps/` will be stored as filename
+/// `apps/abc/def.txt`. Dirs are stored as empty files whose filename ends
+/// with `/`.
+///
+/// GridFS allows multiple revisions of the same filename, the latest one
+/// (by `uploadDate`) will be used, and older revisions will be removed
+/// after a new one has been written.
+///
+/// # Configuration
+///
+/// - `root`: Set the working directory of `OpenDAL`
+/// - `connection_string`: Set the connection string of mongodb
+/// - `database`: Set the database of GridFS bucket
+/// - `bucket`: Set the bucket name, default to `fs`
+/// - `chunk_size`: Set the chunk size for new files, default to 255 KiB
+///
+/// You can refer to [`GridfsBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Gridfs;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Gridfs::default();
+///     builder.connection_string("mongodb://127.0.0.1:27017");
+///     builder.database("attachments");
+///     builder.bucket("fs");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("test_file");
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct GridfsBuilder {
+    /// The connection string of mongodb.
+    ///
+    /// default is "mongodb://127.0.0.1:27017"
+    connection_string: Option<String>,
+    /// The database of GridFS bucket.
+    database: Option<String>,
+    /// The bucket name.
+    ///
+    /// default is "fs"
+    bucket: Option<String>,
+    /// The chunk size for new files.
+    chunk_size: Option<usize>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    root: Option<String>,
+}
+
+// implement `Debug` manually, or password in connection string may be leaked.
+impl Debug for GridfsBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Builder");
+        if self.connection_string.is_some() {
+            ds.field("connection_string", &"<redacted>");
+        }
+        ds.field("database", &self.database);
+        ds.field("bucket", &self.bucket);
+        ds.field("chunk_size", &self.chunk_size);
+        ds.field("root", &self.root);
+        ds.finish()
+    }
+}
+
+impl GridfsBuilder {
+    /// Set the connection string of mongodb.
+    ///
+    /// default: "mongodb://127.0.0.1:27017"
+    pub fn connection_string(&mut self, v: &str) -> &mut Self {
+        if !v.is_empty() {
+            self.connection_string = Some(v.to_owned());
+        }
+        self
+    }
+
+    /// Set the database of GridFS bucket.
+    ///
+    /// This param is required.
+    pub fn database(&mut self, database: &str) -> &mut Self {
+        if !database.is_empty() {
+            self.database = Some(database.to_owned());
+        }
+        self
+    }
+
+    /// Set the bucket name, collections `<bucket>.files` and
+    /// `<bucket>.chunks` will be used.
+    ///
+    /// default: "fs"
+    pub fn bucket(&mut self, bucket: &str) -> &mut Self {
+        if !bucket.is_empty() {
+            self.bucket = Some(bucket.to_owned());
+        }
+        self
+    }
+
+    /// Set the chunk size for new files.
+    ///
+    /// default: 255 KiB
+    pub fn chunk_size(&mut self, chunk_size: usize) -> &mut Self {
+        if chunk_size > 0 {
+            self.chunk_size = Some(chunk_size);
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+}
+
+impl Builder for GridfsBuilder {
+    const SCHEME: Scheme = Scheme::Gridfs;
+    type Accessor = GridfsBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = GridfsBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("connection_string")
+            .map(|v| builder.connection_string(v));
+        map.get("database").map(|v| builder.database(v));
+        map.get("bucket").map(|v| builder.bucket(v));
+        map.get("chunk_size")
+            .and_then(|v| v.parse::<usize>().ok())
+            .map(|v| builder.chunk_size(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let database = self.database.clone().ok_or_else(|| {
+            Error::new(ErrorKind::BackendConfigInvalid, "database is empty")
+                .with_context("service", Scheme::Gridfs)
+        })?;
+
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(GridfsBackend {
+            connection_string: self
+                .connection_string
+                .clone()
+                .unwrap_or_else(|| DEFAULT_CONNECTION_STRING.to_string()),
+            database,
+            bucket: self
+                .bucket
+                .clone()
+                .unwrap_or_else(|| DEFAULT_BUCKET.to_string()),
+            chunk_size: self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
+            root,
+            client: Arc::new(OnceCell::new()),
+        })
+    }
+}
+
+/// Backend for GridFS services.
+#[derive(Clone)]
+pub struct GridfsBackend {
+    connection_string: String,
+    database: String,
+    bucket: String,
+    chunk_size: usize,
+    root: String,
+    client: Arc<OnceCell<Client>>,
+}
+
+// implement `Debug` manually, or password in connection string may be leaked.
+impl Debug for GridfsBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("GridfsBackend");
+        ds.field("database", &self.database);
+        ds.field("bucket", &self.bucket);
+        ds.field("chunk_size", &self.chunk_size);
+        ds.field("root", &self.root);
+        ds.finish()
+    }
+}
+
+#[async_trait]
+impl Accessor for GridfsBackend {
+    type Reader = output::Reader;
+    type BlockingReader = ();
+    type Pager = DirStream;
+    type BlockingPager = ();
+
+    fn metadata(&self) -> AccessorMetadata {
+        let mut am = AccessorMetadata::default();
+        am.set_scheme(Scheme::Gridfs)
+            .set_root(&self.root)
+            .set_name(&format!("{}/{}", self.database, self.bucket))
+            .set_capabilities(
+                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
+            )
+            .set_hints(AccessorHint::ReadIsStreamable);
+
+        am
+    }
+
+    async fn create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let filename = build_abs_path(&self.root, path);
+
+        self.gridfs_upload(&filename, None, Box::new(futures::io::empty()))
+            .await?;
+
+        Ok(RpCreate::default())
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let filename = build_abs_path(&self.root, path);
+
+        let file = self.gridfs_find_file(&filename).await?.ok_or_else(|| {
+            Error::new(ErrorKind::ObjectNotFound, "file not found in gridfs")
+                .with_context("filename", &filename)
+        })?;
+
+        let (start, end) = args.range().to_bounds(file.length);
+        let meta = file.metadata().with_content_length(end - start);
+
+        if start == end {
+            let r: output::Reader = Box::new(output::Cursor::from(Vec::new()));
+            return Ok((RpRead::with_metadata(meta), r));
+        }
+
+        if file.chunk_size == 0 {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "file has invalid chunk size")
+                    .with_context("filename", &filename),
+            );
+        }
+
+        // Compute the chunks that cover the range, only these chunks will
+        // be fetched from mongodb.
+        let first = start / file.chunk_size;
+        let last = (end - 1) / file.chunk_size;
+
+        let cursor = self
+            .chunks()
+            .await?
+            .find(
+                doc! {
+                    "files_id": file.id.clone(),
+                    "n": { "$gte": first as i64, "$lte": last as i64 },
+                },
+                FindOptions::builder().sort(doc! { "n": 1 }).build(),
+            )
+            .await?;
+
+        let r = ChunkReader::new(
+            cursor,
+            first as i64,
+            (start - first * file.chunk_size) as usize,
+            end - start,
+        );
+
+        Ok((RpRead::with_metadata(meta), Box::new(r)))
+    }
+
+    async fn write(&self, path: &str, args: OpWrite, r: input::Reader) -> Result<RpWrite> {
+        let filename = build_abs_path(&self.root, path);
+
+        let length = self
+            .gridfs_upload(&filename, args.content_type(), r)
+            .await?;
+
+        Ok(RpWrite::new(length))
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // GridFS doesn't have dirs, stat dir always returns a DIR.
+        if path.ends_with('/') {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let filename = build_abs_path(&self.root, path);
+
+        match self.gridfs_find_file(&filename).await? {
+            Some(file) => Ok(RpStat::new(file.metadata())),
+            None => Err(
+                Error::new(ErrorKind::ObjectNotFound, "file not found in gridfs")
+                    .with_context("filename", &filename),
+            ),
+        }
+    }
+
+    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let filename = build_abs_path(&self.root, path);
+
+        self.gridfs_delete_revisions(&filename, None).await?;
+
+        Ok(RpDelete::default())
+    }
+
+    async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+}
+
+impl GridfsBackend {
+    /// Connect to mongodb and make sure indexes required by GridFS spec
+    /// exist.
+    async fn database(&self) -> Result<mongodb::Database> {
+        let client = self
+            .client
+            .get_or_try_init(|| async {
+                let client = Client::with_uri_str(&self.connection_string).await?;
+                let db = client.database(&self.database);
+
+                db.collection::<Document>(&format!("{}.files", self.bucket))
+                    .create_index(
+                        IndexModel::builder()
+                            .keys(doc! { "filename": 1, "uploadDate": 1 })
+                            .build(),
+                        None,
+                    )
+                    .await?;
+                db.collection::<Document>(&format!("{}.chunks", self.bucket))
+                    .create_index(
+                        IndexModel::builder()
+                            .keys(doc! { "files_id": 1, "n": 1 })
+                            .options(IndexOptions::builder().unique(true).build())
+                            .build(),
+                        None,
+                    )
+                    .await?;
+
+                Ok::<_, Error>(client)
+            })
+            .await?;
+
+        Ok(client.database(&self.database))
+    }
+
+    async fn files(&self) -> Result<Collection<Document>> {
+        Ok(self
+            .database()
+            .await?
+            .collection(&format!("{}.files", self.bucket)))
+    }
+
+    async fn chunks(&self) -> Result<Collection<Document>> {
+        Ok(self
+            .database()
+            .await?
+            .collection(&format!("{}.chunks", self.bucket)))
+    }
+
+    /// Find the latest revision of given filename.
+    async fn gridfs_find_file(&self, filename: &str) -> Result<Option<GridfsFile>> {
+        let doc = self
+            .files()
+            .await?
+            .find_one(
+                doc! { "filename": filename },
+                FindOneOptions::builder()
+                    .sort(doc! { "uploadDate": -1 })
+                    .build(),
+            )
+            .await?;
+
+        doc.map(GridfsFile::from_document).transpose()
+    }
+
+    /// List files whose filename starts with `prefix` and greater than
+    /// `marker`, sorted by filename and then upload date in desc.
+    pub(super) async fn gridfs_list_files(
+        &self,
+        prefix: &str,
+        marker: Option<&str>,
+    ) -> Result<Vec<GridfsFile>> {
+        let mut cond = doc! { "$regex": format!("^{}", escape_regex(prefix)) };
+        if let Some(marker) = marker {
+            cond.insert("$gt", marker);
+        }
+
+        let docs: Vec<Document> = self
+            .files()
+            .await?
+            .find(
+                doc! { "filename": cond },
+                FindOptions::builder()
+                    .sort(doc! { "filename": 1, "uploadDate": -1 })
+                    .limit(LIST_LIMIT as i64)
+                    .build(),
+            )
+            .await?
+            .try_collect()
+            .await?;
+
+        docs.into_iter().map(GridfsFile::from_document).collect()
+    }
+
+    /// Upload content from reader as a new revision of `filename`.
+    ///
+    /// Chunks are inserted while reading so that the whole content will
+    /// never be buffered in memory. The files document is inserted after
+    /// all chunks have been written, and older revisions will be removed
+    /// then.
+    async fn gridfs_upload(
+        &self,
+        filename: &str,
+        content_type: Option<&str>,
+        mut r: input::Reader,
+    ) -> Result<u64> {
+        let files = self.files().await?;
+        let chunks = self.chunks().await?;
+
+        let id = ObjectId::new();
+        let mut length = 0;
+
+        let res = async {
+            let mut buf = vec![0; self.chunk_size];
+            let mut n: i32 = 0;
+
+            loop {
+                let size = read_full(&mut r, &mut buf).await?;
+                if size == 0 {
+                    break;
+                }
+
+                chunks
+                    .insert_one(
+                        doc! {
+                            "files_id": id,
+                            "n": n,
+                            "data": Binary {
+                                subtype: BinarySubtype::Generic,
+                                bytes: buf[..size].to_vec(),
+                            },
+                        },
+                        None,
+                    )
+                    .await?;
+                n += 1;
+                length += size as u64;
+
+                if size < buf.len() {
+                    break;
+                }
+            }
+
+            let mut file = doc! {
+                "_id": id,
+                "length": length as i64,
+                "chunkSize": self.chunk_size as i32,
+                "uploadDate": DateTime::now(),
+                "filename": filename,
+            };
+            if let Some(v) = content_type {
+                file.insert("metadata", doc! { "contentType": v });
+            }
+            files.insert_one(file, None).await?;
+
+            Ok::<_, Error>(())
+        }
+        .await;
+
+        if let Err(err) = res {
+            // Try our best to cleanup chunks that have been written.
+            let _ = chunks.delete_many(doc! { "files_id": id }, None).await;
+            return Err(err);
+        }
+
+        self.gridfs_delete_revisions(filename, Some(id)).await?;
+
+        Ok(length)
+    }
+
+    /// Delete all revisions of `filename` except `keep`.
+    ///
+    /// The files documents will be deleted before chunks to make sure
+    /// users can't read a file with chunks partially deleted.
+    async fn gridfs_delete_revisions(&self, filename: &str, keep: Option<ObjectId>) -> Result<()> {
+        let files = self.files().await?;
+        let chunks = self.chunks().await?;
+
+        let mut filter = doc! { "filename": filename };
+        if let Some(id) = keep {
+            filter.insert("_id", doc! { "$ne": id });
+        }
+
+        let ids: Vec<Bson> = files
+            .find(
+                filter,
+                FindOptions::builder().projection(doc! { "_id": 1 }).build(),
+            )
+            .await?
+            .try_collect::<Vec<Document>>()
+            .await?
+            .into_iter()
+            .filter_map(|v| v.get("_id").cloned())
+            .collect();
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        files
+            .delete_many(doc! { "_id": { "$in": ids.clone() } }, None)
+            .await?;
+        chunks
+            .delete_many(doc! { "files_id": { "$in": ids } }, None)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// GridfsFile is the parsed document in files collection.
+pub(super) struct GridfsFile {
+    id: Bson,
+    pub filename: String,
+    length: u64,
+    chunk_size: u64,
+    upload_date: Option<OffsetDateTime>,
+    content_type: Option<String>,
+}
+
+impl GridfsFile {
+    fn from_document(doc: Document) -> Result<Self> {
+        let id = doc
+            .get("_id")
+            .cloned()
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "files document doesn't have _id"))?;
+        let filename = doc
+            .get_str("filename")
+            .map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "files document has invalid filename")
+                    .set_source(err)
+            })?
+            .to_string();
+
+        let upload_date = match doc.get_datetime("uploadDate") {
+            Ok(v) => Some(
+                OffsetDateTime::from_unix_timestamp_nanos(v.timestamp_millis() as i128 * 1_000_000)
+                    .map_err(|err| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "files document has invalid uploadDate",
+                        )
+                        .set_source(err)
+                    })?,
+            ),
+            Err(_) => None,
+        };
+
+        // `contentType` is deprecated by GridFS spec, but it could still be
+        // written by old drivers.
+        let content_type = doc
+            .get_document("metadata")
+            .ok()
+            .and_then(|v| v.get_str("contentType").ok())
+            .or_else(|| doc.get_str("contentType").ok())
+            .map(|v| v.to_string());
+
+        Ok(GridfsFile {
+            length: get_u64(&doc, "length")?,
+            chunk_size: get_u64(&doc, "chunkSize")?,
+            id,
+            filename,
+            upload_date,
+            content_type,
+        })
+    }
+
+    pub fn metadata(&self) -> ObjectMetadata {
+        let mode = if self.filename.ends_with('/') {
+            ObjectMode::DIR
+        } else {
+            ObjectMode::FILE
+        };
+
+        let mut meta = ObjectMetadata::new(mode).with_content_length(self.length);
+        if let Bson::ObjectId(id) = &self.id {
+            meta.set_etag(&id.to_hex());
+        }
+        if let Some(v) = self.upload_date {
+            meta.set_last_modified(v);
+        }
+        if let Some(v) = &self.content_type {
+            meta.set_content_type(v);
+        }
+        meta
+    }
+}
+
+/// Numbers in files document could be stored as int32, int64 or double by
+/// different drivers.
+fn get_u64(doc: &Document, key: &str) -> Result<u64> {
+    match doc.get(key) {
+        Some(Bson::Int32(v)) if *v >= 0 => Ok(*v as u64),
+        Some(Bson::Int64(v)) if *v >= 0 => Ok(*v as u64),
+        Some(Bson::Double(v)) if *v >= 0.0 => Ok(*v as u64),
+        _ => Err(
+            Error::new(ErrorKind::Unexpected, "files document has invalid number")
+                .with_context("key", key),
+        ),
+    }
+}
+
+/// Read until `buf` is full or reader returns EOF.
+async fn read_full(r: &mut input::Reader, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = r.read(&mut buf[filled..]).await.map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "read data from input").set_source(err)
+        })?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+/// Escape regex meta characters so that filename can be used as prefix in
+/// `$regex` query.
+fn escape_regex(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl From<MongoError> for Error {
+    fn from(e: MongoError) -> Self {
+        let err = Error::new(ErrorKind::Unexpected, "got mongodb error");
+
+        // Network errors could be recovered by retry.
+        match e.kind.as_ref() {
+            MongoErrorKind::Io(_) | MongoErrorKind::ConnectionPoolCleared { .. } => {
+                err.set_temporary().set_source(e)
+            }
+            _ => err.set_source(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_regex() {
+        assert_eq!(escape_regex("abc/def.txt"), r"abc/def\.txt");
+        assert_eq!(escape_regex("a+b (1)/"), r"a\+b \(1\)/");
+        assert_eq!(escape_regex(""), "");
+    }
+
+    #[test]
+    fn test_gridfs_file_from_document() {
+        let id = ObjectId::new();
+        let doc = doc! {
+            "_id": id,
+            "length": 1024_i32,
+            "chunkSize": 261120_i64,
+            "uploadDate": DateTime::from_millis(1676030400000),
+            "filename": "dir/hello.txt",
+            "contentType": "text/plain",
+        };
+
+        let file = GridfsFile::from_document(doc).expect("must success");
+        assert_eq!(file.filename, "dir/hello.txt");
+        assert_eq!(file.length, 1024);
+        assert_eq!(file.chunk_size, 261120);
+
+        let meta = file.metadata();
+        assert_eq!(meta.mode(), ObjectMode::FILE);
+        assert_eq!(meta.content_length(), 1024);
+        assert_eq!(meta.etag(), Some(id.to_hex().as_str()));
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        assert_eq!(
+            meta.last_modified().map(|v| v.unix_timestamp()),
+            Some(1676030400)
+        );
+
+        let doc = doc! { "_id": id, "length": -1_i64, "chunkSize": 1, "filename": "a" };
+        assert!(GridfsFile::from_document(doc).is_err());
+    }
+}
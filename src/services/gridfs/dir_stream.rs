@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::backend::GridfsBackend;
+use super::backend::LIST_LIMIT;
+use crate::raw::*;
+use crate::ObjectMetadata;
+use crate::ObjectMode;
+use crate::Result;
+
+/// DirStream lists files whose filename starts with given path in the
+/// order of filename, and folds files in sub dirs into dir entries.
+pub struct DirStream {
+    backend: Arc<GridfsBackend>,
+    root: String,
+    path: String,
+
+    /// The last filename we have seen, used as the marker of next page.
+    marker: Option<String>,
+    /// The last dir we have returned, used to avoid returning the same
+    /// dir across pages.
+    last_dir: Option<String>,
+    done: bool,
+}
+
+impl DirStream {
+    pub fn new(backend: Arc<GridfsBackend>, root: &str, path: &str) -> Self {
+        Self {
+            backend,
+            root: root.to_string(),
+            path: path.to_string(),
+            marker: None,
+            last_dir: None,
+            done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl output::Page for DirStream {
+    async fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let prefix = build_abs_path(&self.root, &self.path);
+        let files = self
+            .backend
+            .gridfs_list_files(&prefix, self.marker.as_deref())
+            .await?;
+
+        self.done = files.len() < LIST_LIMIT;
+
+        let mut entries = Vec::with_capacity(files.len());
+        for file in files {
+            // Files are sorted by `filename` and then `uploadDate` in desc,
+            // so only the first revision of the same file will be used.
+            if self.marker.as_deref() == Some(file.filename.as_str()) {
+                continue;
+            }
+            self.marker = Some(file.filename.clone());
+
+            let rest = &file.filename[prefix.len()..];
+            // The dir itself will be returned while listing it.
+            if rest.is_empty() {
+                continue;
+            }
+
+            match rest.find('/') {
+                Some(idx) => {
+                    let dir = &file.filename[..prefix.len() + idx + 1];
+                    if self.last_dir.as_deref() == Some(dir) {
+                        continue;
+                    }
+                    self.last_dir = Some(dir.to_string());
+
+                    entries.push(output::Entry::new(
+                        &build_rel_path(&self.root, dir),
+                        ObjectMetadata::new(ObjectMode::DIR).with_complete(),
+                    ));
+                }
+                None => entries.push(output::Entry::new(
+                    &build_rel_path(&self.root, &file.filename),
+                    file.metadata(),
+                )),
+            }
+        }
+
+        Ok(Some(entries))
+    }
+}
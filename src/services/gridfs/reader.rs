@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::Bytes;
+use futures::ready;
+use futures::StreamExt;
+use mongodb::bson::Document;
+use mongodb::Cursor;
+
+use crate::raw::*;
+
+/// ChunkReader streams the content of a range from chunks of a file.
+///
+/// Chunks are fetched in the order of `n`, data before the start of range
+/// in the first chunk and after the end of range in the last chunk will
+/// be dropped.
+pub struct ChunkReader {
+    cursor: Cursor<Document>,
+    /// The `n` of chunk we expect to read next.
+    next_n: i64,
+    /// Bytes to skip in the first chunk.
+    skip: usize,
+    remaining: u64,
+    buf: Bytes,
+}
+
+/// Cursor is only `Send` but all access to it requires `&mut self`, so
+/// it's safe to share ChunkReader between threads.
+unsafe impl Sync for ChunkReader {}
+
+impl ChunkReader {
+    /// Create a new reader which starts from chunk `first_n`, skips `skip`
+    /// bytes in it and then reads `size` bytes.
+    pub fn new(cursor: Cursor<Document>, first_n: i64, skip: usize, size: u64) -> Self {
+        Self {
+            cursor,
+            next_n: first_n,
+            skip,
+            remaining: size,
+            buf: Bytes::new(),
+        }
+    }
+
+    fn parse_chunk(&mut self, doc: Document) -> io::Result<Bytes> {
+        let n = match doc.get("n") {
+            Some(mongodb::bson::Bson::Int32(v)) => *v as i64,
+            Some(mongodb::bson::Bson::Int64(v)) => *v,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "chunk doesn't have a valid n",
+                ))
+            }
+        };
+        if n != self.next_n {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("chunk {} is missing, got chunk {n}", self.next_n),
+            ));
+        }
+        self.next_n += 1;
+
+        let data = doc.get_binary_generic("data").map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("chunk {n} doesn't have valid data: {err}"),
+            )
+        })?;
+
+        let mut bs = Bytes::from(data.clone());
+        bs.advance(self.skip.min(bs.len()));
+        self.skip = 0;
+        bs.truncate(self.remaining.min(bs.len() as u64) as usize);
+        self.remaining -= bs.len() as u64;
+
+        Ok(bs)
+    }
+}
+
+impl output::Read for ChunkReader {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        if !self.buf.has_remaining() {
+            match ready!(output::Read::poll_next(self, cx)) {
+                Some(bs) => self.buf = bs?,
+                None => return Poll::Ready(Ok(0)),
+            }
+        }
+
+        let size = self.buf.remaining().min(buf.len());
+        self.buf.copy_to_slice(&mut buf[..size]);
+        Poll::Ready(Ok(size))
+    }
+
+    fn poll_seek(&mut self, _: &mut Context<'_>, _: io::SeekFrom) -> Poll<io::Result<u64>> {
+        Poll::Ready(Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "output reader doesn't support seeking",
+        )))
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<Bytes>>> {
+        if self.buf.has_remaining() {
+            return Poll::Ready(Some(Ok(self.buf.split_off(0))));
+        }
+
+        loop {
+            if self.remaining == 0 {
+                return Poll::Ready(None);
+            }
+
+            let doc = match ready!(self.cursor.poll_next_unpin(cx)) {
+                Some(doc) => doc.map_err(|err| io::Error::new(io::ErrorKind::Other, err))?,
+                None => {
+                    return Poll::Ready(Some(Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("chunk {} is missing", self.next_n),
+                    ))))
+                }
+            };
+
+            let bs = self.parse_chunk(doc)?;
+            // Skip empty chunks which could be written by other drivers.
+            if !bs.is_empty() {
+                return Poll::Ready(Some(Ok(bs)));
+            }
+        }
+    }
+}
@@ -15,18 +15,30 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::ops::Range;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream;
+use futures::StreamExt;
+use futures::TryStreamExt;
 use http::Request;
 use http::Response;
 use http::StatusCode;
 use log::debug;
 
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::ops::*;
 use crate::raw::*;
 use crate::*;
 
+/// The max number of ranges that sent in one `Range` header.
+///
+/// Servers usually limit the size of request headers, nginx also limits
+/// the number of ranges via `max_ranges`.
+const MAX_RANGES_PER_REQUEST: usize = 32;
+
 /// HTTP Read-only service support like Nginx and Caddy.
 ///
 /// # Capabilities
@@ -38,17 +50,22 @@ use crate::*;
 /// - [ ] ~~list~~
 /// - [ ] ~~presign~~
 /// - [ ] ~~multipart~~
-/// - [ ] blocking
+/// - [x] blocking
 ///
 /// # Notes
 ///
 /// Only `read` ans `stat` are supported. We can use this service to visit any
 /// HTTP Server like nginx, caddy.
 ///
+/// `read_ranges` via `multipart/byteranges` is disabled by default since not
+/// all servers support multiple ranges in one request. Use
+/// [`HttpBuilder::enable_read_ranges`] to enable it.
+///
 /// # Configuration
 ///
 /// - `endpoint`: set the endpoint for http
 /// - `root`: Set the work directory for backend
+/// - `enable_read_ranges`: Enable reading multiple ranges in one request
 ///
 /// You can refer to [`HttpBuilder`]'s docs for more information
 ///
@@ -78,6 +95,7 @@ use crate::*;
 pub struct HttpBuilder {
     endpoint: Option<String>,
     root: Option<String>,
+    enable_read_ranges: bool,
     http_client: Option<HttpClient>,
 }
 
@@ -86,6 +104,7 @@ impl Debug for HttpBuilder {
         let mut de = f.debug_struct("Builder");
         de.field("endpoint", &self.endpoint);
         de.field("root", &self.root);
+        de.field("enable_read_ranges", &self.enable_read_ranges);
 
         de.finish()
     }
@@ -116,6 +135,17 @@ impl HttpBuilder {
         self
     }
 
+    /// Enable reading multiple ranges in one request via
+    /// `multipart/byteranges`.
+    ///
+    /// Ranges will be sent in batches of at most 32 ranges. If server
+    /// doesn't support multiple ranges and returns the whole content,
+    /// ranges in the batch will be read one by one instead.
+    pub fn enable_read_ranges(&mut self) -> &mut Self {
+        self.enable_read_ranges = true;
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -137,6 +167,9 @@ impl Builder for HttpBuilder {
 
         map.get("root").map(|v| builder.root(v));
         map.get("endpoint").map(|v| builder.endpoint(v));
+        map.get("enable_read_ranges")
+            .filter(|v| *v == "on" || *v == "true")
+            .map(|_| builder.enable_read_ranges());
 
         builder
     }
@@ -170,6 +203,7 @@ impl Builder for HttpBuilder {
         Ok(HttpBackend {
             endpoint: endpoint.to_string(),
             root,
+            enable_read_ranges: self.enable_read_ranges,
             client,
         })
     }
@@ -180,6 +214,7 @@ impl Builder for HttpBuilder {
 pub struct HttpBackend {
     endpoint: String,
     root: String,
+    enable_read_ranges: bool,
     client: HttpClient,
 }
 
@@ -188,6 +223,7 @@ impl Debug for HttpBackend {
         f.debug_struct("Backend")
             .field("endpoint", &self.endpoint)
             .field("root", &self.root)
+            .field("enable_read_ranges", &self.enable_read_ranges)
             .field("client", &self.client)
             .finish()
     }
@@ -196,15 +232,20 @@ impl Debug for HttpBackend {
 #[async_trait]
 impl Accessor for HttpBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = ();
     type BlockingPager = ();
 
     fn metadata(&self) -> AccessorMetadata {
+        let mut capabilities = AccessorCapability::Read | AccessorCapability::Blocking;
+        if self.enable_read_ranges {
+            capabilities |= AccessorCapability::ReadRanges;
+        }
+
         let mut ma = AccessorMetadata::default();
         ma.set_scheme(Scheme::Http)
             .set_root(&self.root)
-            .set_capabilities(AccessorCapability::Read)
+            .set_capabilities(capabilities)
             .set_hints(AccessorHint::ReadIsStreamable);
 
         ma
@@ -244,10 +285,61 @@ impl Accessor for HttpBackend {
             _ => Err(parse_error(resp).await?),
         }
     }
+
+    async fn read_ranges(&self, path: &str, args: OpReadRanges) -> Result<RpReadRanges> {
+        let batches: Vec<Vec<Bytes>> = stream::iter(args.ranges().chunks(MAX_RANGES_PER_REQUEST))
+            .map(|ranges| self.http_read_ranges(path, ranges, args.concurrent()))
+            .buffered(args.concurrent())
+            .try_collect()
+            .await?;
+
+        Ok(RpReadRanges::new(batches.into_iter().flatten().collect()))
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let req = self
+            .http_get_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let req = self.http_head_request(path)?.map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            // HTTP Server like nginx could return FORBIDDEN if auto-index
+            // is not enabled, we should ignore them.
+            StatusCode::NOT_FOUND | StatusCode::FORBIDDEN if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
 }
 
 impl HttpBackend {
-    async fn http_get(&self, path: &str, range: BytesRange) -> Result<Response<IncomingAsyncBody>> {
+    fn http_get_request(&self, path: &str, range: BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let url = format!("{}{}", self.endpoint, percent_encode_path(&p));
@@ -262,10 +354,126 @@ impl HttpBackend {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn http_get(&self, path: &str, range: BytesRange) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.http_get_request(path, range)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn http_get_ranges_request(
+        &self,
+        path: &str,
+        ranges: &[Range<u64>],
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_rooted_abs_path(&self.root, path);
+
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&p));
+
+        let range = ranges
+            .iter()
+            .map(|r| format!("{}-{}", r.start, r.end - 1))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let req = Request::get(&url)
+            .header(http::header::RANGE, format!("bytes={range}"))
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn http_get_ranges(
+        &self,
+        path: &str,
+        ranges: &[Range<u64>],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.http_get_ranges_request(path, ranges)?;
+
         self.client.send_async(req).await
     }
 
-    async fn http_head(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    /// Read a batch of ranges in one request.
+    async fn http_read_ranges(
+        &self,
+        path: &str,
+        ranges: &[Range<u64>],
+        concurrent: usize,
+    ) -> Result<Vec<Bytes>> {
+        let resp = self.http_get_ranges(path, ranges).await?;
+
+        let status = resp.status();
+
+        let parts = match status {
+            StatusCode::PARTIAL_CONTENT => {
+                let is_multipart = parse_content_type(resp.headers())?
+                    .map(|v| v.starts_with("multipart/byteranges"))
+                    .unwrap_or_default();
+
+                if is_multipart {
+                    let headers = resp.headers().clone();
+                    let bs = resp.into_body().bytes().await?;
+                    parse_byteranges(&headers, bs)?
+                        .into_iter()
+                        .filter_map(|(range, bs)| range.range().map(|r| (r, bs)))
+                        .collect()
+                } else {
+                    // Server could merge all ranges into one.
+                    let range = parse_content_range(resp.headers())?
+                        .and_then(|v| v.range())
+                        .ok_or_else(|| {
+                            Error::new(
+                                ErrorKind::Unexpected,
+                                "partial content response doesn't have valid content range",
+                            )
+                        })?;
+                    let bs = resp.into_body().bytes().await?;
+                    vec![(range, bs)]
+                }
+            }
+            // Server doesn't support multiple ranges and returns the whole
+            // content. Drop the response without reading the body and read
+            // ranges one by one instead.
+            StatusCode::OK => {
+                drop(resp);
+
+                return stream::iter(ranges.iter().cloned())
+                    .map(|range| self.http_read_range(path, range))
+                    .buffered(concurrent)
+                    .try_collect()
+                    .await;
+            }
+            _ => return Err(parse_error(resp).await?),
+        };
+
+        ranges
+            .iter()
+            .map(|range| slice_range(&parts, range))
+            .collect()
+    }
+
+    /// Read a single range, used while server doesn't support multiple
+    /// ranges.
+    async fn http_read_range(&self, path: &str, range: Range<u64>) -> Result<Bytes> {
+        let resp = self.http_get(path, range.clone().into()).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::PARTIAL_CONTENT => Ok(resp.into_body().bytes().await?),
+            // Server doesn't support range at all.
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                slice_range(&[(0..bs.len() as u64, bs)], &range)
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    fn http_head_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let url = format!("{}{}", self.endpoint, percent_encode_path(&p));
@@ -276,13 +484,37 @@ impl HttpBackend {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn http_head(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.http_head_request(path)?;
+
         self.client.send_async(req).await
     }
 }
 
+/// Slice the data of given range out of returned parts.
+///
+/// Ranges that exceed the end of content will be truncated.
+fn slice_range(parts: &[(Range<u64>, Bytes)], range: &Range<u64>) -> Result<Bytes> {
+    let (part_range, bs) = parts
+        .iter()
+        .find(|(r, _)| r.start <= range.start && range.start < r.end)
+        .ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "range is not returned by server")
+                .with_context("range", format!("{range:?}"))
+        })?;
+
+    let start = (range.start - part_range.start) as usize;
+    let end = (range.end.min(part_range.end) - part_range.start) as usize;
+    Ok(bs.slice(start..end.min(bs.len())))
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
+    use wiremock::matchers::header;
     use wiremock::matchers::method;
     use wiremock::matchers::path;
     use wiremock::Mock;
@@ -318,6 +550,198 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_blocking_read() -> Result<()> {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-length", "13")
+                    .set_body_string("Hello, World!"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        let op = Operator::create(builder)?.finish();
+
+        let bs = op.object("hello").blocking_read()?;
+
+        assert_eq!(bs, b"Hello, World!");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_ranges() -> Result<()> {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .and(header("range", "bytes=0-4,7-11"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("content-type", "multipart/byteranges; boundary=opendal")
+                    .set_body_string(
+                        "--opendal\r\n\
+                         Content-Range: bytes 0-4/13\r\n\
+                         \r\n\
+                         Hello\r\n\
+                         --opendal\r\n\
+                         Content-Range: bytes 7-11/13\r\n\
+                         \r\n\
+                         World\r\n\
+                         --opendal--",
+                    ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        builder.enable_read_ranges();
+        let op = Operator::create(builder)?.finish();
+
+        // Disable range coalescing to make sure two ranges are requested.
+        let bs = op
+            .object("hello")
+            .read_ranges_with(OpReadRanges::new(vec![0..5, 7..12]).with_gap(0))
+            .await?;
+
+        assert_eq!(bs, vec![Bytes::from("Hello"), Bytes::from("World")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_ranges_fallback() -> Result<()> {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mock_server = MockServer::start().await;
+        // Server ignores multiple ranges and returns the whole content.
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .and(header("range", "bytes=0-4,7-11"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("Hello, World!"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .and(header("range", "bytes=0-4"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("content-range", "bytes 0-4/13")
+                    .set_body_string("Hello"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/hello"))
+            .and(header("range", "bytes=7-11"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("content-range", "bytes 7-11/13")
+                    .set_body_string("World"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        builder.enable_read_ranges();
+        let op = Operator::create(builder)?.finish();
+
+        let bs = op
+            .object("hello")
+            .read_ranges_with(OpReadRanges::new(vec![0..5, 7..12]).with_gap(0))
+            .await?;
+
+        assert_eq!(bs, vec![Bytes::from("Hello"), Bytes::from("World")]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_ranges_in_batches() -> Result<()> {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let content = "0123456789".repeat(10);
+        let ranges: Vec<Range<u64>> = (0..MAX_RANGES_PER_REQUEST as u64 + 1)
+            .map(|i| i * 2..i * 2 + 1)
+            .collect();
+
+        let mock_server = MockServer::start().await;
+        // Returns the whole content to make sure every batch is sent in
+        // its own request.
+        for batch in ranges.chunks(MAX_RANGES_PER_REQUEST) {
+            let header_value = batch
+                .iter()
+                .map(|r| format!("{}-{}", r.start, r.end - 1))
+                .collect::<Vec<_>>()
+                .join(",");
+            let (start, end) = (batch[0].start, batch[batch.len() - 1].end);
+            Mock::given(method("GET"))
+                .and(path("/hello"))
+                .and(header("range", format!("bytes={header_value}").as_str()))
+                .respond_with(
+                    ResponseTemplate::new(206)
+                        .insert_header(
+                            "content-range",
+                            format!("bytes {}-{}/100", start, end - 1).as_str(),
+                        )
+                        .set_body_string(&content[start as usize..end as usize]),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint(&mock_server.uri());
+        builder.root("/");
+        builder.enable_read_ranges();
+        let op = Operator::create(builder)?.finish();
+
+        let bs = op
+            .object("hello")
+            .read_ranges_with(OpReadRanges::new(ranges.clone()).with_gap(0))
+            .await?;
+
+        let expected: Vec<Bytes> = ranges
+            .iter()
+            .map(|r| Bytes::from(content[r.start as usize..r.end as usize].to_string()))
+            .collect();
+        assert_eq!(bs, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ranges_capability() {
+        let mut builder = HttpBuilder::default();
+        builder.endpoint("http://127.0.0.1");
+        let acc = builder.build().expect("build must succeed");
+        assert!(!acc
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::ReadRanges));
+
+        let mut builder = HttpBuilder::default();
+        builder.endpoint("http://127.0.0.1");
+        builder.enable_read_ranges();
+        let acc = builder.build().expect("build must succeed");
+        assert!(acc
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::ReadRanges));
+    }
+
     #[tokio::test]
     async fn test_stat() -> Result<()> {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bytes::Bytes;
 use http::Response;
 use http::StatusCode;
 
@@ -25,6 +26,21 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse blocking error response into Error.
+pub fn parse_blocking_error(resp: Response<IncomingBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes()?;
+
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse error response which body has been read into Error.
+fn parse_error_from_bytes(resp: Response<Bytes>) -> Error {
+    let (parts, bs) = resp.into_parts();
+
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::ObjectNotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::ObjectPermissionDenied, false),
@@ -42,5 +58,5 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         err = err.set_temporary();
     }
 
-    Ok(err)
+    err
 }
@@ -69,7 +69,7 @@ impl kv::Adapter for Adapter {
         kv::Metadata::new(
             Scheme::Memory,
             &format!("{:?}", &self.inner as *const _),
-            AccessorCapability::Read | AccessorCapability::Write,
+            AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::Batch,
         )
     }
 
@@ -103,11 +103,23 @@ impl kv::Adapter for Adapter {
 
         Ok(())
     }
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        let mut inner = self.inner.lock();
+        for path in paths {
+            inner.remove(path);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ops::ChecksumAlgorithm;
+    use crate::ops::OpPrefetch;
+    use crate::ops::OpWrite;
 
     #[test]
     fn test_accessor_metadata_name() {
@@ -117,4 +129,75 @@ mod tests {
         let b2 = MemoryBuilder::default().build().unwrap();
         assert_ne!(b1.metadata().name(), b2.metadata().name())
     }
+
+    #[tokio::test]
+    async fn test_write_with_checksum_mismatch() {
+        let op = Operator::create(MemoryBuilder::default()).unwrap().finish();
+        let content = b"Hello, World!".to_vec();
+
+        let algorithm = ChecksumAlgorithm::Crc32c;
+        let checksum = algorithm.checksum(&content);
+        op.object("matched")
+            .write_with(
+                OpWrite::new(content.len() as u64).with_checksum(algorithm, &checksum),
+                content.clone(),
+            )
+            .await
+            .expect("write with matched checksum must succeed");
+
+        let err = op
+            .object("mismatched")
+            .write_with(
+                OpWrite::new(content.len() as u64)
+                    .with_checksum(algorithm, &algorithm.checksum(b"Hello")),
+                content,
+            )
+            .await
+            .expect_err("write with mismatched checksum must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+        assert!(!op.object("mismatched").is_exist().await.unwrap());
+    }
+    #[tokio::test]
+    async fn test_read_with_corrupted_content() {
+        let adapter = Adapter {
+            inner: Arc::new(Mutex::new(BTreeMap::default())),
+        };
+        let op = Operator::new(MemoryBackend::new(adapter.clone())).finish();
+        let content = b"Hello, World!".to_vec();
+
+        let algorithm = ChecksumAlgorithm::Sha256;
+        let o = op.object("corrupted");
+        o.write_with(
+            OpWrite::new(content.len() as u64)
+                .with_checksum(algorithm, &algorithm.checksum(&content)),
+            content.clone(),
+        )
+        .await
+        .expect("write with matched checksum must succeed");
+        assert_eq!(o.read().await.expect("read must succeed"), content);
+        assert_eq!(
+            o.stat().await.expect("stat must succeed").checksum(),
+            Some((algorithm, algorithm.checksum(&content).as_str()))
+        );
+
+        // Corrupt the content behind opendal.
+        kv::Adapter::blocking_set(&adapter, "corrupted", b"Hello, Worlt!")
+            .expect("set must succeed");
+
+        let err = o.read().await.expect_err("read must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+
+        let mut r = o.reader().await.expect("create reader must succeed");
+        let mut bs = Vec::new();
+        let err = futures::AsyncReadExt::read_to_end(&mut r, &mut bs)
+            .await
+            .expect_err("read from reader must fail");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let err = o
+            .download(OpPrefetch::new())
+            .await
+            .expect_err("download must fail");
+        assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+    }
 }
@@ -16,12 +16,29 @@
 //!
 //! More ongoing services support is tracked at [opendal#5](https://github.com/datafuselabs/opendal/issues/5). Please feel free to submit issues if there are services not covered.
 
+#[cfg(feature = "services-archive")]
+mod archive;
+#[cfg(feature = "services-archive")]
+pub use self::archive::Archive;
+
 mod azblob;
 pub use azblob::Azblob;
 
 mod azdfs;
 pub use azdfs::Azdfs;
 
+#[cfg(feature = "services-cacache")]
+mod cacache;
+#[cfg(feature = "services-cacache")]
+pub use self::cacache::Cacache;
+#[cfg(feature = "services-cacache")]
+pub use self::cacache::CacacheBackend;
+
+#[cfg(feature = "services-etcd")]
+mod etcd;
+#[cfg(feature = "services-etcd")]
+pub use self::etcd::Etcd;
+
 mod fs;
 pub use fs::Fs;
 
@@ -36,6 +53,16 @@ pub use gcs::Gcs;
 mod ghac;
 pub use ghac::Ghac;
 
+#[cfg(feature = "services-git")]
+mod git;
+#[cfg(feature = "services-git")]
+pub use self::git::Git;
+
+#[cfg(feature = "services-gridfs")]
+mod gridfs;
+#[cfg(feature = "services-gridfs")]
+pub use self::gridfs::Gridfs;
+
 #[cfg(feature = "services-hdfs")]
 mod hdfs;
 #[cfg(feature = "services-hdfs")]
@@ -65,12 +92,27 @@ mod moka;
 #[cfg(feature = "services-moka")]
 pub use self::moka::Moka;
 
+#[cfg(feature = "services-mysql")]
+mod mysql;
+#[cfg(feature = "services-mysql")]
+pub use self::mysql::Mysql;
+
 mod obs;
 pub use obs::Obs;
 
 mod oss;
 pub use oss::Oss;
 
+#[cfg(feature = "services-postgresql")]
+mod postgresql;
+#[cfg(feature = "services-postgresql")]
+pub use self::postgresql::Postgresql;
+
+#[cfg(feature = "services-redb")]
+mod redb;
+#[cfg(feature = "services-redb")]
+pub use self::redb::Redb;
+
 #[cfg(feature = "services-redis")]
 mod redis;
 #[cfg(feature = "services-redis")]
@@ -89,6 +131,14 @@ mod sled;
 #[cfg(feature = "services-sled")]
 pub use self::sled::Sled;
 
+#[cfg(feature = "services-sqlite")]
+mod sqlite;
+#[cfg(feature = "services-sqlite")]
+pub use self::sqlite::Sqlite;
+
+mod swift;
+pub use swift::Swift;
+
 mod webdav;
 pub use webdav::Webdav;
 
@@ -18,6 +18,10 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Buf;
+use bytes::Bytes;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::Request;
@@ -25,10 +29,18 @@ use http::Response;
 use http::StatusCode;
 use http::Uri;
 use log::debug;
+use md5::Digest;
+use md5::Md5;
 use reqsign::HuaweicloudObsSigner;
+use serde::Deserialize;
+use serde::Serialize;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
+use super::error::parse_error_code;
+use super::error::parse_xml_deserialize_error;
+use super::error::parse_xml_serialize_error;
 use crate::ops::*;
 use crate::raw::*;
 use crate::*;
@@ -42,9 +54,9 @@ use crate::*;
 /// - [x] read
 /// - [x] write
 /// - [x] list
-/// - [ ] presign
-/// - [ ] multipart
-/// - [ ] blocking
+/// - [x] presign
+/// - [x] multipart
+/// - [x] blocking
 ///
 /// # Configuration
 ///
@@ -303,9 +315,9 @@ pub struct ObsBackend {
 #[async_trait]
 impl Accessor for ObsBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
         let mut am = AccessorMetadata::default();
@@ -313,7 +325,13 @@ impl Accessor for ObsBackend {
             .set_root(&self.root)
             .set_name(&self.bucket)
             .set_capabilities(
-                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
+                AccessorCapability::Read
+                    | AccessorCapability::Write
+                    | AccessorCapability::List
+                    | AccessorCapability::Presign
+                    | AccessorCapability::Multipart
+                    | AccessorCapability::Blocking
+                    | AccessorCapability::Batch,
             )
             .set_hints(AccessorHint::ReadIsStreamable);
 
@@ -414,14 +432,406 @@ impl Accessor for ObsBackend {
             DirStream::new(Arc::new(self.clone()), &self.root, path),
         ))
     }
-}
 
-impl ObsBackend {
-    async fn obs_get_object(
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let mut req = self
+            .obs_put_object_request(path, Some(0), None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut req = self
+            .obs_get_object_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
         &self,
         path: &str,
-        range: BytesRange,
-    ) -> Result<Response<IncomingAsyncBody>> {
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let mut req = self
+            .obs_put_object_request(
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let mut req = self.obs_head_object_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let mut req = self.obs_delete_object_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT | StatusCode::ACCEPTED | StatusCode::NOT_FOUND => {
+                Ok(RpDelete::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+
+    fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
+        // We will not send this request out, just for signing.
+        let mut req = match args.operation() {
+            PresignOperation::Stat(_) => self.obs_head_object_request(path)?,
+            PresignOperation::Read(v) => self.obs_get_object_request(path, v.range())?,
+            PresignOperation::Write(v) => {
+                self.obs_put_object_request(path, None, v.content_type(), AsyncBody::Empty)?
+            }
+            PresignOperation::WriteMultipart(v) => self.obs_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                AsyncBody::Empty,
+            )?,
+            PresignOperation::Post(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "obs doesn't support presign post",
+                ))
+            }
+        };
+
+        self.signer
+            .sign_query(&mut req, args.expire())
+            .map_err(new_request_sign_error)?;
+
+        // We don't need this request anymore, consume it directly.
+        let (parts, _) = req.into_parts();
+
+        Ok(RpPresign::new(PresignedRequest::new(
+            parts.method,
+            parts.uri,
+            parts.headers,
+        )))
+    }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.obs_initiate_multipart_upload(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::Reader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self.obs_upload_part_request(
+            path,
+            args.upload_id(),
+            args.part_number(),
+            Some(args.size()),
+            AsyncBody::Reader(r),
+        )?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume().await?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp = self
+            .obs_complete_multipart_upload(path, args.upload_id(), args.parts())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self
+            .obs_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.obs_blocking_initiate_multipart_upload(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes()?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self
+            .obs_upload_part_request(
+                path,
+                args.upload_id(),
+                args.part_number(),
+                Some(args.size()),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume()?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp =
+            self.obs_blocking_complete_multipart_upload(path, args.upload_id(), args.parts())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self.obs_blocking_abort_multipart_upload(path, args.upload_id())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = match args.into_operation() {
+            BatchOperations::Delete(ops) => ops,
+        };
+        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
+
+        let mut results = Vec::with_capacity(paths.len());
+        for paths in paths.chunks(DELETE_OBJECTS_LIMIT) {
+            let resp = self.obs_delete_objects(paths).await?;
+
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let bs = resp.into_body().bytes().await?;
+            let result: DeleteObjectsResult =
+                quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+            for v in result.deleted {
+                let path = build_rel_path(&self.root, &v.key);
+                results.push((path, Ok(RpDelete::default())));
+            }
+            for v in result.error {
+                let path = build_rel_path(&self.root, &v.key);
+                let (kind, retryable) =
+                    parse_error_code(&v.code).unwrap_or((ErrorKind::Unexpected, false));
+                let mut err = Error::new(kind, &format!("{v:?}"));
+                if retryable {
+                    err = err.set_temporary();
+                }
+                results.push((path, Err(err)));
+            }
+        }
+
+        Ok(RpBatch::new(BatchedResults::Delete(results)))
+    }
+}
+
+impl ObsBackend {
+    fn obs_get_object_request(&self, path: &str, range: BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
@@ -432,10 +842,20 @@ impl ObsBackend {
             req = req.header(http::header::RANGE, range.to_header())
         }
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn obs_get_object(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_get_object_request(path, range)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
@@ -467,7 +887,7 @@ impl ObsBackend {
         Ok(req)
     }
 
-    async fn obs_get_head_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn obs_head_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
@@ -477,36 +897,254 @@ impl ObsBackend {
 
         let req = Request::head(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn obs_get_head_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_head_object_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn obs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn obs_delete_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
 
         let req = Request::delete(&url);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn obs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_delete_object_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    pub(crate) async fn obs_list_objects(
+    fn obs_initiate_multipart_upload_request(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}?uploads", self.endpoint, percent_encode_path(&p));
+
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn obs_initiate_multipart_upload(
         &self,
         path: &str,
-        next_marker: &str,
     ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_initiate_multipart_upload_request(path)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn obs_blocking_initiate_multipart_upload(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .obs_initiate_multipart_upload_request(path)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn obs_upload_part_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn obs_complete_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}?uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+
+        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest {
+            part: parts
+                .iter()
+                .map(|v| CompleteMultipartUploadRequestPart {
+                    part_number: v.part_number(),
+                    etag: v.etag().to_string(),
+                })
+                .collect(),
+        })
+        .map_err(parse_xml_serialize_error)?;
+
+        // Make sure content length has been set to avoid post with chunked encoding.
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn obs_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_complete_multipart_upload_request(path, upload_id, parts)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn obs_blocking_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .obs_complete_multipart_upload_request(path, upload_id, parts)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn obs_abort_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}?uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+
+        let req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn obs_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_abort_multipart_upload_request(path, upload_id)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn obs_blocking_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .obs_abort_multipart_upload_request(path, upload_id)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    async fn obs_delete_objects(&self, paths: &[String]) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}/?delete", self.endpoint);
+
+        let content = quick_xml::se::to_string(&DeleteObjectsRequest {
+            object: paths
+                .iter()
+                .map(|path| DeleteObjectsRequestObject {
+                    key: build_abs_path(&self.root, path),
+                })
+                .collect(),
+        })
+        .map_err(parse_xml_serialize_error)?;
+
+        // DeleteObjects requires `Content-MD5` header.
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .header(
+                "Content-MD5",
+                BASE64_STANDARD.encode(Md5::digest(content.as_bytes()).as_slice()),
+            )
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send_async(req).await
+    }
+
+    fn obs_list_objects_request(
+        &self,
+        path: &str,
+        next_marker: &str,
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!("{}?delimiter=/", self.endpoint);
@@ -518,12 +1156,97 @@ impl ObsBackend {
             write!(url, "&marker={next_marker}").expect("write into string must succeed");
         }
 
-        let mut req = Request::get(&url)
+        let req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    pub(crate) async fn obs_list_objects(
+        &self,
+        path: &str,
+        next_marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.obs_list_objects_request(path, next_marker)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
+
+    pub(crate) fn obs_blocking_list_objects(
+        &self,
+        path: &str,
+        next_marker: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .obs_list_objects_request(path, next_marker)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+}
+
+/// The max number of keys that allowed in one DeleteObjects request.
+const DELETE_OBJECTS_LIMIT: usize = 1000;
+
+/// Request of DeleteObjects
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "Delete", rename_all = "PascalCase")]
+struct DeleteObjectsRequest {
+    object: Vec<DeleteObjectsRequestObject>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteObjectsRequestObject {
+    key: String,
+}
+
+/// Result of DeleteObjects
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename = "DeleteResult", rename_all = "PascalCase")]
+struct DeleteObjectsResult {
+    deleted: Vec<DeleteObjectsResultDeleted>,
+    error: Vec<DeleteObjectsResultError>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteObjectsResultDeleted {
+    key: String,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct DeleteObjectsResultError {
+    key: String,
+    code: String,
+    message: String,
+}
+
+/// Result of InitiateMultipartUpload
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct InitiateMultipartUploadResult {
+    upload_id: String,
+}
+
+/// Request of CompleteMultipartUpload
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
+struct CompleteMultipartUploadRequest {
+    part: Vec<CompleteMultipartUploadRequestPart>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct CompleteMultipartUploadRequestPart {
+    #[serde(rename = "PartNumber")]
+    part_number: usize,
+    #[serde(rename = "ETag")]
+    etag: String,
 }
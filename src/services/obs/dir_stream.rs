@@ -16,10 +16,12 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
 use quick_xml::de;
 use serde::Deserialize;
 
 use super::backend::ObsBackend;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::Error;
@@ -67,6 +69,32 @@ impl output::Page for DirStream {
 
         let bs = resp.into_body().bytes().await?;
 
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .obs_blocking_list_objects(&self.path, &self.next_marker)?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_blocking_error(resp)?);
+        }
+
+        let bs = resp.into_body().bytes()?;
+
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<output::Entry>> {
         let output: Output = de::from_reader(bs.reader())
             .map_err(|e| Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e))?;
 
@@ -103,7 +131,7 @@ impl output::Page for DirStream {
             entries.push(de);
         }
 
-        Ok(Some(entries))
+        Ok(entries)
     }
 }
 
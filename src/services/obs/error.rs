@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bytes::Buf;
+use bytes::Bytes;
 use http::Response;
 use http::StatusCode;
 use quick_xml::de;
@@ -39,6 +40,23 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse blocking error response into Error.
+pub fn parse_blocking_error(resp: Response<IncomingBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes()?;
+
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse error response which body has been read into Error.
+fn parse_error_from_bytes(resp: Response<Bytes>) -> Error {
+    let (parts, bs) = resp.into_parts();
+
+    let obs_err = de::from_reader::<_, ObsError>(bs.clone().reader()).ok();
+
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::ObjectNotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::ObjectPermissionDenied, false),
@@ -49,12 +67,15 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         // OBS could return `520 Origin Error` errors which should be retried.
         v if v.as_u16() == 520 => (ErrorKind::Unexpected, true),
 
-        _ => (ErrorKind::Unexpected, false),
+        _ => obs_err
+            .as_ref()
+            .and_then(|v| parse_error_code(&v.code))
+            .unwrap_or((ErrorKind::Unexpected, false)),
     };
 
-    let message = match de::from_reader::<_, ObsError>(bs.clone().reader()) {
-        Ok(obs_error) => format!("{obs_error:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let message = match obs_err {
+        Some(obs_err) => format!("{obs_err:?}"),
+        None => String::from_utf8_lossy(&bs).into_owned(),
     };
 
     let mut err = Error::new(kind, &message).with_context("response", format!("{parts:?}"));
@@ -63,7 +84,31 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         err = err.set_temporary();
     }
 
-    Ok(err)
+    err
+}
+
+/// Parse error code returned by obs into error kind and whether it's
+/// retryable.
+///
+/// Error code could be returned with `200 OK`, for example, the keys that
+/// failed in `DeleteObjects`.
+pub fn parse_error_code(code: &str) -> Option<(ErrorKind, bool)> {
+    match code {
+        "NoSuchKey" => Some((ErrorKind::ObjectNotFound, false)),
+        "AccessDenied" => Some((ErrorKind::ObjectPermissionDenied, false)),
+        "BadDigest" => Some((ErrorKind::ChecksumMismatch, false)),
+        "SlowDown" => Some((ErrorKind::ObjectRateLimited, true)),
+        "InternalError" | "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        _ => None,
+    }
+}
+
+pub fn parse_xml_deserialize_error(e: quick_xml::DeError) -> Error {
+    Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e)
+}
+
+pub fn parse_xml_serialize_error(e: quick_xml::DeError) -> Error {
+    Error::new(ErrorKind::Unexpected, "serialize xml").set_source(e)
 }
 
 #[cfg(test)]
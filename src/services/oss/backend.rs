@@ -18,6 +18,12 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use bytes::Buf;
+use bytes::Bytes;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::RANGE;
@@ -26,11 +32,21 @@ use http::Response;
 use http::StatusCode;
 use http::Uri;
 use log::debug;
+use md5::Digest;
+use md5::Md5;
 use reqsign::AliyunOssBuilder;
 use reqsign::AliyunOssSigner;
+use serde::Deserialize;
+use serde::Serialize;
+use sha1::Sha1;
+use time::Duration;
+use time::OffsetDateTime;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
+use super::error::parse_xml_deserialize_error;
+use super::error::parse_xml_serialize_error;
 use crate::ops::*;
 use crate::raw::*;
 use crate::*;
@@ -44,9 +60,9 @@ use crate::*;
 /// - [x] read
 /// - [x] write
 /// - [x] list
-/// - [ ] presign
-/// - [ ] multipart
-/// - [ ] blocking
+/// - [x] presign
+/// - [x] multipart
+/// - [x] blocking
 ///
 /// # Configuration
 ///
@@ -352,6 +368,10 @@ impl Builder for OssBuilder {
             client,
             bucket: self.bucket.clone(),
             signer: Arc::new(signer),
+            access_key: self
+                .access_key_id
+                .clone()
+                .zip(self.access_key_secret.clone()),
         })
     }
 }
@@ -370,6 +390,8 @@ pub struct OssBackend {
     endpoint: String,
     presign_endpoint: String,
     signer: Arc<AliyunOssSigner>,
+    /// Static `(access_key_id, access_key_secret)` used to sign post policy.
+    access_key: Option<(String, String)>,
 }
 
 impl Debug for OssBackend {
@@ -386,21 +408,28 @@ impl Debug for OssBackend {
 #[async_trait]
 impl Accessor for OssBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
+        let mut capabilities = AccessorCapability::Read
+            | AccessorCapability::Write
+            | AccessorCapability::List
+            | AccessorCapability::Presign
+            | AccessorCapability::Multipart
+            | AccessorCapability::Blocking
+            | AccessorCapability::Batch;
+        // Presign post can only be signed with static access key.
+        if self.access_key.is_some() {
+            capabilities |= AccessorCapability::PresignPost;
+        }
+
         let mut am = AccessorMetadata::default();
         am.set_scheme(Scheme::Oss)
             .set_root(&self.root)
             .set_name(&self.bucket)
-            .set_capabilities(
-                AccessorCapability::Read
-                    | AccessorCapability::Write
-                    | AccessorCapability::List
-                    | AccessorCapability::Presign,
-            )
+            .set_capabilities(capabilities)
             .set_hints(AccessorHint::ReadIsStreamable);
         am
     }
@@ -494,6 +523,121 @@ impl Accessor for OssBackend {
         ))
     }
 
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let mut req = self
+            .oss_put_object_request(path, None, None, AsyncBody::Empty, false)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        let resp = self.client.send(req)?;
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut req = self
+            .oss_get_object_request(path, args.range(), false)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
+        &self,
+        path: &str,
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let mut req = self
+            .oss_put_object_request(
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+                false,
+            )?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        if path == "/" {
+            let m = ObjectMetadata::new(ObjectMode::DIR);
+            return Ok(RpStat::new(m));
+        }
+
+        let mut req = self
+            .oss_head_object_request(path, false)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                let m = ObjectMetadata::new(ObjectMode::DIR);
+                Ok(RpStat::new(m))
+            }
+
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let mut req = self.oss_delete_object_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                resp.into_body().consume()?;
+                Ok(RpDelete::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+
     fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
@@ -502,12 +646,15 @@ impl Accessor for OssBackend {
             PresignOperation::Write(v) => {
                 self.oss_put_object_request(path, None, v.content_type(), AsyncBody::Empty, true)?
             }
-            _ => {
-                return Err(Error::new(
-                    ErrorKind::Unsupported,
-                    "oss doesn't support multipart now",
-                ))
-            }
+            PresignOperation::WriteMultipart(v) => self.oss_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                AsyncBody::Empty,
+                true,
+            )?,
+            PresignOperation::Post(v) => return self.oss_presign_post(path, v, args.expire()),
         };
 
         self.signer
@@ -523,9 +670,289 @@ impl Accessor for OssBackend {
             parts.headers,
         )))
     }
+
+    async fn create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.oss_initiate_multipart_upload(path).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::Reader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self.oss_upload_part_request(
+            path,
+            args.upload_id(),
+            args.part_number(),
+            Some(args.size()),
+            AsyncBody::Reader(r),
+            false,
+        )?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume().await?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp = self
+            .oss_complete_multipart_upload(path, args.upload_id(), args.parts())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self
+            .oss_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.oss_blocking_initiate_multipart_upload(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes()?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self
+            .oss_upload_part_request(
+                path,
+                args.upload_id(),
+                args.part_number(),
+                Some(args.size()),
+                AsyncBody::Empty,
+                false,
+            )?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume()?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp =
+            self.oss_blocking_complete_multipart_upload(path, args.upload_id(), args.parts())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self.oss_blocking_abort_multipart_upload(path, args.upload_id())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = match args.into_operation() {
+            BatchOperations::Delete(ops) => ops,
+        };
+        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
+
+        let mut results = Vec::with_capacity(paths.len());
+        for paths in paths.chunks(DELETE_MULTIPLE_OBJECTS_LIMIT) {
+            let resp = self.oss_delete_multiple_objects(paths).await?;
+
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let bs = resp.into_body().bytes().await?;
+            let result: DeleteMultipleObjectsResult =
+                quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+            // OSS will fail the whole request if any key failed to delete,
+            // so all keys in `Deleted` are succeeded.
+            for v in result.deleted {
+                let path = build_rel_path(&self.root, &v.key);
+                results.push((path, Ok(RpDelete::default())));
+            }
+        }
+
+        Ok(RpBatch::new(BatchedResults::Delete(results)))
+    }
 }
 
 impl OssBackend {
+    /// Generate form fields for [browser based POST uploads](https://help.aliyun.com/document_detail/31988.html)
+    /// signed with static access key.
+    fn oss_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+        expire: Duration,
+    ) -> Result<RpPresign> {
+        let (ak, sk) = self.access_key.as_ref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "oss presign post requires access_key_id and access_key_secret",
+            )
+        })?;
+
+        let p = build_abs_path(&self.root, path);
+        let (policy, key) = PostPolicy::new(OffsetDateTime::now_utc() + expire)
+            .eq("bucket", &self.bucket)
+            .with_op(&p, args);
+        let policy = policy.to_base64()?;
+
+        let mut mac =
+            Hmac::<Sha1>::new_from_slice(sk.as_bytes()).expect("hmac can take key of any size");
+        mac.update(policy.as_bytes());
+        let signature = BASE64_STANDARD.encode(mac.finalize().into_bytes());
+
+        let mut fields = vec![("key".to_string(), key)];
+        if let Some(v) = args.content_type() {
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        fields.push(("OSSAccessKeyId".to_string(), ak.to_string()));
+        fields.push(("policy".to_string(), policy));
+        fields.push(("Signature".to_string(), signature));
+
+        let uri = format!("{}/", self.presign_endpoint)
+            .parse()
+            .map_err(new_request_build_error)?;
+
+        Ok(RpPresign::new(
+            PresignedRequest::new(http::Method::POST, uri, http::HeaderMap::new())
+                .with_form_fields(fields),
+        ))
+    }
+
     fn oss_put_object_request(
         &self,
         path: &str,
@@ -668,12 +1095,229 @@ impl OssBackend {
         self.client.send_async(req).await
     }
 
+    pub(super) fn oss_blocking_list_object(
+        &self,
+        path: &str,
+        token: Option<String>,
+    ) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .oss_list_object_request(path, token)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send(req)
+    }
+
     async fn obs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
         let mut req = self.oss_delete_object_request(path)?;
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
         self.client.send_async(req).await
     }
 
+    fn oss_initiate_multipart_upload_request(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let endpoint = self.get_endpoint(false);
+        let url = format!("{}/{}?uploads", endpoint, percent_encode_path(&p));
+
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn oss_initiate_multipart_upload(
+        &self,
+        path: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.oss_initiate_multipart_upload_request(path)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send_async(req).await
+    }
+
+    fn oss_blocking_initiate_multipart_upload(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .oss_initiate_multipart_upload_request(path)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send(req)
+    }
+
+    fn oss_upload_part_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: Option<u64>,
+        body: AsyncBody,
+        is_presign: bool,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let endpoint = self.get_endpoint(is_presign);
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+
+        let mut req = Request::put(&url);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+        Ok(req)
+    }
+
+    fn oss_complete_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let endpoint = self.get_endpoint(false);
+        let url = format!(
+            "{}/{}?uploadId={}",
+            endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+
+        let content = quick_xml::se::to_string(&CompleteMultipartUploadRequest {
+            part: parts
+                .iter()
+                .map(|v| CompleteMultipartUploadRequestPart {
+                    part_number: v.part_number(),
+                    etag: v.etag().to_string(),
+                })
+                .collect(),
+        })
+        .map_err(parse_xml_serialize_error)?;
+
+        // Make sure content length has been set to avoid post with chunked encoding.
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn oss_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.oss_complete_multipart_upload_request(path, upload_id, parts)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send_async(req).await
+    }
+
+    fn oss_blocking_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .oss_complete_multipart_upload_request(path, upload_id, parts)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send(req)
+    }
+
+    fn oss_abort_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let endpoint = self.get_endpoint(false);
+        let url = format!(
+            "{}/{}?uploadId={}",
+            endpoint,
+            percent_encode_path(&p),
+            percent_encode_path(upload_id)
+        );
+
+        let req = Request::delete(&url)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    async fn oss_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.oss_abort_multipart_upload_request(path, upload_id)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send_async(req).await
+    }
+
+    fn oss_blocking_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .oss_abort_multipart_upload_request(path, upload_id)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send(req)
+    }
+
+    async fn oss_delete_multiple_objects(
+        &self,
+        paths: &[String],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let endpoint = self.get_endpoint(false);
+        let url = format!("{endpoint}/?delete");
+
+        let content = quick_xml::se::to_string(&DeleteMultipleObjectsRequest {
+            quiet: false,
+            object: paths
+                .iter()
+                .map(|path| DeleteMultipleObjectsRequestObject {
+                    key: build_abs_path(&self.root, path),
+                })
+                .collect(),
+        })
+        .map_err(parse_xml_serialize_error)?;
+
+        // DeleteMultipleObjects requires `Content-MD5` header.
+        let mut req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/xml")
+            .header(
+                "Content-MD5",
+                BASE64_STANDARD.encode(Md5::digest(content.as_bytes()).as_slice()),
+            )
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+        self.client.send_async(req).await
+    }
+
     fn get_endpoint(&self, is_presign: bool) -> &str {
         if is_presign {
             &self.presign_endpoint
@@ -682,3 +1326,92 @@ impl OssBackend {
         }
     }
 }
+
+/// The max number of keys that allowed in one DeleteMultipleObjects request.
+const DELETE_MULTIPLE_OBJECTS_LIMIT: usize = 1000;
+
+/// Request of DeleteMultipleObjects
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "Delete", rename_all = "PascalCase")]
+struct DeleteMultipleObjectsRequest {
+    quiet: bool,
+    object: Vec<DeleteMultipleObjectsRequestObject>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteMultipleObjectsRequestObject {
+    key: String,
+}
+
+/// Result of DeleteMultipleObjects
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename = "DeleteResult", rename_all = "PascalCase")]
+struct DeleteMultipleObjectsResult {
+    deleted: Vec<DeleteMultipleObjectsResultDeleted>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteMultipleObjectsResultDeleted {
+    key: String,
+}
+
+/// Result of InitiateMultipartUpload
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct InitiateMultipartUploadResult {
+    upload_id: String,
+}
+
+/// Request of CompleteMultipartUpload
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "CompleteMultipartUpload", rename_all = "PascalCase")]
+struct CompleteMultipartUploadRequest {
+    part: Vec<CompleteMultipartUploadRequestPart>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct CompleteMultipartUploadRequestPart {
+    #[serde(rename = "PartNumber")]
+    part_number: usize,
+    #[serde(rename = "ETag")]
+    etag: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presign_read() {
+        let mut builder = OssBuilder::default();
+        builder
+            .bucket("test")
+            .endpoint("https://oss-cn-hangzhou.aliyuncs.com")
+            .access_key_id("access_key_id")
+            .access_key_secret("access_key_secret");
+        let backend = builder.build().expect("build must succeed");
+        assert!(backend
+            .metadata()
+            .capabilities()
+            .contains(AccessorCapability::Presign));
+
+        let rp = backend
+            .presign(
+                "path/to/file",
+                OpPresign::new(OpRead::new(), std::time::Duration::from_secs(3600)),
+            )
+            .expect("presign must succeed");
+        let req = rp.into_presigned_request();
+
+        assert_eq!(req.method(), http::Method::GET);
+        assert_eq!(req.uri().host(), Some("test.oss-cn-hangzhou.aliyuncs.com"));
+        assert_eq!(req.uri().path(), "/path/to/file");
+        assert!(req
+            .uri()
+            .query()
+            .map_or(false, |v| v.contains("Signature=")));
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
 use quick_xml::de;
 use quick_xml::escape::unescape;
 use serde::Deserialize;
@@ -23,6 +24,7 @@ use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
 use super::backend::OssBackend;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::raw::*;
 use crate::Error;
@@ -73,6 +75,32 @@ impl output::Page for DirStream {
 
         let bs = resp.into_body().bytes().await?;
 
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .oss_blocking_list_object(&self.path, self.token.clone())?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_blocking_error(resp)?);
+        }
+
+        let bs = resp.into_body().bytes()?;
+
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<output::Entry>> {
         let output: ListBucketOutput = de::from_reader(bs.reader())
             .map_err(|e| Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e))?;
 
@@ -115,7 +143,7 @@ impl output::Page for DirStream {
             entries.push(de);
         }
 
-        Ok(Some(entries))
+        Ok(entries)
     }
 }
 
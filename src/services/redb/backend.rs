@@ -0,0 +1,355 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use redb::Database;
+use redb::ReadableTable;
+use redb::TableDefinition;
+use redb::TableError;
+
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::Result;
+use crate::*;
+
+const DEFAULT_TABLE: &str = "opendal";
+
+/// [redb](https://github.com/cberner/redb) service support.
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [x] write
+/// - [x] list
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [x] blocking
+///
+/// # Note
+///
+/// redb is an embedded key-value database written in pure rust. All keys
+/// will be stored under `root` as prefix in one table, every write and
+/// delete happens in its own transaction.
+///
+/// redb only allows one process to open the database at the same time.
+///
+/// # Configuration
+///
+/// - `root`: Set the working directory of `OpenDAL`
+/// - `datadir`: Set the path to the redb database file
+/// - `table`: Set the table name, default to `opendal`
+///
+/// You can refer to [`RedbBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Redb;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Redb::default();
+///     builder.datadir("/tmp/opendal/redb");
+///     builder.table("opendal");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("test_file");
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct RedbBuilder {
+    /// The path to the redb database file.
+    datadir: Option<String>,
+    /// The table to store data.
+    ///
+    /// default is "opendal"
+    table: Option<String>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    root: Option<String>,
+}
+
+impl RedbBuilder {
+    /// Set the path to the redb database file. Will create if not exists.
+    pub fn datadir(&mut self, path: &str) -> &mut Self {
+        if !path.is_empty() {
+            self.datadir = Some(path.to_owned());
+        }
+        self
+    }
+
+    /// Set the table name to store data.
+    ///
+    /// default: "opendal"
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        if !table.is_empty() {
+            self.table = Some(table.to_owned());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+}
+
+impl Builder for RedbBuilder {
+    const SCHEME: Scheme = Scheme::Redb;
+    type Accessor = RedbBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = RedbBuilder::default();
+
+        map.get("datadir").map(|v| builder.datadir(v));
+        map.get("table").map(|v| builder.table(v));
+        map.get("root").map(|v| builder.root(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let path = self.datadir.take().ok_or_else(|| {
+            Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "datadir is required but not set",
+            )
+            .with_context("service", Scheme::Redb)
+        })?;
+
+        // redb will not create the parent directories for us.
+        if let Some(parent) = std::path::Path::new(&path).parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::new(ErrorKind::BackendConfigInvalid, "create redb parent dir")
+                    .with_context("service", Scheme::Redb)
+                    .with_context("datadir", path.clone())
+                    .set_source(e)
+            })?;
+        }
+
+        let db = Database::create(&path).map_err(|e| {
+            Error::new(ErrorKind::BackendConfigInvalid, "open redb database")
+                .with_context("service", Scheme::Redb)
+                .with_context("datadir", path.clone())
+                .set_source(e)
+        })?;
+
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(RedbBackend::new(Adapter {
+            datadir: path,
+            table: self
+                .table
+                .take()
+                .unwrap_or_else(|| DEFAULT_TABLE.to_string()),
+            root: root.clone(),
+            db: Arc::new(db),
+        })
+        .with_root(&root))
+    }
+}
+
+/// Backend for redb services.
+pub type RedbBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    datadir: String,
+    table: String,
+    root: String,
+    db: Arc<Database>,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("path", &self.datadir);
+        ds.field("table", &self.table);
+        ds.field("root", &self.root);
+        ds.finish()
+    }
+}
+
+impl Adapter {
+    fn table(&self) -> TableDefinition<'_, &'static str, &'static [u8]> {
+        TableDefinition::new(&self.table)
+    }
+
+    /// Build the key of given path with root as prefix.
+    fn key(&self, path: &str) -> String {
+        if path == "/" {
+            self.root.clone()
+        } else {
+            format!("{}{}", self.root, path)
+        }
+    }
+}
+
+#[async_trait]
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Redb,
+            &self.datadir,
+            AccessorCapability::Read
+                | AccessorCapability::Write
+                | AccessorCapability::List
+                | AccessorCapability::Blocking
+                | AccessorCapability::Batch,
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        self.blocking_get(path)
+    }
+
+    fn blocking_get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let txn = self.db.begin_read().map_err(parse_redb_error)?;
+        let table = match txn.open_table(self.table()) {
+            Ok(table) => table,
+            // Table will be created by the first write.
+            Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(e) => return Err(parse_redb_error(e)),
+        };
+
+        let value = table
+            .get(self.key(path).as_str())
+            .map_err(parse_redb_error)?;
+
+        Ok(value.map(|v| v.value().to_vec()))
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        self.blocking_stat(path)
+    }
+
+    fn blocking_stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        let txn = self.db.begin_read().map_err(parse_redb_error)?;
+        let table = match txn.open_table(self.table()) {
+            Ok(table) => table,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(e) => return Err(parse_redb_error(e)),
+        };
+
+        let value = table
+            .get(self.key(path).as_str())
+            .map_err(parse_redb_error)?;
+
+        Ok(value.map(|v| {
+            ObjectMetadata::new(ObjectMode::FILE).with_content_length(v.value().len() as u64)
+        }))
+    }
+
+    async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
+        self.blocking_set(path, value)
+    }
+
+    fn blocking_set(&self, path: &str, value: &[u8]) -> Result<()> {
+        let txn = self.db.begin_write().map_err(parse_redb_error)?;
+        {
+            let mut table = txn.open_table(self.table()).map_err(parse_redb_error)?;
+            table
+                .insert(self.key(path).as_str(), value)
+                .map_err(parse_redb_error)?;
+        }
+        txn.commit().map_err(parse_redb_error)
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.blocking_delete(path)
+    }
+
+    fn blocking_delete(&self, path: &str) -> Result<()> {
+        let txn = self.db.begin_write().map_err(parse_redb_error)?;
+        {
+            let mut table = txn.open_table(self.table()).map_err(parse_redb_error)?;
+            table
+                .remove(self.key(path).as_str())
+                .map_err(parse_redb_error)?;
+        }
+        txn.commit().map_err(parse_redb_error)
+    }
+
+    async fn scan(&self, prefix: &str) -> Result<Vec<String>> {
+        self.blocking_scan(prefix)
+    }
+
+    fn blocking_scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let txn = self.db.begin_read().map_err(parse_redb_error)?;
+        let table = match txn.open_table(self.table()) {
+            Ok(table) => table,
+            Err(TableError::TableDoesNotExist(_)) => return Ok(vec![]),
+            Err(e) => return Err(parse_redb_error(e)),
+        };
+
+        // Keys are sorted, so we can stop at the first key without prefix.
+        let prefix = self.key(prefix);
+        let mut keys = Vec::new();
+        for item in table.range(prefix.as_str()..).map_err(parse_redb_error)? {
+            let (key, _) = item.map_err(parse_redb_error)?;
+            let key = key.value();
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            if let Some(key) = key.strip_prefix(&self.root) {
+                keys.push(key.to_string());
+            }
+        }
+
+        Ok(keys)
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        let txn = self.db.begin_write().map_err(parse_redb_error)?;
+        {
+            let mut table = txn.open_table(self.table()).map_err(parse_redb_error)?;
+            for path in paths {
+                table
+                    .remove(self.key(path).as_str())
+                    .map_err(parse_redb_error)?;
+            }
+        }
+        txn.commit().map_err(parse_redb_error)
+    }
+}
+
+/// redb returns different error types for different operations, convert
+/// them into `redb::Error` first.
+fn parse_redb_error(e: impl Into<redb::Error>) -> Error {
+    Error::new(ErrorKind::Unexpected, "got redb error").set_source(e.into())
+}
@@ -15,18 +15,37 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use bb8::RunError;
 use http::Uri;
-use redis::aio::ConnectionManager;
+use redis::aio::ConnectionLike;
+use redis::aio::MultiplexedConnection;
+use redis::cluster::ClusterClient;
+use redis::cluster::ClusterClientBuilder;
+use redis::cluster_async::ClusterConnection;
+use redis::sentinel::Sentinel;
+use redis::sentinel::SentinelNodeConnectionInfo;
 use redis::AsyncCommands;
 use redis::Client;
+use redis::ClientTlsConfig;
+use redis::Cmd;
 use redis::ConnectionAddr;
 use redis::ConnectionInfo;
+use redis::Pipeline;
 use redis::RedisConnectionInfo;
 use redis::RedisError;
+use redis::RedisFuture;
+use redis::TlsCertificates;
+use redis::TlsMode;
+use redis::Value;
+use tokio::sync::Mutex;
 use tokio::sync::OnceCell;
 
 use crate::raw::adapters::kv;
@@ -35,6 +54,12 @@ use crate::*;
 
 const DEFAULT_REDIS_ENDPOINT: &str = "tcp://127.0.0.1:6379";
 const DEFAULT_REDIS_PORT: u16 = 6379;
+/// Values larger than this will be split into chunks.
+const DEFAULT_CHUNK_SIZE: usize = 512 * 1024;
+/// The magic prefix of chunk manifest.
+///
+/// Starts and ends with `\0` so that it's unlikely to conflict with user data.
+const CHUNK_MANIFEST_MAGIC: &[u8] = b"\0opendal:redis:chunked:v1\0";
 
 /// [Redis](https://redis.io/) services support.
 ///
@@ -49,13 +74,36 @@ const DEFAULT_REDIS_PORT: u16 = 6379;
 /// - [ ] ~~multipart~~
 /// - [ ] blocking
 ///
+/// # Note
+///
+/// Values larger than `chunk_size` will be split into multiple keys named
+/// `{key}#{generation}#{index}`, the key itself stores a small manifest
+/// instead. Reading a chunked value only fetches one chunk at a time, so
+/// large values won't block the server.
+///
+/// `SET ... GET` and `GETDEL` are used to clean up stale chunks, which
+/// requires Redis 6.2 or later. On older servers, `GET` followed by
+/// `SET` or `DEL` will be used instead. They are not atomic, so stale
+/// chunks could be leaked under concurrent writes to the same key.
+///
+/// In sentinel mode, connections to a node that is no longer the master
+/// will be dropped and reconnected to the new master.
+///
 /// # Configuration
 ///
 /// - `root`: Set the working directory of `OpenDAL`
 /// - `endpoint`: Set the network address of redis server
+/// - `cluster_endpoints`: Set the comma separated addresses of redis cluster nodes
+/// - `sentinel_endpoints`: Set the comma separated addresses of redis sentinels
+/// - `sentinel_service_name`: Set the name of master monitored by sentinels
 /// - `username`: Set the username of Redis
 /// - `password`: Set the password for authentication
 /// - `db`: Set the DB of redis
+/// - `ca_path`: Set the path to the CA certificate for TLS
+/// - `cert_path`: Set the path to the client certificate for TLS
+/// - `key_path`: Set the path to the client key for TLS
+/// - `connection_pool_max_size`: Set the max size of connection pool
+/// - `chunk_size`: Set the max size of a single value, larger values will be chunked
 ///
 /// You can refer to [`RedisBuilder`]'s docs for more information
 ///
@@ -79,12 +127,40 @@ const DEFAULT_REDIS_PORT: u16 = 6379;
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Redis Cluster with TLS
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Redis;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Redis::default();
+///     builder.cluster_endpoints("rediss://10.0.0.1:6379,rediss://10.0.0.2:6379");
+///     builder.ca_path("/path/to/ca.pem");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Default)]
 pub struct RedisBuilder {
     /// network address of the Redis service. Can be "tcp://127.0.0.1:6379", e.g.
     ///
     /// default is "tcp://127.0.0.1:6379"
     endpoint: Option<String>,
+    /// comma separated network addresses of Redis cluster nodes.
+    ///
+    /// Cluster mode will be enabled if set.
+    cluster_endpoints: Option<String>,
+    /// comma separated network addresses of Redis sentinels.
+    ///
+    /// Sentinel mode will be enabled if set.
+    sentinel_endpoints: Option<String>,
+    /// the name of master monitored by sentinels.
+    sentinel_service_name: Option<String>,
     /// the username to connect redis service.
     ///
     /// default is None
@@ -103,6 +179,20 @@ pub struct RedisBuilder {
     db: i64,
     /// The default ttl for put operations.
     default_ttl: Option<Duration>,
+    /// the path to the CA certificate
+    ca_path: Option<String>,
+    /// the path to the client certificate
+    cert_path: Option<String>,
+    /// the path to the client key
+    key_path: Option<String>,
+    /// the max size of connection pool
+    ///
+    /// default is decided by bb8
+    connection_pool_max_size: Option<u32>,
+    /// the max size of a single value
+    ///
+    /// default is 512 KiB
+    chunk_size: Option<usize>,
 }
 
 impl Debug for RedisBuilder {
@@ -113,12 +203,25 @@ impl Debug for RedisBuilder {
         if let Some(endpoint) = self.endpoint.clone() {
             ds.field("endpoint", &endpoint);
         }
+        if let Some(endpoints) = self.cluster_endpoints.clone() {
+            ds.field("cluster_endpoints", &endpoints);
+        }
+        if let Some(endpoints) = self.sentinel_endpoints.clone() {
+            ds.field("sentinel_endpoints", &endpoints);
+        }
+        if let Some(name) = self.sentinel_service_name.clone() {
+            ds.field("sentinel_service_name", &name);
+        }
         if let Some(username) = self.username.clone() {
             ds.field("username", &username);
         }
         if self.password.is_some() {
             ds.field("password", &"<redacted>");
         }
+        ds.field("ca_path", &self.ca_path);
+        ds.field("cert_path", &self.cert_path);
+        ds.field("connection_pool_max_size", &self.connection_pool_max_size);
+        ds.field("chunk_size", &self.chunk_size);
         ds.finish()
     }
 }
@@ -129,6 +232,7 @@ impl RedisBuilder {
     /// currently supported schemes:
     /// - no scheme: will be seen as "tcp"
     /// - "tcp" or "redis": unsecured redis connections
+    /// - "rediss": secured redis connections via TLS
     /// - "unix" or "redis+unix": unix socket connection
     pub fn endpoint(&mut self, endpoint: &str) -> &mut Self {
         if !endpoint.is_empty() {
@@ -137,6 +241,38 @@ impl RedisBuilder {
         self
     }
 
+    /// set the network addresses of redis cluster nodes, separated by `,`.
+    ///
+    /// Cluster mode will be enabled if set, `endpoint` and `db` will be
+    /// ignored. Only part of the nodes is required, others will be
+    /// discovered from them.
+    pub fn cluster_endpoints(&mut self, endpoints: &str) -> &mut Self {
+        if !endpoints.is_empty() {
+            self.cluster_endpoints = Some(endpoints.to_owned());
+        }
+        self
+    }
+
+    /// set the network addresses of redis sentinels, separated by `,`.
+    ///
+    /// Sentinel mode will be enabled if set, `sentinel_service_name` must be
+    /// set too. `endpoint` will be ignored, connections will be made to the
+    /// master discovered by sentinels.
+    pub fn sentinel_endpoints(&mut self, endpoints: &str) -> &mut Self {
+        if !endpoints.is_empty() {
+            self.sentinel_endpoints = Some(endpoints.to_owned());
+        }
+        self
+    }
+
+    /// set the name of master monitored by sentinels.
+    pub fn sentinel_service_name(&mut self, name: &str) -> &mut Self {
+        if !name.is_empty() {
+            self.sentinel_service_name = Some(name.to_owned());
+        }
+        self
+    }
+
     /// set the username for redis
     ///
     /// default: no username
@@ -182,84 +318,276 @@ impl RedisBuilder {
         }
         self
     }
-}
 
-impl Builder for RedisBuilder {
-    const SCHEME: Scheme = Scheme::Redis;
-    type Accessor = RedisBackend;
-
-    fn from_map(map: HashMap<String, String>) -> Self {
-        let mut builder = RedisBuilder::default();
+    /// Set the path to the CA certificate, TLS will be enabled if set.
+    pub fn ca_path(&mut self, ca_path: &str) -> &mut Self {
+        if !ca_path.is_empty() {
+            self.ca_path = Some(ca_path.to_owned());
+        }
+        self
+    }
 
-        map.get("root").map(|v| builder.root(v));
-        map.get("endpoint").map(|v| builder.endpoint(v));
-        map.get("username").map(|v| builder.username(v));
-        map.get("password").map(|v| builder.password(v));
-        map.get("db")
-            .map(|v| v.parse::<i64>().map(|v| builder.db(v)));
+    /// Set the path to the client certificate, must be used with `key_path`.
+    pub fn cert_path(&mut self, cert_path: &str) -> &mut Self {
+        if !cert_path.is_empty() {
+            self.cert_path = Some(cert_path.to_owned());
+        }
+        self
+    }
 
-        builder
+    /// Set the path to the client key, must be used with `cert_path`.
+    pub fn key_path(&mut self, key_path: &str) -> &mut Self {
+        if !key_path.is_empty() {
+            self.key_path = Some(key_path.to_owned());
+        }
+        self
     }
 
-    fn build(&mut self) -> Result<Self::Accessor> {
-        let endpoint = self
-            .endpoint
-            .clone()
-            .unwrap_or_else(|| DEFAULT_REDIS_ENDPOINT.to_string());
+    /// Set the max size of connection pool.
+    ///
+    /// default: decided by bb8
+    pub fn connection_pool_max_size(&mut self, size: u32) -> &mut Self {
+        if size > 0 {
+            self.connection_pool_max_size = Some(size);
+        }
+        self
+    }
 
-        let ep_url = endpoint.parse::<Uri>().map_err(|e| {
-            Error::new(ErrorKind::BackendConfigInvalid, "endpoint is invalid")
-                .with_context("service", Scheme::Redis)
-                .with_context("endpoint", endpoint)
-                .set_source(e)
-        })?;
+    /// Set the max size of a single value, larger values will be split
+    /// into multiple keys.
+    ///
+    /// default: 512 KiB
+    pub fn chunk_size(&mut self, size: usize) -> &mut Self {
+        if size > 0 {
+            self.chunk_size = Some(size);
+        }
+        self
+    }
 
-        let con_addr = match ep_url.scheme_str() {
-            Some("tcp") | Some("redis") | None => {
-                let host = ep_url
-                    .host()
-                    .map(|h| h.to_string())
-                    .unwrap_or_else(|| "127.0.0.1".to_string());
-                let port = ep_url.port_u16().unwrap_or(DEFAULT_REDIS_PORT);
-                ConnectionAddr::Tcp(host, port)
-            }
-            // TODO: wait for upstream to support `rustls` based TLS connection.
-            Some("unix") | Some("redis+unix") => {
-                let path = PathBuf::from(ep_url.path());
-                ConnectionAddr::Unix(path)
-            }
-            Some(s) => {
+    /// Load certificates from `ca_path`, `cert_path` and `key_path`.
+    ///
+    /// Returns `None` if no certificates are configured.
+    fn tls_certificates(&self) -> Result<Option<TlsCertificates>> {
+        let client_tls = match (&self.cert_path, &self.key_path) {
+            (Some(cert_path), Some(key_path)) => Some(ClientTlsConfig {
+                client_cert: read_pem(cert_path)?,
+                client_key: read_pem(key_path)?,
+            }),
+            (None, None) => None,
+            _ => {
                 return Err(Error::new(
                     ErrorKind::BackendConfigInvalid,
-                    "invalid or unsupported scheme",
+                    "cert_path and key_path must be set together",
                 )
-                .with_context("service", Scheme::Redis)
-                .with_context("scheme", s))
+                .with_context("service", Scheme::Redis))
             }
         };
+        let root_cert = match &self.ca_path {
+            Some(ca_path) => Some(read_pem(ca_path)?),
+            None => None,
+        };
 
-        let redis_info = RedisConnectionInfo {
+        if client_tls.is_none() && root_cert.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(TlsCertificates {
+            client_tls,
+            root_cert,
+        }))
+    }
+
+    fn redis_connection_info(&self) -> RedisConnectionInfo {
+        RedisConnectionInfo {
             db: self.db,
             username: self.username.clone(),
             password: self.password.clone(),
-        };
+            ..Default::default()
+        }
+    }
+
+    fn build_single(&self, certs: Option<TlsCertificates>) -> Result<RedisClient> {
+        let endpoint = self
+            .endpoint
+            .clone()
+            .unwrap_or_else(|| DEFAULT_REDIS_ENDPOINT.to_string());
 
         let con_info = ConnectionInfo {
-            addr: con_addr,
-            redis: redis_info,
+            addr: parse_endpoint(&endpoint, certs.is_some())?,
+            redis: self.redis_connection_info(),
         };
 
-        let client = Client::open(con_info).map_err(|e| {
+        let client = match certs {
+            Some(certs) => Client::build_with_tls(con_info, certs),
+            None => Client::open(con_info),
+        }
+        .map_err(|e| {
+            Error::new(ErrorKind::BackendConfigInvalid, "invalid redis config")
+                .with_context("service", Scheme::Redis)
+                .with_context("endpoint", &endpoint)
+                .with_context("db", self.db.to_string())
+                .set_source(e)
+        })?;
+
+        Ok(RedisClient::Single(client))
+    }
+
+    fn build_cluster(
+        &self,
+        endpoints: &str,
+        certs: Option<TlsCertificates>,
+    ) -> Result<RedisClient> {
+        if self.db != 0 {
+            return Err(Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "redis cluster only supports db 0",
+            )
+            .with_context("service", Scheme::Redis)
+            .with_context("db", self.db.to_string()));
+        }
+
+        let mut tls = certs.is_some();
+        let mut nodes = Vec::new();
+        for endpoint in split_endpoints(endpoints) {
+            let addr = parse_endpoint(endpoint, certs.is_some())?;
+            tls |= matches!(addr, ConnectionAddr::TcpTls { .. });
+            nodes.push(ConnectionInfo {
+                addr,
+                redis: RedisConnectionInfo::default(),
+            });
+        }
+
+        let mut builder = ClusterClientBuilder::new(nodes);
+        if let Some(username) = &self.username {
+            builder = builder.username(username.clone());
+        }
+        if let Some(password) = &self.password {
+            builder = builder.password(password.clone());
+        }
+        if tls {
+            builder = builder.tls(TlsMode::Secure);
+        }
+        if let Some(certs) = certs {
+            builder = builder.certs(certs);
+        }
+
+        let client = builder.build().map_err(|e| {
             Error::new(
                 ErrorKind::BackendConfigInvalid,
-                "invalid or unsupported scheme",
+                "invalid redis cluster config",
+            )
+            .with_context("service", Scheme::Redis)
+            .with_context("cluster_endpoints", endpoints)
+            .set_source(e)
+        })?;
+
+        Ok(RedisClient::Cluster(client))
+    }
+
+    fn build_sentinel(
+        &self,
+        endpoints: &str,
+        certs: Option<TlsCertificates>,
+    ) -> Result<RedisClient> {
+        let service_name = self.sentinel_service_name.clone().ok_or_else(|| {
+            Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "sentinel_service_name is required in sentinel mode",
+            )
+            .with_context("service", Scheme::Redis)
+        })?;
+
+        // redis-rs doesn't allow custom certificates for sentinel nodes.
+        if certs.is_some() {
+            return Err(Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "custom certificates are not supported in sentinel mode",
+            )
+            .with_context("service", Scheme::Redis));
+        }
+
+        let mut tls = false;
+        let mut sentinels = Vec::new();
+        for endpoint in split_endpoints(endpoints) {
+            let addr = parse_endpoint(endpoint, false)?;
+            tls |= matches!(addr, ConnectionAddr::TcpTls { .. });
+            sentinels.push(ConnectionInfo {
+                addr,
+                redis: RedisConnectionInfo::default(),
+            });
+        }
+
+        let sentinel = Sentinel::build(sentinels).map_err(|e| {
+            Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "invalid redis sentinel config",
             )
             .with_context("service", Scheme::Redis)
-            .with_context("endpoint", self.endpoint.as_ref().unwrap())
-            .with_context("db", self.db.to_string())
+            .with_context("sentinel_endpoints", endpoints)
             .set_source(e)
         })?;
 
+        let node_info = SentinelNodeConnectionInfo {
+            tls_mode: if tls { Some(TlsMode::Secure) } else { None },
+            redis_connection_info: Some(self.redis_connection_info()),
+        };
+
+        Ok(RedisClient::Sentinel {
+            sentinel: Mutex::new(sentinel),
+            service_name,
+            node_info,
+        })
+    }
+}
+
+impl Builder for RedisBuilder {
+    const SCHEME: Scheme = Scheme::Redis;
+    type Accessor = RedisBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = RedisBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("endpoint").map(|v| builder.endpoint(v));
+        map.get("cluster_endpoints")
+            .map(|v| builder.cluster_endpoints(v));
+        map.get("sentinel_endpoints")
+            .map(|v| builder.sentinel_endpoints(v));
+        map.get("sentinel_service_name")
+            .map(|v| builder.sentinel_service_name(v));
+        map.get("username").map(|v| builder.username(v));
+        map.get("password").map(|v| builder.password(v));
+        map.get("db")
+            .map(|v| v.parse::<i64>().map(|v| builder.db(v)));
+        map.get("ca_path").map(|v| builder.ca_path(v));
+        map.get("cert_path").map(|v| builder.cert_path(v));
+        map.get("key_path").map(|v| builder.key_path(v));
+        map.get("connection_pool_max_size").map(|v| {
+            v.parse::<u32>()
+                .map(|v| builder.connection_pool_max_size(v))
+        });
+        map.get("chunk_size")
+            .map(|v| v.parse::<usize>().map(|v| builder.chunk_size(v)));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let certs = self.tls_certificates()?;
+
+        let client = match (&self.cluster_endpoints, &self.sentinel_endpoints) {
+            (Some(_), Some(_)) => {
+                return Err(Error::new(
+                    ErrorKind::BackendConfigInvalid,
+                    "cluster_endpoints and sentinel_endpoints can't be set together",
+                )
+                .with_context("service", Scheme::Redis))
+            }
+            (Some(endpoints), None) => self.build_cluster(endpoints, certs)?,
+            (None, Some(endpoints)) => self.build_sentinel(endpoints, certs)?,
+            (None, None) => self.build_single(certs)?,
+        };
+
         let root = normalize_root(
             self.root
                 .clone()
@@ -267,25 +595,103 @@ impl Builder for RedisBuilder {
                 .as_str(),
         );
 
-        let conn = OnceCell::new();
         Ok(RedisBackend::new(Adapter {
-            client,
-            conn,
+            endpoint: self
+                .cluster_endpoints
+                .clone()
+                .or_else(|| self.sentinel_endpoints.clone())
+                .or_else(|| self.endpoint.clone())
+                .unwrap_or_else(|| DEFAULT_REDIS_ENDPOINT.to_string()),
+            db: self.db,
+            manager: RedisConnectionManager {
+                client: Arc::new(client),
+            },
+            pool: OnceCell::new(),
+            connection_pool_max_size: self.connection_pool_max_size,
+            legacy_commands: Arc::new(AtomicBool::new(false)),
             default_ttl: self.default_ttl,
+            chunk_size: self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
         })
         .with_root(&root))
     }
 }
 
+/// Split comma separated endpoints.
+fn split_endpoints(endpoints: &str) -> impl Iterator<Item = &str> {
+    endpoints
+        .split(',')
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+}
+
+/// Parse endpoint into [`ConnectionAddr`].
+///
+/// TCP connections will be upgraded to TLS if `tls` is true.
+fn parse_endpoint(endpoint: &str, tls: bool) -> Result<ConnectionAddr> {
+    let ep_url = endpoint.parse::<Uri>().map_err(|e| {
+        Error::new(ErrorKind::BackendConfigInvalid, "endpoint is invalid")
+            .with_context("service", Scheme::Redis)
+            .with_context("endpoint", endpoint)
+            .set_source(e)
+    })?;
+
+    let host = || {
+        ep_url
+            .host()
+            .map(|h| h.to_string())
+            .unwrap_or_else(|| "127.0.0.1".to_string())
+    };
+    let port = ep_url.port_u16().unwrap_or(DEFAULT_REDIS_PORT);
+
+    let addr = match ep_url.scheme_str() {
+        Some("tcp") | Some("redis") | None if !tls => ConnectionAddr::Tcp(host(), port),
+        Some("tcp") | Some("redis") | Some("rediss") | None => ConnectionAddr::TcpTls {
+            host: host(),
+            port,
+            insecure: false,
+            tls_params: None,
+        },
+        Some("unix") | Some("redis+unix") => {
+            let path = PathBuf::from(ep_url.path());
+            ConnectionAddr::Unix(path)
+        }
+        Some(s) => {
+            return Err(Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "invalid or unsupported scheme",
+            )
+            .with_context("service", Scheme::Redis)
+            .with_context("scheme", s))
+        }
+    };
+
+    Ok(addr)
+}
+
+fn read_pem(path: &str) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|err| {
+        Error::new(ErrorKind::BackendConfigInvalid, "read pem file failed")
+            .with_context("service", Scheme::Redis)
+            .with_context("path", path)
+            .set_source(err)
+    })
+}
+
 /// Backend for redis services.
 pub type RedisBackend = kv::Backend<Adapter>;
 
 #[derive(Clone)]
 pub struct Adapter {
-    client: Client,
-    conn: OnceCell<ConnectionManager>,
+    endpoint: String,
+    db: i64,
+    manager: RedisConnectionManager,
+    pool: OnceCell<bb8::Pool<RedisConnectionManager>>,
+    connection_pool_max_size: Option<u32>,
+    /// Set if server doesn't support `SET ... GET` and `GETDEL`.
+    legacy_commands: Arc<AtomicBool>,
 
     default_ttl: Option<Duration>,
+    chunk_size: usize,
 }
 
 // implement `Debug` manually, or password may be leaked.
@@ -293,21 +699,179 @@ impl Debug for Adapter {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut ds = f.debug_struct("Adapter");
 
-        let info = self.client.get_connection_info();
-        ds.field("addr", &info.addr);
-        ds.field("db", &info.redis.db);
-        ds.field("user", &info.redis.username);
+        ds.field("endpoint", &self.endpoint);
+        ds.field("db", &self.db);
+        ds.field("chunk_size", &self.chunk_size);
         ds.finish()
     }
 }
 
 impl Adapter {
-    async fn conn(&self) -> Result<ConnectionManager> {
-        Ok(self
-            .conn
-            .get_or_try_init(|| async { ConnectionManager::new(self.client.clone()).await })
-            .await?
-            .clone())
+    async fn conn(&self) -> Result<bb8::PooledConnection<'_, RedisConnectionManager>> {
+        let pool = self
+            .pool
+            .get_or_try_init(|| async {
+                let mut builder = bb8::Pool::builder();
+                if let Some(size) = self.connection_pool_max_size {
+                    builder = builder.max_size(size);
+                }
+                builder.build(self.manager.clone()).await.map_err(|err| {
+                    Error::new(ErrorKind::BackendConfigInvalid, "connect to redis failed")
+                        .with_context("service", Scheme::Redis)
+                        .with_context("endpoint", &self.endpoint)
+                        .set_source(err)
+                })
+            })
+            .await?;
+
+        pool.get().await.map_err(|err| match err {
+            RunError::User(err) => err.into(),
+            RunError::TimedOut => {
+                Error::new(ErrorKind::Unexpected, "get connection from pool failed").set_temporary()
+            }
+        })
+    }
+
+    fn set_cmd(&self, key: &str, value: &[u8]) -> Cmd {
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key).arg(value);
+        if let Some(ttl) = self.default_ttl {
+            cmd.arg("EX").arg(ttl.as_secs());
+        }
+        cmd
+    }
+
+    /// Set a single key, returns the old value.
+    async fn set_key(
+        &self,
+        conn: &mut RedisConnection,
+        key: &str,
+        value: &[u8],
+    ) -> Result<Option<Vec<u8>>> {
+        if !self.legacy_commands.load(Ordering::Relaxed) {
+            let mut cmd = self.set_cmd(key, value);
+            cmd.arg("GET");
+
+            match cmd.query_async(conn).await {
+                Err(err) if is_unsupported_command_error(&err) => {
+                    self.legacy_commands.store(true, Ordering::Relaxed)
+                }
+                res => return Ok(res?),
+            }
+        }
+
+        let old: Option<Vec<u8>> = conn.get(key).await?;
+        let _: () = self.set_cmd(key, value).query_async(conn).await?;
+        Ok(old)
+    }
+
+    /// Delete a single key, returns the old value.
+    async fn delete_key(&self, conn: &mut RedisConnection, key: &str) -> Result<Option<Vec<u8>>> {
+        if !self.legacy_commands.load(Ordering::Relaxed) {
+            match redis::cmd("GETDEL").arg(key).query_async(conn).await {
+                Err(err) if is_unsupported_command_error(&err) => {
+                    self.legacy_commands.store(true, Ordering::Relaxed)
+                }
+                res => return Ok(res?),
+            }
+        }
+
+        let old: Option<Vec<u8>> = conn.get(key).await?;
+        let _: () = conn.del(key).await?;
+        Ok(old)
+    }
+
+    /// Write all chunks of value, chunks that have been written will be
+    /// removed if any of them failed.
+    async fn set_chunks(
+        &self,
+        conn: &mut RedisConnection,
+        key: &str,
+        manifest: &ChunkManifest,
+        value: &[u8],
+    ) -> Result<()> {
+        for (idx, chunk) in value.chunks(self.chunk_size).enumerate() {
+            let chunk_key = manifest.chunk_key(key, idx as u64);
+            if let Err(err) = self
+                .set_cmd(&chunk_key, chunk)
+                .query_async::<_, ()>(conn)
+                .await
+            {
+                // Chunks are not referenced by any manifest yet, it's safe
+                // to remove them. The failed one is included since it could
+                // be written without a response. Errors are ignored since
+                // we are already in an error path.
+                let _ = self
+                    .delete_chunks(conn, key, manifest, idx as u64 + 1)
+                    .await;
+                return Err(err.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the chunks from `first` to `last` (inclusive) of given manifest.
+    async fn get_chunks(
+        &self,
+        conn: &mut RedisConnection,
+        key: &str,
+        manifest: &ChunkManifest,
+        first: u64,
+        last: u64,
+    ) -> Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(((last - first + 1) * manifest.chunk_size) as usize);
+        for idx in first..=last {
+            let chunk: Option<Vec<u8>> = conn.get(manifest.chunk_key(key, idx)).await?;
+            match chunk {
+                Some(chunk) if chunk.len() as u64 == manifest.chunk_len(idx) => {
+                    buf.extend_from_slice(&chunk)
+                }
+                // The value could be overwritten or deleted during reading.
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::Unexpected,
+                        "redis value chunk is missing or corrupted",
+                    )
+                    .with_context("service", Scheme::Redis)
+                    .with_context("key", key)
+                    .with_context("chunk", idx.to_string())
+                    .set_temporary())
+                }
+            }
+        }
+
+        Ok(buf)
+    }
+
+    /// Remove the first `count` chunks of given manifest.
+    async fn delete_chunks(
+        &self,
+        conn: &mut RedisConnection,
+        key: &str,
+        manifest: &ChunkManifest,
+        count: u64,
+    ) -> Result<()> {
+        // Chunks may live in different slots, delete them one by one.
+        for idx in 0..count {
+            let _: () = conn.del(manifest.chunk_key(key, idx)).await?;
+        }
+        Ok(())
+    }
+
+    /// Remove chunks of given old value if it's a manifest.
+    async fn delete_stale_chunks(
+        &self,
+        conn: &mut RedisConnection,
+        key: &str,
+        old: Option<Vec<u8>>,
+    ) -> Result<()> {
+        match old.as_deref().and_then(ChunkManifest::decode) {
+            Some(manifest) => {
+                self.delete_chunks(conn, key, &manifest, manifest.chunks())
+                    .await
+            }
+            None => Ok(()),
+        }
     }
 }
 
@@ -316,7 +880,7 @@ impl kv::Adapter for Adapter {
     fn metadata(&self) -> kv::Metadata {
         kv::Metadata::new(
             Scheme::Redis,
-            &self.client.get_connection_info().addr.to_string(),
+            &self.endpoint,
             AccessorCapability::Read | AccessorCapability::Write,
         )
     }
@@ -324,27 +888,390 @@ impl kv::Adapter for Adapter {
     async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
         let mut conn = self.conn().await?;
         let bs: Option<Vec<u8>> = conn.get(key).await?;
-        Ok(bs)
+
+        match bs.as_deref().and_then(ChunkManifest::decode) {
+            Some(manifest) if manifest.size == 0 => Ok(Some(vec![])),
+            Some(manifest) => {
+                let last = manifest.chunks() - 1;
+                let bs = self.get_chunks(&mut conn, key, &manifest, 0, last).await?;
+                Ok(Some(bs))
+            }
+            None => Ok(bs),
+        }
     }
 
-    async fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+    async fn get_range(&self, key: &str, br: BytesRange) -> Result<Option<Vec<u8>>> {
         let mut conn = self.conn().await?;
-        match self.default_ttl {
-            Some(ttl) => conn.set_ex(key, value, ttl.as_secs() as usize).await?,
-            None => conn.set(key, value).await?,
+        let bs: Option<Vec<u8>> = conn.get(key).await?;
+
+        let bs = match bs {
+            Some(bs) => bs,
+            None => return Ok(None),
+        };
+        let manifest = match ChunkManifest::decode(&bs) {
+            Some(manifest) => manifest,
+            None => {
+                let (start, end) = br.to_bounds(bs.len() as u64);
+                return Ok(Some(bs[start as usize..end as usize].to_vec()));
+            }
+        };
+
+        // Only fetch the chunks that cover the given range.
+        let (start, end) = br.to_bounds(manifest.size);
+        if start >= end {
+            return Ok(Some(vec![]));
         }
-        Ok(())
+        let (first, last) = (start / manifest.chunk_size, (end - 1) / manifest.chunk_size);
+        let bs = self
+            .get_chunks(&mut conn, key, &manifest, first, last)
+            .await?;
+
+        let offset = (start - first * manifest.chunk_size) as usize;
+        Ok(Some(bs[offset..offset + (end - start) as usize].to_vec()))
+    }
+
+    async fn stat(&self, key: &str) -> Result<Option<ObjectMetadata>> {
+        let mut conn = self.conn().await?;
+        let bs: Option<Vec<u8>> = conn.get(key).await?;
+
+        Ok(bs.map(|bs| {
+            let size = match ChunkManifest::decode(&bs) {
+                Some(manifest) => manifest.size,
+                None => bs.len() as u64,
+            };
+            ObjectMetadata::new(ObjectMode::FILE).with_content_length(size)
+        }))
+    }
+
+    async fn set(&self, key: &str, value: &[u8]) -> Result<()> {
+        let mut conn = self.conn().await?;
+
+        let old = if value.len() <= self.chunk_size && ChunkManifest::decode(value).is_none() {
+            self.set_key(&mut conn, key, value).await?
+        } else {
+            let manifest = ChunkManifest::new(value.len() as u64, self.chunk_size as u64);
+            self.set_chunks(&mut conn, key, &manifest, value).await?;
+
+            // Only switch to the new chunks after all of them are written.
+            match self.set_key(&mut conn, key, &manifest.encode()).await {
+                Ok(old) => old,
+                // The write failed, chunks of this generation are
+                // useless even if the manifest was stored without a response.
+                Err(err) => {
+                    let _ = self
+                        .delete_chunks(&mut conn, key, &manifest, manifest.chunks())
+                        .await;
+                    return Err(err);
+                }
+            }
+        };
+
+        self.delete_stale_chunks(&mut conn, key, old).await
     }
 
     async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
-        let _: () = conn.del(key).await?;
-        Ok(())
+        let old = self.delete_key(&mut conn, key).await?;
+
+        self.delete_stale_chunks(&mut conn, key, old).await
     }
 }
 
 impl From<RedisError> for Error {
     fn from(e: RedisError) -> Self {
-        Error::new(ErrorKind::Unexpected, e.category()).set_source(e)
+        let err = Error::new(ErrorKind::Unexpected, e.category());
+        // The connection has been dropped from pool, retry will pick up
+        // the new master.
+        if e.kind() == redis::ErrorKind::ReadOnly {
+            err.set_temporary().set_source(e)
+        } else {
+            err.set_source(e)
+        }
+    }
+}
+
+/// Check if the error is returned by redis servers older than 6.2 which
+/// don't support `SET ... GET` and `GETDEL`.
+fn is_unsupported_command_error(e: &RedisError) -> bool {
+    e.kind() == redis::ErrorKind::ResponseError
+        && e.detail().map_or(false, |v| {
+            v.starts_with("syntax error") || v.starts_with("unknown command")
+        })
+}
+
+/// Manifest of a value that has been split into chunks.
+///
+/// It's stored as `{MAGIC}{generation}:{size}:{chunk_size}` in the key of
+/// value. Every write uses a new generation, so readers will never see
+/// chunks mixed from different writes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChunkManifest {
+    generation: String,
+    size: u64,
+    chunk_size: u64,
+}
+
+impl ChunkManifest {
+    fn new(size: u64, chunk_size: u64) -> Self {
+        Self {
+            generation: uuid::Uuid::new_v4().simple().to_string(),
+            size,
+            chunk_size,
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bs = CHUNK_MANIFEST_MAGIC.to_vec();
+        bs.extend_from_slice(
+            format!("{}:{}:{}", self.generation, self.size, self.chunk_size).as_bytes(),
+        );
+        bs
+    }
+
+    /// Decode manifest from value, returns `None` if it's not a manifest.
+    fn decode(bs: &[u8]) -> Option<Self> {
+        let s = std::str::from_utf8(bs.strip_prefix(CHUNK_MANIFEST_MAGIC)?).ok()?;
+        let mut parts = s.splitn(3, ':');
+        let generation = parts.next()?.to_string();
+        let size = parts.next()?.parse().ok()?;
+        let chunk_size = parts.next()?.parse().ok()?;
+        if generation.is_empty() || chunk_size == 0 {
+            return None;
+        }
+
+        Some(Self {
+            generation,
+            size,
+            chunk_size,
+        })
+    }
+
+    /// The number of chunks.
+    fn chunks(&self) -> u64 {
+        (self.size + self.chunk_size - 1) / self.chunk_size
+    }
+
+    /// The expected length of chunk at `idx`.
+    fn chunk_len(&self, idx: u64) -> u64 {
+        (self.size - idx * self.chunk_size).min(self.chunk_size)
+    }
+
+    fn chunk_key(&self, key: &str, idx: u64) -> String {
+        format!("{key}#{}#{idx}", self.generation)
+    }
+}
+
+/// Clients for different deploy modes of redis.
+enum RedisClient {
+    Single(Client),
+    Cluster(ClusterClient),
+    Sentinel {
+        sentinel: Mutex<Sentinel>,
+        service_name: String,
+        node_info: SentinelNodeConnectionInfo,
+    },
+}
+
+/// Connection for different deploy modes of redis.
+///
+/// Cluster connection routes commands to nodes by slots of keys.
+#[derive(Clone)]
+enum RedisConnection {
+    Single {
+        conn: MultiplexedConnection,
+        /// Set if server returns `READONLY`, which means the node has been
+        /// demoted to replica. The connection will be dropped by pool.
+        readonly: bool,
+    },
+    Cluster(ClusterConnection),
+}
+
+impl RedisConnection {
+    fn single(conn: MultiplexedConnection) -> Self {
+        RedisConnection::Single {
+            conn,
+            readonly: false,
+        }
+    }
+}
+
+fn is_readonly_error<T>(res: &redis::RedisResult<T>) -> bool {
+    matches!(res, Err(e) if e.kind() == redis::ErrorKind::ReadOnly)
+}
+
+impl ConnectionLike for RedisConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        match self {
+            RedisConnection::Single { conn, readonly } => Box::pin(async move {
+                let res = conn.req_packed_command(cmd).await;
+                *readonly |= is_readonly_error(&res);
+                res
+            }),
+            RedisConnection::Cluster(conn) => conn.req_packed_command(cmd),
+        }
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        match self {
+            RedisConnection::Single { conn, readonly } => Box::pin(async move {
+                let res = conn.req_packed_commands(cmd, offset, count).await;
+                *readonly |= is_readonly_error(&res);
+                res
+            }),
+            RedisConnection::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        match self {
+            RedisConnection::Single { conn, .. } => conn.get_db(),
+            RedisConnection::Cluster(conn) => conn.get_db(),
+        }
+    }
+}
+
+/// A `bb8::ManageConnection` for redis.
+#[derive(Clone)]
+struct RedisConnectionManager {
+    client: Arc<RedisClient>,
+}
+
+#[async_trait]
+impl bb8::ManageConnection for RedisConnectionManager {
+    type Connection = RedisConnection;
+    type Error = RedisError;
+
+    async fn connect(&self) -> std::result::Result<Self::Connection, Self::Error> {
+        match self.client.as_ref() {
+            RedisClient::Single(client) => Ok(RedisConnection::single(
+                client.get_multiplexed_tokio_connection().await?,
+            )),
+            RedisClient::Cluster(client) => Ok(RedisConnection::Cluster(
+                client.get_async_connection().await?,
+            )),
+            // Ask sentinels for the master every time, so that we can
+            // follow failovers by reconnecting.
+            RedisClient::Sentinel {
+                sentinel,
+                service_name,
+                node_info,
+            } => {
+                let client = sentinel
+                    .lock()
+                    .await
+                    .async_master_for(service_name, Some(node_info))
+                    .await?;
+                Ok(RedisConnection::single(
+                    client.get_multiplexed_tokio_connection().await?,
+                ))
+            }
+        }
+    }
+
+    async fn is_valid(&self, conn: &mut Self::Connection) -> std::result::Result<(), Self::Error> {
+        if !matches!(self.client.as_ref(), RedisClient::Sentinel { .. }) {
+            return redis::cmd("PING").query_async(conn).await;
+        }
+
+        // The master could be demoted after failover, check the role so
+        // that we can reconnect to the new master.
+        let role: Vec<Value> = redis::cmd("ROLE").query_async(conn).await?;
+        match role.first().map(redis::from_redis_value::<String>) {
+            Some(Ok(role)) if role == "master" => Ok(()),
+            _ => Err(RedisError::from((
+                redis::ErrorKind::ReadOnly,
+                "connected redis node is not master",
+            ))),
+        }
+    }
+
+    fn has_broken(&self, conn: &mut Self::Connection) -> bool {
+        matches!(conn, RedisConnection::Single { readonly: true, .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_manifest() {
+        let manifest = ChunkManifest::new(10, 4);
+        assert_eq!(manifest.chunks(), 3);
+        assert_eq!(manifest.chunk_len(0), 4);
+        assert_eq!(manifest.chunk_len(2), 2);
+
+        let bs = manifest.encode();
+        assert_eq!(ChunkManifest::decode(&bs), Some(manifest.clone()));
+        assert_eq!(
+            manifest.chunk_key("/abc", 1),
+            format!("/abc#{}#1", manifest.generation)
+        );
+
+        assert_eq!(ChunkManifest::new(8, 4).chunks(), 2);
+        assert_eq!(ChunkManifest::new(0, 4).chunks(), 0);
+    }
+
+    #[test]
+    fn test_chunk_manifest_decode_invalid() {
+        assert_eq!(ChunkManifest::decode(b"hello"), None);
+        assert_eq!(ChunkManifest::decode(b""), None);
+
+        let mut bs = CHUNK_MANIFEST_MAGIC.to_vec();
+        bs.extend_from_slice(b"abc:10:0");
+        assert_eq!(ChunkManifest::decode(&bs), None);
+
+        let mut bs = CHUNK_MANIFEST_MAGIC.to_vec();
+        bs.extend_from_slice(b"abc:ten:4");
+        assert_eq!(ChunkManifest::decode(&bs), None);
+    }
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert!(matches!(
+            parse_endpoint("tcp://127.0.0.1:6380", false).unwrap(),
+            ConnectionAddr::Tcp(host, 6380) if host == "127.0.0.1"
+        ));
+        assert!(matches!(
+            parse_endpoint("rediss://example.com", false).unwrap(),
+            ConnectionAddr::TcpTls { host, port: 6379, .. } if host == "example.com"
+        ));
+        assert!(matches!(
+            parse_endpoint("redis://127.0.0.1:6379", true).unwrap(),
+            ConnectionAddr::TcpTls { .. }
+        ));
+        assert!(matches!(
+            parse_endpoint("unix:///tmp/redis.sock", false).unwrap(),
+            ConnectionAddr::Unix(_)
+        ));
+        assert!(parse_endpoint("http://127.0.0.1", false).is_err());
+    }
+
+    #[test]
+    fn test_is_unsupported_command_error() {
+        let new_err = |detail: &str| {
+            RedisError::from((
+                redis::ErrorKind::ResponseError,
+                "An error was signalled by the server",
+                detail.to_string(),
+            ))
+        };
+
+        assert!(is_unsupported_command_error(&new_err("syntax error")));
+        assert!(is_unsupported_command_error(&new_err(
+            "unknown command 'GETDEL', with args beginning with: 'abc'"
+        )));
+        assert!(!is_unsupported_command_error(&new_err(
+            "value is not an integer or out of range"
+        )));
+        assert!(!is_unsupported_command_error(&RedisError::from((
+            redis::ErrorKind::ReadOnly,
+            "readonly"
+        ))));
     }
 }
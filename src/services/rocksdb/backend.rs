@@ -155,7 +155,7 @@ impl kv::Adapter for Adapter {
         kv::Metadata::new(
             Scheme::Rocksdb,
             &self.db.path().to_string_lossy(),
-            AccessorCapability::Read | AccessorCapability::Write,
+            AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::Batch,
         )
     }
 
@@ -182,6 +182,15 @@ impl kv::Adapter for Adapter {
     fn blocking_delete(&self, path: &str) -> Result<()> {
         Ok(self.db.delete(path)?)
     }
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        let mut batch = rocksdb::WriteBatch::default();
+        for path in paths {
+            batch.delete(path);
+        }
+
+        Ok(self.db.write(batch)?)
+    }
 }
 
 impl From<rocksdb::Error> for Error {
@@ -23,9 +23,12 @@ use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use bytes::Buf;
 use bytes::Bytes;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::HeaderName;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
+use http::HeaderMap;
 use http::HeaderValue;
 use http::Request;
 use http::Response;
@@ -42,10 +45,16 @@ use reqsign::AwsCredentialLoader;
 use reqsign::AwsV4Signer;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Sha256;
+use time::Duration;
+use time::OffsetDateTime;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
+use super::error::parse_error_code;
 use super::error::parse_xml_deserialize_error;
+use super::error::parse_xml_serialize_error;
 use crate::ops::*;
 use crate::raw::*;
 use crate::*;
@@ -72,6 +81,9 @@ mod constants {
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID: &str =
         "x-amz-server-side-encryption-aws-kms-key-id";
     pub const X_AMZ_BUCKET_REGION: &str = "x-amz-bucket-region";
+    pub const X_AMZ_CHECKSUM_MODE: &str = "x-amz-checksum-mode";
+    pub const X_AMZ_CHECKSUM_CRC32C: &str = "x-amz-checksum-crc32c";
+    pub const X_AMZ_CHECKSUM_SHA256: &str = "x-amz-checksum-sha256";
 }
 
 /// Aws S3 and compatible services (including minio, digitalocean space and so on) support
@@ -85,7 +97,7 @@ mod constants {
 /// - [x] list
 /// - [x] presign
 /// - [x] multipart
-/// - [ ] blocking
+/// - [x] blocking
 ///
 /// # Configuration
 ///
@@ -985,7 +997,7 @@ impl Builder for S3Builder {
         signer_builder.service("s3");
         signer_builder.allow_anonymous();
         signer_builder.config_loader(cfg.clone());
-        signer_builder.credential_loader({
+        let credential_loader = {
             let mut cred_loader = AwsCredentialLoader::new(cfg);
             cred_loader = cred_loader.with_allow_anonymous();
             cred_loader = cred_loader.with_client(client.sync_client());
@@ -1000,7 +1012,10 @@ impl Builder for S3Builder {
             }
 
             cred_loader
-        });
+        };
+        // Credential loader will be shared with presign post which needs
+        // to sign the policy by ourselves.
+        signer_builder.credential_loader(credential_loader.clone());
 
         let signer = signer_builder
             .build()
@@ -1010,7 +1025,9 @@ impl Builder for S3Builder {
         Ok(S3Backend {
             root,
             endpoint,
+            region,
             signer: Arc::new(signer),
+            credential_loader,
             bucket: self.bucket.clone(),
             client,
 
@@ -1028,7 +1045,9 @@ impl Builder for S3Builder {
 pub struct S3Backend {
     bucket: String,
     endpoint: String,
+    region: String,
     signer: Arc<AwsV4Signer>,
+    credential_loader: AwsCredentialLoader,
     client: HttpClient,
     // root will be "/" or "/abc/"
     root: String,
@@ -1106,9 +1125,9 @@ impl S3Backend {
 #[async_trait]
 impl Accessor for S3Backend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
         let mut am = AccessorMetadata::default();
@@ -1120,7 +1139,10 @@ impl Accessor for S3Backend {
                     | AccessorCapability::Write
                     | AccessorCapability::List
                     | AccessorCapability::Presign
-                    | AccessorCapability::Multipart,
+                    | AccessorCapability::PresignPost
+                    | AccessorCapability::Multipart
+                    | AccessorCapability::Blocking
+                    | AccessorCapability::Batch,
             )
             .set_hints(AccessorHint::ReadIsStreamable);
 
@@ -1152,7 +1174,7 @@ impl Accessor for S3Backend {
 
         match status {
             StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
-                let meta = parse_into_object_metadata(path, resp.headers())?;
+                let meta = parse_s3_object_metadata(path, resp.headers())?;
                 Ok((RpRead::with_metadata(meta), resp.into_body()))
             }
             _ => Err(parse_error(resp).await?),
@@ -1167,6 +1189,8 @@ impl Accessor for S3Backend {
             AsyncBody::Reader(r),
         )?;
 
+        insert_checksum_header(&mut req, &args)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         let resp = self.client.send_async(req).await?;
@@ -1193,7 +1217,7 @@ impl Accessor for S3Backend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::OK => parse_s3_object_metadata(path, resp.headers()).map(RpStat::new),
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
             }
@@ -1219,6 +1243,127 @@ impl Accessor for S3Backend {
         ))
     }
 
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let mut req = self
+            .s3_put_object_request(path, Some(0), None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let mut req = self
+            .s3_get_object_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        insert_checksum_mode_header(&mut req);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_s3_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
+        &self,
+        path: &str,
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let mut req = self
+            .s3_put_object_request(
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        insert_checksum_header(&mut req, &args)?;
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let mut req = self.s3_head_object_request(path)?.map(|_| Body::Empty);
+
+        insert_checksum_mode_header(&mut req);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_s3_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let mut req = self.s3_delete_object_request(path)?.map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => Ok(RpDelete::default()),
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+
     fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let mut req = match args.operation() {
@@ -1234,6 +1379,7 @@ impl Accessor for S3Backend {
                 None,
                 AsyncBody::Empty,
             )?,
+            PresignOperation::Post(v) => return self.s3_presign_post(path, v, args.expire()),
         };
 
         self.signer
@@ -1352,9 +1498,223 @@ impl Accessor for S3Backend {
             _ => Err(parse_error(resp).await?),
         }
     }
+
+    fn blocking_create_multipart(
+        &self,
+        path: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let resp = self.s3_blocking_initiate_multipart_upload(path)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes()?;
+
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+                Ok(RpCreateMultipart::new(&result.upload_id))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let mut req = self
+            .s3_upload_part_request(
+                path,
+                args.upload_id(),
+                args.part_number(),
+                Some(args.size()),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .to_string();
+
+                resp.into_body().consume()?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let resp =
+            self.s3_blocking_complete_multipart_upload(path, args.upload_id(), args.parts())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let resp = self.s3_blocking_abort_multipart_upload(path, args.upload_id())?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+
+                Ok(RpAbortMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let ops = match args.into_operation() {
+            BatchOperations::Delete(ops) => ops,
+        };
+        let paths: Vec<String> = ops.into_iter().map(|(p, _)| p).collect();
+
+        let mut results = Vec::with_capacity(paths.len());
+        for paths in paths.chunks(DELETE_OBJECTS_LIMIT) {
+            let resp = self.s3_delete_objects(paths).await?;
+
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp).await?);
+            }
+
+            let bs = resp.into_body().bytes().await?;
+            let result: DeleteObjectsResult =
+                quick_xml::de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
+
+            for v in result.deleted {
+                let path = build_rel_path(&self.root, &v.key);
+                results.push((path, Ok(RpDelete::default())));
+            }
+            for v in result.error {
+                let path = build_rel_path(&self.root, &v.key);
+                let (kind, retryable) =
+                    parse_error_code(&v.code).unwrap_or((ErrorKind::Unexpected, false));
+                let mut err = Error::new(kind, &format!("{v:?}"));
+                if retryable {
+                    err = err.set_temporary();
+                }
+                results.push((path, Err(err)));
+            }
+        }
+
+        Ok(RpBatch::new(BatchedResults::Delete(results)))
+    }
 }
 
 impl S3Backend {
+    /// Generate form fields for [browser based POST uploads](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-UsingHTTPPOST.html)
+    /// signed with AWS Signature Version 4.
+    fn s3_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+        expire: Duration,
+    ) -> Result<RpPresign> {
+        let cred = self.credential_loader.load().ok_or_else(|| {
+            Error::new(
+                ErrorKind::ObjectPermissionDenied,
+                "presign post requires valid credential but not found",
+            )
+        })?;
+
+        let now = OffsetDateTime::now_utc();
+        let date = format!(
+            "{:04}{:02}{:02}",
+            now.year(),
+            u8::from(now.month()),
+            now.day()
+        );
+        let amz_date = format!(
+            "{date}T{:02}{:02}{:02}Z",
+            now.hour(),
+            now.minute(),
+            now.second()
+        );
+        let credential = format!(
+            "{}/{date}/{}/s3/aws4_request",
+            cred.access_key_id(),
+            self.region
+        );
+
+        let p = build_abs_path(&self.root, path);
+        let (mut policy, key) = PostPolicy::new(now + expire)
+            .eq("bucket", &self.bucket)
+            .with_op(&p, args);
+        policy = policy
+            .eq("x-amz-algorithm", "AWS4-HMAC-SHA256")
+            .eq("x-amz-credential", &credential)
+            .eq("x-amz-date", &amz_date);
+        if let Some(token) = cred.security_token() {
+            policy = policy.eq("x-amz-security-token", token);
+        }
+        let policy = policy.to_base64()?;
+
+        let signature = sign_post_policy(cred.secret_access_key(), &date, &self.region, &policy);
+
+        let mut fields = vec![("key".to_string(), key)];
+        if let Some(v) = args.content_type() {
+            fields.push(("Content-Type".to_string(), v.to_string()));
+        }
+        fields.push((
+            "x-amz-algorithm".to_string(),
+            "AWS4-HMAC-SHA256".to_string(),
+        ));
+        fields.push(("x-amz-credential".to_string(), credential));
+        fields.push(("x-amz-date".to_string(), amz_date));
+        if let Some(token) = cred.security_token() {
+            fields.push(("x-amz-security-token".to_string(), token.to_string()));
+        }
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-amz-signature".to_string(), signature));
+
+        let uri = format!("{}/", self.endpoint)
+            .parse()
+            .map_err(new_request_build_error)?;
+
+        Ok(RpPresign::new(
+            PresignedRequest::new(http::Method::POST, uri, http::HeaderMap::new())
+                .with_form_fields(fields),
+        ))
+    }
+
     fn s3_head_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
@@ -1400,6 +1760,8 @@ impl S3Backend {
     ) -> Result<Response<IncomingAsyncBody>> {
         let mut req = self.s3_get_object_request(path, range)?;
 
+        insert_checksum_mode_header(&mut req);
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
@@ -1436,31 +1798,61 @@ impl S3Backend {
     }
 
     async fn s3_head_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
-        let p = build_abs_path(&self.root, path);
+        let mut req = self.s3_head_object_request(path)?;
 
-        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        insert_checksum_mode_header(&mut req);
 
-        let mut req = Request::head(&url);
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
-        // Set SSE headers.
-        req = self.insert_sse_headers(req, false);
+        self.client.send_async(req).await
+    }
 
-        let mut req = req
+    fn s3_delete_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let req = Request::delete(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn s3_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.s3_delete_object_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn s3_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
-        let p = build_abs_path(&self.root, path);
+    async fn s3_delete_objects(&self, paths: &[String]) -> Result<Response<IncomingAsyncBody>> {
+        let url = format!("{}/?delete", self.endpoint);
 
-        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+        let req = Request::post(&url);
 
-        let mut req = Request::delete(&url)
-            .body(AsyncBody::Empty)
+        let content = quick_xml::se::to_string(&DeleteObjectsRequest {
+            object: paths
+                .iter()
+                .map(|path| DeleteObjectsRequestObject {
+                    key: build_abs_path(&self.root, path),
+                })
+                .collect(),
+        })
+        .map_err(parse_xml_serialize_error)?;
+
+        let req = req.header(CONTENT_LENGTH, content.len());
+        // Set content-type to `application/xml` to avoid mixed with form post.
+        let req = req.header(CONTENT_TYPE, "application/xml");
+        // DeleteObjects requires `Content-MD5` header.
+        let req = req.header(
+            "Content-MD5",
+            BASE64_STANDARD.encode(Md5::digest(content.as_bytes()).as_slice()),
+        );
+
+        let mut req = req
+            .body(AsyncBody::Bytes(Bytes::from(content)))
             .map_err(new_request_build_error)?;
 
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
@@ -1468,13 +1860,11 @@ impl S3Backend {
         self.client.send_async(req).await
     }
 
-    /// Make this functions as `pub(suber)` because `DirStream` depends
-    /// on this.
-    pub(super) async fn s3_list_objects(
+    fn s3_list_objects_request(
         &self,
         path: &str,
         continuation_token: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let mut url = format!(
@@ -1495,19 +1885,44 @@ impl S3Backend {
             .expect("write into string must succeed");
         }
 
-        let mut req = Request::get(&url)
+        let req = Request::get(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    /// Make this functions as `pub(suber)` because `DirStream` depends
+    /// on this.
+    pub(super) async fn s3_list_objects(
+        &self,
+        path: &str,
+        continuation_token: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.s3_list_objects_request(path, continuation_token)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn s3_initiate_multipart_upload(
+    /// Make this functions as `pub(suber)` because `DirStream` depends
+    /// on this.
+    pub(super) fn s3_blocking_list_objects(
         &self,
         path: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+        continuation_token: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let mut req = self
+            .s3_list_objects_request(path, continuation_token)?
+            .map(|_| Body::Empty);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn s3_initiate_multipart_upload_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}?uploads", self.endpoint, percent_encode_path(&p));
@@ -1517,15 +1932,35 @@ impl S3Backend {
         // Set SSE headers.
         let req = self.insert_sse_headers(req, true);
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn s3_initiate_multipart_upload(
+        &self,
+        path: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.s3_initiate_multipart_upload_request(path)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
+    fn s3_blocking_initiate_multipart_upload(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .s3_initiate_multipart_upload_request(path)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
     fn s3_upload_part_request(
         &self,
         path: &str,
@@ -1559,12 +1994,12 @@ impl S3Backend {
         Ok(req)
     }
 
-    async fn s3_complete_multipart_upload(
+    fn s3_complete_multipart_upload_request(
         &self,
         path: &str,
         upload_id: &str,
         parts: &[ObjectPart],
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -1588,26 +2023,53 @@ impl S3Backend {
                 })
                 .collect(),
         })
-        .map_err(parse_xml_deserialize_error)?;
+        .map_err(parse_xml_serialize_error)?;
         // Make sure content length has been set to avoid post with chunked encoding.
         let req = req.header(CONTENT_LENGTH, content.len());
         // Set content-type to `application/xml` to avoid mixed with form post.
         let req = req.header(CONTENT_TYPE, "application/xml");
 
-        let mut req = req
+        let req = req
             .body(AsyncBody::Bytes(Bytes::from(content)))
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn s3_complete_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.s3_complete_multipart_upload_request(path, upload_id, parts)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
 
-    async fn s3_abort_multipart_upload(
+    fn s3_blocking_complete_multipart_upload(
         &self,
         path: &str,
         upload_id: &str,
-    ) -> Result<Response<IncomingAsyncBody>> {
+        parts: &[ObjectPart],
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .s3_complete_multipart_upload_request(path, upload_id, parts)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+
+    fn s3_abort_multipart_upload_request(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!(
@@ -1617,14 +2079,98 @@ impl S3Backend {
             upload_id,
         );
 
-        let mut req = Request::delete(&url)
+        let req = Request::delete(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn s3_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let mut req = self.s3_abort_multipart_upload_request(path, upload_id)?;
+
         self.signer.sign(&mut req).map_err(new_request_sign_error)?;
 
         self.client.send_async(req).await
     }
+
+    fn s3_blocking_abort_multipart_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let (head, body) = self
+            .s3_abort_multipart_upload_request(path, upload_id)?
+            .into_parts();
+        let mut req = Request::from_parts(head, body.into_blocking()?);
+
+        self.signer.sign(&mut req).map_err(new_request_sign_error)?;
+
+        self.client.send(req)
+    }
+}
+
+/// Sign the base64 encoded post policy with the signing key of SigV4.
+///
+/// reqsign only signs http requests, so we need to sign the policy by
+/// ourselves. Refer to [Calculating a Signature](https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html#signing-request-intro)
+/// for details.
+fn sign_post_policy(secret_access_key: &str, date: &str, region: &str, policy: &str) -> String {
+    let hmac_sha256 = |key: &[u8], content: &str| {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac can take key of any size");
+        mac.update(content.as_bytes());
+        mac.finalize().into_bytes()
+    };
+
+    let signing_key = [date, region, "s3", "aws4_request"]
+        .iter()
+        .fold(format!("AWS4{secret_access_key}").into_bytes(), |key, v| {
+            hmac_sha256(&key, v).to_vec()
+        });
+
+    format!("{:x}", hmac_sha256(&signing_key, policy))
+}
+
+/// The max number of keys that allowed in one DeleteObjects request.
+const DELETE_OBJECTS_LIMIT: usize = 1000;
+
+/// Request of DeleteObjects.
+#[derive(Default, Debug, Serialize)]
+#[serde(default, rename = "Delete", rename_all = "PascalCase")]
+struct DeleteObjectsRequest {
+    object: Vec<DeleteObjectsRequestObject>,
+}
+
+#[derive(Default, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteObjectsRequestObject {
+    key: String,
+}
+
+/// Result of DeleteObjects.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename = "DeleteResult", rename_all = "PascalCase")]
+struct DeleteObjectsResult {
+    deleted: Vec<DeleteObjectsResultDeleted>,
+    error: Vec<DeleteObjectsResultError>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DeleteObjectsResultDeleted {
+    key: String,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct DeleteObjectsResultError {
+    key: String,
+    code: String,
+    message: String,
 }
 
 /// Result of CreateMultipartUpload
@@ -1678,6 +2224,59 @@ struct CompleteMultipartUploadRequestPart {
     etag: String,
 }
 
+/// Insert checksum header for `PutObject` if checksum has been computed.
+fn insert_checksum_header<T>(req: &mut Request<T>, args: &OpWrite) -> Result<()> {
+    let (algorithm, checksum) = match args.checksum() {
+        Some(v) => v,
+        None => return Ok(()),
+    };
+
+    let name = match algorithm {
+        ChecksumAlgorithm::Md5 => HeaderName::from_static("content-md5"),
+        ChecksumAlgorithm::Crc32c => HeaderName::from_static(constants::X_AMZ_CHECKSUM_CRC32C),
+        ChecksumAlgorithm::Sha256 => HeaderName::from_static(constants::X_AMZ_CHECKSUM_SHA256),
+    };
+    let value =
+        HeaderValue::from_str(checksum).map_err(|err| new_request_build_error(err.into()))?;
+    req.headers_mut().insert(name, value);
+
+    Ok(())
+}
+
+/// Ask S3 to return the checksum of object in `GetObject` and `HeadObject`.
+///
+/// This header is not added for presign, because users will need to send
+/// it as well.
+fn insert_checksum_mode_header<T>(req: &mut Request<T>) {
+    req.headers_mut().insert(
+        HeaderName::from_static(constants::X_AMZ_CHECKSUM_MODE),
+        HeaderValue::from_static("ENABLED"),
+    );
+}
+
+/// Parse object metadata along with the checksum returned by S3.
+///
+/// Checksums of multipart uploads like `<checksum>-<parts>` are computed
+/// over parts instead of the whole object, so they will be ignored.
+fn parse_s3_object_metadata(path: &str, headers: &HeaderMap) -> Result<ObjectMetadata> {
+    let mut meta = parse_into_object_metadata(path, headers)?;
+
+    for (name, algorithm) in [
+        (constants::X_AMZ_CHECKSUM_SHA256, ChecksumAlgorithm::Sha256),
+        (constants::X_AMZ_CHECKSUM_CRC32C, ChecksumAlgorithm::Crc32c),
+    ] {
+        match headers.get(name).and_then(|v| v.to_str().ok()) {
+            Some(v) if !v.contains('-') => {
+                meta.set_checksum(algorithm, v);
+                break;
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(meta)
+}
+
 #[cfg(test)]
 mod tests {
     use backon::BlockingRetryable;
@@ -1815,4 +2414,101 @@ mod tests {
                 .replace('"', "&quot;")
         )
     }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
+    #[test]
+    fn test_serialize_delete_objects_request() {
+        let req = DeleteObjectsRequest {
+            object: vec![
+                DeleteObjectsRequestObject {
+                    key: "sample1.txt".to_string(),
+                },
+                DeleteObjectsRequestObject {
+                    key: "sample2.txt".to_string(),
+                },
+            ],
+        };
+
+        let actual = quick_xml::se::to_string(&req).expect("must succeed");
+
+        pretty_assertions::assert_eq!(
+            actual,
+            r#"<Delete>
+             <Object>
+             <Key>sample1.txt</Key>
+             </Object>
+             <Object>
+               <Key>sample2.txt</Key>
+             </Object>
+            </Delete>"#
+                // Cleanup space and new line
+                .replace([' ', '\n'], "")
+        )
+    }
+
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteObjects.html#API_DeleteObjects_Examples
+    #[test]
+    fn test_deserialize_delete_objects_result() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Deleted>
+                <Key>sample1.txt</Key>
+              </Deleted>
+              <Error>
+                <Key>sample2.txt</Key>
+                <Code>AccessDenied</Code>
+                <Message>Access Denied</Message>
+              </Error>
+            </DeleteResult>"#,
+        );
+
+        let out: DeleteObjectsResult =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.deleted.len(), 1);
+        assert_eq!(out.deleted[0].key, "sample1.txt");
+        assert_eq!(out.error.len(), 1);
+        assert_eq!(out.error[0].key, "sample2.txt");
+        assert_eq!(out.error[0].code, "AccessDenied");
+        assert_eq!(out.error[0].message, "Access Denied");
+    }
+
+    #[test]
+    fn test_parse_s3_object_metadata() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_LENGTH, HeaderValue::from_static("13"));
+        headers.insert(
+            constants::X_AMZ_CHECKSUM_CRC32C,
+            HeaderValue::from_static("n03x6A=="),
+        );
+        let meta = parse_s3_object_metadata("abc", &headers).expect("must success");
+        assert_eq!(
+            meta.checksum(),
+            Some((ChecksumAlgorithm::Crc32c, "n03x6A=="))
+        );
+
+        // Checksum of multipart upload should be ignored.
+        headers.insert(
+            constants::X_AMZ_CHECKSUM_CRC32C,
+            HeaderValue::from_static("n03x6A==-3"),
+        );
+        let meta = parse_s3_object_metadata("abc", &headers).expect("must success");
+        assert_eq!(meta.checksum(), None);
+    }
+
+    #[test]
+    fn test_sign_post_policy() {
+        let signature = sign_post_policy(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20151229",
+            "us-east-1",
+            "eyJleHBpcmF0aW9uIjoiMjAxNS0xMi0zMFQxMjowMDowMC4wMDBaIn0=",
+        );
+
+        assert_eq!(
+            signature,
+            "19569cb5f9b36d7da00f22d10fa1e57404fd54365034c15795379e27d6048e12"
+        );
+    }
 }
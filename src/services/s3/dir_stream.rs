@@ -16,12 +16,14 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
 use quick_xml::de;
 use serde::Deserialize;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
 use super::backend::S3Backend;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use super::error::parse_xml_deserialize_error;
 use crate::raw::*;
@@ -71,6 +73,32 @@ impl output::Page for DirStream {
 
         let bs = resp.into_body().bytes().await?;
 
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .s3_blocking_list_objects(&self.path, &self.token)?;
+
+        if resp.status() != http::StatusCode::OK {
+            return Err(parse_blocking_error(resp)?);
+        }
+
+        let bs = resp.into_body().bytes()?;
+
+        self.parse_page(bs).map(Some)
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<output::Entry>> {
         let output: Output = de::from_reader(bs.reader()).map_err(parse_xml_deserialize_error)?;
 
         // Try our best to check whether this list is done.
@@ -109,7 +137,6 @@ impl output::Page for DirStream {
             let mut meta = ObjectMetadata::new(ObjectMode::FILE);
 
             meta.set_etag(&object.etag);
-            meta.set_content_md5(object.etag.trim_matches('"'));
             meta.set_content_length(object.size);
 
             // object.last_modified provides more precious time that contains
@@ -133,7 +160,7 @@ impl output::Page for DirStream {
             entries.push(de);
         }
 
-        Ok(Some(entries))
+        Ok(entries)
     }
 }
 
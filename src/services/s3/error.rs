@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bytes::Buf;
+use bytes::Bytes;
 use http::Response;
 use http::StatusCode;
 use quick_xml::de;
@@ -38,6 +39,23 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
     let (parts, body) = resp.into_parts();
     let bs = body.bytes().await?;
 
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse blocking error response into Error.
+pub fn parse_blocking_error(resp: Response<IncomingBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes()?;
+
+    Ok(parse_error_from_bytes(Response::from_parts(parts, bs)))
+}
+
+/// Parse error response which body has been read into Error.
+fn parse_error_from_bytes(resp: Response<Bytes>) -> Error {
+    let (parts, bs) = resp.into_parts();
+
+    let s3_err = de::from_reader::<_, S3Error>(bs.clone().reader()).ok();
+
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::ObjectNotFound, false),
         StatusCode::FORBIDDEN => (ErrorKind::ObjectPermissionDenied, false),
@@ -45,12 +63,16 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         | StatusCode::BAD_GATEWAY
         | StatusCode::SERVICE_UNAVAILABLE
         | StatusCode::GATEWAY_TIMEOUT => (ErrorKind::Unexpected, true),
-        _ => (ErrorKind::Unexpected, false),
+        // S3 returns `BadDigest` if the checksum we sent doesn't match.
+        _ => s3_err
+            .as_ref()
+            .and_then(|v| parse_error_code(&v.code))
+            .unwrap_or((ErrorKind::Unexpected, false)),
     };
 
-    let message = match de::from_reader::<_, S3Error>(bs.clone().reader()) {
-        Ok(s3_err) => format!("{s3_err:?}"),
-        Err(_) => String::from_utf8_lossy(&bs).into_owned(),
+    let message = match s3_err {
+        Some(s3_err) => format!("{s3_err:?}"),
+        None => String::from_utf8_lossy(&bs).into_owned(),
     };
 
     let mut err = Error::new(kind, &message).with_context("response", format!("{parts:?}"));
@@ -59,13 +81,33 @@ pub async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Error> {
         err = err.set_temporary();
     }
 
-    Ok(err)
+    err
+}
+
+/// Parse error code returned by s3 into error kind and whether it's
+/// retryable.
+///
+/// Error code could be returned with `200 OK`, for example, the keys that
+/// failed in `DeleteObjects`.
+pub fn parse_error_code(code: &str) -> Option<(ErrorKind, bool)> {
+    match code {
+        "NoSuchKey" => Some((ErrorKind::ObjectNotFound, false)),
+        "AccessDenied" => Some((ErrorKind::ObjectPermissionDenied, false)),
+        "BadDigest" => Some((ErrorKind::ChecksumMismatch, false)),
+        "SlowDown" => Some((ErrorKind::ObjectRateLimited, true)),
+        "InternalError" | "ServiceUnavailable" => Some((ErrorKind::Unexpected, true)),
+        _ => None,
+    }
 }
 
 pub fn parse_xml_deserialize_error(e: quick_xml::DeError) -> Error {
     Error::new(ErrorKind::Unexpected, "deserialize xml").set_source(e)
 }
 
+pub fn parse_xml_serialize_error(e: quick_xml::DeError) -> Error {
+    Error::new(ErrorKind::Unexpected, "serialize xml").set_source(e)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,4 +135,22 @@ mod tests {
         assert_eq!(out.resource, "/mybucket/myfoto.jpg");
         assert_eq!(out.request_id, "4442587FB7D0A2F9");
     }
+
+    #[test]
+    fn test_parse_error_code() {
+        let cases = vec![
+            ("NoSuchKey", Some((ErrorKind::ObjectNotFound, false))),
+            (
+                "AccessDenied",
+                Some((ErrorKind::ObjectPermissionDenied, false)),
+            ),
+            ("SlowDown", Some((ErrorKind::ObjectRateLimited, true))),
+            ("InternalError", Some((ErrorKind::Unexpected, true))),
+            ("UnknownCode", None),
+        ];
+
+        for (code, expected) in cases {
+            assert_eq!(parse_error_code(code), expected, "{code}");
+        }
+    }
 }
@@ -0,0 +1,433 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rusqlite::blob::Blob;
+use rusqlite::params;
+use rusqlite::Connection;
+use rusqlite::DatabaseName;
+use rusqlite::OptionalExtension;
+
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::*;
+
+const DEFAULT_SQLITE_TABLE: &str = "opendal";
+const DEFAULT_SQLITE_KEY_FIELD: &str = "key";
+const DEFAULT_SQLITE_VALUE_FIELD: &str = "value";
+
+/// [SQLite](https://www.sqlite.org/) services support.
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [x] write
+/// - [ ] ~~list~~
+/// - [ ] ~~presign~~
+/// - [ ] ~~multipart~~
+/// - [x] blocking
+///
+/// # Note
+///
+/// Values are stored as `BLOB` and read via SQLite's incremental blob I/O,
+/// so range reads will not load the whole value into memory. The table
+/// will be created if not exists, users who bring their own table must
+/// make sure it's a rowid table with a unique key column.
+///
+/// # Configuration
+///
+/// - `root`: Set the working directory of `OpenDAL`
+/// - `path`: Set the path to the sqlite database file
+/// - `table`: Set the table name, default to `opendal`
+/// - `key_field`: Set the key column name, default to `key`
+/// - `value_field`: Set the value column name, default to `value`
+///
+/// You can refer to [`SqliteBuilder`]'s docs for more information
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Sqlite;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let mut builder = Sqlite::default();
+///     builder.path("/tmp/opendal/sqlite.db");
+///     builder.table("objects");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///     let _: Object = op.object("test_file");
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct SqliteBuilder {
+    /// The path to the sqlite database file.
+    path: Option<String>,
+    /// The table to store objects.
+    table: Option<String>,
+    /// The column to store keys.
+    key_field: Option<String>,
+    /// The column to store values.
+    value_field: Option<String>,
+    /// the working directory of the service. Can be "/path/to/dir"
+    ///
+    /// default is "/"
+    root: Option<String>,
+}
+
+impl SqliteBuilder {
+    /// Set the path to the sqlite database file. Will create if not exists.
+    pub fn path(&mut self, path: &str) -> &mut Self {
+        if !path.is_empty() {
+            self.path = Some(path.to_string());
+        }
+        self
+    }
+
+    /// Set the table name to store objects.
+    ///
+    /// default: "opendal"
+    pub fn table(&mut self, table: &str) -> &mut Self {
+        if !table.is_empty() {
+            self.table = Some(table.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store keys.
+    ///
+    /// default: "key"
+    pub fn key_field(&mut self, key_field: &str) -> &mut Self {
+        if !key_field.is_empty() {
+            self.key_field = Some(key_field.to_string());
+        }
+        self
+    }
+
+    /// Set the column name to store values.
+    ///
+    /// default: "value"
+    pub fn value_field(&mut self, value_field: &str) -> &mut Self {
+        if !value_field.is_empty() {
+            self.value_field = Some(value_field.to_string());
+        }
+        self
+    }
+
+    /// set the working directory, all operations will be performed under it.
+    ///
+    /// default: "/"
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_owned());
+        }
+        self
+    }
+}
+
+impl Builder for SqliteBuilder {
+    const SCHEME: Scheme = Scheme::Sqlite;
+    type Accessor = SqliteBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = SqliteBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("path").map(|v| builder.path(v));
+        map.get("table").map(|v| builder.table(v));
+        map.get("key_field").map(|v| builder.key_field(v));
+        map.get("value_field").map(|v| builder.value_field(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        let path = self.path.take().ok_or_else(|| {
+            Error::new(
+                ErrorKind::BackendConfigInvalid,
+                "path is required but not set",
+            )
+            .with_context("service", Scheme::Sqlite)
+        })?;
+        let table = self
+            .table
+            .take()
+            .unwrap_or_else(|| DEFAULT_SQLITE_TABLE.to_string());
+        let key_field = self
+            .key_field
+            .take()
+            .unwrap_or_else(|| DEFAULT_SQLITE_KEY_FIELD.to_string());
+        let value_field = self
+            .value_field
+            .take()
+            .unwrap_or_else(|| DEFAULT_SQLITE_VALUE_FIELD.to_string());
+
+        let conn = Connection::open(&path).map_err(|e| {
+            Error::new(ErrorKind::BackendConfigInvalid, "open sqlite database")
+                .with_context("service", Scheme::Sqlite)
+                .with_context("path", &path)
+                .set_source(e)
+        })?;
+        conn.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} ({} TEXT PRIMARY KEY NOT NULL, {} BLOB NOT NULL)",
+                quote(&table),
+                quote(&key_field),
+                quote(&value_field)
+            ),
+            [],
+        )
+        .map_err(|e| {
+            Error::new(ErrorKind::BackendConfigInvalid, "create sqlite table")
+                .with_context("service", Scheme::Sqlite)
+                .with_context("path", &path)
+                .with_context("table", &table)
+                .set_source(e)
+        })?;
+
+        let root = normalize_root(
+            self.root
+                .clone()
+                .unwrap_or_else(|| "/".to_string())
+                .as_str(),
+        );
+
+        Ok(SqliteBackend::new(Adapter {
+            conn: Arc::new(Mutex::new(conn)),
+            path,
+            table,
+            key_field,
+            value_field,
+        })
+        .with_root(&root))
+    }
+}
+
+/// Backend for sqlite services.
+pub type SqliteBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    conn: Arc<Mutex<Connection>>,
+    path: String,
+    table: String,
+    key_field: String,
+    value_field: String,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("path", &self.path);
+        ds.field("table", &self.table);
+        ds.field("key_field", &self.key_field);
+        ds.field("value_field", &self.value_field);
+        ds.finish()
+    }
+}
+
+impl Adapter {
+    /// Open the value of given key as a read-only blob.
+    fn blob<'c>(&self, conn: &'c Connection, path: &str) -> Result<Option<Blob<'c>>> {
+        let rowid: Option<i64> = conn
+            .query_row(
+                &format!(
+                    "SELECT rowid FROM {} WHERE {} = ?1",
+                    quote(&self.table),
+                    quote(&self.key_field)
+                ),
+                [path],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        match rowid {
+            Some(rowid) => Ok(Some(conn.blob_open(
+                DatabaseName::Main,
+                &self.table,
+                &self.value_field,
+                rowid,
+                true,
+            )?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl kv::Adapter for Adapter {
+    fn metadata(&self) -> kv::Metadata {
+        kv::Metadata::new(
+            Scheme::Sqlite,
+            &self.path,
+            AccessorCapability::Read
+                | AccessorCapability::Write
+                | AccessorCapability::Batch
+                | AccessorCapability::Blocking,
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        self.blocking_get(path)
+    }
+
+    fn blocking_get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        self.blocking_get_range(path, BytesRange::new(None, None))
+    }
+
+    async fn get_range(&self, path: &str, br: BytesRange) -> Result<Option<Vec<u8>>> {
+        self.blocking_get_range(path, br)
+    }
+
+    fn blocking_get_range(&self, path: &str, br: BytesRange) -> Result<Option<Vec<u8>>> {
+        let conn = self.conn.lock();
+        let blob = match self.blob(&conn, path)? {
+            Some(blob) => blob,
+            None => return Ok(None),
+        };
+
+        let (start, end) = br.to_bounds(blob.len() as u64);
+        let mut bs = vec![0; (end - start) as usize];
+        blob.read_at_exact(&mut bs, start as usize)?;
+
+        Ok(Some(bs))
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        self.blocking_stat(path)
+    }
+
+    fn blocking_stat(&self, path: &str) -> Result<Option<ObjectMetadata>> {
+        let conn = self.conn.lock();
+        Ok(self.blob(&conn, path)?.map(|blob| {
+            ObjectMetadata::new(ObjectMode::FILE).with_content_length(blob.len() as u64)
+        }))
+    }
+
+    async fn set(&self, path: &str, value: &[u8]) -> Result<()> {
+        self.blocking_set(path, value)
+    }
+
+    fn blocking_set(&self, path: &str, value: &[u8]) -> Result<()> {
+        let conn = self.conn.lock();
+        conn.execute(
+            &format!(
+                "INSERT OR REPLACE INTO {} ({}, {}) VALUES (?1, ?2)",
+                quote(&self.table),
+                quote(&self.key_field),
+                quote(&self.value_field)
+            ),
+            params![path, value],
+        )?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.blocking_delete(path)
+    }
+
+    fn blocking_delete(&self, path: &str) -> Result<()> {
+        let conn = self.conn.lock();
+        conn.execute(
+            &format!(
+                "DELETE FROM {} WHERE {} = ?1",
+                quote(&self.table),
+                quote(&self.key_field)
+            ),
+            [path],
+        )?;
+
+        Ok(())
+    }
+
+    async fn batch_delete(&self, paths: &[String]) -> Result<()> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(&format!(
+                "DELETE FROM {} WHERE {} = ?1",
+                quote(&self.table),
+                quote(&self.key_field)
+            ))?;
+            for path in paths {
+                stmt.execute([path])?;
+            }
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+}
+
+/// Quote an identifier so that it can be used in SQL safely.
+fn quote(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Error::new(ErrorKind::Unexpected, "got sqlite error").set_source(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_range_read() -> Result<()> {
+        let mut builder = SqliteBuilder::default();
+        builder.path(&format!(
+            "{}/{}.db",
+            env::temp_dir().display(),
+            uuid::Uuid::new_v4()
+        ));
+        builder.table("my \"objects\"");
+        builder.key_field("k");
+        builder.value_field("v");
+        let op = Operator::create(builder)?.finish();
+
+        let o = op.object("hello");
+        o.blocking_write("Hello, World!")?;
+
+        assert_eq!(o.blocking_metadata()?.content_length(), 13);
+        assert_eq!(o.blocking_read()?, b"Hello, World!");
+        assert_eq!(o.blocking_range_read(7..12)?, b"World");
+        assert_eq!(o.blocking_range_read(7..100)?, b"World!");
+
+        o.blocking_delete()?;
+        assert_eq!(
+            o.blocking_metadata().unwrap_err().kind(),
+            ErrorKind::ObjectNotFound
+        );
+        Ok(())
+    }
+}
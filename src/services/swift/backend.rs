@@ -0,0 +1,1409 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::header::ACCEPT;
+use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_TYPE;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use log::debug;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::dir_stream::DirStream;
+use super::dir_stream::ListEntry;
+use super::dir_stream::LIST_LIMIT;
+use super::error::parse_blocking_error;
+use super::error::parse_error;
+use super::error::parse_json_deserialize_error;
+use super::keystone::Credential;
+use super::keystone::Keystone;
+use crate::ops::*;
+use crate::raw::*;
+use crate::*;
+
+/// OpenStack Swift services support.
+///
+/// # Capabilities
+///
+/// This service can be used to:
+///
+/// - [x] read
+/// - [x] write
+/// - [x] list
+/// - [ ] presign
+/// - [x] multipart
+/// - [x] blocking
+///
+/// # Configuration
+///
+/// - `root`: Set the work directory for backend
+/// - `container`: Set the container name for backend
+/// - `endpoint`: Set the keystone v3 endpoint for backend
+/// - `username`: Set the keystone username
+/// - `password`: Set the keystone password
+/// - `user_domain`: Set the domain of user, default to `Default`
+/// - `project`: Set the project that token scoped to
+/// - `project_domain`: Set the domain of project, default to `Default`
+/// - `region`: Set the region used to pick storage url from catalog
+/// - `token`: Set the pre-obtained token
+/// - `storage_url`: Set the storage url like `https://swift.example.com/v1/AUTH_account`
+/// - `segment_container`: Set the container to store segments of multipart uploads
+///
+/// Either keystone credentials or a pre-obtained `token` with `storage_url`
+/// must be provided.
+///
+/// You can refer to [`SwiftBuilder`]'s docs for more information
+///
+/// # Multipart
+///
+/// Multipart uploads are implemented via [Static Large Object](https://docs.openstack.org/swift/latest/overview_large_objects.html).
+/// Every part will be uploaded as a segment into `segment_container` and
+/// a manifest will be created at the target path after upload completed.
+///
+/// Deleting an object will remove its segments too.
+///
+/// # Example
+///
+/// ## Via Builder
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use opendal::services::Swift;
+/// use opendal::Object;
+/// use opendal::Operator;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     // create backend builder
+///     let mut builder = Swift::default();
+///
+///     // set the container for OpenDAL
+///     builder.container("test");
+///     // set the keystone credential, OpenDAL will issue and refresh
+///     // tokens automatically.
+///     builder.endpoint("https://keystone.example.com/v3");
+///     builder.username("demo");
+///     builder.password("secret");
+///     builder.project("demo");
+///
+///     let op: Operator = Operator::create(builder)?.finish();
+///
+///     // Create an object handle to start operation on object.
+///     let _: Object = op.object("test_file");
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct SwiftBuilder {
+    root: Option<String>,
+    container: Option<String>,
+    endpoint: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+    user_domain: Option<String>,
+    project: Option<String>,
+    project_domain: Option<String>,
+    region: Option<String>,
+    token: Option<String>,
+    storage_url: Option<String>,
+    segment_container: Option<String>,
+    http_client: Option<HttpClient>,
+}
+
+impl Debug for SwiftBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Builder")
+            .field("root", &self.root)
+            .field("container", &self.container)
+            .field("endpoint", &self.endpoint)
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("user_domain", &self.user_domain)
+            .field("project", &self.project)
+            .field("project_domain", &self.project_domain)
+            .field("region", &self.region)
+            .field("token", &"<redacted>")
+            .field("storage_url", &self.storage_url)
+            .field("segment_container", &self.segment_container)
+            .finish()
+    }
+}
+
+impl SwiftBuilder {
+    /// Set root of this backend.
+    ///
+    /// All operations will happen under this root.
+    pub fn root(&mut self, root: &str) -> &mut Self {
+        if !root.is_empty() {
+            self.root = Some(root.to_string())
+        }
+
+        self
+    }
+
+    /// Set container of this backend.
+    /// The param is required.
+    pub fn container(&mut self, container: &str) -> &mut Self {
+        if !container.is_empty() {
+            self.container = Some(container.to_string());
+        }
+
+        self
+    }
+
+    /// Set keystone v3 endpoint of this backend.
+    ///
+    /// For example: `https://keystone.example.com/v3`
+    pub fn endpoint(&mut self, endpoint: &str) -> &mut Self {
+        if !endpoint.is_empty() {
+            self.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+
+    /// Set username used to authenticate with keystone.
+    pub fn username(&mut self, username: &str) -> &mut Self {
+        if !username.is_empty() {
+            self.username = Some(username.to_string());
+        }
+
+        self
+    }
+
+    /// Set password used to authenticate with keystone.
+    pub fn password(&mut self, password: &str) -> &mut Self {
+        if !password.is_empty() {
+            self.password = Some(password.to_string());
+        }
+
+        self
+    }
+
+    /// Set domain name of user.
+    ///
+    /// Default to `Default`.
+    pub fn user_domain(&mut self, user_domain: &str) -> &mut Self {
+        if !user_domain.is_empty() {
+            self.user_domain = Some(user_domain.to_string());
+        }
+
+        self
+    }
+
+    /// Set project name that token scoped to.
+    pub fn project(&mut self, project: &str) -> &mut Self {
+        if !project.is_empty() {
+            self.project = Some(project.to_string());
+        }
+
+        self
+    }
+
+    /// Set domain name of project.
+    ///
+    /// Default to `Default`.
+    pub fn project_domain(&mut self, project_domain: &str) -> &mut Self {
+        if !project_domain.is_empty() {
+            self.project_domain = Some(project_domain.to_string());
+        }
+
+        self
+    }
+
+    /// Set region used to pick the object-store endpoint from catalog.
+    ///
+    /// The first public object-store endpoint will be used if not set.
+    pub fn region(&mut self, region: &str) -> &mut Self {
+        if !region.is_empty() {
+            self.region = Some(region.to_string());
+        }
+
+        self
+    }
+
+    /// Set a pre-obtained token of this backend.
+    ///
+    /// `storage_url` must be set at the same time. Keystone settings will
+    /// be ignored if token is set.
+    pub fn token(&mut self, token: &str) -> &mut Self {
+        if !token.is_empty() {
+            self.token = Some(token.to_string());
+        }
+
+        self
+    }
+
+    /// Set storage url of this backend.
+    ///
+    /// For example: `https://swift.example.com/v1/AUTH_account`
+    ///
+    /// - If token is set, this param is required.
+    /// - If not, it will override the storage url found in keystone catalog.
+    pub fn storage_url(&mut self, storage_url: &str) -> &mut Self {
+        if !storage_url.is_empty() {
+            self.storage_url = Some(storage_url.trim_end_matches('/').to_string());
+        }
+
+        self
+    }
+
+    /// Set the container to store segments of multipart uploads.
+    ///
+    /// Default to `<container>_segments`, which will be created on demand.
+    pub fn segment_container(&mut self, segment_container: &str) -> &mut Self {
+        if !segment_container.is_empty() {
+            self.segment_container = Some(segment_container.to_string());
+        }
+
+        self
+    }
+
+    /// Specify the http client that used by this service.
+    ///
+    /// # Notes
+    ///
+    /// This API is part of OpenDAL's Raw API. `HttpClient` could be changed
+    /// during minor updates.
+    pub fn http_client(&mut self, client: HttpClient) -> &mut Self {
+        self.http_client = Some(client);
+        self
+    }
+}
+
+impl Builder for SwiftBuilder {
+    const SCHEME: Scheme = Scheme::Swift;
+    type Accessor = SwiftBackend;
+
+    fn from_map(map: HashMap<String, String>) -> Self {
+        let mut builder = SwiftBuilder::default();
+
+        map.get("root").map(|v| builder.root(v));
+        map.get("container").map(|v| builder.container(v));
+        map.get("endpoint").map(|v| builder.endpoint(v));
+        map.get("username").map(|v| builder.username(v));
+        map.get("password").map(|v| builder.password(v));
+        map.get("user_domain").map(|v| builder.user_domain(v));
+        map.get("project").map(|v| builder.project(v));
+        map.get("project_domain").map(|v| builder.project_domain(v));
+        map.get("region").map(|v| builder.region(v));
+        map.get("token").map(|v| builder.token(v));
+        map.get("storage_url").map(|v| builder.storage_url(v));
+        map.get("segment_container")
+            .map(|v| builder.segment_container(v));
+
+        builder
+    }
+
+    fn build(&mut self) -> Result<Self::Accessor> {
+        debug!("backend build started: {:?}", &self);
+
+        let root = normalize_root(&self.root.take().unwrap_or_default());
+        debug!("backend use root {}", root);
+
+        let container = match &self.container {
+            Some(container) => Ok(container.to_string()),
+            None => Err(
+                Error::new(ErrorKind::BackendConfigInvalid, "container is empty")
+                    .with_context("service", Scheme::Swift),
+            ),
+        }?;
+        debug!("backend use container {}", &container);
+
+        let segment_container = self
+            .segment_container
+            .take()
+            .unwrap_or_else(|| format!("{container}_segments"));
+        debug!("backend use segment container {}", &segment_container);
+
+        let (keystone, credential) = match &self.token {
+            Some(token) => {
+                let storage_url = self.storage_url.clone().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::BackendConfigInvalid,
+                        "storage_url is required while token is set",
+                    )
+                    .with_context("service", Scheme::Swift)
+                })?;
+
+                let cred = Credential {
+                    token: token.clone(),
+                    storage_url,
+                    expires_at: None,
+                };
+                (None, Some(cred))
+            }
+            None => {
+                let required = |name: &str, v: &Option<String>| {
+                    v.clone().ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::BackendConfigInvalid,
+                            &format!("{name} is empty while token is not set"),
+                        )
+                        .with_context("service", Scheme::Swift)
+                    })
+                };
+
+                let keystone = Keystone {
+                    endpoint: required("endpoint", &self.endpoint)?,
+                    username: required("username", &self.username)?,
+                    password: required("password", &self.password)?,
+                    user_domain: self
+                        .user_domain
+                        .clone()
+                        .unwrap_or_else(|| "Default".to_string()),
+                    project: required("project", &self.project)?,
+                    project_domain: self
+                        .project_domain
+                        .clone()
+                        .unwrap_or_else(|| "Default".to_string()),
+                    region: self.region.clone(),
+                };
+                debug!("backend use keystone endpoint {}", &keystone.endpoint);
+
+                (Some(Arc::new(keystone)), None)
+            }
+        };
+
+        let client = if let Some(client) = self.http_client.take() {
+            client
+        } else {
+            HttpClient::new().map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Swift)
+            })?
+        };
+
+        debug!("backend build finished: {:?}", &self);
+        Ok(SwiftBackend {
+            client,
+            root,
+            container,
+            segment_container,
+            storage_url: self.storage_url.take(),
+            keystone,
+            credential: Arc::new(Mutex::new(credential)),
+        })
+    }
+}
+
+/// Backend for OpenStack Swift services.
+#[derive(Clone)]
+pub struct SwiftBackend {
+    client: HttpClient,
+    root: String,
+    container: String,
+    segment_container: String,
+    storage_url: Option<String>,
+    keystone: Option<Arc<Keystone>>,
+    credential: Arc<Mutex<Option<Credential>>>,
+}
+
+impl Debug for SwiftBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SwiftBackend")
+            .field("root", &self.root)
+            .field("container", &self.container)
+            .field("segment_container", &self.segment_container)
+            .field("storage_url", &self.storage_url)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Accessor for SwiftBackend {
+    type Reader = IncomingAsyncBody;
+    type BlockingReader = IncomingBody;
+    type Pager = DirStream;
+    type BlockingPager = DirStream;
+
+    fn metadata(&self) -> AccessorMetadata {
+        let mut am = AccessorMetadata::default();
+        am.set_scheme(Scheme::Swift)
+            .set_root(&self.root)
+            .set_name(&self.container)
+            .set_capabilities(
+                AccessorCapability::Read
+                    | AccessorCapability::Write
+                    | AccessorCapability::List
+                    | AccessorCapability::Multipart
+                    | AccessorCapability::Blocking,
+            )
+            .set_hints(AccessorHint::ReadIsStreamable);
+
+        am
+    }
+
+    async fn create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let cred = self.credential().await?;
+        let req = self.swift_put_object_request(&cred, path, Some(0), None, AsyncBody::Empty)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let cred = self.credential().await?;
+        let req = self.swift_get_object_request(&cred, path, args.range())?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write(&self, path: &str, args: OpWrite, r: input::Reader) -> Result<RpWrite> {
+        let cred = self.credential().await?;
+        let req = self.swift_put_object_request(
+            &cred,
+            path,
+            Some(args.size()),
+            args.content_type(),
+            AsyncBody::Reader(r),
+        )?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let cred = self.credential().await?;
+        let req = self.swift_head_object_request(&cred, path)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let cred = self.credential().await?;
+        let req = self.swift_delete_object_request(&cred, path)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                parse_bulk_delete_result(bs)?;
+                Ok(RpDelete::default())
+            }
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                resp.into_body().consume().await?;
+                Ok(RpDelete::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Pager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_put_object_request(&cred, path, Some(0), None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_get_object_request(&cred, path, args.range())?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
+        &self,
+        path: &str,
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_put_object_request(
+                &cred,
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_head_object_request(&cred, path)?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            StatusCode::NOT_FOUND if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_delete_object_request(&cred, path)?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes()?;
+                parse_bulk_delete_result(bs)?;
+                Ok(RpDelete::default())
+            }
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                resp.into_body().consume()?;
+                Ok(RpDelete::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        Ok((
+            RpList::default(),
+            DirStream::new(Arc::new(self.clone()), &self.root, path),
+        ))
+    }
+
+    async fn create_multipart(&self, _: &str, _: OpCreateMultipart) -> Result<RpCreateMultipart> {
+        // Swift doesn't have the concept of upload id, we make sure the
+        // segment container exists and generate an id for segments.
+        let cred = self.credential().await?;
+        let req = self.swift_put_container_request(&cred, &self.segment_container)?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::ACCEPTED | StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCreateMultipart::new(&uuid::Uuid::new_v4().to_string()))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::Reader,
+    ) -> Result<RpWriteMultipart> {
+        let cred = self.credential().await?;
+        let req = self.swift_put_segment_request(
+            &cred,
+            path,
+            args.upload_id(),
+            args.part_number(),
+            Some(args.size()),
+            AsyncBody::Reader(r),
+        )?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .trim_matches('"')
+                    .to_string();
+
+                resp.into_body().consume().await?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let cred = self.credential().await?;
+        let req = self.swift_put_manifest_request(&cred, path, args.upload_id(), args.parts())?;
+
+        let resp = self.client.send_async(req).await?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume().await?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    async fn abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let cred = self.credential().await?;
+        let prefix = self.segment_prefix(path, args.upload_id());
+
+        let mut marker = String::new();
+        loop {
+            let req = self.swift_list_objects_request(
+                &cred,
+                &self.segment_container,
+                &prefix,
+                false,
+                &marker,
+            )?;
+            let resp = self.client.send_async(req).await?;
+
+            let entries = match resp.status() {
+                StatusCode::OK => {
+                    let bs = resp.into_body().bytes().await?;
+                    parse_list_entries(bs)?
+                }
+                StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                    resp.into_body().consume().await?;
+                    vec![]
+                }
+                _ => return Err(parse_error(resp).await?),
+            };
+
+            for v in &entries {
+                let req =
+                    self.swift_delete_segment_request(&cred, &self.segment_container, v.name())?;
+                let resp = self.client.send_async(req).await?;
+
+                match resp.status() {
+                    StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                        resp.into_body().consume().await?
+                    }
+                    _ => return Err(parse_error(resp).await?),
+                }
+            }
+
+            if entries.len() < LIST_LIMIT {
+                break;
+            }
+            marker = entries[entries.len() - 1].name().to_string();
+        }
+
+        Ok(RpAbortMultipart::default())
+    }
+
+    fn blocking_create_multipart(
+        &self,
+        _: &str,
+        _: OpCreateMultipart,
+    ) -> Result<RpCreateMultipart> {
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_put_container_request(&cred, &self.segment_container)?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::ACCEPTED | StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+
+                Ok(RpCreateMultipart::new(&uuid::Uuid::new_v4().to_string()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write_multipart(
+        &self,
+        path: &str,
+        args: OpWriteMultipart,
+        r: input::BlockingReader,
+    ) -> Result<RpWriteMultipart> {
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_put_segment_request(
+                &cred,
+                path,
+                args.upload_id(),
+                args.part_number(),
+                Some(args.size()),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                let etag = parse_etag(resp.headers())?
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::Unexpected,
+                            "ETag not present in returning response",
+                        )
+                    })?
+                    .trim_matches('"')
+                    .to_string();
+
+                resp.into_body().consume()?;
+
+                Ok(RpWriteMultipart::new(args.part_number(), &etag))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_complete_multipart(
+        &self,
+        path: &str,
+        args: OpCompleteMultipart,
+    ) -> Result<RpCompleteMultipart> {
+        let cred = self.blocking_credential()?;
+        let (head, body) = self
+            .swift_put_manifest_request(&cred, path, args.upload_id(), args.parts())?
+            .into_parts();
+        let req = Request::from_parts(head, body.into_blocking()?);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+
+                Ok(RpCompleteMultipart::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_abort_multipart(
+        &self,
+        path: &str,
+        args: OpAbortMultipart,
+    ) -> Result<RpAbortMultipart> {
+        let cred = self.blocking_credential()?;
+        let prefix = self.segment_prefix(path, args.upload_id());
+
+        let mut marker = String::new();
+        loop {
+            let req = self
+                .swift_list_objects_request(
+                    &cred,
+                    &self.segment_container,
+                    &prefix,
+                    false,
+                    &marker,
+                )?
+                .map(|_| Body::Empty);
+            let resp = self.client.send(req)?;
+
+            let entries = match resp.status() {
+                StatusCode::OK => {
+                    let bs = resp.into_body().bytes()?;
+                    parse_list_entries(bs)?
+                }
+                StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => {
+                    resp.into_body().consume()?;
+                    vec![]
+                }
+                _ => return Err(parse_blocking_error(resp)?),
+            };
+
+            for v in &entries {
+                let req = self
+                    .swift_delete_segment_request(&cred, &self.segment_container, v.name())?
+                    .map(|_| Body::Empty);
+                let resp = self.client.send(req)?;
+
+                match resp.status() {
+                    StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => resp.into_body().consume()?,
+                    _ => return Err(parse_blocking_error(resp)?),
+                }
+            }
+
+            if entries.len() < LIST_LIMIT {
+                break;
+            }
+            marker = entries[entries.len() - 1].name().to_string();
+        }
+
+        Ok(RpAbortMultipart::default())
+    }
+}
+
+impl SwiftBackend {
+    /// Get a valid credential, issue a new token from keystone if the
+    /// cached one is expired.
+    async fn credential(&self) -> Result<Credential> {
+        if let Some(cred) = self.cached_credential() {
+            return Ok(cred);
+        }
+
+        let keystone = self.keystone()?;
+        let req = keystone.auth_request()?;
+
+        let resp = self.client.send_async(req).await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                let (parts, body) = resp.into_parts();
+                let bs = body.bytes().await?;
+
+                let cred = keystone.parse_auth_response(
+                    &parts.headers,
+                    bs,
+                    self.storage_url.as_deref(),
+                )?;
+                *self.credential.lock() = Some(cred.clone());
+                Ok(cred)
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+
+    fn blocking_credential(&self) -> Result<Credential> {
+        if let Some(cred) = self.cached_credential() {
+            return Ok(cred);
+        }
+
+        let keystone = self.keystone()?;
+        let (head, body) = keystone.auth_request()?.into_parts();
+        let req = Request::from_parts(head, body.into_blocking()?);
+
+        let resp = self.client.send(req)?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => {
+                let (parts, body) = resp.into_parts();
+                let bs = body.bytes()?;
+
+                let cred = keystone.parse_auth_response(
+                    &parts.headers,
+                    bs,
+                    self.storage_url.as_deref(),
+                )?;
+                *self.credential.lock() = Some(cred.clone());
+                Ok(cred)
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn cached_credential(&self) -> Option<Credential> {
+        self.credential
+            .lock()
+            .as_ref()
+            .filter(|v| v.is_valid())
+            .cloned()
+    }
+
+    fn keystone(&self) -> Result<&Keystone> {
+        self.keystone.as_deref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "token is expired and keystone is not configured",
+            )
+            .with_context("service", Scheme::Swift)
+        })
+    }
+
+    /// Prefix of all segments that belong to given upload.
+    fn segment_prefix(&self, path: &str, upload_id: &str) -> String {
+        let p = build_abs_path(&self.root, path);
+
+        format!("{p}/{upload_id}/")
+    }
+
+    fn swift_get_object_request(
+        &self,
+        cred: &Credential,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}",
+            cred.storage_url,
+            percent_encode_path(&self.container),
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::get(&url).header("X-Auth-Token", &cred.token);
+
+        if !range.is_full() {
+            req = req.header(http::header::RANGE, range.to_header())
+        }
+
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn swift_put_object_request(
+        &self,
+        cred: &Credential,
+        path: &str,
+        size: Option<u64>,
+        content_type: Option<&str>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}",
+            cred.storage_url,
+            percent_encode_path(&self.container),
+            percent_encode_path(&p)
+        );
+
+        let mut req = Request::put(&url).header("X-Auth-Token", &cred.token);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size)
+        }
+
+        if let Some(mime) = content_type {
+            req = req.header(CONTENT_TYPE, mime)
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn swift_head_object_request(
+        &self,
+        cred: &Credential,
+        path: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}",
+            cred.storage_url,
+            percent_encode_path(&self.container),
+            percent_encode_path(&p)
+        );
+
+        let req = Request::head(&url)
+            .header("X-Auth-Token", &cred.token)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Delete object with `multipart-manifest=delete` so that segments of
+    /// SLO will be removed too. Swift will delete non-SLO objects as
+    /// usual.
+    fn swift_delete_object_request(
+        &self,
+        cred: &Credential,
+        path: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?multipart-manifest=delete",
+            cred.storage_url,
+            percent_encode_path(&self.container),
+            percent_encode_path(&p)
+        );
+
+        let req = Request::delete(&url)
+            .header("X-Auth-Token", &cred.token)
+            .header(ACCEPT, "application/json")
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn swift_put_container_request(
+        &self,
+        cred: &Credential,
+        container: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let url = format!("{}/{}", cred.storage_url, percent_encode_path(container));
+
+        let req = Request::put(&url)
+            .header("X-Auth-Token", &cred.token)
+            .header(CONTENT_LENGTH, 0)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn swift_put_segment_request(
+        &self,
+        cred: &Credential,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        size: Option<u64>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let key = format!("{}{:08}", self.segment_prefix(path, upload_id), part_number);
+
+        let url = format!(
+            "{}/{}/{}",
+            cred.storage_url,
+            percent_encode_path(&self.segment_container),
+            percent_encode_path(&key)
+        );
+
+        let mut req = Request::put(&url).header("X-Auth-Token", &cred.token);
+
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
+
+        let req = req.body(body).map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn swift_delete_segment_request(
+        &self,
+        cred: &Credential,
+        container: &str,
+        key: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let url = format!(
+            "{}/{}/{}",
+            cred.storage_url,
+            percent_encode_path(container),
+            percent_encode_path(key)
+        );
+
+        let req = Request::delete(&url)
+            .header("X-Auth-Token", &cred.token)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Create the SLO manifest which points to all uploaded segments.
+    ///
+    /// ref: https://docs.openstack.org/swift/latest/api/large_objects.html#static-large-objects
+    fn swift_put_manifest_request(
+        &self,
+        cred: &Credential,
+        path: &str,
+        upload_id: &str,
+        parts: &[ObjectPart],
+    ) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/{}/{}?multipart-manifest=put",
+            cred.storage_url,
+            percent_encode_path(&self.container),
+            percent_encode_path(&p)
+        );
+
+        let prefix = self.segment_prefix(path, upload_id);
+        let content = serde_json::to_vec(
+            &parts
+                .iter()
+                .map(|v| ManifestSegment {
+                    path: format!(
+                        "/{}/{}{:08}",
+                        self.segment_container,
+                        prefix,
+                        v.part_number()
+                    ),
+                    etag: v.etag().to_string(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .map_err(parse_json_deserialize_error)?;
+
+        // Make sure content length has been set to avoid put with chunked encoding.
+        let req = Request::put(&url)
+            .header("X-Auth-Token", &cred.token)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    fn swift_list_objects_request(
+        &self,
+        cred: &Credential,
+        container: &str,
+        prefix: &str,
+        delimiter: bool,
+        marker: &str,
+    ) -> Result<Request<AsyncBody>> {
+        let mut url = format!(
+            "{}/{}?format=json&limit={}",
+            cred.storage_url,
+            percent_encode_path(container),
+            LIST_LIMIT
+        );
+        if delimiter {
+            url.push_str("&delimiter=/");
+        }
+        if !prefix.is_empty() {
+            write!(url, "&prefix={}", percent_encode_path(prefix))
+                .expect("write into string must succeed");
+        }
+        if !marker.is_empty() {
+            write!(url, "&marker={}", percent_encode_path(marker))
+                .expect("write into string must succeed");
+        }
+
+        let req = Request::get(&url)
+            .header("X-Auth-Token", &cred.token)
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    pub(crate) async fn swift_list_objects(
+        &self,
+        path: &str,
+        marker: &str,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let cred = self.credential().await?;
+        let req = self.swift_list_objects_request(&cred, &self.container, &p, true, marker)?;
+
+        self.client.send_async(req).await
+    }
+
+    pub(crate) fn swift_blocking_list_objects(
+        &self,
+        path: &str,
+        marker: &str,
+    ) -> Result<Response<IncomingBody>> {
+        let p = build_abs_path(&self.root, path);
+
+        let cred = self.blocking_credential()?;
+        let req = self
+            .swift_list_objects_request(&cred, &self.container, &p, true, marker)?
+            .map(|_| Body::Empty);
+
+        self.client.send(req)
+    }
+}
+
+fn parse_list_entries(bs: Bytes) -> Result<Vec<ListEntry>> {
+    serde_json::from_slice(&bs).map_err(parse_json_deserialize_error)
+}
+
+/// Check the result of deleting object with `multipart-manifest=delete`.
+///
+/// Swift will always return `200 OK` and put the real status in body.
+fn parse_bulk_delete_result(bs: Bytes) -> Result<()> {
+    let result: BulkDeleteResult =
+        serde_json::from_slice(&bs).map_err(parse_json_deserialize_error)?;
+
+    let status = result.response_status.as_str();
+    if status.starts_with('2') || status.starts_with("404") {
+        return Ok(());
+    }
+
+    let kind = if status.starts_with("401") || status.starts_with("403") {
+        ErrorKind::ObjectPermissionDenied
+    } else {
+        ErrorKind::Unexpected
+    };
+
+    Err(Error::new(kind, &format!("{result:?}")))
+}
+
+/// Segment in SLO manifest.
+#[derive(Default, Debug, Serialize)]
+struct ManifestSegment {
+    path: String,
+    etag: String,
+}
+
+/// Result of bulk delete.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct BulkDeleteResult {
+    #[serde(rename = "Response Status")]
+    response_status: String,
+    #[serde(rename = "Response Body")]
+    response_body: String,
+    #[serde(rename = "Number Deleted")]
+    number_deleted: u64,
+    #[serde(rename = "Number Not Found")]
+    number_not_found: u64,
+    #[serde(rename = "Errors")]
+    errors: Vec<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bulk_delete_result() {
+        let bs = Bytes::from(
+            r#"{"Number Not Found": 0, "Response Status": "200 OK", "Response Body": "", "Errors": [], "Number Deleted": 3}"#,
+        );
+        assert!(parse_bulk_delete_result(bs).is_ok());
+
+        let bs = Bytes::from(
+            r#"{"Number Not Found": 1, "Response Status": "404 Not Found", "Response Body": "", "Errors": [], "Number Deleted": 0}"#,
+        );
+        assert!(parse_bulk_delete_result(bs).is_ok());
+
+        let bs = Bytes::from(
+            r#"{"Number Not Found": 0, "Response Status": "401 Unauthorized", "Response Body": "", "Errors": [["/c/o", "401 Unauthorized"]], "Number Deleted": 0}"#,
+        );
+        let err = parse_bulk_delete_result(bs).expect_err("must fail");
+        assert_eq!(err.kind(), ErrorKind::ObjectPermissionDenied);
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::StatusCode;
+use serde::Deserialize;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use super::backend::SwiftBackend;
+use super::error::parse_blocking_error;
+use super::error::parse_error;
+use super::error::parse_json_deserialize_error;
+use crate::raw::*;
+use crate::Error;
+use crate::ErrorKind;
+use crate::ObjectMetadata;
+use crate::ObjectMode;
+use crate::Result;
+
+/// The max number of entries returned in one container listing.
+pub(super) const LIST_LIMIT: usize = 1000;
+
+pub struct DirStream {
+    backend: Arc<SwiftBackend>,
+    root: String,
+    path: String,
+
+    next_marker: String,
+    done: bool,
+}
+
+impl DirStream {
+    pub fn new(backend: Arc<SwiftBackend>, root: &str, path: &str) -> Self {
+        Self {
+            backend,
+            root: root.to_string(),
+            path: path.to_string(),
+            next_marker: "".to_string(),
+            done: false,
+        }
+    }
+}
+
+#[async_trait]
+impl output::Page for DirStream {
+    async fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .swift_list_objects(&self.path, &self.next_marker)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes().await?;
+                self.parse_page(bs).map(Some)
+            }
+            // Old swift returns `204 No Content` for empty listing.
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume().await?;
+                self.done = true;
+                Ok(Some(vec![]))
+            }
+            _ => Err(parse_error(resp).await?),
+        }
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let resp = self
+            .backend
+            .swift_blocking_list_objects(&self.path, &self.next_marker)?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let bs = resp.into_body().bytes()?;
+                self.parse_page(bs).map(Some)
+            }
+            StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+                self.done = true;
+                Ok(Some(vec![]))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+}
+
+impl DirStream {
+    fn parse_page(&mut self, bs: Bytes) -> Result<Vec<output::Entry>> {
+        let output: Vec<ListEntry> =
+            serde_json::from_slice(&bs).map_err(parse_json_deserialize_error)?;
+
+        // Swift doesn't tell us whether there are more entries, the listing
+        // is done if we got less entries than requested.
+        self.done = output.len() < LIST_LIMIT;
+
+        let last_marker = std::mem::take(&mut self.next_marker);
+        if let Some(v) = output.last() {
+            self.next_marker = v.name().to_string();
+        }
+
+        let mut entries = Vec::with_capacity(output.len());
+
+        for v in output {
+            match v {
+                ListEntry::Subdir { subdir } => {
+                    // Listing with marker equals to a subdir could return
+                    // this subdir again.
+                    if subdir == last_marker {
+                        continue;
+                    }
+
+                    let de = output::Entry::new(
+                        &build_rel_path(&self.root, &subdir),
+                        ObjectMetadata::new(ObjectMode::DIR).with_complete(),
+                    );
+                    entries.push(de);
+                }
+                ListEntry::Object {
+                    name,
+                    bytes,
+                    hash,
+                    last_modified,
+                    content_type,
+                } => {
+                    // The dir itself will be returned while listing it.
+                    if name.ends_with('/') {
+                        continue;
+                    }
+
+                    let mut meta = ObjectMetadata::new(ObjectMode::FILE)
+                        .with_content_length(bytes)
+                        .with_etag(&hash);
+                    if !content_type.is_empty() {
+                        meta.set_content_type(&content_type);
+                    }
+                    if !last_modified.is_empty() {
+                        meta.set_last_modified(parse_last_modified(&last_modified)?);
+                    }
+
+                    let de = output::Entry::new(&build_rel_path(&self.root, &name), meta);
+                    entries.push(de);
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Swift returns `last_modified` like `2023-02-10T12:00:00.123456` which
+/// is in UTC but without the timezone suffix.
+fn parse_last_modified(s: &str) -> Result<OffsetDateTime> {
+    let v = if s.ends_with('Z') {
+        s.to_string()
+    } else {
+        format!("{s}Z")
+    };
+
+    OffsetDateTime::parse(&v, &Rfc3339).map_err(|e| {
+        Error::new(ErrorKind::Unexpected, "parse last modified of swift object").set_source(e)
+    })
+}
+
+/// Entry of container listing in json format.
+///
+/// ref: https://docs.openstack.org/api-ref/object-store/#show-container-details-and-list-objects
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum ListEntry {
+    Subdir {
+        subdir: String,
+    },
+    Object {
+        name: String,
+        #[serde(default)]
+        bytes: u64,
+        #[serde(default)]
+        hash: String,
+        #[serde(default)]
+        last_modified: String,
+        #[serde(default)]
+        content_type: String,
+    },
+}
+
+impl ListEntry {
+    pub(super) fn name(&self) -> &str {
+        match self {
+            ListEntry::Subdir { subdir } => subdir,
+            ListEntry::Object { name, .. } => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json() {
+        let bs = Bytes::from(
+            r#"[
+  {"subdir": "dir/sub/"},
+  {
+    "hash": "d41d8cd98f00b204e9800998ecf8427e",
+    "last_modified": "2023-02-10T12:00:00.123456",
+    "bytes": 0,
+    "name": "dir/",
+    "content_type": "application/octet-stream"
+  },
+  {
+    "hash": "451e372e48e0f6b1114fa0724aa79fa1",
+    "last_modified": "2023-02-10T12:00:00.000000",
+    "bytes": 14,
+    "name": "dir/hello.txt",
+    "content_type": "text/plain"
+  }
+]"#,
+        );
+
+        let out: Vec<ListEntry> = serde_json::from_slice(&bs).expect("must success");
+        assert_eq!(out.len(), 3);
+        assert_eq!(out[0].name(), "dir/sub/");
+        assert!(matches!(out[0], ListEntry::Subdir { .. }));
+        match &out[2] {
+            ListEntry::Object {
+                name, bytes, hash, ..
+            } => {
+                assert_eq!(name, "dir/hello.txt");
+                assert_eq!(*bytes, 14);
+                assert_eq!(hash, "451e372e48e0f6b1114fa0724aa79fa1");
+            }
+            v => panic!("unexpected entry: {v:?}"),
+        }
+
+        assert_eq!(
+            parse_last_modified("2023-02-10T12:00:00.000000")
+                .expect("must success")
+                .unix_timestamp(),
+            1676030400
+        );
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use http::header::CONTENT_LENGTH;
+use http::header::CONTENT_TYPE;
+use http::HeaderMap;
+use http::Request;
+use serde::Deserialize;
+use serde::Serialize;
+use time::format_description::well_known::Rfc3339;
+use time::Duration;
+use time::OffsetDateTime;
+
+use super::error::parse_json_deserialize_error;
+use crate::raw::*;
+use crate::*;
+
+/// Credential is the token and storage url used to access swift.
+#[derive(Clone)]
+pub struct Credential {
+    pub token: String,
+    pub storage_url: String,
+    /// Pre-obtained tokens are treated as never expired.
+    pub expires_at: Option<OffsetDateTime>,
+}
+
+impl Credential {
+    /// Check if this credential is still valid.
+    ///
+    /// We will refresh the token 2 minutes before it's expired to avoid
+    /// sending requests with an about-to-expire token.
+    pub fn is_valid(&self) -> bool {
+        match self.expires_at {
+            None => true,
+            Some(t) => t > OffsetDateTime::now_utc() + Duration::minutes(2),
+        }
+    }
+}
+
+/// Keystone v3 password authentication settings.
+#[derive(Clone)]
+pub struct Keystone {
+    /// Keystone endpoint like `https://keystone.example.com/v3`.
+    pub endpoint: String,
+    pub username: String,
+    pub password: String,
+    pub user_domain: String,
+    pub project: String,
+    pub project_domain: String,
+    /// Region used to pick the object-store endpoint from catalog.
+    pub region: Option<String>,
+}
+
+impl Keystone {
+    /// Build the request to issue a project scoped token.
+    ///
+    /// ref: https://docs.openstack.org/api-ref/identity/v3/#password-authentication-with-scoped-authorization
+    pub fn auth_request(&self) -> Result<Request<AsyncBody>> {
+        let url = format!("{}/auth/tokens", self.endpoint);
+
+        let content = serde_json::to_vec(&AuthRequest {
+            auth: AuthRequestAuth {
+                identity: AuthRequestIdentity {
+                    methods: vec!["password"],
+                    password: AuthRequestPassword {
+                        user: AuthRequestUser {
+                            name: &self.username,
+                            domain: AuthRequestDomain {
+                                name: &self.user_domain,
+                            },
+                            password: &self.password,
+                        },
+                    },
+                },
+                scope: AuthRequestScope {
+                    project: AuthRequestProject {
+                        name: &self.project,
+                        domain: AuthRequestDomain {
+                            name: &self.project_domain,
+                        },
+                    },
+                },
+            },
+        })
+        .map_err(parse_json_deserialize_error)?;
+
+        let req = Request::post(&url)
+            .header(CONTENT_LENGTH, content.len())
+            .header(CONTENT_TYPE, "application/json")
+            .body(AsyncBody::Bytes(Bytes::from(content)))
+            .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    /// Parse the token from `X-Subject-Token` header and the storage url
+    /// from the service catalog.
+    ///
+    /// If `storage_url` is set by user, we will use it instead of the one
+    /// in catalog.
+    pub fn parse_auth_response(
+        &self,
+        headers: &HeaderMap,
+        bs: Bytes,
+        storage_url: Option<&str>,
+    ) -> Result<Credential> {
+        let token = headers
+            .get("X-Subject-Token")
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "X-Subject-Token not present in keystone response",
+                )
+            })?
+            .to_str()
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::Unexpected,
+                    "header value is not valid utf-8 string",
+                )
+                .set_source(e)
+            })?
+            .to_string();
+
+        let resp: AuthResponse =
+            serde_json::from_slice(&bs).map_err(parse_json_deserialize_error)?;
+
+        let expires_at = OffsetDateTime::parse(&resp.token.expires_at, &Rfc3339).map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "parse token expires_at").set_source(e)
+        })?;
+
+        let storage_url = match storage_url {
+            Some(v) => v.to_string(),
+            None => resp
+                .token
+                .catalog
+                .iter()
+                .filter(|v| v.kind == "object-store")
+                .flat_map(|v| v.endpoints.iter())
+                .find(|v| {
+                    v.interface == "public"
+                        && self
+                            .region
+                            .as_ref()
+                            .map_or(true, |r| v.region_id.as_ref() == Some(r))
+                })
+                .map(|v| v.url.trim_end_matches('/').to_string())
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "object-store endpoint not found in keystone catalog",
+                    )
+                    .with_context("region", self.region.as_deref().unwrap_or_default())
+                })?,
+        };
+
+        Ok(Credential {
+            token,
+            storage_url,
+            expires_at: Some(expires_at),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct AuthRequest<'a> {
+    auth: AuthRequestAuth<'a>,
+}
+
+#[derive(Serialize)]
+struct AuthRequestAuth<'a> {
+    identity: AuthRequestIdentity<'a>,
+    scope: AuthRequestScope<'a>,
+}
+
+#[derive(Serialize)]
+struct AuthRequestIdentity<'a> {
+    methods: Vec<&'a str>,
+    password: AuthRequestPassword<'a>,
+}
+
+#[derive(Serialize)]
+struct AuthRequestPassword<'a> {
+    user: AuthRequestUser<'a>,
+}
+
+#[derive(Serialize)]
+struct AuthRequestUser<'a> {
+    name: &'a str,
+    domain: AuthRequestDomain<'a>,
+    password: &'a str,
+}
+
+#[derive(Serialize)]
+struct AuthRequestScope<'a> {
+    project: AuthRequestProject<'a>,
+}
+
+#[derive(Serialize)]
+struct AuthRequestProject<'a> {
+    name: &'a str,
+    domain: AuthRequestDomain<'a>,
+}
+
+#[derive(Serialize)]
+struct AuthRequestDomain<'a> {
+    name: &'a str,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct AuthResponse {
+    token: AuthResponseToken,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct AuthResponseToken {
+    expires_at: String,
+    catalog: Vec<CatalogEntry>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct CatalogEntry {
+    #[serde(rename = "type")]
+    kind: String,
+    endpoints: Vec<CatalogEndpoint>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+struct CatalogEndpoint {
+    interface: String,
+    region_id: Option<String>,
+    url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    fn keystone(region: Option<&str>) -> Keystone {
+        Keystone {
+            endpoint: "http://127.0.0.1:5000/v3".to_string(),
+            username: "demo".to_string(),
+            password: "secret".to_string(),
+            user_domain: "Default".to_string(),
+            project: "demo".to_string(),
+            project_domain: "Default".to_string(),
+            region: region.map(|v| v.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_auth_response() {
+        let bs = Bytes::from(
+            r#"{
+  "token": {
+    "methods": ["password"],
+    "expires_at": "2023-02-10T12:00:00.000000Z",
+    "catalog": [
+      {
+        "type": "identity",
+        "name": "keystone",
+        "endpoints": [
+          {"interface": "public", "region_id": "RegionOne", "url": "http://127.0.0.1:5000/v3"}
+        ]
+      },
+      {
+        "type": "object-store",
+        "name": "swift",
+        "endpoints": [
+          {"interface": "internal", "region_id": "RegionOne", "url": "http://10.0.0.1:8080/v1/AUTH_demo"},
+          {"interface": "public", "region_id": "RegionOne", "url": "http://127.0.0.1:8080/v1/AUTH_demo"},
+          {"interface": "public", "region_id": "RegionTwo", "url": "http://127.0.0.2:8080/v1/AUTH_demo/"}
+        ]
+      }
+    ]
+  }
+}"#,
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Subject-Token", HeaderValue::from_static("gAAAAABj"));
+
+        let cred = keystone(None)
+            .parse_auth_response(&headers, bs.clone(), None)
+            .expect("must success");
+        assert_eq!(cred.token, "gAAAAABj");
+        assert_eq!(cred.storage_url, "http://127.0.0.1:8080/v1/AUTH_demo");
+        assert_eq!(
+            cred.expires_at.map(|v| v.unix_timestamp()),
+            Some(1676030400)
+        );
+
+        let cred = keystone(Some("RegionTwo"))
+            .parse_auth_response(&headers, bs.clone(), None)
+            .expect("must success");
+        assert_eq!(cred.storage_url, "http://127.0.0.2:8080/v1/AUTH_demo");
+
+        let cred = keystone(None)
+            .parse_auth_response(&headers, bs.clone(), Some("http://swift/v1/AUTH_x"))
+            .expect("must success");
+        assert_eq!(cred.storage_url, "http://swift/v1/AUTH_x");
+
+        assert!(keystone(Some("RegionThree"))
+            .parse_auth_response(&headers, bs, None)
+            .is_err());
+    }
+}
@@ -24,6 +24,7 @@ use http::Response;
 use http::StatusCode;
 use log::debug;
 
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use crate::ops::*;
 use crate::raw::*;
@@ -40,7 +41,7 @@ use crate::*;
 /// - [ ] list
 /// - [ ] ~~presign~~
 /// - [ ] ~~multipart~~
-/// - [ ] blocking
+/// - [x] blocking
 ///
 /// # Notes
 ///
@@ -201,7 +202,7 @@ impl Debug for WebdavBackend {
 #[async_trait]
 impl Accessor for WebdavBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = ();
     type BlockingPager = ();
 
@@ -209,7 +210,9 @@ impl Accessor for WebdavBackend {
         let mut ma = AccessorMetadata::default();
         ma.set_scheme(Scheme::Webdav)
             .set_root(&self.root)
-            .set_capabilities(AccessorCapability::Read | AccessorCapability::Write)
+            .set_capabilities(
+                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::Blocking,
+            )
             .set_hints(AccessorHint::ReadIsStreamable);
 
         ma
@@ -302,14 +305,115 @@ impl Accessor for WebdavBackend {
             _ => Err(parse_error(resp).await?),
         }
     }
-}
 
-impl WebdavBackend {
-    async fn webdav_get(
+    fn blocking_create(&self, path: &str, _: OpCreate) -> Result<RpCreate> {
+        let req = self
+            .webdav_put_request(path, Some(0), None, AsyncBody::Empty)?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED
+            | StatusCode::OK
+            // create existing dir will retrun conflict
+            | StatusCode::CONFLICT
+            // create existing file will return no_content
+            | StatusCode::NO_CONTENT => {
+                resp.into_body().consume()?;
+                Ok(RpCreate::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let req = self
+            .webdav_get_request(path, args.range())?
+            .map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
         &self,
         path: &str,
-        range: BytesRange,
-    ) -> Result<Response<IncomingAsyncBody>> {
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let req = self
+            .webdav_put_request(
+                path,
+                Some(args.size()),
+                args.content_type(),
+                AsyncBody::Empty,
+            )?
+            .map(|_| Body::Reader(r));
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::new(args.size()))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // Stat root always returns a DIR.
+        if path == "/" {
+            return Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)));
+        }
+
+        let req = self.webdav_head_request(path)?.map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::OK => parse_into_object_metadata(path, resp.headers()).map(RpStat::new),
+            // HTTP Server like nginx could return FORBIDDEN if auto-index
+            // is not enabled, we should ignore them.
+            StatusCode::NOT_FOUND | StatusCode::FORBIDDEN if path.ends_with('/') => {
+                Ok(RpStat::new(ObjectMetadata::new(ObjectMode::DIR)))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let req = self.webdav_delete_request(path)?.map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::NO_CONTENT | StatusCode::NOT_FOUND => Ok(RpDelete::default()),
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+}
+
+impl WebdavBackend {
+    fn webdav_get_request(&self, path: &str, range: BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let url = format!("{}{}", self.endpoint, percent_encode_path(&p));
@@ -324,16 +428,16 @@ impl WebdavBackend {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.client.send_async(req).await
+        Ok(req)
     }
 
-    async fn webdav_put(
+    fn webdav_put_request(
         &self,
         path: &str,
         size: Option<u64>,
         content_type: Option<&str>,
         body: AsyncBody,
-    ) -> Result<Response<IncomingAsyncBody>> {
+    ) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
@@ -351,10 +455,10 @@ impl WebdavBackend {
         // Set body
         let req = req.body(body).map_err(new_request_build_error)?;
 
-        self.client.send_async(req).await
+        Ok(req)
     }
 
-    async fn webdav_head(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn webdav_head_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_rooted_abs_path(&self.root, path);
 
         let url = format!("{}{}", self.endpoint, percent_encode_path(&p));
@@ -365,10 +469,10 @@ impl WebdavBackend {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
-        self.client.send_async(req).await
+        Ok(req)
     }
 
-    async fn webdav_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+    fn webdav_delete_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
 
         let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
@@ -377,6 +481,40 @@ impl WebdavBackend {
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
 
+        Ok(req)
+    }
+
+    async fn webdav_get(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.webdav_get_request(path, range)?;
+
+        self.client.send_async(req).await
+    }
+
+    async fn webdav_put(
+        &self,
+        path: &str,
+        size: Option<u64>,
+        content_type: Option<&str>,
+        body: AsyncBody,
+    ) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.webdav_put_request(path, size, content_type, body)?;
+
+        self.client.send_async(req).await
+    }
+
+    async fn webdav_head(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.webdav_head_request(path)?;
+
+        self.client.send_async(req).await
+    }
+
+    async fn webdav_delete(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.webdav_delete_request(path)?;
+
         self.client.send_async(req).await
     }
 }
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use bytes::Buf;
+use bytes::Bytes;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::response::Parts;
@@ -27,8 +28,10 @@ use log::error;
 use tokio::sync::OnceCell;
 
 use super::dir_stream::DirStream;
+use super::error::parse_blocking_error;
 use super::error::parse_error;
 use super::message::BooleanResp;
+use super::message::FileStatus;
 use super::message::FileStatusType;
 use super::message::FileStatusWrapper;
 use super::message::FileStatusesWrapper;
@@ -55,7 +58,7 @@ const WEBHDFS_DEFAULT_ENDPOINT: &str = "http://127.0.0.1:9870";
 /// - [x] list
 /// - [ ] ~~presign~~
 /// - [ ] ~~multipart~~
-/// - [ ] blocking
+/// - [x] blocking
 ///
 /// # Differences with hdfs
 ///
@@ -249,13 +252,7 @@ pub struct WebhdfsBackend {
 
 impl WebhdfsBackend {
     // create object or make a directory
-    async fn webhdfs_create_object_req(
-        &self,
-        path: &str,
-        size: Option<u64>,
-        content_type: Option<&str>,
-        body: AsyncBody,
-    ) -> Result<Request<AsyncBody>> {
+    fn webhdfs_create_object_request(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
         let op = if path.ends_with('/') {
             "MKDIRS"
@@ -275,6 +272,19 @@ impl WebhdfsBackend {
         let req = Request::put(&url)
             .body(AsyncBody::Empty)
             .map_err(new_request_build_error)?;
+
+        Ok(req)
+    }
+
+    // create object or make a directory
+    async fn webhdfs_create_object_req(
+        &self,
+        path: &str,
+        size: Option<u64>,
+        content_type: Option<&str>,
+        body: AsyncBody,
+    ) -> Result<Request<AsyncBody>> {
+        let req = self.webhdfs_create_object_request(path)?;
         // mkdir does not redirect
         if path.ends_with('/') {
             return Ok(req);
@@ -286,7 +296,31 @@ impl WebhdfsBackend {
             .await
     }
 
-    async fn webhdfs_open_req(&self, path: &str, range: &BytesRange) -> Result<Request<AsyncBody>> {
+    // create object or make a directory in blocking way
+    fn webhdfs_blocking_create_object_req(
+        &self,
+        path: &str,
+        size: Option<u64>,
+        content_type: Option<&str>,
+        body: Body,
+    ) -> Result<Request<Body>> {
+        let req = self
+            .webhdfs_create_object_request(path)?
+            .map(|_| Body::Empty);
+        // mkdir does not redirect
+        if path.ends_with('/') {
+            return Ok(req);
+        }
+
+        let resp = self.client.send(req)?;
+
+        self.webhdfs_blocking_put_redirect(resp, size, content_type, body)
+    }
+
+    /// Build open request with a webhdfs compatible bytes range.
+    ///
+    /// Range that reads from end must be converted before calling this.
+    fn webhdfs_open_request(&self, path: &str, range: &BytesRange) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
             "{}/webhdfs/v1/{}?op=OPEN&noredirect=true",
@@ -297,22 +331,6 @@ impl WebhdfsBackend {
             url += format!("&{auth}").as_str();
         }
 
-        // make a Webhdfs compatible bytes range
-        //
-        // Webhdfs does not support read from end
-        // have to solve manually
-        let range = match (range.offset(), range.size()) {
-            // avoiding reading the whole file
-            (None, Some(size)) => {
-                debug!("converting bytes range to webhdfs compatible");
-                let status = self.stat(path, OpStat::default()).await?;
-                let total_size = status.into_metadata().content_length();
-                let offset = total_size - size;
-                BytesRange::new(Some(offset), Some(size))
-            }
-            _ => *range,
-        };
-
         let (offset, size) = (range.offset(), range.size());
 
         match (offset, size) {
@@ -338,6 +356,50 @@ impl WebhdfsBackend {
         Ok(req)
     }
 
+    async fn webhdfs_open_req(&self, path: &str, range: &BytesRange) -> Result<Request<AsyncBody>> {
+        // make a Webhdfs compatible bytes range
+        //
+        // Webhdfs does not support read from end
+        // have to solve manually
+        let range = match (range.offset(), range.size()) {
+            // avoiding reading the whole file
+            (None, Some(size)) => {
+                debug!("converting bytes range to webhdfs compatible");
+                let status = self.stat(path, OpStat::default()).await?;
+                let total_size = status.into_metadata().content_length();
+                let offset = total_size - size;
+                BytesRange::new(Some(offset), Some(size))
+            }
+            _ => *range,
+        };
+
+        self.webhdfs_open_request(path, &range)
+    }
+
+    fn webhdfs_blocking_open_req(
+        &self,
+        path: &str,
+        range: &BytesRange,
+    ) -> Result<Request<AsyncBody>> {
+        // make a Webhdfs compatible bytes range
+        //
+        // Webhdfs does not support read from end
+        // have to solve manually
+        let range = match (range.offset(), range.size()) {
+            // avoiding reading the whole file
+            (None, Some(size)) => {
+                debug!("converting bytes range to webhdfs compatible");
+                let status = self.blocking_stat(path, OpStat::default())?;
+                let total_size = status.into_metadata().content_length();
+                let offset = total_size - size;
+                BytesRange::new(Some(offset), Some(size))
+            }
+            _ => *range,
+        };
+
+        self.webhdfs_open_request(path, &range)
+    }
+
     fn webhdfs_list_status_req(&self, path: &str) -> Result<Request<AsyncBody>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
@@ -355,6 +417,43 @@ impl WebhdfsBackend {
             .map_err(new_request_build_error)?;
         Ok(req)
     }
+
+    fn webhdfs_status_object_req(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=GETFILESTATUS",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+        debug!("webhdfs status url: {}", url);
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::get(&url);
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+        Ok(req)
+    }
+
+    fn webhdfs_delete_object_req(&self, path: &str) -> Result<Request<AsyncBody>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=DELETE&recursive=false",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::delete(&url);
+        let req = req
+            .body(AsyncBody::Empty)
+            .map_err(new_request_build_error)?;
+        Ok(req)
+    }
 }
 
 impl WebhdfsBackend {
@@ -381,45 +480,52 @@ impl WebhdfsBackend {
         self.client.send_async(redirected).await
     }
 
-    async fn webhdfs_status_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
-        let p = build_abs_path(&self.root, path);
-        let mut url = format!(
-            "{}/webhdfs/v1/{}?op=GETFILESTATUS",
-            self.endpoint,
-            percent_encode_path(&p),
-        );
-        debug!("webhdfs status url: {}", url);
-        if let Some(auth) = &self.auth {
-            url += format!("&{auth}").as_str();
+    /// get object from webhdfs in blocking way
+    fn webhdfs_blocking_get_object(
+        &self,
+        path: &str,
+        range: BytesRange,
+    ) -> Result<Response<IncomingBody>> {
+        let req = self
+            .webhdfs_blocking_open_req(path, &range)?
+            .map(|_| Body::Empty);
+        let resp = self.client.send(req)?;
+
+        // this should be an 200 OK http response
+        // with JSON redirect message in its body
+        if resp.status() != StatusCode::OK {
+            // let the outside handle this error
+            return Ok(resp);
         }
 
-        let req = Request::get(&url);
-        let req = req
-            .body(AsyncBody::Empty)
-            .map_err(new_request_build_error)?;
+        let redirected = self.webhdfs_blocking_get_redirect(resp)?;
+        self.client.send(redirected)
+    }
+
+    async fn webhdfs_status_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.webhdfs_status_object_req(path)?;
 
         self.client.send_async(req).await
     }
 
-    async fn webhdfs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
-        let p = build_abs_path(&self.root, path);
-        let mut url = format!(
-            "{}/webhdfs/v1/{}?op=DELETE&recursive=false",
-            self.endpoint,
-            percent_encode_path(&p),
-        );
-        if let Some(auth) = &self.auth {
-            url += format!("&{auth}").as_str();
-        }
+    fn webhdfs_blocking_status_object(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let req = self.webhdfs_status_object_req(path)?.map(|_| Body::Empty);
 
-        let req = Request::delete(&url);
-        let req = req
-            .body(AsyncBody::Empty)
-            .map_err(new_request_build_error)?;
+        self.client.send(req)
+    }
+
+    async fn webhdfs_delete_object(&self, path: &str) -> Result<Response<IncomingAsyncBody>> {
+        let req = self.webhdfs_delete_object_req(path)?;
 
         self.client.send_async(req).await
     }
 
+    fn webhdfs_blocking_delete_object(&self, path: &str) -> Result<Response<IncomingBody>> {
+        let req = self.webhdfs_delete_object_req(path)?.map(|_| Body::Empty);
+
+        self.client.send(req)
+    }
+
     /// get redirect destination from 307 TEMPORARY_REDIRECT http response
     async fn follow_redirect(&self, resp: Response<IncomingAsyncBody>) -> Result<String> {
         let bs = resp.into_body().bytes().await.map_err(|e| {
@@ -427,16 +533,19 @@ impl WebhdfsBackend {
                 .with_context("service", Scheme::Webhdfs)
                 .set_source(e)
         })?;
-        let loc = serde_json::from_reader::<_, Redirection>(bs.reader())
-            .map_err(|e| {
-                Error::new(ErrorKind::Unexpected, "redirection fail")
-                    .with_context("service", Scheme::Webhdfs)
-                    .set_permanent()
-                    .set_source(e)
-            })?
-            .location;
 
-        Ok(loc)
+        parse_redirection(bs)
+    }
+
+    /// get redirect destination from 307 TEMPORARY_REDIRECT http response
+    fn blocking_follow_redirect(&self, resp: Response<IncomingBody>) -> Result<String> {
+        let bs = resp.into_body().bytes().map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "redirection receive fail")
+                .with_context("service", Scheme::Webhdfs)
+                .set_source(e)
+        })?;
+
+        parse_redirection(bs)
     }
 }
 
@@ -452,6 +561,17 @@ impl WebhdfsBackend {
             .map_err(new_request_build_error)
     }
 
+    fn webhdfs_blocking_get_redirect(
+        &self,
+        redirection: Response<IncomingBody>,
+    ) -> Result<Request<Body>> {
+        let redirect = self.blocking_follow_redirect(redirection)?;
+
+        Request::get(redirect)
+            .body(Body::Empty)
+            .map_err(new_request_build_error)
+    }
+
     async fn webhdfs_put_redirect(
         &self,
         resp: Response<IncomingAsyncBody>,
@@ -461,14 +581,19 @@ impl WebhdfsBackend {
     ) -> Result<Request<AsyncBody>> {
         let redirect = self.follow_redirect(resp).await?;
 
-        let mut req = Request::put(redirect);
-        if let Some(size) = size {
-            req = req.header(CONTENT_LENGTH, size.to_string());
-        }
-        if let Some(content_type) = content_type {
-            req = req.header(CONTENT_TYPE, content_type);
-        }
-        req.body(body).map_err(new_request_build_error)
+        build_put_redirect_request(redirect, size, content_type, body)
+    }
+
+    fn webhdfs_blocking_put_redirect(
+        &self,
+        resp: Response<IncomingBody>,
+        size: Option<u64>,
+        content_type: Option<&str>,
+        body: Body,
+    ) -> Result<Request<Body>> {
+        let redirect = self.blocking_follow_redirect(resp)?;
+
+        build_put_redirect_request(redirect, size, content_type, body)
     }
 
     fn consume_success_mkdir(&self, path: &str, parts: Parts, body: &str) -> Result<RpCreate> {
@@ -497,28 +622,7 @@ impl WebhdfsBackend {
         match resp.status() {
             StatusCode::OK => {
                 let body_bs = resp.into_body().bytes().await?;
-
-                let file_status = serde_json::from_reader::<_, FileStatusWrapper>(body_bs.reader())
-                    .map_err(|e| {
-                        Error::new(ErrorKind::Unexpected, "cannot parse returned json")
-                            .with_context("service", Scheme::Webhdfs)
-                            .set_source(e)
-                    })?
-                    .file_status;
-
-                match file_status.ty {
-                    FileStatusType::File => {
-                        error!("working directory is occupied!");
-                        return Err(Error::new(
-                            ErrorKind::BackendConfigInvalid,
-                            "root is occupied!",
-                        )
-                        .with_context("service", Scheme::Webhdfs));
-                    }
-                    FileStatusType::Directory => {
-                        debug!("working directory exists, do nothing");
-                    }
-                }
+                check_root_status(body_bs)?;
             }
 
             StatusCode::NOT_FOUND => {
@@ -531,36 +635,50 @@ impl WebhdfsBackend {
         debug!("working directory is ready!");
         Ok(())
     }
+
+    fn blocking_check_root(&self) -> Result<()> {
+        let resp = self.webhdfs_blocking_status_object("/")?;
+        match resp.status() {
+            StatusCode::OK => {
+                let body_bs = resp.into_body().bytes()?;
+                check_root_status(body_bs)?;
+            }
+
+            StatusCode::NOT_FOUND => {
+                debug!("working directory does not exists, creating...");
+                self.blocking_create("/", OpCreate::new(ObjectMode::DIR))?;
+            }
+
+            _ => return Err(parse_blocking_error(resp)?),
+        }
+        debug!("working directory is ready!");
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl Accessor for WebhdfsBackend {
     type Reader = IncomingAsyncBody;
-    type BlockingReader = ();
+    type BlockingReader = IncomingBody;
     type Pager = DirStream;
-    type BlockingPager = ();
+    type BlockingPager = DirStream;
 
     fn metadata(&self) -> AccessorMetadata {
         let mut am = AccessorMetadata::default();
         am.set_scheme(Scheme::Webhdfs)
             .set_root(&self.root)
             .set_capabilities(
-                AccessorCapability::Read | AccessorCapability::Write | AccessorCapability::List,
+                AccessorCapability::Read
+                    | AccessorCapability::Write
+                    | AccessorCapability::List
+                    | AccessorCapability::Blocking,
             );
         am
     }
 
     /// Create a file or directory
     async fn create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
-        // if the path ends with '/', it will be treated as a directory
-        // otherwise, it will be treated as a file
-        let path = if args.mode().is_file() && path.ends_with('/') {
-            path.trim_end_matches('/').to_owned()
-        } else if args.mode().is_dir() && !path.ends_with('/') {
-            path.to_owned() + "/"
-        } else {
-            path.to_owned()
-        };
+        let path = normalize_create_path(path, &args);
 
         let req = self
             .webhdfs_create_object_req(&path, Some(0), None, AsyncBody::Empty)
@@ -639,24 +757,10 @@ impl Accessor for WebhdfsBackend {
         match status {
             StatusCode::OK => {
                 debug!("stat object: {} ok", path);
-                let mut meta = parse_into_object_metadata(path, resp.headers())?;
+                let meta = parse_into_object_metadata(path, resp.headers())?;
                 let body_bs = resp.into_body().bytes().await?;
 
-                let file_status = serde_json::from_reader::<_, FileStatusWrapper>(body_bs.reader())
-                    .map_err(|e| {
-                        Error::new(ErrorKind::Unexpected, "cannot parse returned json")
-                            .with_context("service", Scheme::Webhdfs)
-                            .set_source(e)
-                    })?
-                    .file_status;
-                debug!("file status: {:?}", file_status);
-                let status_meta: ObjectMetadata = file_status.try_into()?;
-
-                // is ok to unwrap here
-                // all metadata field of status meta is present and checked by `TryFrom`
-                meta.set_last_modified(status_meta.last_modified().unwrap())
-                    .set_content_length(status_meta.content_length());
-                Ok(RpStat::new(meta))
+                parse_file_status(meta, body_bs).map(RpStat::new)
             }
 
             _ => Err(parse_error(resp).await?),
@@ -682,15 +786,7 @@ impl Accessor for WebhdfsBackend {
         match resp.status() {
             StatusCode::OK => {
                 let body_bs = resp.into_body().bytes().await?;
-                let file_statuses =
-                    serde_json::from_reader::<_, FileStatusesWrapper>(body_bs.reader())
-                        .map_err(|e| {
-                            Error::new(ErrorKind::Unexpected, "cannot parse returned json")
-                                .with_context("service", Scheme::Webhdfs)
-                                .set_source(e)
-                        })?
-                        .file_statuses
-                        .file_status;
+                let file_statuses = parse_file_statuses(body_bs)?;
 
                 let objects = DirStream::new(path, file_statuses);
                 Ok((RpList::default(), objects))
@@ -702,4 +798,224 @@ impl Accessor for WebhdfsBackend {
             _ => Err(parse_error(resp).await?),
         }
     }
+
+    fn blocking_create(&self, path: &str, args: OpCreate) -> Result<RpCreate> {
+        let path = normalize_create_path(path, &args);
+
+        let req = self.webhdfs_blocking_create_object_req(&path, Some(0), None, Body::Empty)?;
+
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+
+        match status {
+            StatusCode::CREATED | StatusCode::OK => {
+                if !path.ends_with('/') {
+                    // create file's http resp could be ignored
+                    resp.into_body().consume()?;
+                    return Ok(RpCreate::default());
+                }
+                let (parts, body) = resp.into_parts();
+                let bs = body.bytes()?;
+                let s = String::from_utf8_lossy(&bs);
+                self.consume_success_mkdir(&path, parts, &s)
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let range = args.range();
+        let resp = self.webhdfs_blocking_get_object(path, range)?;
+        match resp.status() {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                Ok((RpRead::with_metadata(meta), resp.into_body()))
+            }
+            StatusCode::NOT_FOUND => Err(Error::new(ErrorKind::ObjectNotFound, "object not found")
+                .with_context("service", Scheme::Webhdfs)),
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_write(
+        &self,
+        path: &str,
+        args: OpWrite,
+        r: input::BlockingReader,
+    ) -> Result<RpWrite> {
+        let req = self.webhdfs_blocking_create_object_req(
+            path,
+            Some(args.size()),
+            args.content_type(),
+            Body::Reader(r),
+        )?;
+        let resp = self.client.send(req)?;
+
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::CREATED => {
+                resp.into_body().consume()?;
+                Ok(RpWrite::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        // if root exists and is a directory, stat will be ok
+        if !self.root_checker.initialized() {
+            debug!("checking root existence");
+            self.blocking_check_root()?;
+            // Another thread could have initialized the checker, it's
+            // fine to ignore the error.
+            let _ = self.root_checker.set(());
+        }
+
+        let resp = self.webhdfs_blocking_status_object(path)?;
+        let status = resp.status();
+        match status {
+            StatusCode::OK => {
+                debug!("stat object: {} ok", path);
+                let meta = parse_into_object_metadata(path, resp.headers())?;
+                let body_bs = resp.into_body().bytes()?;
+
+                parse_file_status(meta, body_bs).map(RpStat::new)
+            }
+
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let resp = self.webhdfs_blocking_delete_object(path)?;
+        match resp.status() {
+            StatusCode::OK => {
+                resp.into_body().consume()?;
+                Ok(RpDelete::default())
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, _: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        let path = path.trim_end_matches('/');
+        let req = self.webhdfs_list_status_req(path)?.map(|_| Body::Empty);
+
+        let resp = self.client.send(req)?;
+        match resp.status() {
+            StatusCode::OK => {
+                let body_bs = resp.into_body().bytes()?;
+                let file_statuses = parse_file_statuses(body_bs)?;
+
+                let objects = DirStream::new(path, file_statuses);
+                Ok((RpList::default(), objects))
+            }
+            StatusCode::NOT_FOUND => {
+                let objects = DirStream::new(path, vec![]);
+                Ok((RpList::default(), objects))
+            }
+            _ => Err(parse_blocking_error(resp)?),
+        }
+    }
+}
+
+/// if the path ends with '/', it will be treated as a directory
+/// otherwise, it will be treated as a file
+fn normalize_create_path(path: &str, args: &OpCreate) -> String {
+    if args.mode().is_file() && path.ends_with('/') {
+        path.trim_end_matches('/').to_owned()
+    } else if args.mode().is_dir() && !path.ends_with('/') {
+        path.to_owned() + "/"
+    } else {
+        path.to_owned()
+    }
+}
+
+fn build_put_redirect_request<T>(
+    redirect: String,
+    size: Option<u64>,
+    content_type: Option<&str>,
+    body: T,
+) -> Result<Request<T>> {
+    let mut req = Request::put(redirect);
+    if let Some(size) = size {
+        req = req.header(CONTENT_LENGTH, size.to_string());
+    }
+    if let Some(content_type) = content_type {
+        req = req.header(CONTENT_TYPE, content_type);
+    }
+    req.body(body).map_err(new_request_build_error)
+}
+
+/// parse redirect destination from the body of redirection response
+fn parse_redirection(bs: Bytes) -> Result<String> {
+    let loc = serde_json::from_reader::<_, Redirection>(bs.reader())
+        .map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "redirection fail")
+                .with_context("service", Scheme::Webhdfs)
+                .set_permanent()
+                .set_source(e)
+        })?
+        .location;
+
+    Ok(loc)
+}
+
+/// check whether the returning root status is a directory
+fn check_root_status(bs: Bytes) -> Result<()> {
+    let file_status = serde_json::from_reader::<_, FileStatusWrapper>(bs.reader())
+        .map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "cannot parse returned json")
+                .with_context("service", Scheme::Webhdfs)
+                .set_source(e)
+        })?
+        .file_status;
+
+    match file_status.ty {
+        FileStatusType::File => {
+            error!("working directory is occupied!");
+            Err(
+                Error::new(ErrorKind::BackendConfigInvalid, "root is occupied!")
+                    .with_context("service", Scheme::Webhdfs),
+            )
+        }
+        FileStatusType::Directory => {
+            debug!("working directory exists, do nothing");
+            Ok(())
+        }
+    }
+}
+
+/// fill the metadata with file status returned by GETFILESTATUS
+fn parse_file_status(mut meta: ObjectMetadata, bs: Bytes) -> Result<ObjectMetadata> {
+    let file_status = serde_json::from_reader::<_, FileStatusWrapper>(bs.reader())
+        .map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "cannot parse returned json")
+                .with_context("service", Scheme::Webhdfs)
+                .set_source(e)
+        })?
+        .file_status;
+    debug!("file status: {:?}", file_status);
+    let status_meta: ObjectMetadata = file_status.try_into()?;
+
+    // is ok to unwrap here
+    // all metadata field of status meta is present and checked by `TryFrom`
+    meta.set_last_modified(status_meta.last_modified().unwrap())
+        .set_content_length(status_meta.content_length());
+    Ok(meta)
+}
+
+/// parse file statuses returned by LISTSTATUS
+fn parse_file_statuses(bs: Bytes) -> Result<Vec<FileStatus>> {
+    let file_statuses = serde_json::from_reader::<_, FileStatusesWrapper>(bs.reader())
+        .map_err(|e| {
+            Error::new(ErrorKind::Unexpected, "cannot parse returned json")
+                .with_context("service", Scheme::Webhdfs)
+                .set_source(e)
+        })?
+        .file_statuses
+        .file_status;
+
+    Ok(file_statuses)
 }
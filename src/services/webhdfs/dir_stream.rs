@@ -35,6 +35,18 @@ impl DirStream {
 #[async_trait]
 impl output::Page for DirStream {
     async fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        self.next_entries()
+    }
+}
+
+impl output::BlockingPage for DirStream {
+    fn next_page(&mut self) -> Result<Option<Vec<output::Entry>>> {
+        self.next_entries()
+    }
+}
+
+impl DirStream {
+    fn next_entries(&mut self) -> Result<Option<Vec<output::Entry>>> {
         if self.statuses.is_empty() {
             return Ok(None);
         }
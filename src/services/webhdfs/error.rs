@@ -43,6 +43,13 @@ pub(super) async fn parse_error(resp: Response<IncomingAsyncBody>) -> Result<Err
     parse_error_msg(parts, &s)
 }
 
+pub(super) fn parse_blocking_error(resp: Response<IncomingBody>) -> Result<Error> {
+    let (parts, body) = resp.into_parts();
+    let bs = body.bytes()?;
+    let s = String::from_utf8_lossy(&bs);
+    parse_error_msg(parts, &s)
+}
+
 fn parse_error_msg(parts: Parts, body: &str) -> Result<Error> {
     let (kind, retryable) = match parts.status {
         StatusCode::NOT_FOUND => (ErrorKind::ObjectNotFound, false),
@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use opendal::Operator;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::utils::*;
+
+/// Test services that meet the following capability:
+///
+/// - can_read
+/// - can_write
+/// - can_blocking
+/// - can_multipart
+macro_rules! behavior_blocking_multipart_test {
+    ($service:ident, $($(#[$meta:meta])* $test:ident),*,) => {
+        paste::item! {
+            mod [<services_ $service:lower _blocking_multipart>] {
+                $(
+                    #[test]
+                    $(
+                        #[$meta]
+                    )*
+                    fn [< $test >]() -> anyhow::Result<()> {
+                        let op = $crate::utils::init_service::<opendal::services::$service>(true);
+                        match op {
+                            Some(op) if op.metadata().can_read()
+                                && op.metadata().can_write()
+                                && op.metadata().can_blocking()
+                                && op.metadata().can_multipart() => $crate::blocking_multipart::$test(op),
+                            Some(_) => {
+                                log::warn!("service {} doesn't support blocking multipart, ignored", opendal::Scheme::$service);
+                                Ok(())
+                            },
+                            None => {
+                                log::warn!("service {} not initiated, ignored", opendal::Scheme::$service);
+                                Ok(())
+                            }
+                        }
+                    }
+                )*
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! behavior_blocking_multipart_tests {
+     ($($service:ident),*) => {
+        $(
+            behavior_blocking_multipart_test!(
+                $service,
+
+                test_blocking_multipart_complete,
+                test_blocking_multipart_abort,
+            );
+        )*
+    };
+}
+
+// Blocking multipart complete should succeed.
+pub fn test_blocking_multipart_complete(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    // Create multipart
+    let mp = op.object(&path).blocking_create_multipart()?;
+
+    // Upload first part
+    let mut p1_content = gen_fixed_bytes(5 * 1024 * 1024);
+    let p1 = mp.blocking_write(1, p1_content.clone())?;
+
+    // Upload second part
+    let mut p2_content = gen_fixed_bytes(5 * 1024 * 1024);
+    let p2 = mp.blocking_write(2, p2_content.clone())?;
+
+    // Complete
+    let o = mp.blocking_complete(vec![p1, p2])?;
+
+    let meta = o.blocking_metadata()?;
+
+    assert_eq!(10 * 1024 * 1024, meta.content_length(), "complete size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(o.blocking_read()?)),
+        format!(
+            "{:x}",
+            Sha256::digest({
+                let mut bs = Vec::with_capacity(10 * 1024 * 1024);
+                bs.append(&mut p1_content);
+                bs.append(&mut p2_content);
+                bs
+            })
+        ),
+        "complete content"
+    );
+
+    op.object(&path)
+        .blocking_delete()
+        .expect("delete must succeed");
+    Ok(())
+}
+
+// Blocking multipart abort should succeed.
+pub fn test_blocking_multipart_abort(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    // Create multipart
+    let mp = op.object(&path).blocking_create_multipart()?;
+
+    // Upload first part
+    let p1_content = gen_fixed_bytes(5 * 1024 * 1024);
+    let _ = mp.blocking_write(1, p1_content)?;
+
+    // Upload second part
+    let p2_content = gen_fixed_bytes(5 * 1024 * 1024);
+    let _ = mp.blocking_write(2, p2_content)?;
+
+    // Abort
+    mp.blocking_abort()?;
+    Ok(())
+}
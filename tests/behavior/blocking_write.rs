@@ -14,6 +14,7 @@
 
 use std::io::Read;
 use std::io::Seek;
+use std::io::Write;
 
 use anyhow::Result;
 use log::debug;
@@ -76,6 +77,7 @@ macro_rules! behavior_blocking_write_tests {
                 test_write,
                 test_write_with_dir_path,
                 test_write_with_special_chars,
+                test_writer,
                 test_stat,
                 test_stat_dir,
                 test_stat_with_special_chars,
@@ -83,6 +85,7 @@ macro_rules! behavior_blocking_write_tests {
                 test_read_full,
                 test_read_range,
                 test_read_large_range,
+                test_read_ranges,
                 test_read_not_exist,
                 test_fuzz_range_reader,
                 test_fuzz_offset_reader,
@@ -206,6 +209,33 @@ pub fn test_write(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write file with blocking writer should succeed.
+pub fn test_writer(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let content = gen_fixed_bytes(10 * 1024 * 1024);
+
+    let mut w = op.object(&path).blocking_writer()?;
+    // Write in small chunks to make sure data has been buffered.
+    for chunk in content.chunks(1024 * 1024) {
+        w.write_all(chunk)?;
+    }
+    w.close()?;
+
+    let bs = op.object(&path).blocking_read()?;
+    assert_eq!(bs.len(), content.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.object(&path)
+        .blocking_delete()
+        .expect("delete must succeed");
+    Ok(())
+}
+
 /// Write file with dir path should return an error
 pub fn test_write_with_dir_path(op: Operator) -> Result<()> {
     let path = format!("{}/", uuid::Uuid::new_v4());
@@ -360,6 +390,39 @@ pub fn test_read_range(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read multiple ranges content should match.
+pub fn test_read_ranges(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes();
+    let (offset, length) = gen_offset_length(size);
+    let size = size as u64;
+
+    op.object(&path)
+        .blocking_write(content.clone())
+        .expect("write must succeed");
+
+    let ranges = vec![offset..offset + length, 0..1, size - 1..size + 1024];
+    let bs = op.object(&path).blocking_read_ranges(ranges)?;
+    assert_eq!(bs.len(), 3, "read ranges count");
+    assert_eq!(
+        bs[0],
+        content[offset as usize..(offset + length) as usize],
+        "read content of first range"
+    );
+    assert_eq!(bs[1], content[..1], "read content of second range");
+    assert_eq!(
+        bs[2],
+        content[size as usize - 1..],
+        "read content of range beyond end"
+    );
+
+    op.object(&path)
+        .blocking_delete()
+        .expect("delete must succeed");
+    Ok(())
+}
+
 /// Read large range content should match.
 pub fn test_read_large_range(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
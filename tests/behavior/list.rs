@@ -79,6 +79,9 @@ macro_rules! behavior_list_tests {
                 test_walk_top_down_within_empty_dir,
                 test_walk_bottom_up,
                 test_remove_all,
+                test_remove_all_with_limit,
+                test_glob,
+                test_glob_recursive,
             );
         )*
     };
@@ -436,3 +439,99 @@ pub async fn test_remove_all(op: Operator) -> Result<()> {
     }
     Ok(())
 }
+
+// Remove all should remove all in this path even if they are split into
+// multiple batches.
+pub async fn test_remove_all_with_limit(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    let expected: Vec<String> = ["", "y", "x/", "x/y", "x/x/", "x/x/y", "z"]
+        .iter()
+        .map(|v| format!("{parent}/{v}"))
+        .collect();
+    for path in expected.iter() {
+        op.object(path).create().await?;
+    }
+
+    op.batch()
+        .with_limit(2)
+        .with_concurrent(2)
+        .remove_all(&format!("{parent}/"))
+        .await?;
+
+    for path in expected.iter() {
+        if path.ends_with('/') {
+            continue;
+        }
+        assert!(
+            !op.object(path).is_exist().await?,
+            "{path} should be removed"
+        )
+    }
+    Ok(())
+}
+
+// Glob should only list matched paths.
+pub async fn test_glob(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    for path in ["a.csv", "b.csv", "c.json", "d/e.csv"] {
+        op.object(&format!("{parent}/{path}")).create().await?;
+    }
+
+    let mut actual = op
+        .glob(&format!("{parent}/*.csv"))?
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    actual.sort_unstable();
+
+    assert_eq!(
+        actual,
+        vec![format!("{parent}/a.csv"), format!("{parent}/b.csv")]
+    );
+
+    op.batch().remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
+
+// Glob with `**` should list matched paths in all nested dirs.
+pub async fn test_glob_recursive(op: Operator) -> Result<()> {
+    let parent = uuid::Uuid::new_v4().to_string();
+
+    for path in ["x.csv", "a/x.csv", "a/b/x.csv", "a/b/x.json", "c/d/"] {
+        op.object(&format!("{parent}/{path}")).create().await?;
+    }
+
+    let mut actual = op
+        .glob(&format!("{parent}/**/*.csv"))?
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    actual.sort_unstable();
+
+    assert_eq!(
+        actual,
+        vec![
+            format!("{parent}/a/b/x.csv"),
+            format!("{parent}/a/x.csv"),
+            format!("{parent}/x.csv"),
+        ]
+    );
+
+    let actual = op
+        .glob(&format!("{parent}/*/"))?
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .map(|v| v.path().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(actual.len(), 2, "glob dirs: {actual:?}");
+
+    op.batch().remove_all(&format!("{parent}/")).await?;
+    Ok(())
+}
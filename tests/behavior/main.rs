@@ -17,6 +17,8 @@ mod base;
 #[macro_use]
 mod blocking_list;
 #[macro_use]
+mod blocking_multipart;
+#[macro_use]
 mod blocking_read;
 #[macro_use]
 mod blocking_write;
@@ -59,6 +61,8 @@ macro_rules! behavior_tests {
             behavior_blocking_list_tests!($service);
             // can_read && can_write && can_multipart
             behavior_multipart_tests!($service);
+            // can_read && can_write && can_blocking && can_multipart
+            behavior_blocking_multipart_tests!($service);
             // can_read && can_write && can_multipart && can_presign
             behavior_multipart_presign_tests!($service);
             // can_list && !can_write
@@ -67,8 +71,11 @@ macro_rules! behavior_tests {
     };
 }
 
+cfg_if::cfg_if! { if #[cfg(feature = "services-archive")] { behavior_tests!(Archive); }}
 behavior_tests!(Azblob);
 behavior_tests!(Azdfs);
+cfg_if::cfg_if! { if #[cfg(feature = "services-cacache")] { behavior_tests!(Cacache); }}
+cfg_if::cfg_if! { if #[cfg(feature = "services-etcd")] { behavior_tests!(Etcd); }}
 behavior_tests!(Fs);
 cfg_if::cfg_if! { if #[cfg(feature = "services-ftp")] { behavior_tests!(Ftp); }}
 cfg_if::cfg_if! { if #[cfg(feature = "services-memcached")] { behavior_tests!(Memcached); }}
@@ -76,15 +83,22 @@ behavior_tests!(Memory);
 cfg_if::cfg_if! { if #[cfg(feature = "services-moka")] { behavior_tests!(Moka); }}
 behavior_tests!(Gcs);
 behavior_tests!(Ghac);
+cfg_if::cfg_if! { if #[cfg(feature = "services-git")] { behavior_tests!(Git); }}
+cfg_if::cfg_if! { if #[cfg(feature = "services-gridfs")] { behavior_tests!(Gridfs); }}
 cfg_if::cfg_if! { if #[cfg(feature = "services-ipfs")] { behavior_tests!(Ipfs); }}
 behavior_tests!(Ipmfs);
 cfg_if::cfg_if! { if #[cfg(feature = "services-hdfs")] { behavior_tests!(Hdfs); }}
 cfg_if::cfg_if! { if #[cfg(feature = "services-http")] { behavior_tests!(Http); }}
+cfg_if::cfg_if! { if #[cfg(feature = "services-mysql")] { behavior_tests!(Mysql); }}
 behavior_tests!(Obs);
+cfg_if::cfg_if! { if #[cfg(feature = "services-redb")] { behavior_tests!(Redb); }}
 cfg_if::cfg_if! { if #[cfg(feature = "services-redis")] { behavior_tests!(Redis); }}
 cfg_if::cfg_if! { if #[cfg(feature = "services-rocksdb")] { behavior_tests!(Rocksdb); }}
 behavior_tests!(Oss);
+cfg_if::cfg_if! { if #[cfg(feature = "services-postgresql")] { behavior_tests!(Postgresql); }}
 behavior_tests!(S3);
 cfg_if::cfg_if! { if #[cfg(feature = "services-sled")] { behavior_tests!(Sled); }}
+cfg_if::cfg_if! { if #[cfg(feature = "services-sqlite")] { behavior_tests!(Sqlite); }}
+behavior_tests!(Swift);
 behavior_tests!(Webdav);
 behavior_tests!(Webhdfs);
@@ -17,6 +17,7 @@ use std::str::FromStr;
 use anyhow::Result;
 use http::header;
 use log::debug;
+use opendal::ops::OpPresignPost;
 use opendal::raw;
 use opendal::Operator;
 use reqwest::Url;
@@ -70,6 +71,7 @@ macro_rules! behavior_presign_tests {
                 test_presign_write,
                 test_presign_read,
                 test_presign_stat,
+                test_presign_post,
             );
         )*
     };
@@ -187,3 +189,75 @@ pub async fn test_presign_read(op: Operator) -> Result<()> {
         .expect("delete must succeed");
     Ok(())
 }
+
+/// Presign post should upload content via form and respect the policy.
+pub async fn test_presign_post(op: Operator) -> Result<()> {
+    if !op.metadata().can_presign_post() {
+        log::warn!(
+            "service {} doesn't support presign post, ignored",
+            op.metadata().scheme()
+        );
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes();
+
+    let signed_req = op.object(&path).presign_post(
+        OpPresignPost::new()
+            .with_content_type("application/octet-stream")
+            .with_content_length_range(1, size as u64),
+        Duration::hours(1),
+    )?;
+    debug!("Generated request: {signed_req:?}");
+
+    let client = reqwest::Client::new();
+    let url = Url::from_str(&signed_req.uri().to_string()).expect("must be valid url");
+
+    // Content larger than the allowed range should be rejected.
+    let mut form = reqwest::multipart::Form::new();
+    for (k, v) in signed_req.form_fields() {
+        form = form.text(k.clone(), v.clone());
+    }
+    let mut too_large = content.clone();
+    too_large.push(0);
+    form = form.part("file", reqwest::multipart::Part::bytes(too_large));
+    let resp = client
+        .post(url.clone())
+        .multipart(form)
+        .send()
+        .await
+        .expect("send request must succeed");
+    assert!(
+        !resp.status().is_success(),
+        "too large content must be rejected"
+    );
+
+    let mut form = reqwest::multipart::Form::new();
+    for (k, v) in signed_req.form_fields() {
+        form = form.text(k.clone(), v.clone());
+    }
+    form = form.part("file", reqwest::multipart::Part::bytes(content.clone()));
+    let resp = client
+        .post(url)
+        .multipart(form)
+        .send()
+        .await
+        .expect("send request must succeed");
+    assert!(resp.status().is_success(), "post status: {}", resp.status());
+
+    let bs = op.object(&path).read().await.expect("read must succeed");
+    assert_eq!(size, bs.len(), "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
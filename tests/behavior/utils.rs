@@ -30,7 +30,7 @@ use sha2::Sha256;
 ///
 /// - If `opendal_{schema}_test` is on, construct a new Operator with given root.
 /// - Else, returns a `None` to represent no valid config for operator.
-pub fn init_service<B: Builder>(random_root: bool) -> Option<Operator> {
+pub fn init_service<B: Builder + 'static>(random_root: bool) -> Option<Operator> {
     let _ = env_logger::builder().is_test(true).try_init();
     let _ = dotenvy::dotenv();
 
@@ -59,7 +59,21 @@ pub fn init_service<B: Builder>(random_root: bool) -> Option<Operator> {
         cfg.insert("root".to_string(), root);
     }
 
-    let op = Operator::from_map::<B>(cfg).expect("must succeed");
+    #[allow(unused_mut)]
+    let mut builder = B::from_map(cfg.clone());
+
+    // Archive reads from another operator which can't be set via map,
+    // use fs at `opendal_archive_fs_root` to store the archive.
+    #[cfg(feature = "services-archive")]
+    if let Some(b) =
+        (&mut builder as &mut dyn std::any::Any).downcast_mut::<opendal::services::Archive>()
+    {
+        let mut fs = opendal::services::Fs::default();
+        fs.root(&cfg.get("fs_root").cloned().unwrap_or_default());
+        b.operator(Operator::create(fs).expect("must succeed").finish());
+    }
+
+    let op = Operator::create(builder).expect("must succeed");
 
     #[cfg(feature = "layers-chaos")]
     let op = {
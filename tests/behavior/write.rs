@@ -12,15 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::SeekFrom;
+
 use anyhow::Result;
 use futures::AsyncReadExt;
 use futures::AsyncSeekExt;
 use futures::StreamExt;
 use log::debug;
 use log::warn;
+use opendal::ops::ChecksumAlgorithm;
+use opendal::ops::OpPrefetch;
+use opendal::ops::OpReadRanges;
+use opendal::ops::OpWrite;
 use opendal::ErrorKind;
 use opendal::ObjectMode;
 use opendal::Operator;
+use opendal::Scheme;
 use sha2::Digest;
 use sha2::Sha256;
 
@@ -74,6 +81,10 @@ macro_rules! behavior_write_tests {
                 test_write,
                 test_write_with_dir_path,
                 test_write_with_special_chars,
+                test_write_with_checksum,
+                test_create_file_over_checksum,
+                test_write_with_mismatched_checksum,
+                test_read_with_corrupted_content,
                 test_stat,
                 test_stat_dir,
                 test_stat_with_special_chars,
@@ -83,6 +94,9 @@ macro_rules! behavior_write_tests {
                 test_read_full,
                 test_read_range,
                 test_read_large_range,
+                test_read_ranges,
+                test_prefetch_reader,
+                test_download,
                 test_reader_range,
                 test_reader_from,
                 test_reader_tail,
@@ -256,6 +270,167 @@ pub async fn test_write_with_special_chars(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a single file with checksum should succeed and read should pass
+/// the verification.
+pub async fn test_write_with_checksum(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.object(&path)
+        .write_with(
+            OpWrite::new(size as u64).with_checksum_algorithm(ChecksumAlgorithm::Md5),
+            content.clone(),
+        )
+        .await?;
+
+    let bs = op.object(&path).read().await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
+/// Create a file over the one written with checksum should clear the
+/// checksum, and read should return empty content.
+pub async fn test_create_file_over_checksum(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    let algorithm = ChecksumAlgorithm::Sha256;
+    op.object(&path)
+        .write_with(
+            OpWrite::new(size as u64).with_checksum(algorithm, &algorithm.checksum(&content)),
+            content,
+        )
+        .await?;
+
+    op.object(&path).create().await?;
+
+    let bs = op.object(&path).read().await?;
+    assert!(bs.is_empty(), "read content must be empty");
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
+/// Write with mismatched checksum should return `ChecksumMismatch` and
+/// keep the existing file untouched.
+///
+/// Only fs is tested, because other services may not verify checksum.
+pub async fn test_write_with_mismatched_checksum(op: Operator) -> Result<()> {
+    if op.metadata().scheme() != Scheme::Fs {
+        warn!(
+            "service {} may not verify checksum, ignored",
+            op.metadata().scheme()
+        );
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    op.object(&path).write(content.clone()).await?;
+
+    let algorithm = ChecksumAlgorithm::Sha256;
+    let err = op
+        .object(&path)
+        .write_with(
+            OpWrite::new(size as u64).with_checksum(algorithm, &algorithm.checksum(b"mismatch")),
+            content.clone(),
+        )
+        .await
+        .expect_err("write must fail");
+    assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+
+    let bs = op.object(&path).read().await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
+/// Read a file whose content is corrupted after written with checksum
+/// should return `ChecksumMismatch`.
+///
+/// Only fs is tested, because we need to corrupt the content behind
+/// OpenDAL.
+pub async fn test_read_with_corrupted_content(op: Operator) -> Result<()> {
+    if op.metadata().scheme() != Scheme::Fs {
+        warn!(
+            "service {} can't be corrupted in test, ignored",
+            op.metadata().scheme()
+        );
+        return Ok(());
+    }
+
+    let path = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes();
+
+    let algorithm = ChecksumAlgorithm::Sha256;
+    op.object(&path)
+        .write_with(
+            OpWrite::new(size as u64).with_checksum(algorithm, &algorithm.checksum(&content)),
+            content.clone(),
+        )
+        .await?;
+
+    let meta = op.object(&path).metadata().await?;
+    if meta.checksum().is_none() {
+        warn!("checksum is not stored by underlying filesystem, ignored");
+        op.object(&path).delete().await?;
+        return Ok(());
+    }
+
+    // Flip the first byte without changing the size of file.
+    let mut corrupted = content.clone();
+    corrupted[0] ^= 0xff;
+    {
+        use std::io::Write;
+
+        let abs_path = format!("{}{}", op.metadata().root(), path);
+        let mut f = std::fs::OpenOptions::new().write(true).open(abs_path)?;
+        f.write_all(&corrupted)?;
+    }
+
+    let err = op.object(&path).read().await.expect_err("read must fail");
+    assert_eq!(err.kind(), ErrorKind::ChecksumMismatch);
+
+    let mut r = op.object(&path).reader().await?;
+    let mut bs = Vec::new();
+    let err = r
+        .read_to_end(&mut bs)
+        .await
+        .expect_err("read from reader must fail");
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    // Range read can't be verified and should succeed.
+    let bs = op.object(&path).range_read(1..).await?;
+    assert_eq!(bs, &corrupted[1..]);
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
 /// Stat existing file should return metadata
 pub async fn test_stat(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();
@@ -414,6 +589,115 @@ pub async fn test_read_range(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Read multiple ranges content should match.
+pub async fn test_read_ranges(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes();
+    let (offset, length) = gen_offset_length(size);
+    let size = size as u64;
+
+    op.object(&path)
+        .write(content.clone())
+        .await
+        .expect("write must succeed");
+
+    let ranges = vec![offset..offset + length, 0..1, size - 1..size + 1024];
+    // Disable coalescing to make sure all ranges are fetched separately.
+    for args in [
+        OpReadRanges::new(ranges.clone()),
+        OpReadRanges::new(ranges.clone()).with_gap(0),
+    ] {
+        let bs = op.object(&path).read_ranges_with(args).await?;
+        assert_eq!(bs.len(), 3, "read ranges count");
+        assert_eq!(
+            bs[0],
+            content[offset as usize..(offset + length) as usize],
+            "read content of first range"
+        );
+        assert_eq!(bs[1], content[..1], "read content of second range");
+        assert_eq!(
+            bs[2],
+            content[size as usize - 1..],
+            "read content of range beyond end"
+        );
+    }
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
+/// Read with prefetch reader should match.
+pub async fn test_prefetch_reader(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes();
+    let (offset, _) = gen_offset_length(size);
+
+    op.object(&path)
+        .write(content.clone())
+        .await
+        .expect("write must succeed");
+
+    let mut r = op
+        .object(&path)
+        .prefetch_reader_with(OpPrefetch::new().with_chunk_size(256 * 1024))
+        .await?;
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "read content"
+    );
+
+    r.seek(SeekFrom::Start(offset)).await?;
+    let mut bs = Vec::new();
+    r.read_to_end(&mut bs).await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content[offset as usize..])),
+        "read content after seek"
+    );
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
+/// Download content should match.
+pub async fn test_download(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, _) = gen_bytes();
+
+    op.object(&path)
+        .write(content.clone())
+        .await
+        .expect("write must succeed");
+
+    let bs = op
+        .object(&path)
+        .download(OpPrefetch::new().with_chunk_size(256 * 1024))
+        .await?;
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs)),
+        format!("{:x}", Sha256::digest(&content)),
+        "download content"
+    );
+
+    op.object(&path)
+        .delete()
+        .await
+        .expect("delete must succeed");
+    Ok(())
+}
+
 /// Read large range content should match.
 pub async fn test_read_large_range(op: Operator) -> Result<()> {
     let path = uuid::Uuid::new_v4().to_string();